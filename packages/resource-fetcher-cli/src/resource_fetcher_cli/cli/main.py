@@ -1,16 +1,84 @@
 """Music Album Downloader - Professional CLI Tool."""
 
 import argparse
+import errno
+import json
 import logging
+import math
+import os
+import platform
+import queue
+import re
+import shlex
+import subprocess
 import sys
+import tempfile
+import threading
 import time
+from collections.abc import Callable, Iterator
+from concurrent.futures import Future, ThreadPoolExecutor, as_completed
+from itertools import chain
 from pathlib import Path
 from typing import Any
 
 import requests
 
-from resource_fetcher_core.adapters.registry import get_adapter
-from resource_fetcher_core.core.models import DownloadResult, DownloadStatus
+from resource_fetcher_core.adapters.custom import load_source_definition
+from resource_fetcher_core.adapters.registry import (
+    describe_sources,
+    get_adapter,
+    register_adapter,
+)
+from resource_fetcher_core.core.models import (
+    DownloadResult,
+    DownloadStatus,
+    PolitenessPolicy,
+    Song,
+)
+from resource_fetcher_core.utils.audio import (
+    MERGE_FORMATS,
+    format_duration,
+    is_silent,
+    merge_chapters,
+    normalize_loudness,
+    probe_duration,
+)
+from resource_fetcher_core.utils.content_type import check_content_type
+from resource_fetcher_core.utils.http import (
+    ILLEGAL_FILENAME_CHARS,
+    detect_rate_ban,
+    extract_filename_from_headers,
+    load_cookies,
+    sanitize_filename,
+)
+from resource_fetcher_core.utils.manifest import (
+    build_manifest,
+    file_checksum,
+    merge_manifests,
+    read_manifest,
+    update_track,
+    verify_manifest,
+    write_checksum_sidecars,
+    write_manifest,
+)
+from resource_fetcher_core.utils.paths import (
+    MAX_PATH,
+    exceeds_max_path,
+    extended_path,
+    unique_filenames,
+)
+from resource_fetcher_core.utils.storage import (
+    is_network_path,
+    move_staged_file,
+    path_available,
+    wait_for_path,
+)
+from resource_fetcher_core.utils.title_filter import (
+    compile_title_pattern,
+    match_blacklist,
+    title_selected,
+)
+from resource_fetcher_core.utils.track_range import parse_track_range, track_in_range
 
 # Configure logging
 logging.basicConfig(
@@ -20,6 +88,353 @@ logging.basicConfig(
 )
 logger = logging.getLogger(__name__)
 
+# Accepted values for --log-level
+LOG_LEVELS = ("debug", "info", "warning", "error")
+
+# Seconds to wait for a disconnected network share before giving up
+MOUNT_WAIT_TIMEOUT = 300
+
+# Numbers of parallel connections compared by --benchmark
+BENCHMARK_STREAMS = (1, 2, 4)
+
+# Seconds each --benchmark measurement downloads for
+BENCHMARK_DURATION = 5.0
+
+# Number of downloaded files verified, normalized and checksummed at once
+POST_PROCESS_WORKERS = 2
+
+# Start of the line asking the user for input, followed by a JSON object
+INTERACTION_PREFIX = "交互 (Interaction): "
+
+# Interaction kind asking whether to download a track (--confirm-tracks)
+CONFIRM_TRACK = "confirm-track"
+
+# Line on stdin changing the speed limit of a running download, followed by
+# the new limit in KB/s (0 for none). It is never taken as an answer.
+SPEED_LIMIT_COMMAND = "speed-limit"
+
+# Seconds --confirm-tracks waits for an answer by default
+CONFIRM_TIMEOUT = 60
+
+# Answers accepting or declining a track, optionally after its number ("3 yes")
+CONFIRM_ANSWERS = {"y": True, "yes": True, "n": False, "no": False}
+
+# Times a page is fetched again after the user passed a challenge
+MAX_CHALLENGES = 3
+
+# Name prefix of the files staged in the temp folder, so the GUI's cleanup
+# only removes files the CLI wrote
+STAGED_FILE_PREFIX = "resource-fetcher-staged-"
+
+# Folder where downloads wait until the --scan-command has checked them
+QUARANTINE_DIR = Path(tempfile.gettempdir()) / "resource-fetcher-quarantine"
+
+# Seconds the --scan-command may take for one file
+SCAN_TIMEOUT = 300
+
+# Start of the message of downloads rejected by the --scan-command
+SCAN_FAILED_PREFIX = "Scan failed: "
+
+# Start of the message of downloads the server refused for too many
+# requests, followed by the suggested cooldown in seconds
+RATE_LIMITED_PREFIX = "Rate limited, cooldown: "
+
+# Bytes of the next song fetched while the current one is finished
+PREFETCH_SIZE = 1024 * 1024
+
+# Size of the chunks songs are read in
+CHUNK_SIZE = 8192
+
+# Start of the message of downloads whose bytes aren't the format their
+# extension promises, e.g. an HTML error page saved as .mp3
+WRONG_CONTENT_TYPE_PREFIX = "Wrong content type: "
+
+# Start of the message of downloads failing the --min-size or
+# --detect-silence sanity checks
+SANITY_FAILED_PREFIX = "Sanity check failed: "
+
+
+class RateLimiter:
+    """Keep the combined speed of downloads sharing the limiter below a limit."""
+
+    def __init__(self, max_speed: float | None):
+        """
+        Create a rate limiter.
+
+        Args:
+            max_speed: Speed limit in bytes per second, None for unlimited
+        """
+        self.max_speed = max_speed
+        self._start = time.monotonic()
+        self._received = 0
+        self._lock = threading.Lock()
+
+    def wait(self, size: int) -> None:
+        """
+        Account for received data and sleep until it is within the limit.
+
+        Args:
+            size: Number of bytes just received
+        """
+        with self._lock:
+            if self.max_speed is None:
+                return
+            now = time.monotonic()
+            # Time spent idle, e.g. between songs, isn't saved up for a burst
+            if now - self._start > self._received / self.max_speed + 1.0:
+                self._start, self._received = now, 0
+            self._received += size
+            delay = self._start + self._received / self.max_speed - now
+        if delay > 0:
+            time.sleep(delay)
+
+    def set_max_speed(self, max_speed: float | None) -> None:
+        """
+        Change the limit, e.g. when another window of the GUI's schedule begins.
+
+        Args:
+            max_speed: Speed limit in bytes per second, None for unlimited
+        """
+        with self._lock:
+            self.max_speed = max_speed
+            self._start, self._received = time.monotonic(), 0
+
+    def handle_command(self, argument: str) -> None:
+        """
+        Apply a SPEED_LIMIT_COMMAND read from stdin.
+
+        Args:
+            argument: New limit in KB/s, 0 for none
+        """
+        try:
+            speed = int(argument)
+        except ValueError:
+            speed = -1
+        if speed < 0:
+            logger.warning(f"Ignoring invalid speed limit: {argument!r}")
+            return
+        self.set_max_speed(speed * 1024 if speed else None)
+        limit = f"{speed} KB/s" if speed else "unlimited"
+        logger.info(f"Speed limit changed to {limit}")
+
+
+class FileScanner:
+    """Check downloaded files with a user-configured scanner, e.g. a virus scanner."""
+
+    def __init__(self, command: str, quarantine_dir: Path = QUARANTINE_DIR):
+        """
+        Create a file scanner.
+
+        Args:
+            command: Scanner command; the file path is appended as last argument.
+                Exit code 0 means the file is clean.
+            quarantine_dir: Folder files are downloaded to before they are scanned
+        """
+        self.command = shlex.split(command)
+        self.quarantine_dir = quarantine_dir
+
+    def quarantine_path(self, filename: str) -> Path:
+        """
+        Create a file in the quarantine folder to download into.
+
+        Args:
+            filename: Name of the file in the library
+
+        Returns:
+            Path of a new, empty file in the quarantine folder
+        """
+        self.quarantine_dir.mkdir(parents=True, exist_ok=True)
+        path = Path(filename)
+        fd, name = tempfile.mkstemp(
+            prefix=f"{path.stem}.", suffix=path.suffix, dir=self.quarantine_dir
+        )
+        os.close(fd)
+        return Path(name)
+
+    def scan(self, path: Path) -> str | None:
+        """
+        Run the scanner on a file.
+
+        Args:
+            path: File to scan
+
+        Returns:
+            None if the file is clean, otherwise why it was rejected
+        """
+        try:
+            result = subprocess.run(
+                [*self.command, str(path)],
+                stdout=subprocess.PIPE,
+                stderr=subprocess.STDOUT,
+                text=True,
+                errors="replace",
+                timeout=SCAN_TIMEOUT,
+            )
+        except (OSError, subprocess.TimeoutExpired) as e:
+            return f"scanner could not run: {e}"
+        if result.returncode == 0:
+            return None
+        lines = result.stdout.strip().splitlines()
+        return lines[-1] if lines else f"exit code {result.returncode}"
+
+
+def create_scanner(scan_command: str | None, temp_dir: Path | None) -> FileScanner | None:
+    """
+    Create the scanner for a --scan-command.
+
+    Args:
+        scan_command: Scanner command, or None to not scan
+        temp_dir: Optional local folder to keep the quarantine in
+
+    Returns:
+        Scanner, or None if no command is set
+    """
+    if not scan_command:
+        return None
+    return FileScanner(scan_command, temp_dir / QUARANTINE_DIR.name if temp_dir else QUARANTINE_DIR)
+
+
+class PrefetchedResponse:
+    """A song request opened ahead of time, with the first bytes already read."""
+
+    def __init__(self, url: str, response: requests.Response):
+        """
+        Create a prefetched response.
+
+        Args:
+            url: Requested audio file URL
+            response: Streamed response of the request
+        """
+        self.url = url
+        self.response = response
+        self.head: list[bytes] = []
+        self._chunks: Iterator[bytes] | None = None
+
+    def read_head(self, size: int) -> None:
+        """
+        Read the first bytes of a successful response into memory.
+
+        Args:
+            size: Number of bytes to read, at least
+        """
+        if not self.response.ok:
+            return
+        self._chunks = iter(self.response.iter_content(chunk_size=CHUNK_SIZE))
+        received = 0
+        for chunk in self._chunks:
+            self.head.append(chunk)
+            received += len(chunk)
+            if received >= size:
+                break
+
+    def iter_content(self) -> Iterator[bytes]:
+        """
+        Iterate over the whole body: the bytes read ahead, then the rest.
+
+        Returns:
+            Chunks of the body
+        """
+        if self._chunks is None:
+            return self.response.iter_content(chunk_size=CHUNK_SIZE)
+        return chain(self.head, self._chunks)
+
+    @property
+    def head_size(self) -> int:
+        """Number of bytes read ahead."""
+        return sum(len(chunk) for chunk in self.head)
+
+    def close(self) -> None:
+        """Release the connection of a response that won't be used."""
+        self.response.close()
+
+
+class Prefetcher:
+    """
+    Open the next song's request while the current song is finished.
+
+    Connection setup and the first PREFETCH_SIZE bytes of the next song
+    overlap with checking and reporting the current one, so short songs
+    downloaded one after another aren't dominated by per-request latency.
+    At most one song is prefetched at a time.
+    """
+
+    def __init__(self, timeout: int = 60, cookies: Any | None = None, size: int = PREFETCH_SIZE):
+        """
+        Create a prefetcher.
+
+        Args:
+            timeout: Request timeout in seconds
+            cookies: Optional cookies to send, as for download_song
+            size: Number of bytes to read ahead
+        """
+        self.timeout = timeout
+        self.cookies = cookies
+        self.size = size
+        self._url: str | None = None
+        self._thread: threading.Thread | None = None
+        self._result: PrefetchedResponse | None = None
+
+    def _fetch(self, url: str) -> None:
+        try:
+            response = requests.get(url, stream=True, timeout=self.timeout, cookies=self.cookies)
+            prefetched = PrefetchedResponse(url, response)
+            prefetched.read_head(self.size)
+            self._result = prefetched
+        except requests.exceptions.RequestException as e:
+            # The download requests the song again and handles the error
+            logger.debug(f"Prefetch failed for {url}: {e}")
+
+    def start(self, url: str) -> int:
+        """
+        Start fetching a song in the background, dropping an unused earlier one.
+
+        Args:
+            url: Audio file URL
+
+        Returns:
+            Number of bytes the dropped prefetch had read, as for close
+        """
+        dropped = self.close()
+        logger.debug(f"Prefetching {url}")
+        self._url = url
+        self._thread = threading.Thread(target=self._fetch, args=(url,), daemon=True)
+        self._thread.start()
+        return dropped
+
+    def take(self, url: str) -> PrefetchedResponse | None:
+        """
+        Get the prefetched response of a song, waiting for the prefetch to finish.
+
+        Args:
+            url: Audio file URL about to be downloaded
+
+        Returns:
+            The response, or None if the song wasn't prefetched or the prefetch failed
+        """
+        if self._url != url or self._thread is None:
+            return None
+        self._thread.join()
+        result, self._result = self._result, None
+        self._url, self._thread = None, None
+        return result
+
+    def close(self) -> int:
+        """
+        Drop a prefetched response that wasn't taken.
+
+        Returns:
+            Number of bytes the dropped prefetch had read, 0 if there was none
+        """
+        if self._thread is None:
+            return 0
+        self._thread.join()
+        dropped = 0
+        if self._result:
+            dropped = self._result.head_size
+            self._result.close()
+        self._url, self._thread, self._result = None, None, None
+        return dropped
+
 
 class DownloadProgress:
     """Track and display download progress."""
@@ -29,12 +444,16 @@ class DownloadProgress:
         self.success = 0
         self.failed = 0
         self.skipped = 0
+        self.downloaded_bytes = 0
+        self.transferred_bytes = 0
         self.start_time = time.time()
 
     def update(self, result: DownloadResult) -> None:
         """Update progress with download result."""
+        self.transferred_bytes += result.transferred
         if result.status == DownloadStatus.SUCCESS:
             self.success += 1
+            self.downloaded_bytes += result.size
         elif result.status == DownloadStatus.FAILED:
             self.failed += 1
         else:
@@ -53,6 +472,8 @@ class DownloadProgress:
             f"  失败 (Failed): {self.failed}",
             f"  跳过 (Skipped): {self.skipped}",
             f"  总计 (Total): {self.total}",
+            f"  数据量 (Data): {self.downloaded_bytes} 字节 (bytes)",
+            f"  传输量 (Transferred): {self.transferred_bytes} 字节 (bytes)",
             f"  耗时 (Time): {elapsed:.1f} 秒",
             f"  速度 (Speed): {speed:.2f} 首/秒" if speed > 0 else "  速度 (Speed): N/A",
             "=" * 60,
@@ -69,6 +490,14 @@ def download_song(
     retries: int = 3,
     overwrite: bool = False,
     progress_callback: Any | None = None,
+    filename: str | None = None,
+    replacement: str = "_",
+    max_name_length: int | None = None,
+    cookies: Any | None = None,
+    rate_limiter: RateLimiter | None = None,
+    scanner: FileScanner | None = None,
+    temp_dir: Path | None = None,
+    prefetched: PrefetchedResponse | None = None,
 ) -> DownloadResult:
     """
     Download a single song with retry logic.
@@ -82,46 +511,93 @@ def download_song(
         retries: Number of retry attempts
         overwrite: Whether to overwrite existing files
         progress_callback: Optional callback for progress updates
+        filename: Optional file name to use instead of the title or header name
+        replacement: Text replacing characters not allowed in file names
+        max_name_length: Optional maximum file name length in characters
+        cookies: Optional cookies to send, e.g. a jar from load_cookies
+        rate_limiter: Optional limiter the download speed is kept below
+        scanner: Optional scanner the file has to pass before it is moved from
+            quarantine into the output folder
+        temp_dir: Optional local folder the file is written to before it is
+            moved into the output folder
+        prefetched: Optional response of the song opened ahead of time, used
+            instead of requesting the song for the first attempt
 
     Returns:
         DownloadResult with status and metadata
     """
-    output_dir = Path(output_dir)
+    network = is_network_path(Path(output_dir))
+    # Allow deep output folders and long titles on Windows
+    output_dir = extended_path(Path(output_dir))
     output_dir.mkdir(parents=True, exist_ok=True)
+    if temp_dir:
+        temp_dir.mkdir(parents=True, exist_ok=True)
 
+    # Bytes received by all attempts, including files that were discarded
+    transferred = 0
     for attempt in range(retries):
+        # Temporary file of this attempt, removed if the attempt fails
+        staged: Path | None = None
         try:
             logger.debug(f"Attempting download: {url} (attempt {attempt + 1}/{retries})")
-            response = requests.get(url, stream=True, timeout=timeout)
+            # Bytes of this attempt counted before they are written
+            counted = transferred
+            if prefetched:
+                response, chunks = prefetched.response, prefetched.iter_content()
+                # Counted now, so a skipped or failed song still reports them
+                transferred += prefetched.head_size
+                prefetched = None
+            else:
+                response = requests.get(url, stream=True, timeout=timeout, cookies=cookies)
+                chunks = response.iter_content(chunk_size=CHUNK_SIZE)
             response.raise_for_status()
 
             # Get filename from headers or use title
-            from resource_fetcher_core.utils.http import (
-                extract_filename_from_headers,
-                sanitize_filename,
-            )
-
-            filename = extract_filename_from_headers(dict(response.headers), song_id, song_title)
-            filename = sanitize_filename(filename)
+            if not filename:
+                filename = extract_filename_from_headers(
+                    dict(response.headers), song_id, song_title, replacement
+                )
+                filename = sanitize_filename(filename, replacement, max_name_length)
             output_path = output_dir / filename
 
             # Check if file exists
             if output_path.exists() and not overwrite:
                 logger.info(f"File exists, skipping: {filename}")
+                response.close()
                 return DownloadResult(
-                    status=DownloadStatus.SKIPPED, path=output_path, message="File already exists"
+                    status=DownloadStatus.SKIPPED,
+                    path=output_path,
+                    message="File already exists",
+                    transferred=transferred,
                 )
 
+            # Files for network shares (or with a temp folder) are downloaded
+            # locally and moved when complete
+            target_path = output_path
+            if scanner:
+                # Only a file the scanner rejected is kept in quarantine
+                staged = target_path = scanner.quarantine_path(filename)
+            elif network or temp_dir:
+                fd, name = tempfile.mkstemp(
+                    prefix=STAGED_FILE_PREFIX, suffix=output_path.suffix, dir=temp_dir
+                )
+                os.close(fd)
+                staged = target_path = Path(name)
+
             # Download with progress tracking
             total_size = int(response.headers.get("content-length", 0))
             downloaded_size = 0
             last_progress = 0
 
-            with open(output_path, "wb") as f:
-                for chunk in response.iter_content(chunk_size=8192):
+            with open(target_path, "wb") as f:
+                for chunk in chunks:
                     if chunk:
                         f.write(chunk)
                         downloaded_size += len(chunk)
+                        # The prefetched head was counted when it was taken
+                        transferred = max(transferred, counted + downloaded_size)
+                        if rate_limiter:
+                            rate_limiter.wait(len(chunk))
 
                         # Progress callback (every 10%)
                         if progress_callback and total_size > 0:
@@ -132,23 +608,62 @@ def download_song(
 
             # Verify file integrity
             if total_size > 0 and downloaded_size != total_size:
-                output_path.unlink()
+                target_path.unlink()
                 raise ValueError(f"File incomplete: {downloaded_size}/{total_size} bytes")
 
+            mismatch = check_content_type(target_path)
+            if mismatch:
+                target_path.unlink()
+                logger.error(f"Downloaded file is not audio ({mismatch}): {url}")
+                return DownloadResult(
+                    status=DownloadStatus.FAILED,
+                    message=f"{WRONG_CONTENT_TYPE_PREFIX}{mismatch}",
+                    transferred=transferred,
+                )
+
+            if scanner:
+                verdict = scanner.scan(target_path)
+                if verdict:
+                    logger.error(f"File rejected by scanner, kept in {target_path}: {verdict}")
+                    return DownloadResult(
+                        status=DownloadStatus.FAILED,
+                        message=f"{SCAN_FAILED_PREFIX}{verdict}",
+                        transferred=transferred,
+                    )
+
+            if target_path != output_path:
+                move_staged_file(target_path, output_path, retries)
+
             logger.info(f"Downloaded successfully: {filename} ({downloaded_size:,} bytes)")
             return DownloadResult(
                 status=DownloadStatus.SUCCESS,
                 path=output_path,
                 size=downloaded_size,
                 message="Download successful",
+                transferred=transferred,
             )
 
         except requests.exceptions.RequestException as e:
+            if staged:
+                staged.unlink(missing_ok=True)
+            # Retrying only extends a ban
+            cooldown = response_rate_ban(e.response) if e.response is not None else None
+            if cooldown is not None:
+                logger.error(f"Rate limited by the server, cooldown {cooldown} seconds: {url}")
+                return DownloadResult(
+                    status=DownloadStatus.FAILED,
+                    message=f"{RATE_LIMITED_PREFIX}{cooldown}",
+                    transferred=transferred,
+                )
+
             logger.warning(f"Request failed (attempt {attempt + 1}): {e}")
             if attempt == retries - 1:
                 logger.error(f"Failed to download after {retries} attempts: {url}")
                 return DownloadResult(
-                    status=DownloadStatus.FAILED, path=None, message=f"Download failed: {str(e)}"
+                    status=DownloadStatus.FAILED,
+                    path=None,
+                    message=f"Download failed: {str(e)}",
+                    transferred=transferred,
                 )
             # Exponential backoff
             wait_time = 2**attempt
@@ -156,12 +671,638 @@ def download_song(
             time.sleep(wait_time)
 
         except Exception as e:
+            if staged:
+                staged.unlink(missing_ok=True)
             logger.error(f"Unexpected error: {e}")
             return DownloadResult(
-                status=DownloadStatus.FAILED, path=None, message=f"Error: {str(e)}"
+                status=DownloadStatus.FAILED,
+                path=None,
+                message=f"Error: {str(e)}",
+                transferred=transferred,
             )
 
-    return DownloadResult(status=DownloadStatus.FAILED, message="Unknown error")
+    return DownloadResult(
+        status=DownloadStatus.FAILED, message="Unknown error", transferred=transferred
+    )
+
+
+def song_path(
+    output_dir: Path, song: Song, replacement: str = "_", max_name_length: int | None = None
+) -> Path:
+    """
+    Get the path a song will be downloaded to.
+
+    Args:
+        output_dir: Output directory path
+        song: Song to download
+        replacement: Text replacing characters not allowed in file names
+        max_name_length: Optional maximum file name length in characters
+
+    Returns:
+        Path of the song's file
+    """
+    filename = extract_filename_from_headers({}, song.id, song.title, replacement)
+    return Path(output_dir) / sanitize_filename(filename, replacement, max_name_length)
+
+
+def wait_for_output(output_dir: Path) -> None:
+    """
+    Pause while an output folder on a network share is unreachable.
+
+    Args:
+        output_dir: Output directory path
+
+    Raises:
+        OSError: If the share doesn't come back within MOUNT_WAIT_TIMEOUT seconds
+    """
+    if path_available(output_dir):
+        return
+
+    logger.warning(f"Output folder unreachable, waiting for network share: {output_dir}")
+    print("  存储 (Storage): 网络共享已断开, 等待重新连接 (mount lost)")
+    if not wait_for_path(output_dir, MOUNT_WAIT_TIMEOUT):
+        raise OSError(errno.ENOTCONN, "Network share did not reconnect", str(output_dir))
+    logger.info(f"Output folder reachable again: {output_dir}")
+    print("  存储 (Storage): 网络共享已恢复 (mount restored)")
+
+
+def verify_written_files(downloads: list[tuple[int, Song, DownloadResult]]) -> list[Path]:
+    """
+    Check that downloaded files are complete on disk.
+
+    Args:
+        downloads: Track number, song and download result of each track
+
+    Returns:
+        Files that are missing or don't have the downloaded size
+    """
+    damaged = []
+    for _, _, result in downloads:
+        if not result.is_success() or result.path is None:
+            continue
+        try:
+            if result.path.stat().st_size != result.size:
+                damaged.append(result.path)
+        except OSError:
+            damaged.append(result.path)
+    return damaged
+
+
+def print_song_header(idx: int, total: int, song: Song) -> None:
+    """
+    Print the progress line announcing a song.
+
+    Args:
+        idx: Position of the song in this download
+        total: Number of songs in this download
+        song: Song being downloaded
+    """
+    # Safe print for Windows console
+    try:
+        print(f"[{idx}/{total}] {song.title}")
+    except UnicodeEncodeError:
+        print(f"[{idx}/{total}] Downloading song {idx}...")
+
+
+def response_rate_ban(response: requests.Response) -> int | None:
+    """
+    Check whether the server refused a request for too many requests.
+
+    Args:
+        response: Server response
+
+    Returns:
+        Suggested cooldown in seconds, or None if the response isn't a rate ban
+    """
+    if response.ok:
+        return None
+    cooldown = detect_rate_ban(response.status_code, response.headers, response.text)
+    return math.ceil(cooldown) if cooldown is not None else None
+
+
+def rate_ban_cooldown(result: DownloadResult) -> int | None:
+    """
+    Get the cooldown of a download the server refused for too many requests.
+
+    Args:
+        result: Download result
+
+    Returns:
+        Suggested cooldown in seconds, or None if the download wasn't rate limited
+    """
+    if result.message is None or not result.message.startswith(RATE_LIMITED_PREFIX):
+        return None
+    return int(result.message[len(RATE_LIMITED_PREFIX) :])
+
+
+def report_rate_ban(cooldown: int) -> None:
+    """
+    Print that the server refused requests for sending too many.
+
+    Args:
+        cooldown: Suggested cooldown in seconds
+    """
+    print(f"  限流 (Rate limited): 建议冷却 (cooldown) {cooldown} 秒")
+
+
+def describe_politeness(
+    delay: float, workers: int, policy: PolitenessPolicy, relaxed: bool
+) -> str:
+    """
+    Describe the effective politeness of a download.
+
+    Args:
+        delay: Delay between songs in seconds
+        workers: Number of songs downloaded at once
+        policy: The site's recommended policy
+        relaxed: Whether the user chose to ignore the policy
+
+    Returns:
+        Text like "delay=1.0s workers=2 window=01:00-06:00"
+    """
+    text = f"delay={delay}s workers={workers}"
+    if relaxed:
+        return f"{text} (relaxed)"
+    if policy.window:
+        text += f" window={policy.window[0]}-{policy.window[1]}"
+    return text
+
+
+def wait_for_window(policy: PolitenessPolicy) -> None:
+    """
+    Wait until a site's download window opens.
+
+    Args:
+        policy: The site's recommended policy
+    """
+    seconds = policy.seconds_until_window()
+    if seconds <= 0:
+        return
+
+    window = "-".join(policy.window or ())
+    logger.info(f"Waiting {seconds:.0f} seconds for the download window {window}")
+    print(f"等待 (Waiting): 下载时段 (download window) {window}, {math.ceil(seconds / 60)} 分钟\n")
+    sys.stdout.flush()
+    time.sleep(seconds)
+
+
+def report_scan(result: DownloadResult) -> None:
+    """
+    Print the verdict of a failed virus scan.
+
+    Args:
+        result: Download result, reported only if the scanner rejected the file
+    """
+    if result.message is None or not result.message.startswith(SCAN_FAILED_PREFIX):
+        return
+    reason = result.message[len(SCAN_FAILED_PREFIX) :]
+    print(f"  扫描 (Scan): 未通过 (failed) {reason}")
+
+
+def report_content_type(result: DownloadResult) -> None:
+    """
+    Print why a downloaded file was rejected for its content.
+
+    Args:
+        result: Download result, reported only if the file had the wrong content type
+    """
+    if result.message is None or not result.message.startswith(WRONG_CONTENT_TYPE_PREFIX):
+        return
+    mismatch = result.message[len(WRONG_CONTENT_TYPE_PREFIX) :]
+    print(f"  类型 (Content type): 错误 (wrong-content-type) {mismatch}")
+
+
+def check_sanity(result: DownloadResult, min_size: int = 0, detect_silence: bool = False) -> None:
+    """
+    Fail a downloaded file that is suspiciously small or silent.
+
+    The file is deleted, so the next run downloads it again. Silence is not
+    checked if ffmpeg is not installed or can't decode the file.
+
+    Args:
+        result: Download result, downgraded to a failure if a check fails
+        min_size: Minimum file size in bytes, 0 to not check the size
+        detect_silence: Whether to fail files containing only silence
+    """
+    if not result.is_success() or result.path is None:
+        return
+
+    path = result.path
+    size = path.stat().st_size
+    reason = None
+    if size < min_size:
+        reason = f"{size} bytes, expected at least {min_size}"
+    elif detect_silence:
+        try:
+            if is_silent(path):
+                reason = "audio is silent"
+        except ValueError as e:
+            logger.warning(f"Could not check {path.name} for silence: {e}")
+
+    if reason is None:
+        return
+    logger.error(f"Downloaded file failed the sanity check ({reason}): {path.name}")
+    path.unlink(missing_ok=True)
+    result.status = DownloadStatus.FAILED
+    result.path = None
+    result.message = f"{SANITY_FAILED_PREFIX}{reason}"
+    print(f"  检查 (Sanity): 未通过 (failed) {reason}")
+
+
+def report_transfer(number: int, result: DownloadResult) -> None:
+    """
+    Print the data a track used, including retries and discarded files.
+
+    Args:
+        number: Track number
+        result: Download result
+    """
+    if result.transferred:
+        print(f"  传输 (Transferred): [{number}] {result.transferred} 字节 (bytes)")
+
+
+def report_duration(result: DownloadResult) -> None:
+    """
+    Probe and print the duration of a downloaded file.
+
+    Zero-length and unreadable files are reported as warnings. Nothing is
+    printed if ffprobe is not installed.
+
+    Args:
+        result: Successful download result, updated with the duration
+    """
+    if not result.is_success() or result.path is None:
+        return
+
+    try:
+        result.duration = probe_duration(result.path)
+    except ValueError as e:
+        logger.warning(f"Could not read duration of {result.path.name}: {e}")
+        print("  警告 (Warning): 无法读取时长, 文件可能已损坏 (unreadable audio)")
+        return
+
+    if result.duration is None:
+        return
+    if result.duration == 0:
+        logger.warning(f"Downloaded file has zero length: {result.path.name}")
+        print("  警告 (Warning): 时长为零 (zero-length audio)")
+    else:
+        print(f"  时长 (Duration): {format_duration(result.duration)}")
+
+
+class PostProcessor:
+    """
+    Process downloaded files in a worker pool while the next tracks download.
+
+    New files are checked on disk if requested (network shares) and have
+    their loudness normalized if requested. Every file, including skipped
+    (already existing) ones, is then checksummed for the manifest. Progress
+    is printed by report(), called from the downloading thread, so it isn't
+    interleaved with the download output.
+    """
+
+    def __init__(
+        self,
+        normalize: bool = False,
+        verify: bool = False,
+        temp_dir: Path | None = None,
+        workers: int = POST_PROCESS_WORKERS,
+    ):
+        """
+        Create a post-processor.
+
+        Args:
+            normalize: Whether to normalize the loudness of new files
+            verify: Whether to check that new files are complete on disk
+            temp_dir: Optional folder for the re-encoded files
+            workers: Number of files processed at once
+        """
+        self.normalize = normalize
+        self.verify = verify
+        self.temp_dir = temp_dir
+        self.checksums: dict[int, str] = {}
+        self.damaged: list[Path] = []
+        self._executor = ThreadPoolExecutor(max_workers=workers)
+        self._lock = threading.Lock()
+        self._submitted = 0
+        self._processed = 0
+        self._reported = 0
+        self._ffmpeg_missing = False
+        self._ffmpeg_reported = False
+
+    def submit(self, number: int, song: Song, result: DownloadResult) -> None:
+        """
+        Queue a downloaded file for processing.
+
+        Failed downloads and results without a file are ignored.
+
+        Args:
+            number: Track number
+            song: Downloaded song
+            result: Result of downloading the song
+        """
+        if result.path is None or result.status == DownloadStatus.FAILED:
+            return
+        with self._lock:
+            self._submitted += 1
+        self._executor.submit(self._process, number, song, result, result.path)
+
+    def report(self) -> None:
+        """Print how many of the submitted files are processed, if that changed."""
+        with self._lock:
+            processed, submitted = self._processed, self._submitted
+        if self._ffmpeg_missing and not self._ffmpeg_reported:
+            self._ffmpeg_reported = True
+            logger.warning("ffmpeg not found, skipping loudness normalization")
+            print("  警告 (Warning): 未找到 ffmpeg, 跳过响度标准化 (ffmpeg not found)")
+        if processed != self._reported:
+            self._reported = processed
+            print(f"  处理 (Processed): {processed}/{submitted}")
+
+    def finish(self) -> None:
+        """Wait until all submitted files are processed and report the result."""
+        self._executor.shutdown(wait=True)
+        self.report()
+
+    def _process(self, number: int, song: Song, result: DownloadResult, path: Path) -> None:
+        """
+        Verify, normalize and checksum one downloaded file.
+
+        Args:
+            number: Track number
+            song: Downloaded song
+            result: Result of downloading the song
+            path: Downloaded file
+        """
+        if self.verify and verify_written_files([(number, song, result)]):
+            logger.error(f"File incomplete on network share: {path}")
+            with self._lock:
+                self.damaged.append(path)
+        elif self.normalize and result.is_success() and not self._ffmpeg_missing:
+            try:
+                if normalize_loudness(path, temp_dir=self.temp_dir):
+                    logger.info(f"Normalized loudness: {path.name}")
+                else:
+                    self._ffmpeg_missing = True
+            except (OSError, ValueError) as e:
+                logger.warning(f"Failed to normalize {path.name}: {e}")
+
+        checksum = None
+        try:
+            checksum = file_checksum(path)
+        except OSError as e:
+            logger.warning(f"Failed to checksum {path.name}: {e}")
+        with self._lock:
+            if checksum:
+                self.checksums[number] = checksum
+            self._processed += 1
+
+
+def merge_album(
+    title: str,
+    downloads: list[tuple[int, Song, DownloadResult]],
+    output: Path,
+    temp_dir: Path | None = None,
+) -> bool:
+    """
+    Merge the files of an album into one file with a chapter per track.
+
+    Args:
+        title: Album title
+        downloads: Track number, song and download result of each track
+        output: Merged file to write, its suffix selects the format
+        temp_dir: Optional folder the merged file is written to before it is moved
+
+    Returns:
+        True if the merged file was written, False otherwise
+    """
+    tracks = [
+        (result.path, song.title)
+        for _, song, result in sorted(downloads, key=lambda download: download[0])
+        if result.path and not result.is_failed()
+    ]
+    print(f"\n合并 (Merge): {len(tracks)} 首 -> {output.name}")
+
+    last_reported = -10
+
+    def report(percent: int) -> None:
+        nonlocal last_reported
+        if percent - last_reported >= 10 or percent == 100:
+            print(f"合并 (Merge): {percent}%")
+            last_reported = percent
+
+    try:
+        merge_chapters(tracks, output, title=title, progress_callback=report, temp_dir=temp_dir)
+    except ValueError as e:
+        logger.error(f"Failed to merge album: {e}")
+        print(f"错误: 合并失败 (merge failed): {e}")
+        return False
+
+    logger.info(f"Merged album written: {output}")
+    return True
+
+
+class StdinReader:
+    """Read answers from stdin in a background thread, so waiting for one can time out."""
+
+    def __init__(self, stream: Any):
+        """
+        Start reading a stream.
+
+        Args:
+            stream: Stream the answers arrive on, usually sys.stdin
+        """
+        self.stream = stream
+        self._lines: queue.Queue[str] = queue.Queue()
+        threading.Thread(target=self._read, daemon=True).start()
+
+    def _read(self) -> None:
+        """Queue the lines of the stream, then an empty line once it is closed.
+
+        Lines naming one of the stdin_commands are passed to its handler
+        instead of being queued.
+        """
+        try:
+            for line in iter(self.stream.readline, ""):
+                name, _, argument = line.strip().partition(" ")
+                handler = stdin_commands.get(name)
+                if handler:
+                    handler(argument.strip())
+                    continue
+                self._lines.put(line)
+        except (OSError, ValueError) as e:
+            logger.debug(f"Stopped reading stdin: {e}")
+        self._lines.put("")
+
+    def readline(self, timeout: float | None = None) -> str | None:
+        """
+        Wait for the next line.
+
+        Args:
+            timeout: Optional seconds to wait
+
+        Returns:
+            The line, "" if the stream is closed, or None if no line arrived in time
+        """
+        try:
+            line = self._lines.get(timeout=timeout)
+        except queue.Empty:
+            return None
+        if not line:
+            # Stay closed for later calls
+            self._lines.put(line)
+        return line
+
+
+# Reader of sys.stdin shared by all prompts, so no answer goes to a stale prompt's reader
+_stdin_reader: StdinReader | None = None
+
+# Handlers of command lines on stdin by name, e.g. SPEED_LIMIT_COMMAND
+stdin_commands: dict[str, Callable[[str], None]] = {}
+
+
+def stdin_reader() -> StdinReader:
+    """
+    Get the reader of the current stdin, starting it on first use.
+
+    Returns:
+        StdinReader for sys.stdin
+    """
+    global _stdin_reader
+    if _stdin_reader is None or _stdin_reader.stream is not sys.stdin:
+        _stdin_reader = StdinReader(sys.stdin)
+    return _stdin_reader
+
+
+def follow_speed_limit(rate_limiter: RateLimiter) -> None:
+    """
+    Let SPEED_LIMIT_COMMAND lines on stdin change the speed limit.
+
+    Only when stdin is a pipe, e.g. from the GUI; a terminal isn't read
+    until a prompt asks for input.
+
+    Args:
+        rate_limiter: Limiter of the running download
+    """
+    if sys.stdin is None or sys.stdin.isatty():
+        return
+    stdin_commands[SPEED_LIMIT_COMMAND] = rate_limiter.handle_command
+    stdin_reader()
+
+
+def request_interaction(kind: str, prompt: str, url: str = "") -> str | None:
+    """
+    Ask the user for input and wait for the answer on stdin.
+
+    Prints one line starting with INTERACTION_PREFIX and a JSON object with
+    "kind", "prompt" and "url", which the GUI turns into a prompt. In a
+    terminal the user types the answer.
+
+    Args:
+        kind: "captcha" to pass a challenge in the browser and confirm
+        prompt: What the user should do
+        url: Optional page where the user does it
+
+    Returns:
+        The answer without surrounding whitespace, or None if stdin is closed
+    """
+    message = {"kind": kind, "prompt": prompt, "url": url}
+    print(INTERACTION_PREFIX + json.dumps(message, ensure_ascii=False), flush=True)
+    line = stdin_reader().readline() if sys.stdin else ""
+    if not line:
+        logger.warning(f"No answer to {kind} prompt")
+        return None
+    return line.strip()
+
+
+def confirm_track(
+    number: int, song: Song, timeout: int = CONFIRM_TIMEOUT, default: bool = True
+) -> bool:
+    """
+    Ask the user whether to download a track.
+
+    Prints an interaction line of kind CONFIRM_TRACK with the track number
+    as "index". The answer is "yes" or "no", optionally after the track
+    number; answers to an earlier track that arrive late are ignored.
+
+    Args:
+        number: Album track number
+        song: Song to download
+        timeout: Seconds to wait for an answer
+        default: Whether to download the track without an answer
+
+    Returns:
+        True to download the track, False to skip it
+    """
+    action = "download" if default else "skip"
+    message = {
+        "kind": CONFIRM_TRACK,
+        "prompt": f"Download track {number}: {song.title}? (no answer in {timeout}s: {action})",
+        "url": "",
+        "index": number,
+    }
+    print(INTERACTION_PREFIX + json.dumps(message, ensure_ascii=False), flush=True)
+
+    deadline = time.monotonic() + timeout
+    reader = stdin_reader() if sys.stdin else None
+    while reader:
+        line = reader.readline(max(deadline - time.monotonic(), 0))
+        if line is None:
+            logger.info(f"No answer for track {number} in {timeout}s, {action}")
+            print(f"  确认 (Confirm): 超时 (timed out), {action}")
+            return default
+        if not line:
+            break
+
+        parts = line.split()
+        if len(parts) == 2 and parts[0] != str(number):
+            logger.debug(f"Ignoring late answer for track {parts[0]}")
+            continue
+        answer = CONFIRM_ANSWERS.get(parts[-1].lower() if parts else "")
+        return default if answer is None else answer
+
+    logger.warning(f"No answer for track {number}, stdin closed")
+    return default
+
+
+def pass_challenges(url: str, html: str, adapter: Any, cookies: Any | None = None) -> str | None:
+    """
+    Let the user pass captcha or verification pages served instead of a page.
+
+    Args:
+        url: Page URL
+        html: The fetched page HTML
+        adapter: Adapter for the page's site
+        cookies: Optional cookies to send when fetching the page again
+
+    Returns:
+        The page HTML once it is no challenge, or None if the user gave up
+        or the challenge persists
+    """
+    for _ in range(MAX_CHALLENGES):
+        challenge_url = adapter.detect_challenge(html)
+        if challenge_url is None:
+            return html
+
+        print(f"验证 (Verification): {challenge_url or url}")
+        answer = request_interaction(
+            "captcha",
+            "Complete the verification in your browser, then continue",
+            challenge_url or url,
+        )
+        if answer is None:
+            logger.error(f"Verification not completed for {url}")
+            return None
+
+        logger.info(f"Fetching page again after verification: {url}")
+        response = requests.get(url, timeout=30, cookies=cookies)
+        response.encoding = "utf-8"
+        html = response.text
+
+    if adapter.detect_challenge(html) is None:
+        return html
+    logger.error(f"Still asked for verification after {MAX_CHALLENGES} attempts: {url}")
+    return None
 
 
 def download_album(
@@ -172,6 +1313,27 @@ def download_album(
     timeout: int = 60,
     retries: int = 3,
     delay: float = 0.5,
+    track_range: str | None = None,
+    skip_titles: list[str] | None = None,
+    include: str | None = None,
+    exclude: str | None = None,
+    normalize: bool = False,
+    merge: str | None = None,
+    workers: int = 1,
+    replacement: str = "_",
+    max_name_length: int | None = None,
+    skip_urls: set[str] | None = None,
+    cookies: Any | None = None,
+    max_speed: int | None = None,
+    checksum_sidecars: bool = False,
+    scan_command: str | None = None,
+    temp_dir: Path | None = None,
+    confirm_tracks: bool = False,
+    confirm_timeout: int = CONFIRM_TIMEOUT,
+    confirm_default: bool = True,
+    relax_politeness: bool = False,
+    min_size: int = 0,
+    detect_silence: bool = False,
 ) -> bool:
     """
     Download an entire album.
@@ -184,52 +1346,156 @@ def download_album(
         timeout: Request timeout in seconds
         retries: Number of retry attempts
         delay: Delay between downloads in seconds
+        track_range: Optional track numbers to download, e.g. "5-12,15"
+        skip_titles: Optional title fragments of songs to skip, e.g. ["remix"]
+        include: Optional title pattern of songs to download, e.g. "*grace*"
+        exclude: Optional title pattern of songs not to download
+        normalize: Whether to normalize loudness of each song once it has downloaded
+        merge: Optional format ("m4b" or "mka") to merge the album into one chaptered file
+        workers: Number of songs to download concurrently, no delay is applied between them
+        replacement: Text replacing characters not allowed in file names
+        max_name_length: Optional maximum file name length in characters
+        skip_urls: Optional audio URLs of tracks already downloaded, left out
+        cookies: Optional cookies to send with every request
+        max_speed: Optional speed limit in KB/s for all songs together
+        checksum_sidecars: Whether to write a .sha256 file next to each downloaded file
+        scan_command: Optional scanner command each downloaded file has to pass
+        temp_dir: Optional local folder for partial files and conversions
+        confirm_tracks: Whether to ask the user before downloading each song
+        confirm_timeout: Seconds to wait for the answer to confirm_tracks
+        confirm_default: Whether to download a song nobody confirmed in time
+        relax_politeness: Whether to ignore the site's recommended delay,
+                          concurrency and download window
+        min_size: Minimum size in bytes of each downloaded file, 0 to not check
+        detect_silence: Whether to fail downloaded files containing only silence
 
     Returns:
         True if all downloads succeeded, False otherwise
     """
+    rate_limiter = RateLimiter(max_speed * 1024 if max_speed else None)
+    follow_speed_limit(rate_limiter)
+    scanner = create_scanner(scan_command, temp_dir)
     try:
         # Fetch album page
         logger.info(f"Fetching album page: {url}")
-        response = requests.get(url, timeout=30)
+        response = requests.get(url, timeout=30, cookies=cookies)
         response.encoding = "utf-8"
         html = response.text
 
+        cooldown = response_rate_ban(response)
+        if cooldown is not None:
+            logger.error(f"Rate limited by the server, cooldown {cooldown} seconds: {url}")
+            report_rate_ban(cooldown)
+            return False
+
         # Get appropriate adapter
         adapter = get_adapter(url)
         if not adapter:
             logger.error(f"No adapter found for URL: {url}")
             return False
 
+        html = pass_challenges(url, html, adapter, cookies)
+        if html is None:
+            return False
+
         # Extract album information
         logger.info("Parsing album information...")
         album = adapter.extract_album(html)
 
+        # Follow the site's recommended delay, concurrency and download hours
+        policy = adapter.POLITENESS
+        if not relax_politeness:
+            delay, workers = policy.apply(delay, workers)
+        politeness = describe_politeness(delay, workers, policy, relax_politeness)
+
         # Display album info
         print("\n" + "=" * 60)
         print(f"专辑 (Album): {album.title}")
         print(f"来源 (Source): {album.source}")
+        print(f"礼貌策略 (Politeness): {politeness}")
         print(f"歌曲数 (Songs): {len(album.songs)}")
         print(f"输出目录 (Output): {output_dir}")
         print("=" * 60 + "\n")
 
+        if not relax_politeness:
+            wait_for_window(policy)
+
+        # Apply track range, keeping album track numbers
+        numbered = list(enumerate(album.songs, 1))
+        if track_range:
+            ranges = parse_track_range(track_range)
+            numbered = [item for item in numbered if track_in_range(item[0], ranges)]
+            print(f"范围 (Range): {track_range}, {len(numbered)} 首\n")
+
+        # Apply title patterns
+        if include or exclude:
+            numbered = [
+                (number, song)
+                for number, song in numbered
+                if title_selected(song.title, include, exclude)
+            ]
+            print(f"筛选 (Filter): {len(numbered)} 首\n")
+
+        # Leave out tracks that are already complete
+        if skip_urls is not None:
+            numbered = [(number, song) for number, song in numbered if song.url not in skip_urls]
+            print(f"同步 (Sync): {len(numbered)} 首新的或未完成 (new or incomplete)\n")
+
         # Apply limit
-        songs = album.songs[:limit] if limit else album.songs
+        numbered = numbered[:limit] if limit else numbered
+        songs = [song for _, song in numbered]
         if limit:
             print(f"限制下载 (Limit): {len(songs)} 首\n")
 
+        # Planned file of each track
+        paths = {
+            number: song_path(output_dir, song, replacement, max_name_length)
+            for number, song in numbered
+        }
+
+        # Warn about files many Windows programs won't be able to open
+        if sys.platform == "win32":
+            long_paths = [path for path in paths.values() if exceeds_max_path(path)]
+            if long_paths:
+                logger.warning(f"{len(long_paths)} file paths exceed {MAX_PATH} characters")
+                print(
+                    f"警告 (Warning): {len(long_paths)} 首的文件路径超过 {MAX_PATH} 个字符 "
+                    "(long paths)\n"
+                )
+
         # Initialize progress tracker
         progress = DownloadProgress(len(songs))
+        downloads: list[tuple[int, Song, DownloadResult]] = []
+
+        # Downloaded files are verified, normalized and checksummed while
+        # the next songs download
+        network = is_network_path(output_dir)
+        post = PostProcessor(normalize=normalize, verify=network, temp_dir=temp_dir)
+
+        # Tracks whose file names collide (ignoring case where the file system
+        # does) are numbered instead of skipping or overwriting each other
+        default_names = [paths[number].name for number, _ in numbered]
+        renamed = {
+            number: unique
+            for (number, _), default, unique in zip(
+                numbered, default_names, unique_filenames(default_names)
+            )
+            if unique != default
+        }
+        for number, filename in renamed.items():
+            logger.info(f"File name collision for track {number}, saving as {filename}")
 
-        # Download songs
-        for idx, song in enumerate(songs, 1):
-            # Safe print for Windows console
-            try:
-                print(f"[{idx}/{len(songs)}] {song.title}")
-            except UnicodeEncodeError:
-                print(f"[{idx}/{len(songs)}] Downloading song {idx}...")
+        # Set once the server rate limits a track; the rest aren't requested
+        banned = threading.Event()
+        ban_reported = False
 
-            # Download song
+        def fetch(number: int, song: Song) -> DownloadResult:
+            if banned.is_set():
+                return DownloadResult(
+                    status=DownloadStatus.FAILED, message="Not downloaded: rate limited"
+                )
+            if network:
+                wait_for_output(Path(output_dir))
             result = download_song(
                 url=song.url,
                 output_dir=output_dir,
@@ -238,20 +1504,145 @@ def download_album(
                 timeout=timeout,
                 retries=retries,
                 overwrite=overwrite,
+                filename=renamed.get(number),
+                replacement=replacement,
+                max_name_length=max_name_length,
+                cookies=cookies,
+                rate_limiter=rate_limiter,
+                scanner=scanner,
+                temp_dir=temp_dir,
+                prefetched=prefetcher.take(song.url) if prefetcher else None,
             )
-
-            # Update progress
+            if rate_ban_cooldown(result) is not None:
+                banned.set()
+            return result
+
+        def prefetch_after(index: int) -> None:
+            # Only a song that will be requested right away is worth opening
+            if prefetcher is None or banned.is_set() or index + 1 >= len(numbered):
+                return
+            number, song = numbered[index + 1]
+            path = paths[number].with_name(renamed.get(number, paths[number].name))
+            if match_blacklist(song.title, skip_titles or []) or (path.exists() and not overwrite):
+                return
+            progress.transferred_bytes += prefetcher.start(song.url)
+
+        def finish(number: int, song: Song, result: DownloadResult) -> None:
+            nonlocal ban_reported
+            cooldown = rate_ban_cooldown(result)
+            if cooldown is not None and not ban_reported:
+                report_rate_ban(cooldown)
+                ban_reported = True
+            report_scan(result)
+            report_content_type(result)
+            check_sanity(result, min_size, detect_silence)
+            report_duration(result)
+            report_transfer(number, result)
             progress.update(result)
+            downloads.append((number, song, result))
+            post.submit(number, song, result)
+            post.report()
+
+        # Download songs, several at once if requested. Concurrent songs are
+        # reported when they finish so their output isn't interleaved.
+        executor = ThreadPoolExecutor(max_workers=workers) if workers > 1 else None
+        # One song after another, the next song's request is opened while the
+        # current one is checked and reported
+        prefetcher = Prefetcher(timeout, cookies) if not (executor or confirm_tracks) else None
+        pending: dict[Future[DownloadResult], tuple[int, Song]] = {}
+        reported = 0
+        for index, (number, song) in enumerate(numbered):
+            # Skip blacklisted titles
+            blocked = match_blacklist(song.title, skip_titles or [])
+            declined = (
+                not blocked
+                and confirm_tracks
+                and not confirm_track(number, song, confirm_timeout, confirm_default)
+            )
+            if executor and not (blocked or declined):
+                pending[executor.submit(fetch, number, song)] = (number, song)
+                continue
+
+            reported += 1
+            print_song_header(reported, len(songs), song)
+
+            if blocked:
+                logger.info(f"Title matches blacklist {blocked!r}, skipping: {song.title}")
+                print(f'  跳过 (Skip): 标题黑名单 (title blacklist) "{blocked}"')
+                progress.update(
+                    DownloadResult(
+                        status=DownloadStatus.SKIPPED,
+                        message=f"Title matches blacklist: {blocked}",
+                    )
+                )
+                continue
+
+            if declined:
+                logger.info(f"Track not confirmed, skipping: {song.title}")
+                print("  跳过 (Skip): 未确认 (not confirmed)")
+                progress.update(
+                    DownloadResult(status=DownloadStatus.SKIPPED, message="Not confirmed")
+                )
+                continue
+
+            result = fetch(number, song)
 
             # Small delay between downloads to be polite to the server
-            if idx < len(songs):
+            if reported < len(songs):
                 time.sleep(delay)
+                prefetch_after(index)
+
+            finish(number, song, result)
+
+        if prefetcher:
+            progress.transferred_bytes += prefetcher.close()
+
+        if executor:
+            with executor:
+                for future in as_completed(pending):
+                    number, song = pending[future]
+                    reported += 1
+                    print_song_header(reported, len(songs), song)
+                    finish(number, song, future.result())
+
+        post.finish()
 
         # Display summary
         print(progress.summary())
 
+        # Make sure nothing was lost on the way to a network share
+        verified = True
+        if network:
+            print(f"\n校验 (Verify): {len(post.damaged)} 个文件不完整 (incomplete files)")
+            verified = not post.damaged
+
+        merged = True
+        if merge:
+            filename = sanitize_filename(album.title or "album", replacement, max_name_length)
+            merged = merge_album(
+                album.title, downloads, Path(output_dir) / f"{filename}.{merge}", temp_dir
+            )
+
+        # Record what was downloaded for later verification
+        manifest = build_manifest(album, downloads, post.checksums)
+        try:
+            manifest = merge_manifests(read_manifest(Path(output_dir)), manifest)
+        except (FileNotFoundError, ValueError):
+            pass
+        try:
+            manifest_path = write_manifest(Path(output_dir), manifest)
+            logger.info(f"Manifest written: {manifest_path}")
+        except OSError as e:
+            logger.warning(f"Failed to write manifest: {e}")
+        if checksum_sidecars:
+            try:
+                sidecars = write_checksum_sidecars(Path(output_dir), manifest)
+                logger.info(f"Checksum sidecars written: {len(sidecars)}")
+            except OSError as e:
+                logger.warning(f"Failed to write checksum sidecars: {e}")
+
         # Return success status
-        return progress.failed == 0
+        return progress.failed == 0 and merged and verified
 
     except Exception as e:
         logger.error(f"Album download failed: {e}")
@@ -259,6 +1650,367 @@ def download_album(
         return False
 
 
+def sync_album(url: str, output_dir: Path, **kwargs: Any) -> bool:
+    """
+    Bring a downloaded album up to date.
+
+    Compares the album's current track list with the manifest in the output
+    folder and downloads only tracks that are new, failed before or whose
+    files are missing or damaged. Without a manifest the whole album is
+    downloaded.
+
+    Args:
+        url: Album page URL
+        output_dir: Album folder
+        **kwargs: Further download_album options, except overwrite and skip_urls
+
+    Returns:
+        True if all downloads succeeded, False otherwise
+    """
+    complete: set[str] = set()
+    try:
+        manifest = read_manifest(Path(output_dir))
+        if manifest.source_url == url:
+            damaged = {track.url for track in verify_manifest(Path(output_dir), manifest)}
+            complete = {track.url for track in manifest.tracks} - damaged
+        else:
+            logger.warning(f"Manifest in {output_dir} belongs to {manifest.source_url}")
+    except FileNotFoundError:
+        logger.info(f"No manifest in {output_dir}, downloading the whole album")
+    except ValueError as e:
+        logger.warning(f"Ignoring manifest: {e}")
+
+    logger.info(f"{len(complete)} tracks already complete in {output_dir}")
+
+    # Damaged files are replaced rather than skipped
+    return download_album(url, output_dir, overwrite=True, skip_urls=complete, **kwargs)
+
+
+def list_albums(url: str, cookies: Any | None = None) -> bool:
+    """
+    List the albums linked from an artist page.
+
+    Args:
+        url: Artist page URL
+        cookies: Optional cookies to send with the request
+
+    Returns:
+        True if albums were found, False otherwise
+    """
+    try:
+        logger.info(f"Fetching artist page: {url}")
+        response = requests.get(url, timeout=30, cookies=cookies)
+        response.encoding = "utf-8"
+
+        adapter = get_adapter(url)
+        if not adapter:
+            logger.error(f"No adapter found for URL: {url}")
+            return False
+
+        html = pass_challenges(url, response.text, adapter, cookies)
+        if html is None:
+            return False
+
+        links = adapter.extract_album_links(html)
+        print(f"专辑数 (Albums): {len(links)}")
+        for link in links:
+            print(f"专辑链接 (Album URL): {link}")
+        return bool(links)
+
+    except Exception as e:
+        logger.error(f"Listing albums failed: {e}")
+        print(f"\n错误: {e}")
+        return False
+
+
+def load_custom_sources(folder: Path) -> int:
+    """
+    Register the sites defined by the JSON files in a folder.
+
+    Invalid definitions are reported and skipped.
+
+    Args:
+        folder: Folder with source definition files (*.json)
+
+    Returns:
+        Number of sites registered
+    """
+    count = 0
+    for path in sorted(folder.glob("*.json")):
+        try:
+            adapter = load_source_definition(path)
+        except (OSError, ValueError) as e:
+            logger.warning(f"Skipping source definition {path}: {e}")
+            continue
+        register_adapter(adapter)
+        logger.info(f"Custom source loaded: {adapter.name} ({path.name})")
+        count += 1
+    return count
+
+
+def list_sources(as_json: bool = False) -> None:
+    """
+    Print the supported sites.
+
+    Args:
+        as_json: Print a JSON list with name, URL patterns and capabilities
+                 of each site instead of one line per site
+    """
+    sources = describe_sources()
+    if as_json:
+        print(json.dumps(sources, ensure_ascii=False))
+        return
+
+    for source in sources:
+        patterns = ", ".join(source["url_patterns"])
+        capabilities = ", ".join(source["capabilities"])
+        print(f"来源 (Source): {source['name']} [{patterns}] ({capabilities})")
+
+
+def repair_album(
+    folder: Path,
+    timeout: int = 60,
+    retries: int = 3,
+    delay: float = 0.5,
+    cookies: Any | None = None,
+    max_speed: int | None = None,
+    checksum_sidecars: bool = False,
+    scan_command: str | None = None,
+    temp_dir: Path | None = None,
+    min_size: int = 0,
+    detect_silence: bool = False,
+) -> bool:
+    """
+    Re-download missing or corrupted files of a downloaded album.
+
+    Uses the album's manifest to find damaged files and updates it afterwards.
+
+    Args:
+        folder: Album folder containing a manifest
+        timeout: Request timeout in seconds
+        retries: Number of retry attempts
+        delay: Delay between downloads in seconds
+        cookies: Optional cookies to send with every request
+        max_speed: Optional speed limit in KB/s
+        checksum_sidecars: Whether to rewrite the .sha256 files of the album
+        scan_command: Optional scanner command each downloaded file has to pass
+        temp_dir: Optional local folder for partial files
+        min_size: Minimum size in bytes of each downloaded file, 0 to not check
+        detect_silence: Whether to fail downloaded files containing only silence
+
+    Returns:
+        True if the album is complete afterwards, False otherwise
+    """
+    rate_limiter = RateLimiter(max_speed * 1024 if max_speed else None)
+    follow_speed_limit(rate_limiter)
+    scanner = create_scanner(scan_command, temp_dir)
+    try:
+        manifest = read_manifest(folder)
+        damaged = verify_manifest(folder, manifest)
+
+        print("\n" + "=" * 60)
+        print(f"专辑 (Album): {manifest.title}")
+        print(f"来源 (Source): {manifest.source}")
+        print(f"损坏 (Damaged): {len(damaged)} / {len(manifest.tracks)}")
+        print(f"输出目录 (Output): {folder}")
+        print("=" * 60 + "\n")
+
+        if not damaged:
+            print("所有文件完好 (All files verified)")
+            return True
+
+        progress = DownloadProgress(len(damaged))
+        for idx, track in enumerate(damaged, 1):
+            try:
+                print(f"[{idx}/{len(damaged)}] {track.title}")
+            except UnicodeEncodeError:
+                print(f"[{idx}/{len(damaged)}] Repairing song {track.index}...")
+
+            result = download_song(
+                url=track.url,
+                output_dir=folder,
+                song_title=track.title,
+                timeout=timeout,
+                retries=retries,
+                overwrite=True,
+                cookies=cookies,
+                rate_limiter=rate_limiter,
+                scanner=scanner,
+                temp_dir=temp_dir,
+            )
+            cooldown = rate_ban_cooldown(result)
+            if cooldown is not None:
+                report_rate_ban(cooldown)
+                progress.failed += len(damaged) - idx + 1
+                break
+            report_scan(result)
+            report_content_type(result)
+            check_sanity(result, min_size, detect_silence)
+            report_duration(result)
+            report_transfer(track.index, result)
+            progress.update(result)
+            previous_filename = track.filename
+            update_track(track, result)
+
+            # Remove the damaged file if the new download got a different name
+            if result.is_success() and previous_filename not in (None, track.filename):
+                (folder / previous_filename).unlink(missing_ok=True)
+
+            if idx < len(damaged):
+                time.sleep(delay)
+
+        print(progress.summary())
+        write_manifest(folder, manifest)
+        if checksum_sidecars:
+            write_checksum_sidecars(folder, manifest)
+
+        return progress.failed == 0
+
+    except Exception as e:
+        logger.error(f"Album repair failed: {e}")
+        print(f"\n错误: {e}")
+        return False
+
+
+def measure_throughput(
+    url: str, streams: int, duration: float = BENCHMARK_DURATION, timeout: int = 30
+) -> float:
+    """
+    Measure the download speed of parallel connections to a URL.
+
+    Each connection downloads the URL repeatedly until the time is up.
+
+    Args:
+        url: URL of a file to download
+        streams: Number of parallel connections
+        duration: Seconds to download for
+        timeout: Request timeout in seconds
+
+    Returns:
+        Combined speed in bytes per second
+    """
+    start = time.monotonic()
+    deadline = start + duration
+
+    def download() -> int:
+        received = 0
+        while time.monotonic() < deadline:
+            with requests.get(url, stream=True, timeout=timeout) as response:
+                response.raise_for_status()
+                for chunk in response.iter_content(chunk_size=65536):
+                    received += len(chunk)
+                    if time.monotonic() >= deadline:
+                        break
+        return received
+
+    with ThreadPoolExecutor(max_workers=streams) as executor:
+        total = sum(executor.map(lambda _: download(), range(streams)))
+
+    elapsed = time.monotonic() - start
+    return total / elapsed if elapsed > 0 else 0.0
+
+
+def suggest_workers(speeds: dict[int, float]) -> int:
+    """
+    Suggest a number of parallel downloads from benchmark results.
+
+    Args:
+        speeds: Bytes per second by number of parallel connections
+
+    Returns:
+        Fewest connections reaching 90% of the best measured speed
+    """
+    best = max(speeds.values(), default=0.0)
+    for streams in sorted(speeds):
+        if speeds[streams] >= best * 0.9:
+            return streams
+    return 1
+
+
+def benchmark_connection(
+    url: str,
+    streams: tuple[int, ...] = BENCHMARK_STREAMS,
+    duration: float = BENCHMARK_DURATION,
+    timeout: int = 60,
+) -> bool:
+    """
+    Measure the download speed to a test URL with different numbers of connections.
+
+    Args:
+        url: URL of a file to download, ideally on the site albums come from
+        streams: Numbers of parallel connections to compare
+        duration: Seconds each measurement downloads for
+        timeout: Request timeout in seconds
+
+    Returns:
+        True if all measurements succeeded, False otherwise
+    """
+    try:
+        speeds = {}
+        for count in streams:
+            logger.info(f"Measuring download speed with {count} connections: {url}")
+            speeds[count] = measure_throughput(url, count, duration, timeout)
+            print(
+                f"测速 (Benchmark): {count} 个连接 (streams): "
+                f"{speeds[count] / 1024 / 1024:.2f} MB/s"
+            )
+
+        print(f"建议并行数 (Suggested workers): {suggest_workers(speeds)}")
+        return True
+
+    except Exception as e:
+        logger.error(f"Benchmark failed: {e}")
+        print(f"\n错误: {e}")
+        return False
+
+
+def track_range_arg(value: str) -> str:
+    """Validate a --range argument."""
+    try:
+        parse_track_range(value)
+    except ValueError as e:
+        raise argparse.ArgumentTypeError(str(e)) from e
+    return value
+
+
+def title_pattern_arg(value: str) -> str:
+    """Validate an --include or --exclude argument."""
+    try:
+        compile_title_pattern(value)
+    except ValueError as e:
+        raise argparse.ArgumentTypeError(str(e)) from e
+    return value
+
+
+def positive_int_arg(value: str) -> int:
+    """Validate a positive integer argument."""
+    try:
+        number = int(value)
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"invalid integer: {value!r}") from None
+    if number < 1:
+        raise argparse.ArgumentTypeError(f"must be at least 1: {number}")
+    return number
+
+
+def non_negative_int_arg(value: str) -> int:
+    """Validate a non-negative integer argument."""
+    try:
+        number = int(value)
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"invalid integer: {value!r}") from None
+    if number < 0:
+        raise argparse.ArgumentTypeError(f"must not be negative: {number}")
+    return number
+
+
+def replacement_arg(value: str) -> str:
+    """Validate a --replace-char argument."""
+    if re.search(ILLEGAL_FILENAME_CHARS, value):
+        raise argparse.ArgumentTypeError(f"not allowed in file names: {value!r}")
+    return value
+
+
 def create_parser() -> argparse.ArgumentParser:
     """Create CLI argument parser."""
     parser = argparse.ArgumentParser(
@@ -275,18 +2027,95 @@ Examples:
   # Download first 10 songs only
   %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --limit 10
 
+  # Download songs 5 to 12 and 15 only
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --range 5-12,15
+
+  # Download only songs whose title matches a glob, or a regex with "re:"
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --include "*grace*"
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --exclude "re:\\(live\\)$"
+
+  # Normalize loudness of the downloaded songs (requires ffmpeg)
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --normalize
+
+  # Also merge the album into one audiobook file with chapters (requires ffmpeg)
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --merge m4b
+
+  # Skip remixes and instrumental versions
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --skip-title remix -S instrumental
+
+  # Download 4 songs at once
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --workers 4
+
   # Overwrite existing files
   %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --overwrite
 
+  # Download only tracks added or not completed since the last download
+  %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --sync
+
   # Increase timeout and retries for slow connections
   %(prog)s --url https://www.izanmei.cc/album/hymns-442-1.html --timeout 120 --retries 5
 
+  # List the albums of an artist
+  %(prog)s --url <artist page URL> --list-albums
+
+  # Re-download missing or corrupted files of a downloaded album
+  %(prog)s --repair ./my_music
+
+  # Measure the download speed to a file and suggest a --workers value
+  %(prog)s --benchmark <file URL>
+
+  # List the supported sites, as JSON for other programs
+  %(prog)s --list-sources --json
+
+  # Also support the sites defined in a folder of JSON source definitions
+  %(prog)s --url <album URL> --sources-dir ./my_sources
+
+  # Send browser cookies for sites that need a login
+  %(prog)s --url <album URL> --cookies cookies.txt
+
 For more information, visit: https://github.com/ChHsiching/resource-fetcher
         """,
         formatter_class=argparse.RawDescriptionHelpFormatter,
     )
 
-    parser.add_argument("--url", required=True, help="Album page URL to download")
+    target = parser.add_mutually_exclusive_group(required=True)
+    target.add_argument("--url", help="Album page URL to download")
+    target.add_argument(
+        "--repair",
+        metavar="FOLDER",
+        help="Verify a downloaded album against its manifest and re-download damaged files",
+    )
+    target.add_argument(
+        "--benchmark",
+        metavar="URL",
+        help="Measure the download speed to URL with 1, 2 and 4 connections",
+    )
+    target.add_argument(
+        "--list-sources",
+        action="store_true",
+        help="List the supported sites with their URL patterns and capabilities",
+    )
+
+    parser.add_argument(
+        "--list-albums",
+        action="store_true",
+        help="List the album URLs linked from the --url artist page instead of downloading",
+    )
+    parser.add_argument(
+        "--sources-dir",
+        metavar="FOLDER",
+        help="Folder with custom source definitions (*.json) for sites not built in",
+    )
+    parser.add_argument(
+        "--cookies",
+        metavar="FILE",
+        help="Cookies exported from a browser (cookies.txt) for sites that need a login",
+    )
+    parser.add_argument(
+        "--json",
+        action="store_true",
+        help="Print --list-sources output as JSON",
+    )
 
     parser.add_argument(
         "--output",
@@ -303,10 +2132,138 @@ For more information, visit: https://github.com/ChHsiching/resource-fetcher
         help="Limit number of songs to download (e.g., --limit 10 for first 10 songs)",
     )
 
+    parser.add_argument(
+        "--range",
+        dest="track_range",
+        type=track_range_arg,
+        metavar="TRACKS",
+        help='Download only these track numbers, e.g. "5-12,15" or "20-"',
+    )
+
+    parser.add_argument(
+        "--include",
+        type=title_pattern_arg,
+        metavar="PATTERN",
+        help='Download only songs whose title matches PATTERN (glob, or regex with "re:")',
+    )
+
+    parser.add_argument(
+        "--exclude",
+        type=title_pattern_arg,
+        metavar="PATTERN",
+        help='Leave out songs whose title matches PATTERN (glob, or regex with "re:")',
+    )
+
+    parser.add_argument(
+        "--skip-title",
+        "-S",
+        dest="skip_titles",
+        action="append",
+        metavar="TEXT",
+        help="Skip songs whose title contains TEXT (case-insensitive, repeatable)",
+    )
+
+    parser.add_argument(
+        "--normalize",
+        action="store_true",
+        help="Normalize loudness (EBU R128) of downloaded songs with ffmpeg",
+    )
+
+    parser.add_argument(
+        "--merge",
+        choices=sorted(MERGE_FORMATS),
+        help="Also merge the album into one file with a chapter per song (requires ffmpeg)",
+    )
+
+    parser.add_argument(
+        "--replace-char",
+        dest="replacement",
+        type=replacement_arg,
+        default="_",
+        metavar="TEXT",
+        help='Replace characters not allowed in file names with TEXT (default: "_")',
+    )
+
+    parser.add_argument(
+        "--max-name-length",
+        type=positive_int_arg,
+        metavar="N",
+        help="Shorten file names to at most N characters, extension included",
+    )
+
     parser.add_argument(
         "--overwrite", action="store_true", help="Overwrite existing files instead of skipping them"
     )
 
+    parser.add_argument(
+        "--checksum-sidecars",
+        action="store_true",
+        help="Write a .sha256 file next to each downloaded file, in sha256sum format",
+    )
+
+    parser.add_argument(
+        "--min-size",
+        type=non_negative_int_arg,
+        default=0,
+        metavar="BYTES",
+        help="Fail downloaded files smaller than BYTES, e.g. truncated or empty downloads "
+        "(0 to not check)",
+    )
+
+    parser.add_argument(
+        "--detect-silence",
+        action="store_true",
+        help="Fail downloaded files that contain only silence (requires ffmpeg)",
+    )
+
+    parser.add_argument(
+        "--scan-command",
+        metavar="COMMAND",
+        help="Check each downloaded file with COMMAND (file path appended, exit code 0 = clean) "
+        "before moving it from quarantine into the output folder",
+    )
+
+    parser.add_argument(
+        "--temp-dir",
+        metavar="DIR",
+        help="Write partial files and conversions to DIR (e.g. a fast local disk) "
+        "and move them into the output folder when complete",
+    )
+
+    parser.add_argument(
+        "--confirm-tracks",
+        action="store_true",
+        help="Ask before downloading each song (answer yes or no, optionally after the "
+        "track number)",
+    )
+
+    parser.add_argument(
+        "--confirm-timeout",
+        type=positive_int_arg,
+        default=CONFIRM_TIMEOUT,
+        metavar="SECONDS",
+        help=f"Seconds to wait for an answer with --confirm-tracks (default: {CONFIRM_TIMEOUT})",
+    )
+
+    parser.add_argument(
+        "--confirm-default",
+        choices=("download", "skip"),
+        default="download",
+        help="What to do with a song nobody confirmed in time (default: download)",
+    )
+
+    parser.add_argument(
+        "--relax-politeness",
+        action="store_true",
+        help="Ignore the site's recommended delay, concurrency and download hours",
+    )
+
+    parser.add_argument(
+        "--sync",
+        action="store_true",
+        help="Download only tracks that are new or incomplete according to the album manifest",
+    )
+
     parser.add_argument(
         "--timeout",
         type=int,
@@ -332,10 +2289,50 @@ For more information, visit: https://github.com/ChHsiching/resource-fetcher
     )
 
     parser.add_argument(
-        "--verbose", "-v", action="store_true", help="Enable verbose output for debugging"
+        "--workers",
+        "-w",
+        type=positive_int_arg,
+        default=1,
+        metavar="N",
+        help="Number of songs to download at once, without delay between them (default: 1)",
+    )
+
+    parser.add_argument(
+        "--max-speed",
+        type=positive_int_arg,
+        metavar="KBPS",
+        help="Download speed limit in KB/s for all songs together (default: unlimited)",
+    )
+
+    verbosity = parser.add_mutually_exclusive_group()
+    verbosity.add_argument(
+        "--log-level",
+        choices=LOG_LEVELS,
+        default="info",
+        help="Logging verbosity (default: info)",
+    )
+    verbosity.add_argument(
+        "--verbose",
+        "-v",
+        action="store_const",
+        const="debug",
+        dest="log_level",
+        help="Enable verbose output for debugging (same as --log-level debug)",
+    )
+    verbosity.add_argument(
+        "--quiet",
+        "-q",
+        action="store_const",
+        const="warning",
+        dest="log_level",
+        help="Only log warnings and errors (same as --log-level warning)",
     )
 
-    parser.add_argument("--version", action="version", version="%(prog)s 1.0.0")
+    parser.add_argument(
+        "--version",
+        action="version",
+        version=f"%(prog)s 1.0.0 (Python {platform.python_version()})",
+    )
 
     return parser
 
@@ -346,27 +2343,89 @@ def main() -> None:
     args = parser.parse_args()
 
     # Set logging level
-    if args.verbose:
-        logging.getLogger().setLevel(logging.DEBUG)
-        logger.debug("Verbose mode enabled")
+    logging.getLogger().setLevel(args.log_level.upper())
+    logger.debug(f"Log level set to {args.log_level}")
+
+    if args.sources_dir:
+        load_custom_sources(Path(args.sources_dir))
+
+    if args.list_sources:
+        list_sources(as_json=args.json)
+        sys.exit(0)
+
+    cookies = None
+    if args.cookies:
+        try:
+            cookies = load_cookies(Path(args.cookies))
+        except ValueError as e:
+            parser.error(str(e))
+        logger.info(f"Loaded {len(cookies)} cookies from {args.cookies}")
+
+    temp_dir = Path(args.temp_dir) if args.temp_dir else None
+
+    if args.repair:
+        success = repair_album(
+            folder=Path(args.repair),
+            timeout=args.timeout,
+            retries=args.retries,
+            delay=args.delay,
+            cookies=cookies,
+            max_speed=args.max_speed,
+            checksum_sidecars=args.checksum_sidecars,
+            scan_command=args.scan_command,
+            temp_dir=temp_dir,
+            min_size=args.min_size,
+            detect_silence=args.detect_silence,
+        )
+        sys.exit(0 if success else 1)
+
+    if args.benchmark:
+        if not args.benchmark.startswith("http"):
+            parser.error(f"Invalid URL: {args.benchmark}")
+        sys.exit(0 if benchmark_connection(args.benchmark, timeout=args.timeout) else 1)
 
     # Validate URL
     if not args.url.startswith("http"):
         parser.error(f"Invalid URL: {args.url}")
 
+    if args.list_albums:
+        sys.exit(0 if list_albums(args.url, cookies=cookies) else 1)
+
     # Convert output to Path
     output_dir = Path(args.output)
 
+    options: dict[str, Any] = {
+        "limit": args.limit,
+        "timeout": args.timeout,
+        "retries": args.retries,
+        "delay": args.delay,
+        "track_range": args.track_range,
+        "skip_titles": args.skip_titles,
+        "include": args.include,
+        "exclude": args.exclude,
+        "normalize": args.normalize,
+        "merge": args.merge,
+        "workers": args.workers,
+        "replacement": args.replacement,
+        "max_name_length": args.max_name_length,
+        "cookies": cookies,
+        "max_speed": args.max_speed,
+        "checksum_sidecars": args.checksum_sidecars,
+        "scan_command": args.scan_command,
+        "temp_dir": temp_dir,
+        "confirm_tracks": args.confirm_tracks,
+        "confirm_timeout": args.confirm_timeout,
+        "confirm_default": args.confirm_default == "download",
+        "relax_politeness": args.relax_politeness,
+        "min_size": args.min_size,
+        "detect_silence": args.detect_silence,
+    }
+
     # Download album
-    success = download_album(
-        url=args.url,
-        output_dir=output_dir,
-        limit=args.limit,
-        overwrite=args.overwrite,
-        timeout=args.timeout,
-        retries=args.retries,
-        delay=args.delay,
-    )
+    if args.sync:
+        success = sync_album(args.url, output_dir, **options)
+    else:
+        success = download_album(args.url, output_dir, overwrite=args.overwrite, **options)
 
     # Exit with appropriate code
     sys.exit(0 if success else 1)
@@ -0,0 +1,217 @@
+"""CustomAdapter for sites described by user-supplied definition files."""
+
+import fnmatch
+import json
+import re
+from pathlib import Path
+from typing import Any
+from urllib.parse import urljoin
+
+from resource_fetcher_core.core.interfaces import SiteAdapter
+from resource_fetcher_core.core.models import Album, PolitenessPolicy, Song
+
+# Album title used when a page has none
+UNKNOWN_ALBUM_TITLE = "未知专辑"
+
+
+class CustomAdapter(SiteAdapter):
+    """
+    Adapter extracting albums with regular expressions from a definition.
+
+    A definition is a JSON object with these keys:
+    - name: Site name
+    - url_patterns: Globs of the album page URLs, e.g. ["*example.com/album/*"]
+    - song_pattern: Regex with named groups "id" and "title", and optionally
+      "url" for the audio URL
+    - audio_url: Audio URL template with "{id}", if song_pattern has no "url" group
+    - album_title_pattern: Optional regex whose first group is the album title
+    - album_link_pattern: Optional regex whose first group is an album URL on
+      an artist page
+    - challenge_pattern: Optional regex matching captcha or verification pages;
+      its first group, if any, is the URL where the user passes the challenge
+    - base_url: Optional URL relative audio and album URLs are resolved against
+    - politeness: Optional recommended "min_delay" (seconds), "max_workers"
+      and "window" (["HH:MM", "HH:MM"] downloads may start in)
+    """
+
+    def __init__(self, definition: dict[str, Any]) -> None:
+        """
+        Create an adapter from a source definition.
+
+        Args:
+            definition: The parsed definition
+
+        Raises:
+            ValueError: If a required key is missing or a pattern is invalid
+        """
+        try:
+            self._name = str(definition["name"]).strip()
+            self.URL_PATTERNS = tuple(str(pattern) for pattern in definition["url_patterns"])
+            self.song_pattern = re.compile(definition["song_pattern"])
+            self.album_title_pattern = re.compile(
+                definition.get("album_title_pattern", r"<h1[^>]*>([^<]+)</h1>")
+            )
+            link_pattern = definition.get("album_link_pattern")
+            self.album_link_pattern = re.compile(link_pattern) if link_pattern else None
+            challenge_pattern = definition.get("challenge_pattern")
+            self.challenge_pattern = re.compile(challenge_pattern) if challenge_pattern else None
+        except KeyError as e:
+            raise ValueError(f"Source definition is missing {e}") from e
+        except (TypeError, re.error) as e:
+            raise ValueError(f"Invalid source definition: {e}") from e
+
+        self.audio_url = str(definition.get("audio_url", ""))
+        self.base_url = str(definition.get("base_url", ""))
+
+        if not self._name or not self.URL_PATTERNS:
+            raise ValueError("Source definition needs a name and URL patterns")
+        groups = self.song_pattern.groupindex
+        if "id" not in groups or "title" not in groups:
+            raise ValueError('song_pattern needs named groups "id" and "title"')
+        if "url" not in groups and "{id}" not in self.audio_url:
+            raise ValueError('Source definition needs a "url" group or an audio_url with {id}')
+
+        politeness = definition.get("politeness")
+        if politeness is not None:
+            try:
+                max_workers = politeness.get("max_workers")
+                window = politeness.get("window")
+                if window and len(window) != 2:
+                    raise ValueError("window needs a start and an end time")
+                self.POLITENESS = PolitenessPolicy(
+                    min_delay=float(politeness.get("min_delay", 0)),
+                    max_workers=int(max_workers) if max_workers is not None else None,
+                    window=(str(window[0]), str(window[1])) if window else None,
+                )
+                # Fails on malformed window times
+                self.POLITENESS.seconds_until_window()
+            except (AttributeError, TypeError, ValueError) as e:
+                raise ValueError(f"Invalid politeness in source definition: {e}") from e
+
+    @property
+    def name(self) -> str:
+        """
+        Name of the supported site.
+
+        Returns:
+            The name given in the definition
+        """
+        return self._name
+
+    def capabilities(self) -> list[str]:
+        """
+        List what the adapter can download.
+
+        Returns:
+            "album", plus "artist" if the definition has an album_link_pattern
+        """
+        return ["album", "artist"] if self.album_link_pattern else ["album"]
+
+    def can_handle(self, url: str) -> bool:
+        """
+        Check if URL matches one of the definition's URL patterns.
+
+        Args:
+            url: The URL to check
+
+        Returns:
+            True if any pattern matches, ignoring case
+        """
+        folded = url.casefold()
+        return any(fnmatch.fnmatch(folded, pattern.casefold()) for pattern in self.URL_PATTERNS)
+
+    def extract_album(self, url: str) -> Album:
+        """
+        Extract album information from an album page's HTML.
+
+        Args:
+            url: The album page HTML
+
+        Returns:
+            Album object with all songs
+
+        Raises:
+            ValueError: If no songs found in the HTML
+        """
+        html = url
+        songs: list[Song] = []
+        for match in self.song_pattern.finditer(html):
+            song_id = match.group("id")
+            if any(song.id == song_id for song in songs):
+                continue
+            if "url" in self.song_pattern.groupindex and match.group("url"):
+                audio_url = match.group("url")
+            else:
+                audio_url = self.audio_url.replace("{id}", song_id)
+            songs.append(
+                Song(
+                    id=song_id,
+                    title=match.group("title").strip(),
+                    url=urljoin(self.base_url, audio_url),
+                    metadata={"source": self.name, "track_number": str(len(songs) + 1)},
+                )
+            )
+
+        if not songs:
+            raise ValueError("No songs found in HTML")
+
+        title_match = self.album_title_pattern.search(html)
+        title = title_match.group(1).strip() if title_match else UNKNOWN_ALBUM_TITLE
+        return Album(title=title, url="", songs=songs, source=self.name)
+
+    def detect_challenge(self, html: str) -> str | None:
+        """
+        Check whether a page matches the definition's challenge_pattern.
+
+        Args:
+            html: The fetched page HTML
+
+        Returns:
+            The challenge URL captured by the pattern, else the base URL
+            (possibly ""), or None if the page isn't a challenge
+        """
+        if self.challenge_pattern is None:
+            return None
+        match = self.challenge_pattern.search(html)
+        if not match:
+            return None
+        url = match.group(1) if self.challenge_pattern.groups else ""
+        return urljoin(self.base_url, url) if url else self.base_url
+
+    def extract_album_links(self, html: str) -> list[str]:
+        """
+        Extract the album page URLs linked from an artist page.
+
+        Args:
+            html: The artist page HTML
+
+        Returns:
+            Absolute album page URLs in page order, without duplicates
+        """
+        if self.album_link_pattern is None:
+            return []
+        links = [urljoin(self.base_url, href) for href in self.album_link_pattern.findall(html)]
+        return list(dict.fromkeys(links))
+
+
+def load_source_definition(path: Path) -> CustomAdapter:
+    """
+    Create an adapter from a source definition file.
+
+    Args:
+        path: JSON definition file
+
+    Returns:
+        Adapter for the defined site
+
+    Raises:
+        ValueError: If the file is not valid JSON or not a valid definition
+        OSError: If the file can't be read
+    """
+    try:
+        definition = json.loads(path.read_text(encoding="utf-8"))
+    except json.JSONDecodeError as e:
+        raise ValueError(f"Invalid source definition {path}: {e}") from e
+    if not isinstance(definition, dict):
+        raise ValueError(f"Invalid source definition {path}: expected a JSON object")
+    return CustomAdapter(definition)
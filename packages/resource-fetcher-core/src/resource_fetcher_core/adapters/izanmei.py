@@ -1,9 +1,11 @@
 """IzanmeiAdapter for izanmei.cc website."""
 
 import re
+from urllib.parse import urljoin, urlsplit
 
 from resource_fetcher_core.core.interfaces import SiteAdapter
-from resource_fetcher_core.core.models import Album, Song
+from resource_fetcher_core.core.models import Album, PolitenessPolicy, Song
+from resource_fetcher_core.utils.http import clean_url
 
 
 class IzanmeiAdapter(SiteAdapter):
@@ -15,6 +17,8 @@ class IzanmeiAdapter(SiteAdapter):
 
     BASE_URL = "https://www.izanmei.cc"
     AUDIO_BASE = "https://play.xiaoh.ai/song/p"
+    URL_PATTERNS = ("*izanmei.cc*",)
+    POLITENESS = PolitenessPolicy(min_delay=1.0, max_workers=2)
 
     def can_handle(self, url: str) -> bool:
         """
@@ -28,6 +32,37 @@ class IzanmeiAdapter(SiteAdapter):
         """
         return "izanmei.cc" in url
 
+    def canonicalize_url(self, url: str) -> str:
+        """
+        Bring an izanmei.cc URL into its canonical form.
+
+        Args:
+            url: An izanmei.cc URL
+
+        Returns:
+            The URL on https://www.izanmei.cc without tracking parameters and fragment
+        """
+        parts = urlsplit(clean_url(url))
+        query = f"?{parts.query}" if parts.query else ""
+        return f"{self.BASE_URL}{parts.path or '/'}{query}"
+
+    def content_kind(self, url: str) -> str | None:
+        """
+        Tell what kind of izanmei.cc page a URL points to.
+
+        Args:
+            url: An izanmei.cc URL
+
+        Returns:
+            "album" for /album/ pages, "song" for /song/ pages, None otherwise
+        """
+        path = urlsplit(url).path
+        if re.match(r"/album/[^/]+\.html$", path):
+            return "album"
+        if re.match(r"/song/\d+\.html$", path):
+            return "song"
+        return None
+
     def extract_album(self, url: str) -> Album:
         """
         Extract album information from izanmei.cc HTML.
@@ -134,3 +169,17 @@ class IzanmeiAdapter(SiteAdapter):
         if match:
             return match.group(1).strip()
         return "未知专辑"
+
+    def extract_album_links(self, html: str) -> list[str]:
+        """
+        Extract the album page URLs linked from an izanmei.cc artist page.
+
+        Args:
+            html: The artist page HTML
+
+        Returns:
+            Absolute album page URLs in page order, without duplicates
+        """
+        pattern = r'href="((?:https?://(?:www\.)?izanmei\.cc)?/album/[^"?#]+\.html)"'
+        links = [urljoin(self.BASE_URL, href) for href in re.findall(pattern, html)]
+        return list(dict.fromkeys(links))
@@ -1,5 +1,6 @@
 """Adapter registry for managing website adapters."""
 
+from typing import Any
 
 from resource_fetcher_core.adapters.izanmei import IzanmeiAdapter
 from resource_fetcher_core.core.interfaces import SiteAdapter
@@ -43,4 +44,21 @@ def list_supported_sites() -> list[str]:
     Returns:
         List of supported website names
     """
-    return [adapter.__class__.__name__.replace("Adapter", "") for adapter in ADAPTERS]
+    return [adapter.name for adapter in ADAPTERS]
+
+
+def describe_sources() -> list[dict[str, Any]]:
+    """
+    Describe all supported websites.
+
+    Returns:
+        One entry per adapter with its name, URL patterns and capabilities
+    """
+    return [
+        {
+            "name": adapter.name,
+            "url_patterns": list(adapter.URL_PATTERNS),
+            "capabilities": adapter.capabilities(),
+        }
+        for adapter in ADAPTERS
+    ]
@@ -2,7 +2,8 @@
 
 from abc import ABC, abstractmethod
 
-from resource_fetcher_core.core.models import Album
+from resource_fetcher_core.core.models import Album, PolitenessPolicy
+from resource_fetcher_core.utils.http import clean_url
 
 
 class SiteAdapter(ABC):
@@ -13,8 +14,38 @@ class SiteAdapter(ABC):
     1. Identifying if a URL belongs to its supported site
     2. Extracting album information from the URL
     3. Constructing audio download URLs
+    4. Optionally, listing the albums linked from an artist page
+    5. Optionally, telling what kind of page a URL points to
     """
 
+    # Globs of the URLs can_handle accepts, published by --list-sources
+    URL_PATTERNS: tuple[str, ...] = ()
+
+    # Recommended delay, concurrency and download hours, applied unless relaxed
+    POLITENESS = PolitenessPolicy()
+
+    @property
+    def name(self) -> str:
+        """
+        Name of the supported site.
+
+        Returns:
+            The class name without the "Adapter" suffix
+        """
+        return self.__class__.__name__.replace("Adapter", "")
+
+    def capabilities(self) -> list[str]:
+        """
+        List what the adapter can download.
+
+        Returns:
+            "album", plus "artist" if it can list the albums of an artist page
+        """
+        capabilities = ["album"]
+        if type(self).extract_album_links is not SiteAdapter.extract_album_links:
+            capabilities.append("artist")
+        return capabilities
+
     @abstractmethod
     def can_handle(self, url: str) -> bool:
         """
@@ -44,3 +75,58 @@ class SiteAdapter(ABC):
             requests.RequestException: If network request fails
         """
         pass
+
+    def canonicalize_url(self, url: str) -> str:
+        """
+        Bring a URL of this site into its canonical form.
+
+        Adapters override this to also fix the scheme or host name.
+
+        Args:
+            url: A URL this adapter can handle
+
+        Returns:
+            The URL without tracking parameters and fragment
+        """
+        return clean_url(url)
+
+    def content_kind(self, url: str) -> str | None:
+        """
+        Tell what kind of page a URL points to.
+
+        Args:
+            url: A URL this adapter can handle
+
+        Returns:
+            "album", "song", "playlist" or "artist", or None if unknown
+        """
+        return None
+
+    def detect_challenge(self, html: str) -> str | None:
+        """
+        Check whether a page is a captcha or verification page instead of content.
+
+        Adapters for sites that challenge visitors override this.
+
+        Args:
+            html: The fetched page HTML
+
+        Returns:
+            URL of the page where the user can pass the challenge ("" if
+            unknown), or None if the page isn't a challenge
+        """
+        return None
+
+    def extract_album_links(self, html: str) -> list[str]:
+        """
+        Extract the album page URLs linked from an artist page.
+
+        Adapters for sites with artist pages override this.
+
+        Args:
+            html: The artist page HTML
+
+        Returns:
+            Absolute album page URLs in page order, without duplicates
+        """
+        return []
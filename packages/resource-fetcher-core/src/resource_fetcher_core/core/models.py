@@ -1,9 +1,13 @@
 """Core data models for music downloader."""
 
 from dataclasses import dataclass, field
+from datetime import datetime, timedelta
 from enum import Enum
 from pathlib import Path
 
+# Format of politeness window start and end times
+WINDOW_TIME_FORMAT = "%H:%M"
+
 
 class DownloadStatus(Enum):
     """Download status enumeration."""
@@ -74,12 +78,17 @@ class DownloadResult:
         path: Path to the downloaded file (if successful)
         size: Size of the downloaded file in bytes
         message: Result message
+        duration: Duration of the audio in seconds (None if unknown)
+        transferred: Bytes received over the network, including failed
+            attempts and files that were discarded
     """
 
     status: DownloadStatus
     path: Path | None = None
     size: int = 0
     message: str = ""
+    duration: float | None = None
+    transferred: int = 0
 
     def is_success(self) -> bool:
         """Check if download was successful."""
@@ -88,3 +97,61 @@ class DownloadResult:
     def is_failed(self) -> bool:
         """Check if download failed."""
         return self.status == DownloadStatus.FAILED
+
+
+@dataclass(frozen=True)
+class PolitenessPolicy:
+    """
+    How gently a site should be downloaded from, following its terms of service.
+
+    Attributes:
+        min_delay: Minimum delay between songs in seconds
+        max_workers: Maximum number of songs downloaded at once (None for no limit)
+        window: Optional ("HH:MM", "HH:MM") time of day downloads may start in,
+            spanning midnight if it ends before it starts, e.g. ("01:00", "06:00")
+    """
+
+    min_delay: float = 0.0
+    max_workers: int | None = None
+    window: tuple[str, str] | None = None
+
+    def apply(self, delay: float, workers: int) -> tuple[float, int]:
+        """
+        Bring download settings within the policy.
+
+        Args:
+            delay: Requested delay between songs in seconds
+            workers: Requested number of songs downloaded at once
+
+        Returns:
+            Tuple of (delay, workers) no less polite than the policy
+        """
+        delay = max(delay, self.min_delay)
+        if self.max_workers is not None:
+            workers = min(workers, self.max_workers)
+        return delay, workers
+
+    def seconds_until_window(self, now: datetime | None = None) -> float:
+        """
+        Calculate how long to wait until downloads may start.
+
+        Args:
+            now: Optional current time
+
+        Returns:
+            Seconds until the window opens, 0 inside the window or without one
+        """
+        if self.window is None:
+            return 0.0
+
+        now = now or datetime.now()
+        start, end = (datetime.strptime(value, WINDOW_TIME_FORMAT).time() for value in self.window)
+        moment = now.time()
+        inside = start <= moment < end if start <= end else moment >= start or moment < end
+        if inside:
+            return 0.0
+
+        opening = datetime.combine(now.date(), start)
+        if opening <= now:
+            opening += timedelta(days=1)
+        return (opening - now).total_seconds()
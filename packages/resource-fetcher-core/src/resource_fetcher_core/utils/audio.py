@@ -0,0 +1,329 @@
+"""Audio file inspection and processing using ffprobe and ffmpeg."""
+
+import re
+import shutil
+import subprocess
+import tempfile
+from collections.abc import Callable
+from pathlib import Path
+
+from resource_fetcher_core.utils.storage import move_staged_file
+
+# EBU R128 loudness normalization target: integrated loudness (LUFS),
+# true peak (dBTP) and loudness range (LU)
+LOUDNESS_TARGET = -16.0
+TRUE_PEAK = -1.5
+LOUDNESS_RANGE = 11.0
+
+# Peak volume (dBFS) at or below which a whole file counts as silent
+SILENCE_THRESHOLD = -60.0
+
+# Output formats for merged albums: ffmpeg muxer and audio codec options
+MERGE_FORMATS = {
+    "m4b": ["-f", "mp4", "-c:a", "aac", "-b:a", "128k"],
+    "mka": ["-f", "matroska", "-c:a", "copy"],
+}
+
+
+def probe_duration(path: Path, timeout: int = 30) -> float | None:
+    """
+    Read the duration of an audio file with ffprobe.
+
+    Args:
+        path: Audio file
+        timeout: Maximum time to wait for ffprobe in seconds
+
+    Returns:
+        Duration in seconds, or None if ffprobe is not installed
+
+    Raises:
+        ValueError: If ffprobe can't read a duration from the file
+    """
+    ffprobe = shutil.which("ffprobe")
+    if not ffprobe:
+        return None
+
+    cmd = [
+        ffprobe,
+        "-v",
+        "error",
+        "-show_entries",
+        "format=duration",
+        "-of",
+        "default=noprint_wrappers=1:nokey=1",
+        str(path),
+    ]
+    try:
+        result = subprocess.run(cmd, capture_output=True, text=True, timeout=timeout, check=True)
+        return float(result.stdout.strip())
+    except subprocess.CalledProcessError as e:
+        raise ValueError(e.stderr.strip() or f"ffprobe exited with code {e.returncode}") from e
+    except subprocess.TimeoutExpired as e:
+        raise ValueError(f"ffprobe timed out after {timeout} seconds") from e
+    except ValueError as e:
+        raise ValueError(f"no duration reported for {path.name}") from e
+
+
+def is_silent(path: Path, threshold: float = SILENCE_THRESHOLD, timeout: int = 300) -> bool | None:
+    """
+    Check whether an audio file contains only silence, with ffmpeg's volumedetect filter.
+
+    Args:
+        path: Audio file
+        threshold: Peak volume in dBFS at or below which the file is silent
+        timeout: Maximum time to wait for ffmpeg in seconds
+
+    Returns:
+        True if the file's peak volume is at or below the threshold, False if
+        not, None if ffmpeg is not installed
+
+    Raises:
+        ValueError: If ffmpeg can't decode the file
+    """
+    ffmpeg = shutil.which("ffmpeg")
+    if not ffmpeg:
+        return None
+
+    cmd = [
+        ffmpeg,
+        "-hide_banner",
+        "-nostdin",
+        "-i",
+        str(path),
+        "-af",
+        "volumedetect",
+        "-f",
+        "null",
+        "-",
+    ]
+    try:
+        result = subprocess.run(cmd, capture_output=True, text=True, timeout=timeout, check=True)
+    except subprocess.CalledProcessError as e:
+        error = e.stderr.strip().splitlines()[-1] if e.stderr.strip() else ""
+        raise ValueError(error or f"ffmpeg exited with code {e.returncode}") from e
+    except subprocess.TimeoutExpired as e:
+        raise ValueError(f"ffmpeg timed out after {timeout} seconds") from e
+
+    match = re.search(r"max_volume:\s*(-?inf|-?[\d.]+) dB", result.stderr)
+    if not match:
+        raise ValueError(f"no volume reported for {path.name}")
+    return float(match.group(1)) <= threshold
+
+
+def format_duration(seconds: float) -> str:
+    """
+    Format a duration for display.
+
+    Args:
+        seconds: Duration in seconds
+
+    Returns:
+        "m:ss", or "h:mm:ss" for durations of an hour or more
+    """
+    minutes, secs = divmod(round(seconds), 60)
+    hours, minutes = divmod(minutes, 60)
+    if hours:
+        return f"{hours}:{minutes:02d}:{secs:02d}"
+    return f"{minutes}:{secs:02d}"
+
+
+def normalize_loudness(
+    path: Path,
+    target: float = LOUDNESS_TARGET,
+    timeout: int = 600,
+    temp_dir: Path | None = None,
+) -> bool:
+    """
+    Normalize the loudness of an audio file in place with ffmpeg's loudnorm filter.
+
+    The file is re-encoded to a temporary file next to it (or in temp_dir),
+    which then replaces the original. Tags are kept.
+
+    Args:
+        path: Audio file
+        target: Integrated loudness target in LUFS
+        timeout: Maximum time to wait for ffmpeg in seconds
+        temp_dir: Optional folder for the re-encoded file
+
+    Returns:
+        True if the file was normalized, False if ffmpeg is not installed
+
+    Raises:
+        ValueError: If ffmpeg fails to process the file
+    """
+    ffmpeg = shutil.which("ffmpeg")
+    if not ffmpeg:
+        return False
+
+    temp_path = (temp_dir or path.parent) / f"{path.stem}.normalizing{path.suffix}"
+    cmd = [
+        ffmpeg,
+        "-hide_banner",
+        "-nostdin",
+        "-y",
+        "-i",
+        str(path),
+        "-af",
+        f"loudnorm=I={target}:TP={TRUE_PEAK}:LRA={LOUDNESS_RANGE}",
+        "-map_metadata",
+        "0",
+        str(temp_path),
+    ]
+    try:
+        subprocess.run(cmd, capture_output=True, text=True, timeout=timeout, check=True)
+        move_staged_file(temp_path, path)
+    except subprocess.CalledProcessError as e:
+        error = e.stderr.strip().splitlines()[-1] if e.stderr.strip() else ""
+        raise ValueError(error or f"ffmpeg exited with code {e.returncode}") from e
+    except subprocess.TimeoutExpired as e:
+        raise ValueError(f"ffmpeg timed out after {timeout} seconds") from e
+    except OSError as e:
+        raise ValueError(str(e)) from e
+    finally:
+        temp_path.unlink(missing_ok=True)
+    return True
+
+
+def _escape_metadata(value: str) -> str:
+    """Escape a value for an ffmpeg metadata file."""
+    for char in ("\\", "=", ";", "#", "\n"):
+        value = value.replace(char, "\\" + char)
+    return value
+
+
+def _chapter_metadata(title: str, chapters: list[tuple[str, float]]) -> str:
+    """
+    Build an ffmpeg metadata file with one chapter per track.
+
+    Args:
+        title: Title of the merged file
+        chapters: Title and duration in seconds of each track, in order
+
+    Returns:
+        Contents of the metadata file
+    """
+    lines = [";FFMETADATA1", f"title={_escape_metadata(title)}", ""]
+    start = 0
+    for chapter_title, duration in chapters:
+        end = start + round(duration * 1000)
+        lines += [
+            "[CHAPTER]",
+            "TIMEBASE=1/1000",
+            f"START={start}",
+            f"END={end}",
+            f"title={_escape_metadata(chapter_title)}",
+            "",
+        ]
+        start = end
+    return "\n".join(lines)
+
+
+def merge_chapters(
+    tracks: list[tuple[Path, str]],
+    output: Path,
+    title: str = "",
+    progress_callback: Callable[[int], None] | None = None,
+    temp_dir: Path | None = None,
+) -> None:
+    """
+    Concatenate audio files into one file with a chapter per track.
+
+    The format is chosen by the output suffix (see MERGE_FORMATS): ".m4b"
+    re-encodes to AAC for audiobook players, ".mka" copies the audio as is.
+
+    Args:
+        tracks: Audio file and chapter title of each track, in order
+        output: Merged file to write
+        title: Title of the merged file
+        progress_callback: Optional callback with the conversion progress in percent
+        temp_dir: Optional folder the file is written to before it is moved to output
+
+    Raises:
+        ValueError: If the format is unsupported, ffmpeg or ffprobe is not
+                    installed, or a file can't be processed
+    """
+    codec_options = MERGE_FORMATS.get(output.suffix.lower().lstrip("."))
+    if codec_options is None:
+        raise ValueError(f"Unsupported merge format: {output.suffix}")
+    ffmpeg = shutil.which("ffmpeg")
+    if not tracks:
+        raise ValueError("No files to merge")
+    if not ffmpeg:
+        raise ValueError("ffmpeg not found")
+
+    chapters = []
+    for path, chapter_title in tracks:
+        duration = probe_duration(path)
+        if duration is None:
+            raise ValueError("ffprobe not found")
+        chapters.append((chapter_title, duration))
+    total_ms = sum(duration for _, duration in chapters) * 1000
+
+    with tempfile.TemporaryDirectory(prefix="resource-fetcher-staged-", dir=temp_dir) as work_dir:
+        file_list = Path(work_dir) / "files.txt"
+        metadata = Path(work_dir) / "chapters.txt"
+        staged = Path(work_dir) / output.name if temp_dir else output
+        escaped = [str(path.resolve()).replace("'", "'\\''") for path, _ in tracks]
+        file_list.write_text("".join(f"file '{path}'\n" for path in escaped), encoding="utf-8")
+        metadata.write_text(_chapter_metadata(title, chapters), encoding="utf-8")
+
+        cmd = [
+            ffmpeg,
+            "-hide_banner",
+            "-nostdin",
+            "-y",
+            "-v",
+            "error",
+            "-progress",
+            "pipe:1",
+            "-f",
+            "concat",
+            "-safe",
+            "0",
+            "-i",
+            str(file_list),
+            "-i",
+            str(metadata),
+            "-map",
+            "0:a",
+            "-map_metadata",
+            "1",
+            "-map_chapters",
+            "1",
+            *codec_options,
+            str(staged),
+        ]
+        try:
+            process = subprocess.Popen(
+                cmd,
+                stdout=subprocess.PIPE,
+                stderr=subprocess.PIPE,
+                text=True,
+                encoding="utf-8",
+                errors="replace",
+            )
+        except OSError as e:
+            raise ValueError(str(e)) from e
+
+        # ffmpeg reports progress as key=value lines; out_time_ms is in microseconds
+        last_percent = -1
+        for line in process.stdout or []:
+            key, _, value = line.strip().partition("=")
+            if key != "out_time_ms" or not value.isdigit() or not total_ms:
+                continue
+            percent = min(100, int(int(value) / 1000 / total_ms * 100))
+            if percent != last_percent and progress_callback:
+                progress_callback(percent)
+            last_percent = percent
+
+        _, stderr = process.communicate()
+        if process.returncode != 0:
+            staged.unlink(missing_ok=True)
+            error = stderr.strip().splitlines()[-1] if stderr.strip() else ""
+            raise ValueError(error or f"ffmpeg exited with code {process.returncode}")
+
+        if staged != output:
+            try:
+                move_staged_file(staged, output)
+            except OSError as e:
+                raise ValueError(str(e)) from e
@@ -0,0 +1,89 @@
+"""Content-type verification of downloaded files by their magic bytes."""
+
+from pathlib import Path
+
+# Bytes read from the start of a file to recognize its format
+SNIFF_SIZE = 64
+
+# Formats a file extension may contain; extensions not listed aren't checked
+EXTENSION_FORMATS = {
+    ".mp3": {"mp3"},
+    ".aac": {"aac"},
+    ".m4a": {"mp4"},
+    ".m4b": {"mp4"},
+    ".mp4": {"mp4"},
+    ".flac": {"flac"},
+    ".ogg": {"ogg"},
+    ".oga": {"ogg"},
+    ".opus": {"ogg"},
+    ".wav": {"wav"},
+    ".mka": {"matroska"},
+}
+
+# Starts of text files servers send instead of audio, e.g. error pages
+TEXT_SIGNATURES = {
+    b"<!doctype html": "html",
+    b"<html": "html",
+    b"<head": "html",
+    b"<body": "html",
+    b"<?xml": "xml",
+    b"{": "json",
+}
+
+
+def sniff_format(head: bytes) -> str | None:
+    """
+    Recognize a file format by its first bytes.
+
+    Args:
+        head: First bytes of the file (SNIFF_SIZE is enough)
+
+    Returns:
+        Format name (e.g. "mp3", "mp4", "html"), or None if not recognized
+    """
+    if head.startswith(b"ID3"):
+        return "mp3"
+    if head.startswith(b"fLaC"):
+        return "flac"
+    if head.startswith(b"OggS"):
+        return "ogg"
+    if head.startswith(b"RIFF") and head[8:12] == b"WAVE":
+        return "wav"
+    if head.startswith(b"\x1a\x45\xdf\xa3"):
+        return "matroska"
+    if head[4:8] == b"ftyp":
+        return "mp4"
+    if len(head) >= 2 and head[0] == 0xFF and head[1] & 0xE0 == 0xE0:
+        # ADTS frames have layer bits 00, MPEG audio frames don't
+        return "aac" if head[1] & 0x06 == 0 else "mp3"
+
+    text = head.lstrip(b"\xef\xbb\xbf \t\r\n").lower()
+    for signature, name in TEXT_SIGNATURES.items():
+        if text.startswith(signature):
+            return name
+    return None
+
+
+def check_content_type(path: Path) -> str | None:
+    """
+    Check that a downloaded file contains the format its extension promises.
+
+    Files whose format isn't recognized pass, so unusual but valid files
+    aren't rejected; a known format that doesn't match the extension (like
+    an HTML error page saved as .mp3) fails.
+
+    Args:
+        path: Downloaded file
+
+    Returns:
+        Description of the mismatch, or None if the file is as expected
+    """
+    expected = EXTENSION_FORMATS.get(path.suffix.lower())
+    if expected is None:
+        return None
+
+    with open(path, "rb") as f:
+        actual = sniff_format(f.read(SNIFF_SIZE))
+    if actual is None or actual in expected:
+        return None
+    return f"expected {'/'.join(sorted(expected))}, got {actual}"
@@ -1,7 +1,28 @@
 """HTTP utility functions."""
 
+import os
 import re
-from urllib.parse import unquote
+from collections.abc import Mapping
+from datetime import datetime, timezone
+from email.utils import parsedate_to_datetime
+from http.cookiejar import LoadError, MozillaCookieJar
+from pathlib import Path
+from urllib.parse import parse_qsl, unquote, urlencode, urlsplit, urlunsplit
+
+# Characters not allowed in Windows file names: \ / : * ? " < > |
+ILLEGAL_FILENAME_CHARS = r'[\\/:*?"<>|]'
+
+# Query parameters added by share buttons and ad trackers, not by the site
+TRACKING_PARAMS = {"fbclid", "gclid", "igshid", "mc_cid", "mc_eid", "spm", "yclid"}
+
+# HTTP status of responses to clients sending too many requests
+TOO_MANY_REQUESTS = 429
+
+# Texts of error pages that block a client for sending too many requests
+BAN_SIGNATURES = ("too many requests", "rate limit", "请求过于频繁", "访问过于频繁")
+
+# Cooldown suggested when a site doesn't say how long to wait (seconds)
+DEFAULT_COOLDOWN = 600
 
 
 def fix_mojibake(filename: str) -> str:
@@ -30,7 +51,106 @@ def fix_mojibake(filename: str) -> str:
         return filename
 
 
-def extract_filename_from_headers(headers: dict, song_id: str = "", song_title: str = "") -> str:
+def clean_url(url: str) -> str:
+    """
+    Remove tracking parameters and the fragment from a URL.
+
+    Scheme and host are lowercased. Parameters starting with "utm_" or
+    listed in TRACKING_PARAMS are dropped; other parameters keep their order.
+
+    Args:
+        url: The URL to clean
+
+    Returns:
+        Cleaned URL
+    """
+    parts = urlsplit(url.strip())
+    query = [
+        (name, value)
+        for name, value in parse_qsl(parts.query, keep_blank_values=True)
+        if not name.lower().startswith("utm_") and name.lower() not in TRACKING_PARAMS
+    ]
+    return urlunsplit(
+        (parts.scheme.lower(), parts.netloc.lower(), parts.path, urlencode(query), "")
+    )
+
+
+def parse_retry_after(value: str | None) -> float | None:
+    """
+    Parse a Retry-After header.
+
+    Args:
+        value: Header value, either seconds or an HTTP date
+
+    Returns:
+        Seconds to wait, or None if the value is missing or invalid
+    """
+    if not value:
+        return None
+    value = value.strip()
+    if value.isdigit():
+        return float(value)
+    try:
+        retry_at = parsedate_to_datetime(value)
+    except (TypeError, ValueError):
+        return None
+    if retry_at.tzinfo is None:
+        retry_at = retry_at.replace(tzinfo=timezone.utc)
+    return max(0.0, (retry_at - datetime.now(timezone.utc)).total_seconds())
+
+
+def detect_rate_ban(status: int, headers: Mapping[str, str], text: str = "") -> float | None:
+    """
+    Check whether a response blocks the client for sending too many requests.
+
+    Args:
+        status: HTTP status code
+        headers: Response headers
+        text: Response body, checked for the BAN_SIGNATURES of error pages
+
+    Returns:
+        Suggested cooldown in seconds (from Retry-After or DEFAULT_COOLDOWN),
+        or None if the response isn't a rate ban
+    """
+    if status < 400:
+        return None
+    lowered = text.lower()
+    if status != TOO_MANY_REQUESTS and not any(sig in lowered for sig in BAN_SIGNATURES):
+        return None
+    retry_after = parse_retry_after(headers.get("Retry-After"))
+    return retry_after if retry_after else float(DEFAULT_COOLDOWN)
+
+
+def load_cookies(path: Path) -> MozillaCookieJar:
+    """
+    Load cookies exported from a browser in the Netscape cookies.txt format.
+
+    Session cookies and expired cookies are kept, since exported files
+    often contain only those.
+
+    Args:
+        path: The cookies.txt file
+
+    Returns:
+        Cookie jar to send with requests
+
+    Raises:
+        ValueError: If the file doesn't exist or isn't in cookies.txt format
+    """
+    if not Path(path).is_file():
+        raise ValueError(f"Cookies file not found: {path}")
+
+    jar = MozillaCookieJar(str(path))
+    try:
+        jar.load(ignore_discard=True, ignore_expires=True)
+    except (LoadError, UnicodeDecodeError) as e:
+        raise ValueError(f"Invalid cookies file {path}: {e}") from e
+    return jar
+
+
+def extract_filename_from_headers(
+    headers: dict, song_id: str = "", song_title: str = "", replacement: str = "_"
+) -> str:
     """
     Extract filename from HTTP response headers.
 
@@ -46,13 +166,14 @@ def extract_filename_from_headers(headers: dict, song_id: str = "", song_title:
         headers: HTTP response headers dictionary
         song_id: Optional song ID for fallback
         song_title: Optional song title for fallback (preferred if provided)
+        replacement: Text replacing characters not allowed in the title
 
     Returns:
         Extracted filename or fallback name
     """
     # Priority 1: Use provided song_title (which includes track number)
     if song_title:
-        safe_title = sanitize_filename(song_title)
+        safe_title = sanitize_filename(song_title, replacement)
         return f"{safe_title}.mp3"
 
     # Priority 2: Try to extract from HTTP headers
@@ -75,17 +196,26 @@ def extract_filename_from_headers(headers: dict, song_id: str = "", song_title:
     return f"{song_id}.mp3" if song_id else "unknown.mp3"
 
 
-def sanitize_filename(filename: str) -> str:
+def sanitize_filename(filename: str, replacement: str = "_", max_length: int | None = None) -> str:
     """
     Sanitize filename to be safe for file system.
 
     Args:
         filename: Original filename
+        replacement: Text replacing characters not allowed in file names
+        max_length: Optional maximum length in characters, longer names are
+            shortened before the extension
 
     Returns:
         Sanitized filename safe for Windows/Linux file systems
     """
-    # Windows illegal characters: \ / : * ? " < > |
-    illegal_chars = r'[\\/:*?"<>|]'
-    clean_name = re.sub(illegal_chars, "_", filename)
-    return clean_name.strip()
+    clean_name = re.sub(ILLEGAL_FILENAME_CHARS, lambda _: replacement, filename).strip()
+
+    if max_length and len(clean_name) > max_length:
+        stem, suffix = os.path.splitext(clean_name)
+        # Keep the extension unless it alone is too long
+        if len(suffix) >= max_length:
+            stem, suffix = clean_name, ""
+        clean_name = stem[: max_length - len(suffix)].rstrip() + suffix
+
+    return clean_name
@@ -0,0 +1,257 @@
+"""Album manifest written alongside downloaded files."""
+
+import dataclasses
+import hashlib
+import json
+from dataclasses import dataclass, field
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_core.core.models import Album, DownloadResult, Song
+
+MANIFEST_FILENAME = "manifest.json"
+MANIFEST_VERSION = 1
+
+# Suffix of per-file checksum sidecars, e.g. "01 Song.mp3.sha256"
+CHECKSUM_SIDECAR_SUFFIX = ".sha256"
+
+
+@dataclass
+class ManifestTrack:
+    """
+    A track recorded in an album manifest.
+
+    Attributes:
+        index: Position of the track in the album (1-based)
+        title: Song title
+        url: Download URL of the audio file
+        status: Download status value (success, failed, skipped)
+        filename: Name of the downloaded file (None if not downloaded)
+        size: File size in bytes
+        sha256: SHA-256 checksum of the file (None if not downloaded)
+        duration: Duration of the audio in seconds (None if unknown)
+    """
+
+    index: int
+    title: str
+    url: str
+    status: str
+    filename: str | None = None
+    size: int = 0
+    sha256: str | None = None
+    duration: float | None = None
+
+
+@dataclass
+class AlbumManifest:
+    """
+    Description of a downloaded album.
+
+    Attributes:
+        source_url: Album page URL
+        title: Album title
+        source: Source website identifier
+        fetched_at: ISO 8601 time the album was downloaded
+        tracks: Tracks in album order
+        version: Manifest format version
+    """
+
+    source_url: str
+    title: str
+    source: str
+    fetched_at: str
+    tracks: list[ManifestTrack] = field(default_factory=list)
+    version: int = MANIFEST_VERSION
+
+
+def file_checksum(path: Path) -> str:
+    """
+    Calculate the SHA-256 checksum of a file.
+
+    Args:
+        path: File to hash
+
+    Returns:
+        Hex digest of the file contents
+    """
+    digest = hashlib.sha256()
+    with open(path, "rb") as f:
+        for chunk in iter(lambda: f.read(65536), b""):
+            digest.update(chunk)
+    return digest.hexdigest()
+
+
+def update_track(
+    track: ManifestTrack, result: DownloadResult, checksum: str | None = None
+) -> None:
+    """
+    Record a download result in a manifest track.
+
+    Sizes and checksums are taken from the file on disk, so skipped
+    (already existing) files are recorded as well.
+
+    Args:
+        track: Track to update
+        result: Result of downloading the track
+        checksum: Optional SHA-256 checksum already calculated for the file
+    """
+    track.status = result.status.value
+    if result.path and result.path.is_file():
+        track.filename = result.path.name
+        track.size = result.path.stat().st_size
+        track.sha256 = checksum or file_checksum(result.path)
+    if result.duration is not None:
+        track.duration = result.duration
+
+
+def build_manifest(
+    album: Album,
+    downloads: list[tuple[int, Song, DownloadResult]],
+    checksums: dict[int, str] | None = None,
+) -> AlbumManifest:
+    """
+    Build a manifest from the results of an album download.
+
+    Args:
+        album: Downloaded album
+        downloads: Track number, song and download result of each downloaded track
+        checksums: Optional SHA-256 checksums already calculated, by track number
+
+    Returns:
+        AlbumManifest for the album
+    """
+    tracks = []
+    for index, song, result in downloads:
+        track = ManifestTrack(index=index, title=song.title, url=song.url, status="")
+        update_track(track, result, (checksums or {}).get(index))
+        tracks.append(track)
+
+    return AlbumManifest(
+        source_url=album.url,
+        title=album.title,
+        source=album.source,
+        fetched_at=datetime.now().isoformat(timespec="seconds"),
+        tracks=tracks,
+    )
+
+
+def merge_manifests(previous: AlbumManifest, current: AlbumManifest) -> AlbumManifest:
+    """
+    Combine the manifest of an earlier, partial download with a new one.
+
+    Tracks from the current manifest replace tracks with the same number.
+    Manifests of different albums are not merged.
+
+    Args:
+        previous: Manifest already in the album folder
+        current: Manifest of the latest download
+
+    Returns:
+        Manifest covering the tracks of both downloads
+    """
+    if previous.source_url != current.source_url:
+        return current
+
+    tracks = {track.index: track for track in previous.tracks}
+    tracks.update({track.index: track for track in current.tracks})
+    return dataclasses.replace(current, tracks=[tracks[index] for index in sorted(tracks)])
+
+
+def write_manifest(output_dir: Path, manifest: AlbumManifest) -> Path:
+    """
+    Write a manifest into an album folder.
+
+    Args:
+        output_dir: Album folder
+        manifest: Manifest to write
+
+    Returns:
+        Path to the written manifest file
+    """
+    output_dir.mkdir(parents=True, exist_ok=True)
+    path = output_dir / MANIFEST_FILENAME
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(dataclasses.asdict(manifest), f, indent=2, ensure_ascii=False)
+    return path
+
+
+def write_checksum_sidecars(output_dir: Path, manifest: AlbumManifest) -> list[Path]:
+    """
+    Write a checksum sidecar next to each downloaded file of an album.
+
+    Sidecars use the sha256sum format ("<checksum>  <file name>"), so they
+    can be checked with "sha256sum -c".
+
+    Args:
+        output_dir: Album folder
+        manifest: Manifest of the album
+
+    Returns:
+        Paths to the written sidecar files
+    """
+    written = []
+    for track in manifest.tracks:
+        if not track.filename or not track.sha256:
+            continue
+        path = output_dir / f"{track.filename}{CHECKSUM_SIDECAR_SUFFIX}"
+        with open(path, "w", encoding="utf-8") as f:
+            f.write(f"{track.sha256}  {track.filename}\n")
+        written.append(path)
+    return written
+
+
+def read_manifest(path: Path) -> AlbumManifest:
+    """
+    Read an album manifest.
+
+    Args:
+        path: Manifest file, or the album folder containing it
+
+    Returns:
+        The album manifest
+
+    Raises:
+        FileNotFoundError: If there is no manifest
+        ValueError: If the manifest is invalid or from a newer version
+    """
+    if path.is_dir():
+        path = path / MANIFEST_FILENAME
+    if not path.exists():
+        raise FileNotFoundError(f"Manifest not found: {path}")
+
+    try:
+        with open(path, encoding="utf-8") as f:
+            data = json.load(f)
+        version = data.get("version", 0)
+        if version > MANIFEST_VERSION:
+            raise ValueError(f"Unsupported manifest version: {version}")
+        tracks = [ManifestTrack(**track) for track in data.pop("tracks", [])]
+        return AlbumManifest(**data, tracks=tracks)
+    except (json.JSONDecodeError, AttributeError, TypeError) as e:
+        raise ValueError(f"Invalid manifest {path}: {e}") from e
+
+
+def verify_manifest(folder: Path, manifest: AlbumManifest) -> list[ManifestTrack]:
+    """
+    Find tracks whose files are missing or don't match the manifest.
+
+    Tracks that failed to download are reported as missing.
+
+    Args:
+        folder: Album folder containing the files
+        manifest: Manifest of the album
+
+    Returns:
+        Tracks that need to be downloaded again, in album order
+    """
+    damaged = []
+    for track in manifest.tracks:
+        path = folder / track.filename if track.filename else None
+        if (
+            path is None
+            or not path.is_file()
+            or path.stat().st_size != track.size
+            or (track.sha256 and file_checksum(path) != track.sha256)
+        ):
+            damaged.append(track)
+    return damaged
@@ -0,0 +1,93 @@
+"""Output file paths: Windows path length limit and file name collisions."""
+
+import os
+import sys
+from pathlib import Path
+
+# Longest path most Windows programs can open, including the terminating null
+MAX_PATH = 260
+
+# Prefix lifting the length limit for Windows file APIs
+EXTENDED_PREFIX = "\\\\?\\"
+
+# File systems on these platforms ignore case by default ("Track.mp3" is "track.mp3")
+CASE_INSENSITIVE = sys.platform in ("win32", "darwin")
+
+
+def extended_path(path: Path) -> Path:
+    """
+    Make a path usable beyond MAX_PATH on Windows.
+
+    Absolute paths get the extended-length prefix ("\\\\?\\C:\\..." or
+    "\\\\?\\UNC\\server\\share\\..."). Paths are returned unchanged on other
+    platforms.
+
+    Args:
+        path: File or folder path
+
+    Returns:
+        Path with the extended-length prefix on Windows
+    """
+    if sys.platform != "win32":
+        return path
+
+    absolute = os.path.abspath(path)
+    if absolute.startswith(EXTENDED_PREFIX):
+        return Path(absolute)
+    if absolute.startswith("\\\\"):
+        return Path(EXTENDED_PREFIX + "UNC\\" + absolute[2:])
+    return Path(EXTENDED_PREFIX + absolute)
+
+
+def exceeds_max_path(path: Path) -> bool:
+    """
+    Check whether a path is too long for programs without long path support.
+
+    Args:
+        path: File or folder path
+
+    Returns:
+        True if the absolute path, without extended-length prefix, has
+        MAX_PATH characters or more
+    """
+    absolute = os.path.abspath(path)
+    if absolute.startswith(EXTENDED_PREFIX + "UNC\\"):
+        absolute = "\\\\" + absolute[len(EXTENDED_PREFIX + "UNC\\") :]
+    elif absolute.startswith(EXTENDED_PREFIX):
+        absolute = absolute[len(EXTENDED_PREFIX) :]
+    return len(absolute) >= MAX_PATH
+
+
+def unique_filenames(filenames: list[str], case_insensitive: bool | None = None) -> list[str]:
+    """
+    Number repeated file names so that no two files overwrite each other.
+
+    The first file keeps its name, later ones get " (2)", " (3)", ... before
+    the extension, e.g. "Song.mp3", "Song (2).mp3".
+
+    Args:
+        filenames: File names in download order
+        case_insensitive: Whether names differing only in case collide, defaults
+            to CASE_INSENSITIVE for this platform
+
+    Returns:
+        Unique file names in the same order
+    """
+
+    if case_insensitive is None:
+        case_insensitive = CASE_INSENSITIVE
+
+    def key(name: str) -> str:
+        return name.casefold() if case_insensitive else name
+
+    taken: set[str] = set()
+    unique = []
+    for filename in filenames:
+        stem, suffix = os.path.splitext(filename)
+        candidate, number = filename, 1
+        while key(candidate) in taken:
+            number += 1
+            candidate = f"{stem} ({number}){suffix}"
+        taken.add(key(candidate))
+        unique.append(candidate)
+    return unique
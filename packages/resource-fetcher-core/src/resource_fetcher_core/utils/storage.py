@@ -0,0 +1,234 @@
+"""Robust file writes for output folders on network shares (SMB/NFS)."""
+
+import ctypes
+import errno
+import os
+import shutil
+import sys
+import time
+from collections.abc import Callable
+from pathlib import Path
+from typing import TypeVar
+
+T = TypeVar("T")
+
+# Mounted file system types treated as network shares (Linux)
+NETWORK_FILESYSTEMS = {"cifs", "smb3", "smbfs", "nfs", "nfs4", "afpfs", "9p", "fuse.sshfs"}
+
+# Errors a network share may recover from by itself
+TRANSIENT_ERRNOS = {
+    errno.EIO,
+    errno.EAGAIN,
+    errno.EINTR,
+    errno.EBUSY,
+    errno.ETIMEDOUT,
+    errno.ECONNRESET,
+}
+
+# Errors meaning the share itself is gone
+MOUNT_LOST_ERRNOS = {errno.ENOTCONN, errno.ESTALE}
+
+# Windows: ERROR_BAD_NETPATH, ERROR_UNEXP_NET_ERR, ERROR_NETNAME_DELETED
+MOUNT_LOST_WINERRORS = {53, 59, 64}
+
+# Windows GetDriveTypeW result for network drives
+DRIVE_REMOTE = 4
+
+
+def is_network_path(path: Path) -> bool:
+    """
+    Check whether a path is on a network share.
+
+    Uses /proc/mounts on Linux and the drive type on Windows. Other
+    platforms are treated as local.
+
+    Args:
+        path: File or folder path, need not exist yet
+
+    Returns:
+        True if the path is on an SMB/NFS (or similar) share
+    """
+    resolved = Path(os.path.abspath(path))
+
+    if sys.platform == "win32":
+        if str(resolved).startswith("\\\\"):
+            return True
+        drive = resolved.drive + "\\"
+        return bool(ctypes.windll.kernel32.GetDriveTypeW(drive) == DRIVE_REMOTE)
+
+    try:
+        with open("/proc/mounts", encoding="utf-8") as f:
+            mounts = [line.split()[1:3] for line in f if len(line.split()) >= 3]
+    except OSError:
+        return False
+
+    # The longest mount point containing the path decides
+    best_fstype, best_length = "", -1
+    for mount_point, fstype in mounts:
+        mount_point = mount_point.replace("\\040", " ")
+        if resolved.is_relative_to(mount_point) and len(mount_point) > best_length:
+            best_fstype, best_length = fstype, len(mount_point)
+    return best_fstype in NETWORK_FILESYSTEMS
+
+
+def is_mount_lost_error(error: OSError) -> bool:
+    """
+    Check whether an error means a network share has been disconnected.
+
+    Args:
+        error: Error raised by a file operation
+
+    Returns:
+        True if the share is gone rather than a single operation failing
+    """
+    if getattr(error, "winerror", None) in MOUNT_LOST_WINERRORS:
+        return True
+    return error.errno in MOUNT_LOST_ERRNOS
+
+
+def retry_io(operation: Callable[[], T], retries: int = 3, delay: float = 1.0) -> T:
+    """
+    Run a file operation, retrying transient I/O errors.
+
+    Args:
+        operation: File operation to run
+        retries: Number of attempts
+        delay: Seconds to wait before the first retry, doubled for each further retry
+
+    Returns:
+        Result of the operation
+
+    Raises:
+        OSError: If the error is not transient or all attempts failed
+    """
+    for attempt in range(retries - 1):
+        try:
+            return operation()
+        except OSError as e:
+            if e.errno not in TRANSIENT_ERRNOS:
+                raise
+            # Exponential backoff
+            time.sleep(delay * 2**attempt)
+
+    # Last attempt, errors are no longer retried
+    return operation()
+
+
+def path_available(path: Path) -> bool:
+    """
+    Check whether a folder can be reached.
+
+    Args:
+        path: Folder path
+
+    Returns:
+        True if the folder (or, before it is created, its parent) can be listed
+    """
+    target = path if path.exists() else path.parent
+    try:
+        os.listdir(target)
+    except OSError:
+        return False
+    return True
+
+
+def wait_for_path(path: Path, timeout: float, interval: float = 5.0) -> bool:
+    """
+    Wait for a disconnected folder to become reachable again.
+
+    Args:
+        path: Folder path
+        timeout: Maximum seconds to wait
+        interval: Seconds between checks
+
+    Returns:
+        True if the folder became reachable, False if the timeout expired
+    """
+    deadline = time.monotonic() + timeout
+    while not path_available(path):
+        if time.monotonic() >= deadline:
+            return False
+        time.sleep(interval)
+    return True
+
+
+def move_to_share(source: Path, destination: Path, retries: int = 3) -> None:
+    """
+    Move a finished local file onto a network share.
+
+    The copy is retried on transient errors, flushed to the server with
+    fsync and checked for its size before the local file is removed.
+
+    Args:
+        source: Local file
+        destination: Path on the share
+        retries: Number of attempts for each file operation
+
+    Raises:
+        OSError: If the file could not be copied completely
+    """
+    expected_size = source.stat().st_size
+    try:
+        retry_io(lambda: shutil.copyfile(source, destination), retries)
+        retry_io(lambda: sync_file(destination), retries)
+
+        written_size = destination.stat().st_size
+        if written_size != expected_size:
+            destination.unlink(missing_ok=True)
+            raise OSError(
+                errno.EIO, f"Incomplete copy: {written_size}/{expected_size} bytes", destination
+            )
+    finally:
+        source.unlink(missing_ok=True)
+
+
+def same_volume(first: Path, second: Path) -> bool:
+    """
+    Check whether two folders are on the same volume.
+
+    Files can be renamed between folders on the same volume instead of
+    being copied.
+
+    Args:
+        first: Existing folder
+        second: Existing folder
+
+    Returns:
+        True if both folders are on the same device, False if not or unknown
+    """
+    try:
+        return os.stat(first).st_dev == os.stat(second).st_dev
+    except OSError:
+        return False
+
+
+def move_staged_file(source: Path, destination: Path, retries: int = 3) -> None:
+    """
+    Move a finished file from a staging folder to its destination.
+
+    The file is renamed if both folders are on the same volume, otherwise
+    it is copied and checked like in move_to_share.
+
+    Args:
+        source: Staged file
+        destination: Final path
+        retries: Number of attempts for each file operation
+
+    Raises:
+        OSError: If the file could not be moved completely
+    """
+    if same_volume(source.parent, destination.parent):
+        os.replace(source, destination)
+    else:
+        move_to_share(source, destination, retries)
+
+
+def sync_file(path: Path) -> None:
+    """
+    Flush a file's data to its storage.
+
+    Args:
+        path: File path
+    """
+    with open(path, "rb+") as f:
+        os.fsync(f.fileno())
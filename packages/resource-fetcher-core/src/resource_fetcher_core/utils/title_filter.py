@@ -0,0 +1,82 @@
+"""Track selection by song title."""
+
+import fnmatch
+import re
+
+# Prefix marking a title pattern as a regular expression instead of a glob
+REGEX_PREFIX = "re:"
+
+
+def match_blacklist(title: str, patterns: list[str]) -> str | None:
+    """
+    Find the first blacklist pattern contained in a song title.
+
+    Patterns are matched case-insensitively anywhere in the title,
+    e.g. "remix" matches "Amazing Grace (Remix)".
+
+    Args:
+        title: Song title
+        patterns: Blacklisted title fragments
+
+    Returns:
+        The matching pattern, or None if the title is not blacklisted
+    """
+    folded = title.casefold()
+    for pattern in patterns:
+        if pattern.strip() and pattern.strip().casefold() in folded:
+            return pattern.strip()
+    return None
+
+
+def compile_title_pattern(pattern: str) -> re.Pattern[str]:
+    """
+    Compile a title pattern.
+
+    Patterns are globs matched against the whole title ("*remix*"), or
+    regular expressions searched in the title when prefixed with "re:"
+    ("re:^\\d+\\."). Matching is case-insensitive.
+
+    Args:
+        pattern: Glob or "re:"-prefixed regular expression
+
+    Returns:
+        Compiled pattern, use its search() method to match titles
+
+    Raises:
+        ValueError: If the pattern is empty or not a valid regular expression
+    """
+    if not pattern.strip():
+        raise ValueError("Invalid title pattern: empty pattern")
+
+    if pattern.startswith(REGEX_PREFIX):
+        expression = pattern[len(REGEX_PREFIX) :]
+    else:
+        # Anchor at the start as well, since titles are matched with search()
+        expression = "^" + fnmatch.translate(pattern)
+
+    try:
+        return re.compile(expression, re.IGNORECASE)
+    except re.error as e:
+        raise ValueError(f"Invalid title pattern: {pattern!r} ({e})") from e
+
+
+def title_selected(title: str, include: str | None = None, exclude: str | None = None) -> bool:
+    """
+    Check whether a song title passes the include and exclude patterns.
+
+    Args:
+        title: Song title
+        include: Optional pattern the title must match
+        exclude: Optional pattern the title must not match
+
+    Returns:
+        True if the song should be downloaded
+
+    Raises:
+        ValueError: If a pattern is invalid
+    """
+    if include and not compile_title_pattern(include).search(title):
+        return False
+    if exclude and compile_title_pattern(exclude).search(title):
+        return False
+    return True
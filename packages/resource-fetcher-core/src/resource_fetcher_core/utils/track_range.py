@@ -0,0 +1,56 @@
+"""Track range selection such as "5-12,15"."""
+
+# Inclusive (start, end) pairs of 1-based track numbers; end None means "to the last track"
+TrackRanges = list[tuple[int, int | None]]
+
+
+def parse_track_range(spec: str) -> TrackRanges:
+    """
+    Parse a track range specification.
+
+    The specification is a comma-separated list of track numbers ("15"),
+    inclusive ranges ("5-12") and open ranges ("20-", to the last track).
+
+    Args:
+        spec: Track range specification
+
+    Returns:
+        List of inclusive (start, end) pairs, end None for open ranges
+
+    Raises:
+        ValueError: If the specification is invalid
+    """
+    ranges: TrackRanges = []
+    for part in spec.split(","):
+        part = part.strip()
+        if not part:
+            raise ValueError(f"Invalid track range: {spec!r} (empty part)")
+
+        start_text, separator, end_text = part.partition("-")
+        try:
+            start = int(start_text)
+            end = int(end_text) if end_text.strip() else None
+        except ValueError:
+            raise ValueError(f"Invalid track range: {spec!r} ({part!r} is not a number)") from None
+
+        if not separator:
+            end = start
+        if start < 1 or (end is not None and end < start):
+            raise ValueError(f"Invalid track range: {spec!r} ({part!r} is not a valid range)")
+
+        ranges.append((start, end))
+    return ranges
+
+
+def track_in_range(index: int, ranges: TrackRanges) -> bool:
+    """
+    Check whether a track number is selected by a track range.
+
+    Args:
+        index: 1-based track number
+        ranges: Parsed track range
+
+    Returns:
+        True if the track is selected
+    """
+    return any(start <= index and (end is None or index <= end) for start, end in ranges)
@@ -0,0 +1,171 @@
+"""Per-source concurrency adapted to the error rates downloads run into."""
+
+import dataclasses
+import logging
+import threading
+from collections.abc import Callable
+from dataclasses import dataclass
+from urllib.parse import urlparse
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+
+logger = logging.getLogger(__name__)
+
+# Type of the events describing a change
+CONCURRENCY_ADJUSTED = "concurrency-adjusted"
+
+# Share of a job's tracks failing above which its source is slowed down
+FAILURE_RATE_THRESHOLD = 0.2
+
+# Jobs in a row without problems before a slowed source speeds up a step
+RAMP_UP_AFTER = 3
+
+# Most slow-down steps a source can accumulate
+MAX_LEVEL = 4
+
+# Delay added by the first slow-down step (seconds), doubling with each further step
+DELAY_STEP = 1.0
+
+
+@dataclass
+class ConcurrencyAdjustment:
+    """A change of the speed a source is downloaded with.
+
+    Attributes:
+        source: Host name of the source.
+        level: Slow-down steps in effect after the change, 0 at full speed.
+        reason: Why the speed changed, e.g. "rate limited".
+        type: Event type, always CONCURRENCY_ADJUSTED.
+    """
+
+    source: str
+    level: int
+    reason: str
+    type: str = CONCURRENCY_ADJUSTED
+
+    def describe(self) -> str:
+        """Describe the change for the job log.
+
+        Returns:
+            Text like "Concurrency for example.com slowed to level 2: rate limited".
+        """
+        if self.level == 0:
+            return f"Concurrency for {self.source} back to normal: {self.reason}"
+        return f"Concurrency for {self.source} at slow-down level {self.level}: {self.reason}"
+
+
+def source_of(url: str) -> str:
+    """Get the source a URL is downloaded from.
+
+    Args:
+        url: Album URL.
+
+    Returns:
+        Host name of the URL in lower case, or the URL itself if it has none.
+    """
+    return (urlparse(url).hostname or url).lower()
+
+
+class AdaptiveConcurrency:
+    """Slows down sources that fail or rate limit, and speeds them up again.
+
+    When a job's tracks fail too often or the site rate limits it, the
+    source drops a level: half the workers and a longer delay between
+    tracks for its following jobs. After RAMP_UP_AFTER jobs in a row without
+    problems it goes back up a level. Levels are kept in memory, so a
+    restart begins at full speed. Shared by all lanes of the job manager.
+    """
+
+    def __init__(
+        self, adjustment_callback: Callable[[ConcurrencyAdjustment], None] | None = None
+    ) -> None:
+        """Initialize adaptive concurrency.
+
+        Args:
+            adjustment_callback: Optional callback for each change, called from
+                the download thread.
+        """
+        self.adjustment_callback = adjustment_callback
+        self._levels: dict[str, int] = {}
+        self._clean_jobs: dict[str, int] = {}
+        self._lock = threading.Lock()
+
+    def level(self, url: str) -> int:
+        """Get the slow-down level of a URL's source.
+
+        Args:
+            url: Album URL.
+
+        Returns:
+            Slow-down steps in effect, 0 at full speed.
+        """
+        with self._lock:
+            return self._levels.get(source_of(url), 0)
+
+    def apply(self, url: str, config: DownloadConfig) -> DownloadConfig:
+        """Slow down a job according to its source's level.
+
+        Args:
+            url: Album URL about to be downloaded.
+            config: Configuration the job starts with.
+
+        Returns:
+            The configuration with fewer workers and a longer delay, or the
+            given configuration at full speed.
+        """
+        level = self.level(url)
+        if level == 0:
+            return config
+
+        workers = max(1, config.workers >> level)
+        delay = config.delay + DELAY_STEP * (2**level - 1)
+        logger.info(f"Source {source_of(url)} at level {level}: {workers} workers, {delay}s delay")
+        return dataclasses.replace(config, workers=workers, delay=delay)
+
+    def record(
+        self, url: str, track_count: int, failed_tracks: int, rate_banned: bool
+    ) -> ConcurrencyAdjustment | None:
+        """Record the outcome of a job and adjust its source's level.
+
+        Args:
+            url: Album URL the job downloaded.
+            track_count: Tracks the job downloaded, 0 if unknown.
+            failed_tracks: Tracks that failed.
+            rate_banned: Whether the site rate limited the job.
+
+        Returns:
+            The change, or None if the level stayed the same.
+        """
+        source = source_of(url)
+        failure_rate = failed_tracks / track_count if track_count else 0.0
+        if rate_banned:
+            reason = "rate limited"
+        elif failure_rate > FAILURE_RATE_THRESHOLD:
+            reason = f"{failed_tracks} of {track_count} tracks failed"
+        else:
+            reason = ""
+
+        with self._lock:
+            level = self._levels.get(source, 0)
+            if reason:
+                self._clean_jobs[source] = 0
+                if level == MAX_LEVEL:
+                    return None
+                level += 1
+            else:
+                if level == 0:
+                    return None
+                clean_jobs = self._clean_jobs.get(source, 0) + 1
+                self._clean_jobs[source] = clean_jobs
+                if clean_jobs < RAMP_UP_AFTER:
+                    return None
+                self._clean_jobs[source] = 0
+                level -= 1
+                reason = f"{clean_jobs} jobs without errors"
+            self._levels[source] = level
+
+        adjustment = ConcurrencyAdjustment(source, level, reason)
+        logger.info(adjustment.describe())
+        if self.adjustment_callback:
+            self.adjustment_callback(adjustment)
+        return adjustment
@@ -0,0 +1,212 @@
+"""Backup and restore of the whole application state."""
+
+import dataclasses
+import json
+import logging
+import zipfile
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui import __version__
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir, get_app_data_dir
+
+logger = logging.getLogger(__name__)
+
+# Version of the archive format written by create_backup
+BACKUP_VERSION = 1
+
+# Archive member describing the backup
+BACKUP_MANIFEST = "backup.json"
+
+# Files in the settings directory that make up the application state
+CONFIG_FILES = (
+    "config.json",
+    "profiles.json",
+    "subscriptions.json",
+    "templates.json",
+    "libraries.json",
+    "source_rules.json",
+    "bandwidth_schedule.json",
+    "retention.json",
+    "quota.json",
+    "sounds.json",
+    "maintenance.json",
+    "crash_reports.json",
+    "schema_versions.json",
+)
+
+# Files in the data directory that make up the application state. Job logs,
+# crash reports and the cache are left out, as is the session of the queue.
+DATA_FILES = (
+    "history.jsonl",
+    "notifications.json",
+    "maintenance_runs.json",
+)
+
+
+@dataclasses.dataclass
+class BackupInfo:
+    """Description of a backup archive.
+
+    Attributes:
+        version: Archive format version.
+        app_version: Version of the application that wrote the backup.
+        created_at: ISO 8601 time the backup was written.
+        files: Archive members holding the state, e.g. "config/config.json".
+        credentials: Files with secrets the settings refer to, such as the
+            cookies file, by setting name. Only their paths are kept; the
+            files themselves have to be copied separately.
+    """
+
+    version: int = BACKUP_VERSION
+    app_version: str = __version__
+    created_at: str = ""
+    files: list[str] = dataclasses.field(default_factory=list)
+    credentials: dict[str, str] = dataclasses.field(default_factory=dict)
+
+
+def _state_files(config_dir: Path, data_dir: Path) -> dict[str, Path]:
+    """Map the archive members of the state to their files.
+
+    Args:
+        config_dir: Settings directory.
+        data_dir: Data directory.
+
+    Returns:
+        File of each archive member, whether it exists or not.
+    """
+    files = {f"config/{name}": config_dir / name for name in CONFIG_FILES}
+    files.update({f"data/{name}": data_dir / name for name in DATA_FILES})
+    return files
+
+
+def _credential_references(config_path: Path) -> dict[str, str]:
+    """Read the paths of credential files from the settings.
+
+    Args:
+        config_path: Settings file.
+
+    Returns:
+        Path of each credential file that is set, by setting name.
+    """
+    try:
+        with open(config_path, encoding="utf-8") as f:
+            settings = json.load(f)
+    except (OSError, json.JSONDecodeError):
+        return {}
+    if not isinstance(settings, dict) or not settings.get("cookies_file"):
+        return {}
+    return {"cookies_file": str(settings["cookies_file"])}
+
+
+def create_backup(
+    path: Path,
+    config_dir: Path | None = None,
+    data_dir: Path | None = None,
+) -> BackupInfo:
+    """Write the settings, history, subscriptions and profiles to a zip archive.
+
+    Args:
+        path: Destination zip file.
+        config_dir: Optional settings directory. If not provided, uses the
+            application's settings directory.
+        data_dir: Optional data directory. If not provided, uses the
+            application's data directory.
+
+    Returns:
+        Description of the written backup, also stored in the archive.
+    """
+    config_dir = config_dir or get_app_config_dir()
+    data_dir = data_dir or get_app_data_dir()
+    info = BackupInfo(
+        created_at=datetime.now().isoformat(timespec="seconds"),
+        credentials=_credential_references(config_dir / "config.json"),
+    )
+
+    path.parent.mkdir(parents=True, exist_ok=True)
+    with zipfile.ZipFile(path, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+        for name, file in _state_files(config_dir, data_dir).items():
+            if file.exists():
+                archive.write(file, name)
+                info.files.append(name)
+        archive.writestr(
+            BACKUP_MANIFEST, json.dumps(dataclasses.asdict(info), indent=2, ensure_ascii=False)
+        )
+
+    logger.info(f"Backup of {len(info.files)} files written to {path}")
+    return info
+
+
+def read_backup_info(path: Path) -> BackupInfo:
+    """Read the description of a backup archive.
+
+    Args:
+        path: Backup zip file.
+
+    Returns:
+        Description of the backup.
+
+    Raises:
+        FileNotFoundError: If the backup doesn't exist.
+        ValueError: If the file is not a backup or was written by a newer version.
+    """
+    if not path.exists():
+        raise FileNotFoundError(f"Backup not found: {path}")
+
+    try:
+        with zipfile.ZipFile(path) as archive:
+            info = BackupInfo(**json.loads(archive.read(BACKUP_MANIFEST)))
+    except (zipfile.BadZipFile, KeyError, json.JSONDecodeError, TypeError) as e:
+        raise ValueError(f"Invalid backup: {e}") from e
+
+    if not isinstance(info.version, int) or info.version > BACKUP_VERSION:
+        raise ValueError(f"Unsupported backup version: {info.version}")
+    if not isinstance(info.files, list) or not all(isinstance(name, str) for name in info.files):
+        raise ValueError("Invalid backup: files must be a list of names")
+    return info
+
+
+def restore_backup(
+    path: Path,
+    config_dir: Path | None = None,
+    data_dir: Path | None = None,
+) -> BackupInfo:
+    """Replace the application state with the contents of a backup.
+
+    Files in the backup replace the current ones; files it doesn't contain
+    are left alone. State from an older version is upgraded by the startup
+    migrations, so the application should be restarted afterwards.
+
+    Args:
+        path: Backup zip file written by create_backup.
+        config_dir: Optional settings directory. If not provided, uses the
+            application's settings directory.
+        data_dir: Optional data directory. If not provided, uses the
+            application's data directory.
+
+    Returns:
+        Description of the restored backup.
+
+    Raises:
+        FileNotFoundError: If the backup doesn't exist.
+        ValueError: If the file is not a valid backup. Nothing is restored then.
+    """
+    info = read_backup_info(path)
+    targets = _state_files(config_dir or get_app_config_dir(), data_dir or get_app_data_dir())
+    unknown = [name for name in info.files if name not in targets]
+    if unknown:
+        raise ValueError(f"Invalid backup: unknown files {', '.join(unknown)}")
+
+    with zipfile.ZipFile(path) as archive:
+        try:
+            contents = {name: archive.read(name) for name in info.files}
+        except KeyError as e:
+            raise ValueError(f"Invalid backup: {e}") from e
+
+    for name, data in contents.items():
+        target = targets[name]
+        target.parent.mkdir(parents=True, exist_ok=True)
+        target.write_bytes(data)
+
+    logger.info(f"Restored {len(contents)} files from backup {path}")
+    return info
@@ -0,0 +1,247 @@
+"""Download speed limits by time of day."""
+
+import dataclasses
+import json
+import logging
+from collections.abc import Callable
+from dataclasses import dataclass
+from datetime import datetime, time
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig, get_app_config_dir
+
+logger = logging.getLogger(__name__)
+
+# Format of window start and end times
+TIME_FORMAT = "%H:%M"
+
+# How often the governor checks whether another window became active (seconds)
+GOVERNOR_INTERVAL = 60
+
+
+def parse_time(value: str) -> time:
+    """Parse a time of day.
+
+    Args:
+        value: Time as "HH:MM".
+
+    Returns:
+        Parsed time.
+
+    Raises:
+        ValueError: If the value isn't a valid time.
+    """
+    try:
+        return datetime.strptime(value.strip(), TIME_FORMAT).time()
+    except ValueError:
+        raise ValueError(f"Invalid time {value!r}, expected HH:MM") from None
+
+
+@dataclass
+class BandwidthWindow:
+    """A download speed limit for part of the day.
+
+    Attributes:
+        start: Start time as "HH:MM".
+        end: End time as "HH:MM", excluded. Windows ending before they start
+            span midnight, e.g. 22:00-06:00.
+        max_speed: Speed limit in KB/s, or None for unlimited.
+    """
+
+    start: str
+    end: str
+    max_speed: int | None = None
+
+    def contains(self, moment: time) -> bool:
+        """Check whether a time of day falls into the window.
+
+        Args:
+            moment: Time of day.
+
+        Returns:
+            True if the window is active at that time.
+        """
+        start, end = parse_time(self.start), parse_time(self.end)
+        if start <= end:
+            return start <= moment < end
+        return moment >= start or moment < end
+
+    def describe(self) -> str:
+        """Describe the window for display.
+
+        Returns:
+            Text like "09:00-18:00 1024 KB/s".
+        """
+        limit = f"{self.max_speed} KB/s" if self.max_speed else "unlimited"
+        return f"{self.start}-{self.end} {limit}"
+
+
+class BandwidthSchedule:
+    """Service storing the speed limits that apply at different times of day.
+
+    Windows are tried in order; the first one containing the current time
+    applies. Outside all windows the jobs' own settings apply.
+    """
+
+    def __init__(self, schedule_path: Path | None = None) -> None:
+        """Initialize bandwidth schedule.
+
+        Args:
+            schedule_path: Optional custom path to the schedule file.
+                           If not provided, uses bandwidth_schedule.json in the settings directory.
+        """
+        self.schedule_path = schedule_path or get_app_config_dir() / "bandwidth_schedule.json"
+        logger.debug(f"BandwidthSchedule initialized with path: {self.schedule_path}")
+
+    def load(self) -> list[BandwidthWindow]:
+        """Read the windows from file.
+
+        Returns:
+            Windows in the order they are tried. Empty if the file doesn't
+            exist or is invalid.
+        """
+        if not self.schedule_path.exists():
+            return []
+
+        try:
+            with open(self.schedule_path, encoding="utf-8") as f:
+                return [BandwidthWindow(**item) for item in json.load(f)]
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read bandwidth schedule from {self.schedule_path}: {e}")
+            return []
+
+    def save(self, windows: list[BandwidthWindow]) -> None:
+        """Write the windows to file.
+
+        Args:
+            windows: Windows in the order they are tried.
+        """
+        self.schedule_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.schedule_path, "w", encoding="utf-8") as f:
+            json.dump([dataclasses.asdict(window) for window in windows], f, indent=2)
+
+    def add_window(self, start: str, end: str, max_speed: int | None) -> BandwidthWindow:
+        """Add a window, replacing any existing window with the same times.
+
+        Args:
+            start: Start time as "HH:MM".
+            end: End time as "HH:MM".
+            max_speed: Speed limit in KB/s, or None for unlimited.
+
+        Returns:
+            The stored window.
+
+        Raises:
+            ValueError: If a time is invalid, the window is empty or the
+                limit isn't positive.
+        """
+        start = parse_time(start).strftime(TIME_FORMAT)
+        end = parse_time(end).strftime(TIME_FORMAT)
+        if start == end:
+            raise ValueError("A speed limit window can't start and end at the same time")
+        if max_speed is not None and max_speed <= 0:
+            raise ValueError(f"Speed limit must be positive: {max_speed}")
+
+        window = BandwidthWindow(start, end, max_speed)
+        windows = [item for item in self.load() if (item.start, item.end) != (start, end)]
+        windows.append(window)
+        self.save(windows)
+        logger.info(f"Speed limit window saved: {window.describe()}")
+        return window
+
+    def remove_window(self, start: str, end: str) -> bool:
+        """Remove the window with the given times.
+
+        Args:
+            start: Start time as "HH:MM".
+            end: End time as "HH:MM".
+
+        Returns:
+            True if the window was removed, False if it didn't exist.
+        """
+        windows = self.load()
+        remaining = [item for item in windows if (item.start, item.end) != (start, end)]
+        if len(remaining) == len(windows):
+            return False
+
+        self.save(remaining)
+        logger.info(f"Speed limit window removed: {start}-{end}")
+        return True
+
+    def active_window(self, now: datetime | None = None) -> BandwidthWindow | None:
+        """Find the window that applies at a time.
+
+        Args:
+            now: Optional time to check. If not provided, uses the current time.
+
+        Returns:
+            The first window containing the time, or None.
+        """
+        moment = (now or datetime.now()).time()
+        for window in self.load():
+            try:
+                if window.contains(moment):
+                    return window
+            except ValueError as e:
+                logger.warning(f"Ignoring invalid speed limit window: {e}")
+        return None
+
+    def apply(self, config: DownloadConfig, now: datetime | None = None) -> DownloadConfig:
+        """Apply the active window's limit to a job's configuration.
+
+        Args:
+            config: Configuration of the job.
+            now: Optional time to check. If not provided, uses the current time.
+
+        Returns:
+            The configuration with the stricter of its own and the window's
+            limit; unchanged if no limited window is active.
+        """
+        window = self.active_window(now)
+        if window is None or window.max_speed is None:
+            return config
+        if config.max_speed is not None and config.max_speed <= window.max_speed:
+            return config
+        return dataclasses.replace(config, max_speed=window.max_speed)
+
+
+class BandwidthGovernor:
+    """Follows the schedule and reports when another window becomes active.
+
+    Call check periodically, e.g. every GOVERNOR_INTERVAL seconds.
+    """
+
+    def __init__(
+        self,
+        schedule: BandwidthSchedule,
+        window_changed_callback: Callable[[BandwidthWindow | None], None] | None = None,
+    ) -> None:
+        """Initialize bandwidth governor.
+
+        Args:
+            schedule: Schedule to follow.
+            window_changed_callback: Optional callback with the newly active
+                window, or None when no window applies any more.
+        """
+        self.schedule = schedule
+        self.window_changed_callback = window_changed_callback
+        self.active = schedule.active_window()
+
+    def check(self, now: datetime | None = None) -> bool:
+        """Check whether another window became active.
+
+        Args:
+            now: Optional time to check. If not provided, uses the current time.
+
+        Returns:
+            True if the active window changed.
+        """
+        window = self.schedule.active_window(now)
+        if window == self.active:
+            return False
+
+        self.active = window
+        logger.info(f"Speed limit window changed: {window.describe() if window else 'none'}")
+        if self.window_changed_callback:
+            self.window_changed_callback(window)
+        return True
@@ -0,0 +1,305 @@
+"""Multi-album batch downloads with a scheduling policy."""
+
+import dataclasses
+import logging
+import threading
+from collections import deque
+from collections.abc import Callable
+from dataclasses import dataclass
+
+from resource_fetcher_gui.gui.core.adaptive_concurrency import AdaptiveConcurrency
+from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper, InteractionRequest
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_queue import JOB_COMPLETED, JobQueue, QueuedJob
+from resource_fetcher_gui.gui.core.output_parser import OutputParser, SongProgress
+
+logger = logging.getLogger(__name__)
+
+# Albums are downloaded one after another
+POLICY_SEQUENTIAL = "sequential"
+# Up to max_parallel albums are downloaded at the same time
+POLICY_PARALLEL = "parallel"
+# Tracks of all albums are downloaded in turn
+POLICY_INTERLEAVED = "interleaved"
+
+BATCH_POLICIES = (POLICY_SEQUENTIAL, POLICY_PARALLEL, POLICY_INTERLEAVED)
+
+
+@dataclass
+class BatchProgress:
+    """Aggregate progress of a batch download.
+
+    Attributes:
+        total_jobs: Number of albums in the batch.
+        completed_jobs: Albums downloaded successfully.
+        failed_jobs: Albums that failed or were cancelled.
+        running_jobs: Albums currently downloading.
+        tracks_started: Tracks started across all albums seen so far.
+        tracks_total: Tracks in all albums seen so far.
+        albums_seen: Albums whose track count is known.
+    """
+
+    total_jobs: int
+    completed_jobs: int = 0
+    failed_jobs: int = 0
+    running_jobs: int = 0
+    tracks_started: int = 0
+    tracks_total: int = 0
+    albums_seen: int = 0
+
+    @property
+    def finished(self) -> bool:
+        """Whether every album in the batch has finished."""
+        return self.completed_jobs + self.failed_jobs == self.total_jobs
+
+    @property
+    def percent(self) -> int:
+        """Overall progress of the batch in percent, weighted by track counts.
+
+        Albums that haven't reported their track count yet are weighted as
+        the average album seen so far. Before any album has reported, progress
+        is the share of finished albums.
+        """
+        if self.finished:
+            return 100
+        if not self.albums_seen:
+            return 100 * (self.completed_jobs + self.failed_jobs) // self.total_jobs
+
+        average = self.tracks_total / self.albums_seen
+        estimated_total = self.tracks_total + (self.total_jobs - self.albums_seen) * average
+        if not estimated_total:
+            return 0
+        return min(int(100 * self.tracks_started / estimated_total), 99)
+
+
+class BatchDownload:
+    """Downloads many albums using one job queue per parallel lane.
+
+    Each lane takes the next waiting album when its current album finishes,
+    so lanes stay busy even when albums differ in size.
+    """
+
+    def __init__(
+        self,
+        wrapper_factory: Callable[[], CLIWrapper],
+        policy: str = POLICY_SEQUENTIAL,
+        max_parallel: int = 2,
+        progress_callback: Callable[[BatchProgress], None] | None = None,
+        job_started_callback: Callable[[QueuedJob], None] | None = None,
+        job_finished_callback: Callable[[QueuedJob], None] | None = None,
+        config_resolver: Callable[[str, DownloadConfig], DownloadConfig] | None = None,
+        interaction_callback: Callable[[InteractionRequest], None] | None = None,
+        start_resolver: Callable[[DownloadConfig], DownloadConfig] | None = None,
+        can_start: Callable[[QueuedJob], bool] | None = None,
+        adaptive_concurrency: AdaptiveConcurrency | None = None,
+    ) -> None:
+        """Initialize batch download.
+
+        Args:
+            wrapper_factory: Creates a CLI wrapper for each lane.
+            policy: Scheduling policy ("sequential" or "parallel").
+            max_parallel: Maximum number of albums downloading at once for
+                          the "parallel" policy.
+            progress_callback: Optional callback with aggregate batch progress.
+            job_started_callback: Optional callback when an album starts.
+            job_finished_callback: Optional callback when an album finishes.
+            config_resolver: Optional function adjusting an album's configuration
+                for its URL when it is enqueued.
+            interaction_callback: Optional callback when an album needs input,
+                answered with submit_interaction.
+            start_resolver: Optional function adjusting an album's configuration
+                when it starts downloading.
+            can_start: Optional check before an album starts. If it returns False,
+                the album's lane pauses until resume is called.
+            adaptive_concurrency: Optional per-source slow-down shared by all lanes.
+
+        Raises:
+            ValueError: If the policy or max_parallel is invalid.
+        """
+        if policy == POLICY_INTERLEAVED:
+            raise ValueError(
+                "Interleaved batches need per-track selection, which the CLI doesn't support"
+            )
+        if policy not in BATCH_POLICIES:
+            raise ValueError(f"Unknown batch policy: {policy}")
+        if max_parallel < 1:
+            raise ValueError("max_parallel must be at least 1")
+
+        self.wrapper_factory = wrapper_factory
+        self.policy = policy
+        self.lane_count = max_parallel if policy == POLICY_PARALLEL else 1
+        self.progress_callback = progress_callback
+        self.job_started_callback = job_started_callback
+        self.job_finished_callback = job_finished_callback
+        self.config_resolver = config_resolver
+        self.interaction_callback = interaction_callback
+        self.start_resolver = start_resolver
+        self.can_start = can_start
+        self.adaptive_concurrency = adaptive_concurrency
+        self.lanes: list[JobQueue] = []
+        self.jobs: list[QueuedJob] = []
+        self.progress = BatchProgress(total_jobs=0)
+        self._waiting: deque[tuple[str, DownloadConfig]] = deque()
+        self._album_tracks: dict[str, int] = {}
+        self._lock = threading.RLock()
+
+    def start(self, urls: list[str], config: DownloadConfig) -> None:
+        """Start downloading the albums.
+
+        Args:
+            urls: Album URLs to download.
+            config: Download configuration for every album.
+
+        Raises:
+            ValueError: If no URLs are given.
+        """
+        if not urls:
+            raise ValueError("Batch has no URLs")
+
+        with self._lock:
+            self._waiting.extend((url, config) for url in urls)
+            self.progress = BatchProgress(total_jobs=len(urls))
+            self.lanes = [self._create_lane() for _ in range(min(self.lane_count, len(urls)))]
+
+        logger.info(f"Starting {self.policy} batch: {len(urls)} albums, {len(self.lanes)} lanes")
+        for lane in self.lanes:
+            self._feed(lane)
+
+    def cancel(self) -> None:
+        """Cancel all running and waiting albums."""
+        with self._lock:
+            self.progress.failed_jobs += len(self._waiting)
+            self._waiting.clear()
+
+        for lane in self.lanes:
+            lane.cancel_all()
+        self._emit_progress()
+
+    def resume(self) -> None:
+        """Start albums again in lanes paused by can_start."""
+        for lane in self.lanes:
+            lane.resume()
+
+    def submit_interaction(self, job_id: str, response: str | None) -> bool:
+        """Answer the input an album waits for.
+
+        Args:
+            job_id: Job that asked for input.
+            response: The user's answer, or None to decline.
+
+        Returns:
+            True if the answer was delivered, False if no lane runs the job.
+        """
+        return any(lane.submit_interaction(job_id, response) for lane in self.lanes)
+
+    def confirm_track(self, job_id: str, index: int, accept: bool) -> bool:
+        """Answer whether an album downloads a track.
+
+        Args:
+            job_id: Job that asked.
+            index: Album track number the job asked about.
+            accept: True to download the track, False to skip it.
+
+        Returns:
+            True if the answer was delivered, False if no lane runs the job.
+        """
+        return any(lane.confirm_track(job_id, index, accept) for lane in self.lanes)
+
+    def refresh_speed_limit(self) -> bool:
+        """Pass the running albums' speed limits on again.
+
+        Returns:
+            True if any running album got its limit.
+        """
+        updated = [lane.refresh_speed_limit() for lane in self.lanes]
+        return any(updated)
+
+    def _create_lane(self) -> JobQueue:
+        """Create a job queue with its own CLI wrapper and output parser."""
+        parser = OutputParser()
+        lane = JobQueue(
+            self.wrapper_factory(),
+            config_resolver=self.config_resolver,
+            interaction_callback=self.interaction_callback,
+            start_resolver=self.start_resolver,
+            can_start=self.can_start,
+            adaptive_concurrency=self.adaptive_concurrency,
+        )
+        lane.progress_callback = lambda line: self._on_lane_output(lane, parser, line)
+        lane.job_started_callback = lambda job: self._on_job_started(parser, job)
+        lane.job_finished_callback = lambda job: self._on_job_finished(lane, job)
+        return lane
+
+    def _feed(self, lane: JobQueue) -> None:
+        """Give a lane the next waiting album, if any.
+
+        Args:
+            lane: Idle lane.
+        """
+        with self._lock:
+            if not self._waiting:
+                return
+            url, config = self._waiting.popleft()
+
+        self.jobs.append(lane.enqueue(url, config))
+
+    def _on_job_started(self, parser: OutputParser, job: QueuedJob) -> None:
+        """Track an album that started downloading.
+
+        Args:
+            parser: Output parser of the album's lane.
+            job: The album's job.
+        """
+        parser.reset()
+        with self._lock:
+            self.progress.running_jobs += 1
+        if self.job_started_callback:
+            self.job_started_callback(job)
+        self._emit_progress()
+
+    def _on_lane_output(self, lane: JobQueue, parser: OutputParser, line: str) -> None:
+        """Update track counts from a line of CLI output.
+
+        Args:
+            lane: Lane that produced the line.
+            parser: Output parser of the lane.
+            line: Line of CLI output.
+        """
+        result = parser.parse_line(line)
+        job = lane.current_job
+        if not isinstance(result, SongProgress) or result.status != "downloading" or job is None:
+            return
+
+        with self._lock:
+            if job.job_id not in self._album_tracks:
+                self._album_tracks[job.job_id] = result.total
+                self.progress.tracks_total += result.total
+                self.progress.albums_seen += 1
+            self.progress.tracks_started += 1
+        self._emit_progress()
+
+    def _on_job_finished(self, lane: JobQueue, job: QueuedJob) -> None:
+        """Record a finished album and feed its lane.
+
+        Args:
+            lane: Lane that ran the album.
+            job: The album's job.
+        """
+        with self._lock:
+            self.progress.running_jobs -= 1
+            if job.status == JOB_COMPLETED:
+                self.progress.completed_jobs += 1
+            else:
+                self.progress.failed_jobs += 1
+
+        if self.job_finished_callback:
+            self.job_finished_callback(job)
+        self._emit_progress()
+        self._feed(lane)
+
+    def _emit_progress(self) -> None:
+        """Send the current aggregate progress to the progress callback."""
+        if self.progress_callback:
+            with self._lock:
+                snapshot = dataclasses.replace(self.progress)
+            self.progress_callback(snapshot)
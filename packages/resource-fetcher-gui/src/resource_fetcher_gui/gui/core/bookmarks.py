@@ -0,0 +1,140 @@
+"""Import of album URLs from exported browser bookmarks."""
+
+import json
+import logging
+from dataclasses import dataclass
+from html.parser import HTMLParser
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_core.adapters.registry import get_adapter
+
+from resource_fetcher_gui.gui.core.url_validation import SupportedSource
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class BookmarkCandidate:
+    """A bookmarked URL that can be downloaded.
+
+    Attributes:
+        url: Bookmarked URL.
+        title: Bookmark title, empty if it has none.
+    """
+
+    url: str
+    title: str = ""
+
+
+class _BookmarkHTMLParser(HTMLParser):
+    """Collects links from a Netscape bookmark file (exported by all browsers)."""
+
+    def __init__(self) -> None:
+        super().__init__()
+        self.bookmarks: list[BookmarkCandidate] = []
+        self._current: BookmarkCandidate | None = None
+
+    def handle_starttag(self, tag: str, attrs: list[tuple[str, str | None]]) -> None:
+        href = dict(attrs).get("href")
+        if tag == "a" and href:
+            self._current = BookmarkCandidate(url=href)
+            self.bookmarks.append(self._current)
+
+    def handle_data(self, data: str) -> None:
+        if self._current is not None:
+            self._current.title += data
+
+    def handle_endtag(self, tag: str) -> None:
+        if tag == "a" and self._current is not None:
+            self._current.title = self._current.title.strip()
+            self._current = None
+
+
+def _collect_json_bookmarks(node: Any, bookmarks: list[BookmarkCandidate]) -> None:
+    """Collect bookmarks from a Chrome-style ("url"/"name") or Firefox-style
+    ("uri"/"title") JSON tree.
+
+    Args:
+        node: JSON node to search.
+        bookmarks: List the found bookmarks are appended to.
+    """
+    if isinstance(node, dict):
+        url = node.get("url") or node.get("uri")
+        if isinstance(url, str):
+            title = node.get("name") or node.get("title") or ""
+            bookmarks.append(BookmarkCandidate(url=url, title=str(title)))
+        for value in node.values():
+            _collect_json_bookmarks(value, bookmarks)
+    elif isinstance(node, list):
+        for item in node:
+            _collect_json_bookmarks(item, bookmarks)
+
+
+def read_bookmarks(path: Path) -> list[BookmarkCandidate]:
+    """Read all bookmarks from an exported bookmarks file.
+
+    HTML exports and JSON files (Chrome's Bookmarks file, Firefox backups)
+    are supported.
+
+    Args:
+        path: Bookmarks file.
+
+    Returns:
+        Bookmarks in file order.
+
+    Raises:
+        FileNotFoundError: If the file doesn't exist.
+        ValueError: If the file is not valid JSON.
+    """
+    text = path.read_text(encoding="utf-8", errors="replace")
+
+    if path.suffix.lower() == ".json" or text.lstrip().startswith(("{", "[")):
+        try:
+            document = json.loads(text)
+        except json.JSONDecodeError as e:
+            raise ValueError(f"Invalid bookmarks file {path}: {e}") from e
+        bookmarks: list[BookmarkCandidate] = []
+        _collect_json_bookmarks(document, bookmarks)
+        return bookmarks
+
+    parser = _BookmarkHTMLParser()
+    parser.feed(text)
+    parser.close()
+    return parser.bookmarks
+
+
+def find_supported_bookmarks(
+    path: Path, sources: list[SupportedSource] | None = None
+) -> list[BookmarkCandidate]:
+    """Find bookmarks of albums on supported sites.
+
+    Args:
+        path: Bookmarks file.
+        sources: Sites supported by the installed CLI, including custom
+            sources. If not provided, the site adapters bundled with the GUI decide.
+
+    Returns:
+        Bookmarks with a supported http(s) URL, without duplicates, in file order.
+
+    Raises:
+        FileNotFoundError: If the file doesn't exist.
+        ValueError: If the file is not valid JSON.
+    """
+    candidates = []
+    seen = set()
+    for bookmark in read_bookmarks(path):
+        url = bookmark.url.strip()
+        if not url.startswith("http") or url in seen:
+            continue
+        if sources is None:
+            supported = get_adapter(url) is not None
+        else:
+            supported = any(item.matches(url) for item in sources)
+        if not supported:
+            continue
+        seen.add(url)
+        candidates.append(BookmarkCandidate(url=url, title=bookmark.title))
+
+    logger.info(f"Found {len(candidates)} supported bookmarks in {path}")
+    return candidates
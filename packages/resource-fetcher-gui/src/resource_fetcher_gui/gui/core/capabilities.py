@@ -0,0 +1,112 @@
+"""Optional features available in the current install."""
+
+import logging
+import shutil
+import subprocess
+import sys
+from dataclasses import dataclass, field
+
+from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass(frozen=True)
+class Capabilities:
+    """Optional features available in the current install.
+
+    Attributes:
+        cli: Whether the CLI could be started.
+        cli_options: Options the installed CLI accepts, e.g. "--normalize".
+        ffmpeg: Whether ffmpeg is installed, needed to normalize and merge albums.
+        ffprobe: Whether ffprobe is installed, needed to report track durations.
+        io_priority: Whether running at low priority also lowers the disk priority
+            (ionice on Linux).
+    """
+
+    cli: bool = False
+    cli_options: frozenset[str] = field(default_factory=frozenset)
+    ffmpeg: bool = False
+    ffprobe: bool = False
+    io_priority: bool = False
+
+    def supports(self, option: str) -> bool:
+        """Check whether the installed CLI accepts an option.
+
+        Args:
+            option: Option name, e.g. "--scan-command".
+
+        Returns:
+            True if the option is listed in the CLI's usage.
+        """
+        return option in self.cli_options
+
+    @property
+    def normalize(self) -> bool:
+        """Whether downloaded songs can be normalized."""
+        return self.ffmpeg and self.supports("--normalize")
+
+    @property
+    def merge(self) -> bool:
+        """Whether albums can be merged into one chaptered file."""
+        return self.ffmpeg and self.supports("--merge")
+
+    @property
+    def silence_detection(self) -> bool:
+        """Whether downloaded songs can be checked for silence."""
+        return self.ffmpeg and self.supports("--detect-silence")
+
+    def to_dict(self) -> dict[str, bool]:
+        """Describe which optional features are available.
+
+        Returns:
+            Feature name mapped to availability.
+        """
+        return {
+            "cli": self.cli,
+            "ffmpeg": self.ffmpeg,
+            "ffprobe": self.ffprobe,
+            "io_priority": self.io_priority,
+            "normalize": self.normalize,
+            "merge": self.merge,
+            "checksum_sidecars": self.supports("--checksum-sidecars"),
+            "min_track_size": self.supports("--min-size"),
+            "silence_detection": self.silence_detection,
+            "scan_command": self.supports("--scan-command"),
+            "confirm_tracks": self.supports("--confirm-tracks"),
+            "relax_politeness": self.supports("--relax-politeness"),
+            "cookies": self.supports("--cookies"),
+            "list_albums": self.supports("--list-albums"),
+            "benchmark": self.supports("--benchmark"),
+        }
+
+
+def get_capabilities(cli_wrapper: CLIWrapper | None) -> Capabilities:
+    """Detect which optional features are available.
+
+    Asks the CLI for its options, so call it from a background thread.
+
+    Args:
+        cli_wrapper: Wrapper running the CLI, None if no CLI was found.
+
+    Returns:
+        Available features. CLI features are unavailable if the CLI can't be started.
+    """
+    options: set[str] = set()
+    cli = False
+    if cli_wrapper is not None:
+        try:
+            options = cli_wrapper.list_cli_options()
+            cli = True
+        except (OSError, subprocess.TimeoutExpired) as e:
+            logger.warning(f"Could not read the CLI's options: {e}")
+
+    capabilities = Capabilities(
+        cli=cli,
+        cli_options=frozenset(options),
+        ffmpeg=shutil.which("ffmpeg") is not None,
+        ffprobe=shutil.which("ffprobe") is not None,
+        io_priority=sys.platform.startswith("linux") and shutil.which("ionice") is not None,
+    )
+    logger.info(f"Capabilities: {capabilities.to_dict()}")
+    return capabilities
@@ -0,0 +1,188 @@
+"""Removal of old job logs and files left behind by jobs."""
+
+import dataclasses
+import json
+import logging
+import shutil
+import time
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir
+
+logger = logging.getLogger(__name__)
+
+# Bytes in a megabyte, the unit size limits are configured in
+MEGABYTE = 1024 * 1024
+
+# Seconds in a day, the unit age limits are configured in
+DAY = 24 * 60 * 60
+
+# Name pattern of the files and merge folders the CLI stages in its temp
+# folder. Only these are removed, as the folder may be shared, e.g. /tmp.
+STAGED_FILE_PATTERN = "resource-fetcher-staged-*"
+
+
+@dataclass
+class RetentionPolicy:
+    """How long job logs and leftover job files are kept.
+
+    Attributes:
+        max_age_days: Remove items not changed for this many days, None to keep them.
+        max_size_mb: Remove the oldest items of a folder while it is larger
+            than this, None for no limit.
+    """
+
+    max_age_days: int | None = 30
+    max_size_mb: int | None = None
+
+
+@dataclass
+class CleanupTarget:
+    """A folder whose items are removed by the retention policy.
+
+    Attributes:
+        folder: Folder to clean up.
+        pattern: Glob pattern of the items to consider, e.g. "*.jsonl".
+    """
+
+    folder: Path
+    pattern: str = "*"
+
+
+@dataclass
+class CleanupReport:
+    """Result of a cleanup run.
+
+    Attributes:
+        removed: Removed files and folders.
+        freed_bytes: Disk space reclaimed.
+    """
+
+    removed: list[Path] = field(default_factory=list)
+    freed_bytes: int = 0
+
+
+def item_stats(path: Path) -> tuple[float, int]:
+    """Get the last change time and size of a file or folder.
+
+    Args:
+        path: File or folder.
+
+    Returns:
+        Tuple of (latest modification time of the item or anything in it,
+        total size in bytes).
+    """
+    stat = path.stat()
+    modified = stat.st_mtime
+    size = stat.st_size if path.is_file() else 0
+    if path.is_dir():
+        for child in path.rglob("*"):
+            child_stat = child.stat()
+            modified = max(modified, child_stat.st_mtime)
+            if child.is_file():
+                size += child_stat.st_size
+    return modified, size
+
+
+class CleanupService:
+    """Service applying the retention policy to job logs and leftover files."""
+
+    def __init__(self, policy_path: Path | None = None) -> None:
+        """Initialize cleanup service.
+
+        Args:
+            policy_path: Optional custom path to the retention policy file.
+                         If not provided, uses retention.json in the settings directory.
+        """
+        self.policy_path = policy_path or get_app_config_dir() / "retention.json"
+        logger.debug(f"CleanupService initialized with path: {self.policy_path}")
+
+    def load(self) -> RetentionPolicy:
+        """Read the retention policy.
+
+        Returns:
+            Configured policy. The default one if the file doesn't exist or is invalid.
+        """
+        if not self.policy_path.exists():
+            return RetentionPolicy()
+
+        try:
+            with open(self.policy_path, encoding="utf-8") as f:
+                return RetentionPolicy(**json.load(f))
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read retention policy from {self.policy_path}: {e}")
+            return RetentionPolicy()
+
+    def save(self, policy: RetentionPolicy) -> None:
+        """Write the retention policy to file.
+
+        Args:
+            policy: Policy to configure.
+
+        Raises:
+            ValueError: If a limit isn't positive.
+        """
+        for limit in (policy.max_age_days, policy.max_size_mb):
+            if limit is not None and limit <= 0:
+                raise ValueError(f"Cleanup limit must be positive: {limit}")
+
+        self.policy_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.policy_path, "w", encoding="utf-8") as f:
+            json.dump(dataclasses.asdict(policy), f, indent=2)
+        logger.info(f"Retention policy saved: {policy}")
+
+    def run_cleanup(self, targets: list[CleanupTarget], now: float | None = None) -> CleanupReport:
+        """Remove the items the retention policy no longer keeps.
+
+        Items older than the age limit are removed first. Then the oldest
+        remaining items of each folder are removed until it fits the size
+        limit. Only run it while no job is running, as a running job's
+        files may be removed.
+
+        Args:
+            targets: Folders to clean up. Missing folders are skipped.
+            now: Optional current time (seconds since the epoch).
+
+        Returns:
+            What was removed and how much space that freed.
+        """
+        policy = self.load()
+        now = now or time.time()
+        report = CleanupReport()
+
+        for target in targets:
+            if not target.folder.is_dir():
+                continue
+
+            items = []
+            for path in target.folder.glob(target.pattern):
+                try:
+                    items.append((*item_stats(path), path))
+                except OSError as e:
+                    logger.debug(f"Skipping {path}: {e}")
+            items.sort()
+
+            total = sum(size for _, size, _ in items)
+            for modified, size, path in items:
+                too_old = (
+                    policy.max_age_days is not None and now - modified > policy.max_age_days * DAY
+                )
+                too_big = policy.max_size_mb is not None and total > policy.max_size_mb * MEGABYTE
+                if not (too_old or too_big):
+                    continue
+
+                try:
+                    if path.is_dir():
+                        shutil.rmtree(path)
+                    else:
+                        path.unlink()
+                except OSError as e:
+                    logger.warning(f"Failed to remove {path}: {e}")
+                    continue
+                total -= size
+                report.removed.append(path)
+                report.freed_bytes += size
+
+        logger.info(f"Cleanup removed {len(report.removed)} items ({report.freed_bytes} bytes)")
+        return report
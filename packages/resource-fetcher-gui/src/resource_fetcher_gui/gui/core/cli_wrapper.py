@@ -1,15 +1,121 @@
 """CLI wrapper for executing resource-fetcher as subprocess."""
 
+import dataclasses
+import json
 import logging
+import os
+import platform
+import re
+import shutil
 import subprocess
+import sys
 import threading
+import time
+import uuid
 from collections.abc import Callable
+from dataclasses import dataclass
 from pathlib import Path
+from typing import Any
 
+from resource_fetcher_core.utils.http import ILLEGAL_FILENAME_CHARS, load_cookies
+from resource_fetcher_core.utils.title_filter import compile_title_pattern
+from resource_fetcher_core.utils.track_range import parse_track_range
+
+from resource_fetcher_gui import __version__
 from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.i18n import tr
+from resource_fetcher_gui.gui.core.job_log import JobLogStore, LogEvent, create_log_event
+from resource_fetcher_gui.gui.core.url_validation import SupportedSource
 
 logger = logging.getLogger(__name__)
 
+# Environment forcing the CLI to write UTF-8 whatever the console code page
+UTF8_ENVIRONMENT = {"PYTHONUTF8": "1", "PYTHONIOENCODING": "utf-8"}
+
+# Windows creation flag keeping the CLI from opening a console window
+# (subprocess.CREATE_NO_WINDOW, which only exists on Windows)
+CREATE_NO_WINDOW = 0x08000000
+
+# Environment variables pointing the CLI's temporary files to its job's working directory
+TEMP_VARIABLES = ("TMPDIR", "TEMP", "TMP")
+
+# Niceness increment applied to the CLI when running at low priority (POSIX)
+LOW_PRIORITY_NICENESS = 10
+
+# Exit code reported to complete_callback when a job is killed by a time limit
+JOB_TIMEOUT_EXIT_CODE = -2
+
+# How often the timeout watchdog checks the running job (seconds)
+WATCHDOG_INTERVAL = 1.0
+
+# CLI line announcing the start of a track, e.g. "[3/10] Song Title"
+TRACK_LINE_PATTERN = re.compile(r"^\[\d+/\d+\]")
+
+# CLI line listing an album of an artist
+ALBUM_URL_PATTERN = re.compile(r"专辑链接\s*\(Album URL\):\s*(\S+)")
+
+# Maximum time to wait for the CLI to list an artist's albums (seconds)
+LIST_ALBUMS_TIMEOUT = 60
+
+# Maximum time to wait for the CLI to list the supported sites (seconds)
+LIST_SOURCES_TIMEOUT = 30
+
+# Maximum time to wait for the CLI to print its usage (seconds)
+HELP_TIMEOUT = 30
+
+# Option names in the CLI's usage
+CLI_OPTION_PATTERN = re.compile(r"--[a-z][a-z-]*")
+
+# CLI lines reporting a connection benchmark
+BENCHMARK_SPEED_PATTERN = re.compile(r"测速\s*\(Benchmark\):\s*(\d+).*?([\d.]+)\s*MB/s")
+BENCHMARK_WORKERS_PATTERN = re.compile(r"建议并行数\s*\(Suggested workers\):\s*(\d+)")
+
+# Maximum time to wait for the CLI to benchmark the connection (seconds)
+BENCHMARK_TIMEOUT = 120
+
+# CLI line asking the user for input, e.g. to pass a captcha
+INTERACTION_PATTERN = re.compile(r"^交互\s*\(Interaction\):\s*(\{.*\})\s*$")
+
+# Interaction kind asking whether to download a track (--confirm-tracks)
+CONFIRM_TRACK = "confirm-track"
+
+# Line on the CLI's stdin changing the speed limit of a running download,
+# followed by the new limit in KB/s (0 for none)
+SPEED_LIMIT_COMMAND = "speed-limit"
+
+
+@dataclass
+class InteractionRequest:
+    """Input a running job waits for.
+
+    Attributes:
+        job_id: Job asking for input.
+        kind: "captcha" to pass a challenge in the browser and confirm, or
+            CONFIRM_TRACK to accept or skip a track (answered with confirm_track).
+        prompt: What the user should do.
+        url: Page where the user does it, if any.
+        index: Album track number a CONFIRM_TRACK request is about.
+    """
+
+    job_id: str
+    kind: str
+    prompt: str
+    url: str = ""
+    index: int = 0
+
+
+@dataclass
+class BenchmarkResult:
+    """Download speed measured by the CLI.
+
+    Attributes:
+        speeds: Speed in MB/s by number of parallel connections.
+        suggested_workers: Number of songs to download at once.
+    """
+
+    speeds: dict[int, float]
+    suggested_workers: int
+
 
 class CLIWrapper:
     """Wrapper for executing CLI as subprocess.
@@ -18,15 +124,41 @@ class CLIWrapper:
     and provides callbacks for progress tracking and completion.
     """
 
-    def __init__(self, cli_path: Path) -> None:
+    def __init__(
+        self,
+        cli_path: Path,
+        log_store: JobLogStore | None = None,
+        sources_dir: Path | None = None,
+        work_root: Path | None = None,
+    ) -> None:
         """Initialize CLI wrapper.
 
         Args:
             cli_path: Path to the CLI executable.
+            log_store: Optional store persisting each job's log events.
+            sources_dir: Optional folder with custom source definitions,
+                passed to the CLI while it exists.
+            work_root: Optional folder receiving a working directory for each
+                job (see execute_download). Without it jobs run in the current
+                directory.
         """
         self.cli_path = cli_path
+        self.log_store = log_store
+        self.sources_dir = sources_dir
+        self.work_root = work_root
+        self.current_job_id: str | None = None
         self.current_process: subprocess.Popen[str] | None = None
         self.current_thread: threading.Thread | None = None
+        self.timeout_reason: str | None = None
+        self._track_started_at = 0.0
+        self._sources: list[SupportedSource] | None = None
+        self._options: set[str] | None = None
+        self._version: str | None = None
+        # Set by stop_download when the thread hasn't started the CLI yet
+        self._stop_requested = threading.Event()
+        # Held while the CLI is started, so a stop either prevents the start
+        # or sees the started process
+        self._start_lock = threading.Lock()
 
         logger.debug(f"CLIWrapper initialized with CLI path: {cli_path}")
 
@@ -36,6 +168,10 @@ class CLIWrapper:
         config: DownloadConfig,
         progress_callback: Callable[[str], None] | None = None,
         complete_callback: Callable[[int], None] | None = None,
+        log_callback: Callable[[LogEvent], None] | None = None,
+        job_id: str | None = None,
+        interaction_callback: Callable[[InteractionRequest], None] | None = None,
+        environment_callback: Callable[[dict[str, Any]], None] | None = None,
     ) -> threading.Thread:
         """Execute download in background thread.
 
@@ -43,56 +179,276 @@ class CLIWrapper:
         Captures stdout/stderr in real-time and calls progress_callback for each line.
         Calls complete_callback with exit code when download finishes.
 
+        If the configuration sets a job or track time limit, a watchdog terminates
+        the process once the limit is exceeded, reports an error line through
+        progress_callback and completes with JOB_TIMEOUT_EXIT_CODE.
+
+        Informational (non-track) lines are also turned into LogEvents tagged with
+        the job ID, persisted to the log store and passed to log_callback.
+
+        With a work root, the CLI runs in its own working directory named after
+        the job ID, which also receives its temporary files and log. The
+        directory is removed when the job succeeds and kept for debugging
+        when it fails.
+
+        When the CLI asks for input (e.g. a captcha), interaction_callback is called
+        and the job waits until the answer is passed to submit_interaction. Without
+        the callback the request is declined at once so the job doesn't hang.
+
         Args:
             url: Album URL to download.
             config: Download configuration.
             progress_callback: Optional callback called with each line of CLI output.
             complete_callback: Optional callback called with exit code when done.
+            log_callback: Optional callback called with each informational log event.
+            job_id: Optional job identifier. A new one is generated if not provided.
+            interaction_callback: Optional callback called when the job needs input.
+            environment_callback: Optional callback called with the job's
+                snapshot_environment before the CLI starts. It runs on the
+                download thread, as the first snapshot asks the CLI for its version.
 
         Returns:
             Thread object running the download process.
 
         Raises:
             FileNotFoundError: If CLI executable doesn't exist.
-            ValueError: If URL is invalid.
+            ValueError: If URL, track range, title patterns, file name
+                        replacement or cookies file are invalid.
+            RuntimeError: If this wrapper is already running a process.
         """
         # Validate CLI path
-        if not self.cli_path.exists():
-            raise FileNotFoundError(f"CLI executable not found: {self.cli_path}")
+        self._validate_cli()
 
         # Validate URL
         if not url or not url.startswith("http"):
             raise ValueError(f"Invalid URL: {url}")
 
+        if config.track_range:
+            parse_track_range(config.track_range)
+
+        for pattern in (config.include_pattern, config.exclude_pattern):
+            if pattern:
+                compile_title_pattern(pattern)
+
+        if re.search(ILLEGAL_FILENAME_CHARS, config.filename_replacement):
+            raise ValueError(
+                f"File name replacement contains illegal characters: {config.filename_replacement}"
+            )
+
+        if config.cookies_file:
+            load_cookies(Path(config.cookies_file))
+        self._ensure_idle()
+
+        current_job_id = job_id or uuid.uuid4().hex
+        self.current_job_id = current_job_id
+
         # Build command
-        cmd = self._build_command(url, config)
+        prefix, popen_options = self._priority_options(config)
+        work_dir = self._create_work_dir(current_job_id)
+        if work_dir:
+            config = self._absolute_paths(config)
+            popen_options = {
+                **popen_options,
+                "cwd": str(work_dir),
+                "env": dict.fromkeys(TEMP_VARIABLES, str(work_dir)),
+            }
+        cmd = prefix + self._build_command(url, config)
         logger.debug(f"Executing command: {' '.join(cmd)}")
 
+        def handle_line(line: str) -> None:
+            """Dispatch a line of CLI output to the callbacks and job log."""
+            match = INTERACTION_PATTERN.match(line)
+            if match:
+                request = self._parse_interaction(current_job_id, match.group(1))
+                if request and interaction_callback:
+                    logger.info(f"Job {current_job_id} needs input: {request.prompt}")
+                    interaction_callback(request)
+                else:
+                    self.submit_interaction(current_job_id, None)
+                return
+
+            if TRACK_LINE_PATTERN.match(line):
+                self._track_started_at = time.monotonic()
+            elif line.strip():
+                event = create_log_event(current_job_id, line)
+                if self.log_store:
+                    self.log_store.append(event)
+                if log_callback:
+                    log_callback(event)
+
+            if progress_callback:
+                progress_callback(line)
+
+        def before_start() -> None:
+            """Pass the job's environment to the callback."""
+            if environment_callback:
+                environment_callback(self.snapshot_environment(url, config))
+
+        return self._start_process(
+            cmd,
+            config,
+            popen_options,
+            work_dir,
+            current_job_id,
+            handle_line,
+            complete_callback,
+            before_start,
+        )
+
+    def execute_command(
+        self,
+        args: list[str],
+        progress_callback: Callable[[str], None] | None = None,
+        complete_callback: Callable[[int], None] | None = None,
+        log_callback: Callable[[LogEvent], None] | None = None,
+        job_id: str | None = None,
+    ) -> threading.Thread:
+        """Run a CLI invocation composed by the user in a background thread.
+
+        Meant for the advanced console, where power users debug source
+        problems with options of their choice. The CLI runs like a download:
+        with the same environment and custom sources, temporary files in the
+        job's working directory (removed if the command succeeds), output
+        streamed line by line and kept in the job log, and stop_download
+        cancelling it. It runs in the current directory, so relative paths in
+        the arguments mean what the user expects. Requests for input are
+        declined.
+
+        Args:
+            args: CLI arguments without the executable, e.g. ["--url", url, "--verbose"].
+            progress_callback: Optional callback called with each line of CLI output.
+            complete_callback: Optional callback called with exit code when done.
+            log_callback: Optional callback called with each log event.
+            job_id: Optional job identifier. A new one is generated if not provided.
+
+        Returns:
+            Thread object running the CLI process.
+
+        Raises:
+            FileNotFoundError: If CLI executable doesn't exist.
+            ValueError: If no arguments are given.
+            RuntimeError: If this wrapper is already running a process.
+        """
+        self._validate_cli()
+        if not args:
+            raise ValueError("No CLI arguments given")
+        self._ensure_idle()
+
+        current_job_id = job_id or uuid.uuid4().hex
+        self.current_job_id = current_job_id
+
+        popen_options: dict[str, Any] = {}
+        work_dir = self._create_work_dir(current_job_id)
+        if work_dir:
+            popen_options = {"env": dict.fromkeys(TEMP_VARIABLES, str(work_dir))}
+        sources = [] if "--sources-dir" in args else self._sources_options()
+        cmd = [str(self.cli_path.absolute()), *sources, *args]
+        logger.info(f"Executing console command: {' '.join(cmd)}")
+
+        def handle_line(line: str) -> None:
+            """Pass a line of CLI output to the callbacks and job log."""
+            if INTERACTION_PATTERN.match(line):
+                self.submit_interaction(current_job_id, None)
+                return
+
+            if line.strip():
+                event = create_log_event(current_job_id, line)
+                if self.log_store:
+                    self.log_store.append(event)
+                if log_callback:
+                    log_callback(event)
+
+            if progress_callback:
+                progress_callback(line)
+
+        return self._start_process(
+            cmd,
+            DownloadConfig(),
+            popen_options,
+            work_dir,
+            current_job_id,
+            handle_line,
+            complete_callback,
+        )
+
+    def _start_process(
+        self,
+        cmd: list[str],
+        config: DownloadConfig,
+        popen_options: dict[str, Any],
+        work_dir: Path | None,
+        job_id: str,
+        handle_line: Callable[[str], None],
+        complete_callback: Callable[[int], None] | None,
+        before_start: Callable[[], None] | None = None,
+    ) -> threading.Thread:
+        """Run the CLI in a background thread and report each line and the exit code.
+
+        Args:
+            cmd: Command to execute.
+            config: Download configuration, for the time limits.
+            popen_options: Extra keyword arguments for Popen.
+            work_dir: Optional working directory of the job, removed if the
+                process succeeds.
+            job_id: Identifier of the job.
+            handle_line: Function called with each line of output.
+            complete_callback: Optional callback called with exit code when done.
+            before_start: Optional function called on the thread before the
+                process is started.
+
+        Returns:
+            Thread object running the process.
+        """
+
         def run_process() -> None:
             """Run subprocess in background thread."""
+            finished = threading.Event()
+            process = None
             try:
-                self.current_process = subprocess.Popen(
-                    cmd,
-                    stdout=subprocess.PIPE,
-                    stderr=subprocess.STDOUT,
-                    text=True,
-                    encoding="utf-8",
-                    bufsize=1,  # Line buffered
-                    errors="replace",  # Replace encoding errors instead of failing
-                )
+                self.timeout_reason = None
+                if before_start:
+                    before_start()
+                with self._start_lock:
+                    stopped = self._stop_requested.is_set()
+                    if not stopped:
+                        process = self._spawn(cmd, config, popen_options)
+                        self.current_process = process
+                if stopped:
+                    logger.info("Download stopped before the CLI started")
+                    if complete_callback:
+                        complete_callback(-1)
+                    return
+                self._track_started_at = time.monotonic()
+
+                if config.job_timeout or config.track_timeout:
+                    watchdog = threading.Thread(
+                        target=self._watch_timeouts,
+                        args=(process, config, finished),
+                        daemon=True,
+                    )
+                    watchdog.start()
 
                 # Read output line by line
-                if self.current_process.stdout:
-                    for line in self.current_process.stdout:
+                if process.stdout:
+                    for line in process.stdout:
                         line = line.rstrip()
                         logger.debug(f"CLI output: {line}")
-                        if progress_callback:
-                            progress_callback(line)
+                        handle_line(line)
 
                 # Wait for process to complete
-                exit_code = self.current_process.wait()
+                exit_code = process.wait()
+                finished.set()
                 logger.info(f"Download process completed with exit code: {exit_code}")
 
+                if self.timeout_reason:
+                    handle_line(f"Error: {self.timeout_reason}")
+                    exit_code = JOB_TIMEOUT_EXIT_CODE
+
+                if work_dir and exit_code == 0:
+                    shutil.rmtree(work_dir, ignore_errors=True)
+                elif work_dir:
+                    logger.info(f"Kept working directory of job {job_id}: {work_dir}")
+
                 if complete_callback:
                     complete_callback(exit_code)
 
@@ -101,16 +457,440 @@ class CLIWrapper:
                 if complete_callback:
                     complete_callback(-1)  # Use -1 to indicate error
             finally:
-                self.current_process = None
-                self.current_thread = None
+                finished.set()
+                # The complete callback may already have started the next download
+                if self.current_process is process:
+                    self.current_process = None
+                if self.current_thread is threading.current_thread():
+                    self.current_thread = None
 
         # Start thread
         thread = threading.Thread(target=run_process, daemon=True)
+        self._stop_requested.clear()
         self.current_thread = thread
         thread.start()
 
         return thread
 
+    def _ensure_idle(self) -> None:
+        """Refuse to start a process while another one is running or starting.
+
+        The download thread itself may start the next process from its
+        complete callback.
+
+        Raises:
+            RuntimeError: If this wrapper is already running a process.
+        """
+        thread = self.current_thread
+        starting = (
+            thread is not None and thread.is_alive() and thread is not threading.current_thread()
+        )
+        if starting or self.is_running():
+            raise RuntimeError("Another CLI process is still running")
+
+    def _create_work_dir(self, job_id: str) -> Path | None:
+        """Create the working directory of a job.
+
+        Args:
+            job_id: Job the directory is for.
+
+        Returns:
+            The empty directory, or None without a work root.
+        """
+        if self.work_root is None:
+            return None
+        work_dir = self.work_root / job_id
+        shutil.rmtree(work_dir, ignore_errors=True)
+        work_dir.mkdir(parents=True, exist_ok=True)
+        return work_dir
+
+    @staticmethod
+    def _absolute_paths(config: DownloadConfig) -> DownloadConfig:
+        """Resolve the relative paths of a configuration against the current directory.
+
+        Args:
+            config: Download configuration.
+
+        Returns:
+            Copy of the configuration the CLI can use from another directory.
+        """
+        return dataclasses.replace(
+            config,
+            output_dir=os.path.abspath(config.output_dir),
+            cookies_file=config.cookies_file and os.path.abspath(config.cookies_file),
+            temp_dir=config.temp_dir and os.path.abspath(config.temp_dir),
+        )
+
+    @staticmethod
+    def _parse_interaction(job_id: str, message: str) -> InteractionRequest | None:
+        """Parse the JSON object of an interaction line.
+
+        Args:
+            job_id: Job the line came from.
+            message: JSON object with "kind", "prompt", "url" and, for
+                     CONFIRM_TRACK, "index".
+
+        Returns:
+            Parsed request, or None if the message is invalid.
+        """
+        try:
+            data = json.loads(message)
+            return InteractionRequest(
+                job_id=job_id,
+                kind=str(data["kind"]),
+                prompt=str(data.get("prompt", "")),
+                url=str(data.get("url") or ""),
+                index=int(data.get("index", 0)),
+            )
+        except (json.JSONDecodeError, TypeError, KeyError, ValueError) as e:
+            logger.warning(f"Invalid interaction request from job {job_id}: {e}")
+            return None
+
+    def submit_interaction(self, job_id: str, response: str | None) -> bool:
+        """Answer the input a running job waits for.
+
+        Args:
+            job_id: Job that asked for input.
+            response: The user's answer, or None to decline, which makes the
+                      job give up on the request.
+
+        Returns:
+            True if the answer was delivered, False if the job isn't running.
+        """
+        process = self.current_process
+        stdin = getattr(process, "stdin", None)
+        if process is None or job_id != self.current_job_id or stdin is None:
+            logger.warning(f"Job {job_id} isn't waiting for input")
+            return False
+
+        try:
+            if response is None:
+                stdin.close()
+                logger.info(f"Input request of job {job_id} declined")
+            else:
+                stdin.write(" ".join(response.splitlines()) + "\n")
+                stdin.flush()
+                logger.info(f"Input submitted to job {job_id}")
+            return True
+        except (OSError, ValueError) as e:
+            # The process exited or stdin was already closed
+            logger.warning(f"Failed to submit input to job {job_id}: {e}")
+            return False
+
+    def confirm_track(self, job_id: str, index: int, accept: bool) -> bool:
+        """Answer whether a job in confirmation mode downloads a track.
+
+        Args:
+            job_id: Job that asked.
+            index: Album track number of the CONFIRM_TRACK request.
+            accept: True to download the track, False to skip it.
+
+        Returns:
+            True if the answer was delivered, False if the job isn't running.
+        """
+        return self.submit_interaction(job_id, f"{index} {'yes' if accept else 'no'}")
+
+    def set_speed_limit(self, job_id: str, max_speed: int | None) -> bool:
+        """Change the speed limit of a running job.
+
+        Args:
+            job_id: Job to change.
+            max_speed: Speed limit in KB/s, or None for unlimited.
+
+        Returns:
+            True if the limit was delivered, False if the job isn't running
+            or no longer reads its input.
+        """
+        process = self.current_process
+        stdin = getattr(process, "stdin", None)
+        if process is None or job_id != self.current_job_id or stdin is None:
+            return False
+
+        try:
+            stdin.write(f"{SPEED_LIMIT_COMMAND} {max_speed or 0}\n")
+            stdin.flush()
+        except (OSError, ValueError) as e:
+            logger.warning(f"Failed to change the speed limit of job {job_id}: {e}")
+            return False
+        limit = f"{max_speed} KB/s" if max_speed else "unlimited"
+        logger.info(f"Speed limit of job {job_id} changed to {limit}")
+        return True
+
+    def list_albums(self, url: str) -> list[str]:
+        """List the albums linked from an artist page.
+
+        Runs the CLI in listing mode and waits for it, so call it from a
+        background thread.
+
+        Args:
+            url: Artist page URL.
+
+        Returns:
+            Album page URLs in page order.
+
+        Raises:
+            FileNotFoundError: If CLI executable doesn't exist.
+            ValueError: If the URL is invalid or no albums were found.
+            subprocess.TimeoutExpired: If the CLI doesn't finish in time.
+        """
+        self._validate_cli()
+
+        if not url or not url.startswith("http"):
+            raise ValueError(f"Invalid URL: {url}")
+
+        cmd = [str(self.cli_path), "--url", url, "--list-albums", *self._sources_options()]
+        logger.debug(f"Executing command: {' '.join(cmd)}")
+        output = self._run_to_completion(cmd, LIST_ALBUMS_TIMEOUT)
+
+        urls = ALBUM_URL_PATTERN.findall(output)
+        if not urls:
+            raise ValueError(f"No albums found on {url}")
+        logger.info(f"Found {len(urls)} albums on {url}")
+        return urls
+
+    def list_supported_sources(self, refresh: bool = False) -> list[SupportedSource]:
+        """List the sites the installed CLI supports.
+
+        The CLI is asked once and the result cached. Call it from a background
+        thread unless it has been called before.
+
+        Args:
+            refresh: Ask the CLI again instead of using the cached result.
+
+        Returns:
+            Supported sites with their URL patterns and capabilities.
+
+        Raises:
+            FileNotFoundError: If CLI executable doesn't exist.
+            ValueError: If the CLI doesn't support listing its sources.
+            subprocess.TimeoutExpired: If the CLI doesn't finish in time.
+        """
+        if self._sources is not None and not refresh:
+            return self._sources
+
+        self._validate_cli()
+
+        cmd = [str(self.cli_path), "--list-sources", "--json", *self._sources_options()]
+        logger.debug(f"Executing command: {' '.join(cmd)}")
+        output = self._run_to_completion(cmd, LIST_SOURCES_TIMEOUT)
+
+        try:
+            sources = [
+                SupportedSource(
+                    name=str(item["name"]),
+                    url_patterns=[str(pattern) for pattern in item.get("url_patterns", [])],
+                    capabilities=[str(name) for name in item.get("capabilities", [])],
+                )
+                for item in json.loads(output)
+            ]
+        except (json.JSONDecodeError, TypeError, KeyError, AttributeError) as e:
+            raise ValueError(f"CLI didn't list its sources: {e}") from e
+
+        logger.info(f"CLI supports {len(sources)} sources")
+        self._sources = sources
+        return sources
+
+    def list_cli_options(self, refresh: bool = False) -> set[str]:
+        """List the options the installed CLI accepts.
+
+        The CLI's usage is read once and the result cached. Call it from a
+        background thread unless it has been called before.
+
+        Args:
+            refresh: Ask the CLI again instead of using the cached result.
+
+        Returns:
+            Option names, e.g. "--normalize".
+
+        Raises:
+            FileNotFoundError: If CLI executable doesn't exist.
+            subprocess.TimeoutExpired: If the CLI doesn't finish in time.
+        """
+        if self._options is not None and not refresh:
+            return self._options
+
+        self._validate_cli()
+
+        cmd = [str(self.cli_path), "--help"]
+        logger.debug(f"Executing command: {' '.join(cmd)}")
+        options = set(CLI_OPTION_PATTERN.findall(self._run_to_completion(cmd, HELP_TIMEOUT)))
+
+        logger.info(f"CLI accepts {len(options)} options")
+        self._options = options
+        return options
+
+    def get_cli_version(self) -> str:
+        """Read the version of the installed CLI.
+
+        The CLI is asked once and the result cached. Call it from a
+        background thread unless it has been called before.
+
+        Returns:
+            Version line of the CLI, e.g. "resource-fetcher 1.0.0 (Python 3.12.4)",
+            empty if the CLI can't be asked.
+        """
+        if self._version is None:
+            try:
+                self._validate_cli()
+                cmd = [str(self.cli_path), "--version"]
+                self._version = self._run_to_completion(cmd, HELP_TIMEOUT).strip()
+            except (OSError, subprocess.SubprocessError) as e:
+                logger.warning(f"Failed to read CLI version: {e}")
+                self._version = ""
+        return self._version
+
+    def snapshot_environment(self, url: str, config: DownloadConfig) -> dict[str, Any]:
+        """Describe exactly what a job runs, so it can be reproduced or reported later.
+
+        Like get_cli_version, call it from a background thread.
+
+        Args:
+            url: Album URL the job downloads.
+            config: Download configuration the job runs with.
+
+        Returns:
+            App, Python and CLI versions, the platform and the CLI command
+            with all effective options.
+        """
+        if self.work_root:
+            config = self._absolute_paths(config)
+        return {
+            "app_version": __version__,
+            "python_version": sys.version.split()[0],
+            "platform": platform.platform(),
+            "cli_version": self.get_cli_version(),
+            "command": self._build_command(url, config),
+        }
+
+    def benchmark_connection(self, url: str) -> BenchmarkResult:
+        """Measure the download speed to a test file.
+
+        Runs the CLI's benchmark and waits for it, so call it from a
+        background thread.
+
+        Args:
+            url: URL of a file to download, ideally on the site albums come from.
+
+        Returns:
+            Measured speeds and the suggested number of parallel songs.
+
+        Raises:
+            FileNotFoundError: If CLI executable doesn't exist.
+            ValueError: If the URL is invalid or the benchmark failed.
+            subprocess.TimeoutExpired: If the CLI doesn't finish in time.
+        """
+        self._validate_cli()
+
+        if not url or not url.startswith("http"):
+            raise ValueError(f"Invalid URL: {url}")
+
+        cmd = [str(self.cli_path), "--benchmark", url]
+        logger.debug(f"Executing command: {' '.join(cmd)}")
+        output = self._run_to_completion(cmd, BENCHMARK_TIMEOUT)
+
+        workers = BENCHMARK_WORKERS_PATTERN.search(output)
+        if not workers:
+            raise ValueError(f"Benchmark of {url} failed")
+
+        speeds = {
+            int(streams): float(speed) for streams, speed in BENCHMARK_SPEED_PATTERN.findall(output)
+        }
+        return BenchmarkResult(speeds=speeds, suggested_workers=int(workers.group(1)))
+
+    def _run_to_completion(self, cmd: list[str], timeout: float) -> str:
+        """Run the CLI and collect its output.
+
+        Args:
+            cmd: Command to execute.
+            timeout: Maximum seconds to wait for the CLI.
+
+        Returns:
+            Combined stdout and stderr of the process.
+
+        Raises:
+            subprocess.TimeoutExpired: If the CLI doesn't finish in time.
+        """
+        result = subprocess.run(
+            cmd,
+            stdout=subprocess.PIPE,
+            stderr=subprocess.STDOUT,
+            text=True,
+            encoding="utf-8",
+            errors="replace",
+            timeout=timeout,
+            **self._child_options(),
+        )
+        return result.stdout
+
+    def _sources_options(self) -> list[str]:
+        """Build the CLI arguments loading custom source definitions.
+
+        Returns:
+            --sources-dir and the folder, or nothing if the folder doesn't exist.
+        """
+        if self.sources_dir is None or not self.sources_dir.is_dir():
+            return []
+        return ["--sources-dir", str(self.sources_dir)]
+
+    def _validate_cli(self) -> None:
+        """Check that the CLI executable exists.
+
+        Raises:
+            FileNotFoundError: If CLI executable doesn't exist.
+        """
+        if not self.cli_path.exists():
+            raise FileNotFoundError(f"CLI executable not found: {self.cli_path}")
+
+    def _spawn(
+        self,
+        cmd: list[str],
+        config: DownloadConfig,
+        popen_options: dict[str, Any],
+    ) -> subprocess.Popen[str]:
+        """Start the CLI process.
+
+        Args:
+            cmd: Command to execute.
+            config: Download configuration the command was built from.
+            popen_options: Extra keyword arguments for Popen.
+
+        Returns:
+            Running process with line-buffered text stdout and a stdin pipe
+            for answers to interaction requests.
+        """
+        return subprocess.Popen(
+            cmd,
+            stdin=subprocess.PIPE,
+            stdout=subprocess.PIPE,
+            stderr=subprocess.STDOUT,
+            text=True,
+            encoding="utf-8",
+            bufsize=1,  # Line buffered
+            errors="replace",  # Replace encoding errors instead of failing
+            **self._child_options(popen_options),
+        )
+
+    def _child_options(self, popen_options: dict[str, Any] | None = None) -> dict[str, Any]:
+        """Build the Popen options shared by every CLI process.
+
+        The CLI runs with Python's UTF-8 mode so titles in any language reach
+        the parser intact, whatever the Windows console code page. On Windows
+        the CLI is also started without a console window.
+
+        Args:
+            popen_options: Extra keyword arguments for Popen, e.g. from
+                _priority_options. Their environment variables are added to
+                the current environment.
+
+        Returns:
+            Keyword arguments for Popen or subprocess.run.
+        """
+        options = dict(popen_options or {})
+        options["env"] = {**os.environ, **UTF8_ENVIRONMENT, **options.get("env", {})}
+        if sys.platform == "win32":
+            options["creationflags"] = options.get("creationflags", 0) | CREATE_NO_WINDOW
+        return options
+
     def _build_command(self, url: str, config: DownloadConfig) -> list[str]:
         """Build CLI command from configuration.
 
@@ -122,7 +902,7 @@ class CLIWrapper:
             List of command arguments.
         """
         cmd = [
-            str(self.cli_path),
+            str(self.cli_path.absolute()),
             "--url",
             url,
             "--output",
@@ -139,38 +919,176 @@ class CLIWrapper:
         if config.limit is not None:
             cmd.extend(["--limit", str(config.limit)])
 
+        if config.track_range:
+            cmd.extend(["--range", config.track_range])
+
+        if config.include_pattern:
+            cmd.extend(["--include", config.include_pattern])
+
+        if config.exclude_pattern:
+            cmd.extend(["--exclude", config.exclude_pattern])
+
+        for pattern in config.skip_titles:
+            cmd.extend(["--skip-title", pattern])
+
+        if config.filename_replacement != "_":
+            cmd.extend(["--replace-char", config.filename_replacement])
+
+        if config.max_filename_length is not None:
+            cmd.extend(["--max-name-length", str(config.max_filename_length)])
+
+        if config.workers > 1:
+            cmd.extend(["--workers", str(config.workers)])
+
+        if config.max_speed is not None:
+            cmd.extend(["--max-speed", str(config.max_speed)])
+
         if config.overwrite:
             cmd.append("--overwrite")
 
-        if config.verbose:
-            cmd.append("--verbose")
+        if config.sync_only:
+            cmd.append("--sync")
+
+        if config.normalize_loudness:
+            cmd.append("--normalize")
+
+        if config.checksum_sidecars:
+            cmd.append("--checksum-sidecars")
+
+        if config.merge_format:
+            cmd.extend(["--merge", config.merge_format])
+
+        if config.cookies_file:
+            cmd.extend(["--cookies", config.cookies_file])
+
+        if config.min_track_size is not None:
+            cmd.extend(["--min-size", str(config.min_track_size)])
+
+        if config.detect_silence:
+            cmd.append("--detect-silence")
+
+        if config.scan_command:
+            cmd.extend(["--scan-command", config.scan_command])
+
+        if config.temp_dir:
+            cmd.extend(["--temp-dir", config.temp_dir])
+
+        if config.confirm_tracks:
+            cmd.extend(["--confirm-tracks", "--confirm-timeout", str(config.confirm_timeout)])
+            if config.confirm_default != "download":
+                cmd.extend(["--confirm-default", config.confirm_default])
+
+        if config.relax_politeness:
+            cmd.append("--relax-politeness")
+
+        if config.log_level != "info":
+            cmd.extend(["--log-level", config.log_level])
+
+        cmd.extend(self._sources_options())
 
         return cmd
 
+    def _priority_options(self, config: DownloadConfig) -> tuple[list[str], dict[str, Any]]:
+        """Build command prefix and Popen options for the configured priority.
+
+        When low priority is enabled, Windows creates the process with
+        BELOW_NORMAL_PRIORITY_CLASS. On POSIX the command is wrapped with
+        ``nice``, and on Linux also with ``ionice -c 3`` (idle I/O), if available.
+        Wrapping keeps os.nice out of preexec_fn, which isn't safe while the
+        GUI runs other threads.
+
+        Args:
+            config: Download configuration.
+
+        Returns:
+            Tuple of (command prefix, extra keyword arguments for Popen).
+        """
+        if not config.low_priority:
+            return [], {}
+
+        if sys.platform == "win32":
+            return [], {"creationflags": subprocess.BELOW_NORMAL_PRIORITY_CLASS}
+
+        prefix: list[str] = []
+        if sys.platform.startswith("linux"):
+            ionice = shutil.which("ionice")
+            if ionice:
+                prefix = [ionice, "-c", "3"]
+
+        nice = shutil.which("nice")
+        if nice:
+            prefix += [nice, "-n", str(LOW_PRIORITY_NICENESS)]
+
+        return prefix, {}
+
+    def _watch_timeouts(
+        self,
+        process: subprocess.Popen[str],
+        config: DownloadConfig,
+        finished: threading.Event,
+    ) -> None:
+        """Terminate the process once a job or track time limit is exceeded.
+
+        Args:
+            process: Running CLI process.
+            config: Download configuration with the time limits.
+            finished: Event set when the process has exited.
+        """
+        started_at = time.monotonic()
+
+        while not finished.wait(WATCHDOG_INTERVAL):
+            now = time.monotonic()
+            if config.job_timeout and now - started_at >= config.job_timeout:
+                reason = tr("job_timeout", seconds=config.job_timeout)
+            elif config.track_timeout and now - self._track_started_at >= config.track_timeout:
+                reason = tr("track_timeout", seconds=config.track_timeout)
+            else:
+                continue
+
+            logger.warning(f"{reason}, terminating download")
+            self.timeout_reason = reason
+            self._terminate(process)
+            return
+
+    def _terminate(self, process: subprocess.Popen[str]) -> None:
+        """Terminate a process, forcing a kill if it doesn't exit in time.
+
+        Args:
+            process: Process to terminate.
+        """
+        process.terminate()
+
+        # Give it a moment to terminate gracefully
+        try:
+            process.wait(timeout=5)
+        except subprocess.TimeoutExpired:
+            # Force kill if it didn't terminate
+            logger.warning("Process did not terminate gracefully, forcing kill")
+            process.kill()
+            process.wait()
+
     def stop_download(self) -> bool:
         """Stop the currently running download.
 
-        Attempts to terminate the subprocess if it's running.
+        Attempts to terminate the subprocess if it's running. A download
+        whose thread hasn't started the CLI yet won't start it.
 
         Returns:
             True if process was stopped, False if no process was running.
         """
-        if self.current_process is None:
-            logger.warning("No download process to stop")
-            return False
+        with self._start_lock:
+            process = self.current_process
+            if process is None:
+                if self.current_thread is not None and self.current_thread.is_alive():
+                    logger.info("Stopping download before the CLI starts")
+                    self._stop_requested.set()
+                    return True
+                logger.warning("No download process to stop")
+                return False
 
         try:
             logger.info("Stopping download process...")
-            self.current_process.terminate()
-
-            # Give it a moment to terminate gracefully
-            try:
-                self.current_process.wait(timeout=5)
-            except subprocess.TimeoutExpired:
-                # Force kill if it didn't terminate
-                logger.warning("Process did not terminate gracefully, forcing kill")
-                self.current_process.kill()
-                self.current_process.wait()
+            self._terminate(process)
 
             logger.info("Download process stopped")
             return True
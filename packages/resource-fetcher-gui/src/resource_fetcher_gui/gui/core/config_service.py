@@ -4,13 +4,200 @@ import dataclasses
 import json
 import logging
 import os
+import re
+import shutil
 import sys
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from pathlib import Path
 from typing import Any
 
+from resource_fetcher_core.utils.audio import MERGE_FORMATS
+from resource_fetcher_core.utils.http import ILLEGAL_FILENAME_CHARS
+from resource_fetcher_core.utils.title_filter import compile_title_pattern
+from resource_fetcher_core.utils.track_range import parse_track_range
+
 logger = logging.getLogger(__name__)
 
+# Supported log levels, shared by the GUI logger and the CLI --log-level flag
+LOG_LEVELS = ("debug", "info", "warning", "error")
+
+# What the CLI does with a track nobody confirmed in time (--confirm-default)
+CONFIRM_DEFAULTS = ("download", "skip")
+
+# File beside the executable that switches on portable mode
+PORTABLE_FLAG = "portable.flag"
+
+# Folder beside the executable holding all application data in portable mode
+PORTABLE_DATA_DIR = "data"
+
+# Name of the application's folder in the platform directories
+APP_DIR_NAME = "resource_fetcher"
+
+# Entries of the pre-XDG folder (~/.config/resource_fetcher) that are data
+# rather than configuration; everything else there is configuration
+DATA_ENTRIES = ("history.jsonl", "logs", "crashes", "notifications.json")
+
+_portable_override = False
+
+
+def get_executable_dir() -> Path:
+    """Get the folder of the running program.
+
+    Returns:
+        Folder of the built executable, or the working directory when
+        running from source.
+    """
+    if getattr(sys, "frozen", False):
+        return Path(sys.executable).resolve().parent
+    return Path.cwd()
+
+
+def set_portable(enabled: bool) -> None:
+    """Switch portable mode on or off regardless of the flag file.
+
+    Args:
+        enabled: Whether to keep all application data beside the executable.
+    """
+    global _portable_override
+
+    _portable_override = enabled
+    logger.debug(f"Portable mode override set to: {enabled}")
+
+
+def is_portable() -> bool:
+    """Check whether the application runs in portable mode.
+
+    Portable mode is on if set with set_portable() or if a portable.flag
+    file exists beside the executable, e.g. on a USB stick.
+
+    Returns:
+        True if application data is kept beside the executable.
+    """
+    return _portable_override or (get_executable_dir() / PORTABLE_FLAG).exists()
+
+
+def _xdg_dir(variable: str, default: Path) -> Path:
+    """Get an XDG base directory for the application.
+
+    Args:
+        variable: Environment variable naming the base directory.
+        default: Base directory used if the variable is unset or not absolute.
+
+    Returns:
+        The application's folder in the base directory.
+    """
+    base = os.environ.get(variable, "")
+    return (Path(base) if Path(base).is_absolute() else default) / APP_DIR_NAME
+
+
+def get_legacy_app_dir() -> Path:
+    """Get the folder all application files were kept in before XDG support.
+
+    Returns:
+        Path to ~/.config/resource_fetcher.
+    """
+    return Path.home() / ".config" / APP_DIR_NAME
+
+
+def get_app_config_dir() -> Path:
+    """Get the directory for settings.
+
+    Returns:
+        Path to the data folder beside the executable in portable mode,
+        $XDG_CONFIG_HOME/resource_fetcher on Linux, otherwise the
+        platform-specific resource_fetcher directory.
+    """
+    if is_portable():
+        return get_executable_dir() / PORTABLE_DATA_DIR
+
+    if sys.platform == "win32":
+        base = Path(os.environ.get("APPDATA", Path.home() / "AppData" / "Roaming"))
+        return base / APP_DIR_NAME
+    if sys.platform.startswith("linux"):
+        return _xdg_dir("XDG_CONFIG_HOME", Path.home() / ".config")
+
+    return get_legacy_app_dir()
+
+
+def get_app_data_dir() -> Path:
+    """Get the directory for application data such as history and job logs.
+
+    Returns:
+        $XDG_DATA_HOME/resource_fetcher on Linux, otherwise the settings
+        directory.
+    """
+    if sys.platform.startswith("linux") and not is_portable():
+        return _xdg_dir("XDG_DATA_HOME", Path.home() / ".local" / "share")
+    return get_app_config_dir()
+
+
+def get_app_cache_dir() -> Path:
+    """Get the directory for files that can be recreated at any time.
+
+    Returns:
+        $XDG_CACHE_HOME/resource_fetcher on Linux, otherwise the cache folder
+        in the data directory.
+    """
+    if sys.platform.startswith("linux") and not is_portable():
+        return _xdg_dir("XDG_CACHE_HOME", Path.home() / ".cache")
+    return get_app_data_dir() / "cache"
+
+
+def get_log_dir() -> Path:
+    """Get the directory the GUI log file is written to.
+
+    Returns:
+        The application data directory in portable mode, otherwise the
+        working directory.
+    """
+    return get_app_data_dir() if is_portable() else Path()
+
+
+def get_app_paths() -> dict[str, Path]:
+    """Get where the application keeps its files, for display to the user.
+
+    Returns:
+        Settings, data, cache and log directories by name.
+    """
+    return {
+        "config": get_app_config_dir(),
+        "data": get_app_data_dir(),
+        "cache": get_app_cache_dir(),
+        "logs": get_log_dir().absolute(),
+    }
+
+
+def migrate_legacy_app_dir() -> list[Path]:
+    """Move files from the pre-XDG folder to the XDG directories on Linux.
+
+    Entries already present at their new location are left in place.
+
+    Returns:
+        New locations of the moved files and folders.
+    """
+    legacy = get_legacy_app_dir()
+    if not sys.platform.startswith("linux") or is_portable() or not legacy.is_dir():
+        return []
+
+    moved: list[Path] = []
+    for entry in sorted(legacy.iterdir()):
+        target_dir = get_app_data_dir() if entry.name in DATA_ENTRIES else get_app_config_dir()
+        target = target_dir / entry.name
+        if target_dir == legacy:
+            continue
+        if target.exists():
+            logger.warning(f"Not migrating {entry}: {target} already exists")
+            continue
+        try:
+            target_dir.mkdir(parents=True, exist_ok=True)
+            shutil.move(str(entry), str(target))
+        except OSError as e:
+            logger.warning(f"Failed to migrate {entry} to {target}: {e}")
+            continue
+        logger.info(f"Migrated {entry} to {target}")
+        moved.append(target)
+    return moved
+
 
 @dataclass
 class DownloadConfig:
@@ -20,19 +207,76 @@ class DownloadConfig:
         output_dir: Output directory for downloaded files
         limit: Optional limit on number of songs to download
         overwrite: Whether to overwrite existing files
+        sync_only: Download only tracks that are new or incomplete in the output folder
         timeout: Request timeout in seconds
         retries: Number of retry attempts for failed downloads
         delay: Delay between downloads in seconds
-        verbose: Enable verbose logging
+        workers: Number of songs of an album downloaded at once
+        max_speed: Optional download speed limit in KB/s for all songs of a job
+        log_level: Log verbosity for the GUI and the CLI (debug, info, warning, error)
+        low_priority: Run the CLI at below-normal CPU/IO priority
+        job_timeout: Optional wall-clock limit for the whole download in seconds
+        track_timeout: Optional wall-clock limit for a single track in seconds
+        track_range: Optional track numbers to download, e.g. "5-12,15"
+        skip_titles: Title fragments of songs to skip, e.g. ["remix", "instrumental"]
+        include_pattern: Optional title glob (or "re:" regex) of songs to download
+        exclude_pattern: Optional title glob (or "re:" regex) of songs not to download
+        normalize_loudness: Normalize loudness of downloaded songs with ffmpeg
+        checksum_sidecars: Write a .sha256 checksum file next to each downloaded song
+        merge_format: Optional format ("m4b" or "mka") to merge the album into
+            one file with chapters
+        filename_replacement: Text replacing characters not allowed in file names
+        max_filename_length: Optional maximum file name length in characters
+        cookies_file: Optional cookies.txt file exported from a browser, sent with
+            every request for sources that need a login session
+        scan_command: Optional scanner command (e.g. "clamscan --no-summary") each
+            downloaded file has to pass before it is moved out of quarantine
+        temp_dir: Optional local folder for partial files and conversions, moved
+            into the output directory when complete
+        confirm_tracks: Ask before downloading each track
+        confirm_timeout: Seconds to wait for the answer before confirm_default applies
+        confirm_default: What to do with an unanswered track ("download" or "skip")
+        library: Optional library to download into instead of output_dir,
+            the active library if not set
+        relax_politeness: Ignore the source's recommended delay, concurrency
+            and download hours
+        min_track_size: Optional minimum size in bytes of a downloaded track,
+            smaller files count as failed
+        detect_silence: Fail downloaded tracks that contain only silence (ffmpeg)
     """
 
     output_dir: str = "./downloads"
     limit: int | None = None
     overwrite: bool = False
+    sync_only: bool = False
     timeout: int = 60
     retries: int = 3
     delay: float = 0.5
-    verbose: bool = False
+    workers: int = 1
+    max_speed: int | None = None
+    log_level: str = "info"
+    low_priority: bool = False
+    job_timeout: int | None = None
+    track_timeout: int | None = None
+    track_range: str | None = None
+    skip_titles: list[str] = field(default_factory=list)
+    include_pattern: str | None = None
+    exclude_pattern: str | None = None
+    normalize_loudness: bool = False
+    checksum_sidecars: bool = False
+    merge_format: str | None = None
+    filename_replacement: str = "_"
+    max_filename_length: int | None = None
+    cookies_file: str | None = None
+    scan_command: str | None = None
+    temp_dir: str | None = None
+    confirm_tracks: bool = False
+    confirm_timeout: int = 60
+    confirm_default: str = "download"
+    library: str | None = None
+    relax_politeness: bool = False
+    min_track_size: int | None = None
+    detect_silence: bool = False
 
 
 class ConfigService:
@@ -62,12 +306,7 @@ class ConfigService:
         Returns:
             Path to configuration file in platform-specific location.
         """
-        if sys.platform == "win32":
-            base = Path(os.environ.get("APPDATA", Path.home() / "AppData" / "Roaming"))
-        else:
-            base = Path.home() / ".config"
-
-        return base / "resource_fetcher" / "config.json"
+        return get_app_config_dir() / "config.json"
 
     def load_config(self) -> DownloadConfig:
         """Load configuration from file or return defaults.
@@ -127,6 +366,20 @@ class ConfigService:
             logger.error(f"Failed to save configuration: {e}")
             raise
 
+    def config_from_dict(self, data: dict[str, Any]) -> DownloadConfig:
+        """Create a validated configuration from a dictionary.
+
+        Invalid values are ignored or replaced with defaults, as when loading
+        the configuration file.
+
+        Args:
+            data: Dictionary containing configuration values.
+
+        Returns:
+            DownloadConfig object with validated values.
+        """
+        return self._validate_and_create_config(data)
+
     def _validate_and_create_config(self, data: dict[str, Any]) -> DownloadConfig:
         """Validate configuration data and create DownloadConfig object.
 
@@ -139,6 +392,10 @@ class ConfigService:
         Raises:
             ValueError: If configuration data is invalid.
         """
+        # Migrate the legacy verbose flag to a log level
+        if "log_level" not in data and data.get("verbose"):
+            data = {**data, "log_level": "debug"}
+
         # Extract only valid fields
         valid_fields = {
             k: v
@@ -159,6 +416,25 @@ class ConfigService:
                 logger.warning(f"Invalid limit value: {valid_fields['limit']}, ignoring")
                 del valid_fields["limit"]
 
+        for field_name in (
+            "job_timeout",
+            "track_timeout",
+            "max_filename_length",
+            "max_speed",
+            "confirm_timeout",
+            "min_track_size",
+        ):
+            if field_name in valid_fields:
+                try:
+                    valid_fields[field_name] = int(valid_fields[field_name])
+                    if valid_fields[field_name] <= 0:
+                        raise ValueError(f"{field_name} must be positive")
+                except (ValueError, TypeError):
+                    logger.warning(
+                        f"Invalid {field_name} value: {valid_fields[field_name]}, ignoring"
+                    )
+                    del valid_fields[field_name]
+
         if "timeout" in valid_fields:
             try:
                 valid_fields["timeout"] = int(valid_fields["timeout"])
@@ -186,11 +462,112 @@ class ConfigService:
                 logger.warning(f"Invalid delay value: {valid_fields['delay']}, using default")
                 valid_fields["delay"] = self.DEFAULT_CONFIG.delay
 
+        if "workers" in valid_fields:
+            try:
+                valid_fields["workers"] = int(valid_fields["workers"])
+                if valid_fields["workers"] < 1:
+                    raise ValueError("workers must be positive")
+            except (ValueError, TypeError):
+                logger.warning(f"Invalid workers value: {valid_fields['workers']}, using default")
+                valid_fields["workers"] = self.DEFAULT_CONFIG.workers
+
         if "overwrite" in valid_fields:
             valid_fields["overwrite"] = bool(valid_fields["overwrite"])
 
-        if "verbose" in valid_fields:
-            valid_fields["verbose"] = bool(valid_fields["verbose"])
+        if "sync_only" in valid_fields:
+            valid_fields["sync_only"] = bool(valid_fields["sync_only"])
+
+        if "log_level" in valid_fields:
+            level = str(valid_fields["log_level"]).lower()
+            if level not in LOG_LEVELS:
+                logger.warning(f"Invalid log_level value: {level}, using default")
+                level = self.DEFAULT_CONFIG.log_level
+            valid_fields["log_level"] = level
+
+        if "low_priority" in valid_fields:
+            valid_fields["low_priority"] = bool(valid_fields["low_priority"])
+
+        if "normalize_loudness" in valid_fields:
+            valid_fields["normalize_loudness"] = bool(valid_fields["normalize_loudness"])
+
+        if "checksum_sidecars" in valid_fields:
+            valid_fields["checksum_sidecars"] = bool(valid_fields["checksum_sidecars"])
+
+        if "merge_format" in valid_fields:
+            merge_format = str(valid_fields["merge_format"]).lower()
+            if merge_format in MERGE_FORMATS:
+                valid_fields["merge_format"] = merge_format
+            else:
+                if merge_format:
+                    logger.warning(f"Invalid merge_format value: {merge_format}, ignoring")
+                del valid_fields["merge_format"]
+
+        if valid_fields.get("track_range") is not None:
+            track_range = str(valid_fields["track_range"]).strip()
+            try:
+                parse_track_range(track_range)
+                valid_fields["track_range"] = track_range or None
+            except ValueError:
+                if track_range:
+                    logger.warning(f"Invalid track_range value: {track_range}, ignoring")
+                del valid_fields["track_range"]
+
+        if "skip_titles" in valid_fields:
+            skip_titles = valid_fields["skip_titles"]
+            if isinstance(skip_titles, list):
+                valid_fields["skip_titles"] = [
+                    str(pattern).strip() for pattern in skip_titles if str(pattern).strip()
+                ]
+            else:
+                logger.warning(f"Invalid skip_titles value: {skip_titles}, ignoring")
+                del valid_fields["skip_titles"]
+
+        for field_name in ("include_pattern", "exclude_pattern"):
+            if field_name in valid_fields:
+                pattern = str(valid_fields[field_name])
+                try:
+                    compile_title_pattern(pattern)
+                    valid_fields[field_name] = pattern
+                except ValueError:
+                    if pattern.strip():
+                        logger.warning(f"Invalid {field_name} value: {pattern}, ignoring")
+                    del valid_fields[field_name]
+
+        if "filename_replacement" in valid_fields:
+            replacement = str(valid_fields["filename_replacement"])
+            if re.search(ILLEGAL_FILENAME_CHARS, replacement):
+                logger.warning(f"Invalid filename_replacement value: {replacement}, using default")
+                replacement = self.DEFAULT_CONFIG.filename_replacement
+            valid_fields["filename_replacement"] = replacement
+
+        if valid_fields.get("cookies_file") is not None:
+            # The file itself is checked when a download starts
+            valid_fields["cookies_file"] = str(valid_fields["cookies_file"]).strip() or None
+
+        if valid_fields.get("scan_command") is not None:
+            valid_fields["scan_command"] = str(valid_fields["scan_command"]).strip() or None
+
+        if valid_fields.get("temp_dir") is not None:
+            valid_fields["temp_dir"] = str(valid_fields["temp_dir"]).strip() or None
+
+        if "confirm_tracks" in valid_fields:
+            valid_fields["confirm_tracks"] = bool(valid_fields["confirm_tracks"])
+
+        if "confirm_default" in valid_fields:
+            confirm_default = str(valid_fields["confirm_default"]).lower()
+            if confirm_default not in CONFIRM_DEFAULTS:
+                logger.warning(f"Invalid confirm_default value: {confirm_default}, using default")
+                confirm_default = self.DEFAULT_CONFIG.confirm_default
+            valid_fields["confirm_default"] = confirm_default
+
+        if valid_fields.get("library") is not None:
+            valid_fields["library"] = str(valid_fields["library"]).strip() or None
+
+        if "relax_politeness" in valid_fields:
+            valid_fields["relax_politeness"] = bool(valid_fields["relax_politeness"])
+
+        if "detect_silence" in valid_fields:
+            valid_fields["detect_silence"] = bool(valid_fields["detect_silence"])
 
         # Create config object with defaults for missing fields
         config_dict = dataclasses.asdict(self.DEFAULT_CONFIG)
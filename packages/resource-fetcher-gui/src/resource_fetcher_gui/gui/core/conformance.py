@@ -0,0 +1,273 @@
+"""Conformance test of the CLI output protocol the GUI relies on."""
+
+import dataclasses
+import logging
+import tempfile
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.mock_cli import MockCLIWrapper
+from resource_fetcher_gui.gui.core.output_parser import (
+    AlbumInfo,
+    DownloadSummary,
+    OutputParser,
+    SongProgress,
+)
+from resource_fetcher_gui.gui.core.self_test import SELF_TEST_URL
+
+logger = logging.getLogger(__name__)
+
+# Tracks downloaded by the conformance scenario
+CONFORMANCE_TRACKS = 2
+
+# Maximum time for the scenario's download (seconds)
+CONFORMANCE_TIMEOUT = 180
+
+# Fields and types of each dictionary event of OutputParser.parse_line
+EVENT_SCHEMA: dict[str, dict[str, type]] = {
+    "rate_banned": {"cooldown": int},
+    "scan_failed": {"title": str, "reason": str},
+    "wrong_content_type": {"title": str, "reason": str},
+    "sanity_failed": {"title": str, "reason": str},
+    "merge_progress": {"percent": int},
+    "processed": {"done": int, "total": int},
+    "mount_lost": {},
+    "mount_restored": {},
+    "error": {"message": str},
+}
+
+# Song statuses the progress widget can show
+SONG_STATUSES = ("pending", "downloading", "success", "failed", "skipped")
+
+# Keys of the politeness policy reported in the album header
+POLITENESS_KEYS = {"delay", "workers", "window", "relaxed"}
+
+Event = SongProgress | AlbumInfo | DownloadSummary | dict[str, Any]
+
+
+@dataclass
+class ConformanceCheck:
+    """Result of one step of the conformance scenario.
+
+    Attributes:
+        name: Step name (version, start, exit, album, summary).
+        passed: Whether the step succeeded.
+        message: Details, e.g. why the step failed.
+    """
+
+    name: str
+    passed: bool
+    message: str = ""
+
+
+@dataclass
+class ProtocolViolation:
+    """An output line whose event doesn't match the protocol.
+
+    Attributes:
+        line_number: Line of the CLI output (1-based).
+        line: The line as printed by the CLI.
+        message: What is wrong with the event.
+    """
+
+    line_number: int
+    line: str
+    message: str
+
+
+@dataclass
+class ConformanceReport:
+    """Result of driving a CLI build through the conformance scenario.
+
+    Attributes:
+        cli: Path of the CLI, or "mock" for the simulated CLI.
+        checks: Results of the scenario's steps, in order.
+        violations: Events that don't match the protocol.
+        lines: Number of output lines read.
+        events: Number of typed events validated.
+    """
+
+    cli: str
+    checks: list[ConformanceCheck] = field(default_factory=list)
+    violations: list[ProtocolViolation] = field(default_factory=list)
+    lines: int = 0
+    events: int = 0
+
+    @property
+    def passed(self) -> bool:
+        """Whether every step passed and every event matched the protocol."""
+        return all(check.passed for check in self.checks) and not self.violations
+
+    def to_dict(self) -> dict[str, Any]:
+        """Convert the report to plain data, e.g. for JSON output.
+
+        Returns:
+            The report's fields plus "passed".
+        """
+        return {**dataclasses.asdict(self), "passed": self.passed}
+
+
+def _check_fields(values: dict[str, Any], schema: dict[str, type]) -> list[str]:
+    """Compare an event's fields with their expected types.
+
+    Args:
+        values: Field name mapped to value.
+        schema: Field name mapped to expected type.
+
+    Returns:
+        Problems found, empty if the fields match.
+    """
+    problems = [f"missing field {name}" for name in schema if name not in values]
+    problems += [f"unexpected field {name}" for name in values if name not in schema]
+    for name, expected in schema.items():
+        value = values.get(name)
+        # bool is an int subclass, but never a valid count
+        if name in values and (not isinstance(value, expected) or isinstance(value, bool)):
+            problems.append(f"{name} should be {expected.__name__}, got {value!r}")
+    return problems
+
+
+def validate_event(event: Event) -> list[str]:
+    """Check a parsed event against the protocol.
+
+    Args:
+        event: Event returned by OutputParser.parse_line.
+
+    Returns:
+        Problems found, empty if the event is valid.
+    """
+    if isinstance(event, SongProgress):
+        problems = []
+        if not 1 <= event.index <= event.total:
+            problems.append(f"track {event.index} outside 1-{event.total}")
+        if not event.title.strip():
+            problems.append("empty song title")
+        if event.status not in SONG_STATUSES:
+            problems.append(f"unknown song status {event.status!r}")
+        return problems
+
+    if isinstance(event, AlbumInfo):
+        problems = []
+        if event.song_count < 0:
+            problems.append(f"negative song count {event.song_count}")
+        if event.politeness is not None and set(event.politeness) != POLITENESS_KEYS:
+            problems.append(f"politeness should have {sorted(POLITENESS_KEYS)}")
+        return problems
+
+    if isinstance(event, DownloadSummary):
+        counts = (event.success, event.failed, event.skipped, event.total, event.downloaded_bytes)
+        problems = [] if min(counts) >= 0 else ["negative count in summary"]
+        if event.success + event.failed + event.skipped > event.total:
+            problems.append(
+                f"{event.success} + {event.failed} + {event.skipped} tracks exceed "
+                f"the total {event.total}"
+            )
+        return problems
+
+    event_type = event.get("type")
+    if event_type not in EVENT_SCHEMA:
+        return [f"unknown event type {event_type!r}"]
+    values = {name: value for name, value in event.items() if name != "type"}
+    problems = _check_fields(values, EVENT_SCHEMA[event_type])
+    if problems:
+        return problems
+    if event_type == "merge_progress" and not 0 <= event["percent"] <= 100:
+        problems.append(f"merge progress {event['percent']}% outside 0-100")
+    if event_type == "processed" and not 0 <= event["done"] <= event["total"]:
+        problems.append(f"{event['done']} of {event['total']} files processed")
+    return problems
+
+
+def run_protocol_conformance(
+    cli_path: Path | None,
+    url: str = SELF_TEST_URL,
+    timeout: float = CONFORMANCE_TIMEOUT,
+) -> ConformanceReport:
+    """Drive a CLI through a scripted download and validate everything it prints.
+
+    The scenario asks for the version, then downloads CONFORMANCE_TRACKS
+    tracks of an album into a temporary folder. Every output line is parsed
+    and each event validated; the album header has to come before the first
+    track and the summary has to close the output. Call it from a background
+    thread.
+
+    Args:
+        cli_path: CLI executable to test, or None for the simulated CLI.
+        url: Album to download.
+        timeout: Maximum seconds to wait for the download.
+
+    Returns:
+        The conformance report.
+    """
+    if cli_path is None:
+        cli_wrapper: CLIWrapper = MockCLIWrapper(delay=0.01, fail_every=0)
+        report = ConformanceReport("mock")
+    else:
+        report = ConformanceReport(str(cli_path))
+        try:
+            cli_wrapper = CLIWrapper(cli_path)
+        except (FileNotFoundError, ValueError) as e:
+            report.checks.append(ConformanceCheck("start", False, str(e)))
+            return report
+
+    def check(name: str, passed: bool, message: str = "") -> None:
+        report.checks.append(ConformanceCheck(name, passed, message))
+        logger.info(f"Conformance check {name}: {'passed' if passed else 'failed'} {message}")
+
+    version = cli_wrapper.get_cli_version()
+    check("version", bool(version), version or "No version reported")
+
+    with tempfile.TemporaryDirectory(prefix="resource-fetcher-conformance-") as tmp:
+        config = DownloadConfig(output_dir=tmp, limit=CONFORMANCE_TRACKS, overwrite=True)
+        lines: list[str] = []
+        exit_codes: list[int] = []
+        try:
+            thread = cli_wrapper.execute_download(url, config, lines.append, exit_codes.append)
+        except (OSError, ValueError) as e:
+            check("start", False, str(e))
+            return report
+        check("start", True)
+
+        thread.join(timeout)
+        if thread.is_alive():
+            cli_wrapper.stop_download()
+            check("exit", False, f"No result after {timeout:.0f} seconds")
+            return report
+        exit_code = exit_codes[0] if exit_codes else None
+        check("exit", exit_code == 0, f"Exit code {exit_code}")
+
+    parser = OutputParser()
+    events: list[Event] = []
+    report.lines = len(lines)
+    for number, line in enumerate(lines, 1):
+        event = parser.parse_line(line)
+        if event is None:
+            continue
+        events.append(event)
+        for problem in validate_event(event):
+            report.violations.append(ProtocolViolation(number, line.rstrip("\n"), problem))
+    report.events = len(events)
+
+    kinds = [type(event) for event in events]
+    if AlbumInfo not in kinds:
+        check("album", False, "No album header")
+    elif SongProgress in kinds and kinds.index(SongProgress) < kinds.index(AlbumInfo):
+        check("album", False, "Track progress before the album header")
+    else:
+        check("album", True)
+
+    if DownloadSummary not in kinds:
+        check("summary", False, "No download summary")
+    elif kinds[-1] is not DownloadSummary:
+        check("summary", False, "Events after the download summary")
+    else:
+        check("summary", True)
+
+    logger.info(
+        f"Conformance of {report.cli}: {'passed' if report.passed else 'failed'}, "
+        f"{report.events} events, {len(report.violations)} violations"
+    )
+    return report
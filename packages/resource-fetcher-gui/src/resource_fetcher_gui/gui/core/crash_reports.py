@@ -0,0 +1,145 @@
+"""Opt-in crash reports written to the app data directory."""
+
+import json
+import logging
+import platform
+import sys
+import threading
+import traceback
+from datetime import datetime
+from pathlib import Path
+from types import TracebackType
+
+from resource_fetcher_gui import __version__
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir, get_app_data_dir
+from resource_fetcher_gui.gui.core.debug_bundle import sanitize_text
+
+logger = logging.getLogger(__name__)
+
+# Only the most recent reports are kept
+MAX_CRASH_REPORTS = 20
+
+
+class CrashReporter:
+    """Writes a report for every unhandled exception once the user opted in.
+
+    Reports stay on this computer; they are plain text files the user can
+    attach to a bug report.
+    """
+
+    def __init__(self, crash_dir: Path | None = None, settings_path: Path | None = None) -> None:
+        """Initialize crash reporter.
+
+        Args:
+            crash_dir: Optional folder for crash reports.
+                       If not provided, uses the "crashes" folder in the app data directory.
+            settings_path: Optional path to the file storing the user's consent.
+                           If not provided, uses crash_reports.json in the settings directory.
+        """
+        self.crash_dir = crash_dir or get_app_data_dir() / "crashes"
+        self.settings_path = settings_path or get_app_config_dir() / "crash_reports.json"
+        logger.debug(f"CrashReporter initialized with directory: {self.crash_dir}")
+
+    @property
+    def enabled(self) -> bool:
+        """Whether the user agreed to crash reports being written."""
+        try:
+            with open(self.settings_path, encoding="utf-8") as f:
+                return json.load(f).get("enabled") is True
+        except (OSError, json.JSONDecodeError, AttributeError):
+            return False
+
+    def set_enabled(self, enabled: bool) -> None:
+        """Store the user's consent.
+
+        Args:
+            enabled: Whether crash reports may be written.
+        """
+        self.settings_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.settings_path, "w", encoding="utf-8") as f:
+            json.dump({"enabled": enabled}, f, indent=2)
+        logger.info(f"Crash reports {'enabled' if enabled else 'disabled'}")
+
+    def write_report(
+        self,
+        exc_type: type[BaseException],
+        exc: BaseException,
+        tb: TracebackType | None,
+        thread_name: str | None = None,
+    ) -> Path | None:
+        """Write a report for an unhandled exception.
+
+        Args:
+            exc_type: Exception class.
+            exc: The exception.
+            tb: Traceback of the exception.
+            thread_name: Name of the thread the exception escaped from.
+
+        Returns:
+            Path to the report, or None if crash reports are disabled or
+            the report couldn't be written.
+        """
+        if not self.enabled:
+            return None
+
+        now = datetime.now()
+        lines = [
+            f"Time: {now.isoformat(timespec='seconds')}",
+            f"App version: {__version__}",
+            f"Python version: {sys.version.split()[0]}",
+            f"Platform: {platform.platform()}",
+            f"Thread: {thread_name or threading.current_thread().name}",
+            "",
+            *traceback.format_exception(exc_type, exc, tb),
+        ]
+        path = self.crash_dir / f"crash-{now.strftime('%Y%m%d-%H%M%S-%f')}.txt"
+        try:
+            self.crash_dir.mkdir(parents=True, exist_ok=True)
+            path.write_text(sanitize_text("\n".join(lines)), encoding="utf-8")
+            self._prune()
+        except OSError as e:
+            logger.warning(f"Failed to write crash report: {e}")
+            return None
+
+        logger.info(f"Crash report written to {path}")
+        return path
+
+    def list_reports(self) -> list[Path]:
+        """List the written crash reports.
+
+        Returns:
+            Report files, oldest first.
+        """
+        if not self.crash_dir.is_dir():
+            return []
+        return sorted(self.crash_dir.glob("crash-*.txt"))
+
+    def install(self) -> None:
+        """Report exceptions escaping the main thread and background threads.
+
+        The previous hooks still run, so exceptions are logged as before.
+        """
+        previous_excepthook = sys.excepthook
+        previous_threading_excepthook = threading.excepthook
+
+        def excepthook(
+            exc_type: type[BaseException], exc: BaseException, tb: TracebackType | None
+        ) -> None:
+            if not issubclass(exc_type, KeyboardInterrupt):
+                self.write_report(exc_type, exc, tb)
+            previous_excepthook(exc_type, exc, tb)
+
+        def threading_excepthook(args: threading.ExceptHookArgs) -> None:
+            if args.exc_value is not None:
+                thread_name = args.thread.name if args.thread else None
+                self.write_report(args.exc_type, args.exc_value, args.exc_traceback, thread_name)
+            previous_threading_excepthook(args)
+
+        sys.excepthook = excepthook
+        threading.excepthook = threading_excepthook
+
+    def _prune(self) -> None:
+        """Delete the oldest reports beyond MAX_CRASH_REPORTS."""
+        reports = self.list_reports()
+        for path in reports[: max(0, len(reports) - MAX_CRASH_REPORTS)]:
+            path.unlink(missing_ok=True)
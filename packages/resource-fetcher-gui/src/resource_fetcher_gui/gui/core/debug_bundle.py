@@ -0,0 +1,147 @@
+"""Export of logs, diagnostics and settings for bug reports."""
+
+import dataclasses
+import json
+import logging
+import platform
+import shutil
+import sys
+import zipfile
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui import __version__
+from resource_fetcher_gui.gui.core.config_service import ConfigService, get_log_dir, is_portable
+from resource_fetcher_gui.gui.core.job_log import JobLogStore
+
+logger = logging.getLogger(__name__)
+
+# Log file written by the CLI in the working directory
+CLI_LOG_FILE = Path("downloader.log")
+
+# Only the end of each log file is included (bytes)
+MAX_LOG_BYTES = 1024 * 1024
+
+
+def sanitize_text(text: str) -> str:
+    """Hide the user's home directory (and with it their user name).
+
+    Args:
+        text: Text that may contain local paths, plain or JSON encoded.
+
+    Returns:
+        Text with the home directory replaced by "~".
+    """
+    home = str(Path.home())
+    if len(home) <= 1:
+        return text
+    # Backslashes of Windows paths are doubled in JSON
+    return text.replace(json.dumps(home)[1:-1], "~").replace(home, "~")
+
+
+def create_diagnostics(cli_path: Path) -> dict[str, str | bool | None]:
+    """Describe the environment the GUI runs in.
+
+    Args:
+        cli_path: Path to the CLI executable.
+
+    Returns:
+        Versions, platform and availability of external tools.
+    """
+    return {
+        "app_version": __version__,
+        "python_version": sys.version.split()[0],
+        "platform": platform.platform(),
+        "cli_path": str(cli_path),
+        "cli_found": cli_path.exists(),
+        "ffmpeg": shutil.which("ffmpeg"),
+        "ffprobe": shutil.which("ffprobe"),
+        "portable": is_portable(),
+    }
+
+
+def read_log_tail(path: Path, max_bytes: int = MAX_LOG_BYTES) -> str:
+    """Read the end of a log file.
+
+    Args:
+        path: Log file.
+        max_bytes: Maximum number of bytes to read.
+
+    Returns:
+        The last max_bytes of the file, starting at a full line if cut.
+    """
+    size = path.stat().st_size
+    with open(path, "rb") as f:
+        f.seek(max(0, size - max_bytes))
+        data = f.read()
+    text = data.decode("utf-8", errors="replace")
+    if size > max_bytes:
+        text = text.partition("\n")[2]
+    return text
+
+
+def export_debug_bundle(
+    path: Path,
+    config_service: ConfigService,
+    job_log_store: JobLogStore,
+    cli_path: Path,
+    log_files: tuple[Path, ...] | None = None,
+    work_root: Path | None = None,
+) -> Path:
+    """Write a zip file with everything needed to investigate a problem.
+
+    The bundle contains diagnostics.json, settings.json, the end of each
+    existing log file and the event trail of the most recent job, with the
+    CLI log kept in the job's working directory if it failed. The user's home
+    directory is replaced by "~" in all of them.
+
+    Args:
+        path: Destination zip file.
+        config_service: Service providing the current settings.
+        job_log_store: Store holding the job event logs.
+        cli_path: Path to the CLI executable.
+        log_files: Log files to include if they exist. Defaults to the GUI
+            and CLI logs.
+        work_root: Optional folder with the working directories of jobs.
+
+    Returns:
+        Path to the written bundle.
+    """
+    if log_files is None:
+        log_files = (get_log_dir() / "gui.log", CLI_LOG_FILE)
+    diagnostics = create_diagnostics(cli_path)
+    diagnostics["exported_at"] = datetime.now().isoformat(timespec="seconds")
+    settings = dataclasses.asdict(config_service.load_config())
+
+    path.parent.mkdir(parents=True, exist_ok=True)
+    with zipfile.ZipFile(path, "w", compression=zipfile.ZIP_DEFLATED) as bundle:
+        bundle.writestr(
+            "diagnostics.json",
+            sanitize_text(json.dumps(diagnostics, indent=2, ensure_ascii=False)),
+        )
+        bundle.writestr(
+            "settings.json", sanitize_text(json.dumps(settings, indent=2, ensure_ascii=False))
+        )
+
+        for log_file in log_files:
+            try:
+                bundle.writestr(f"logs/{log_file.name}", sanitize_text(read_log_tail(log_file)))
+            except OSError as e:
+                logger.debug(f"Skipping log file {log_file}: {e}")
+
+        job_id = job_log_store.latest_job_id()
+        if job_id:
+            events = [dataclasses.asdict(event) for event in job_log_store.read(job_id)]
+            bundle.writestr(
+                f"jobs/{job_id}.json",
+                sanitize_text(json.dumps(events, indent=2, ensure_ascii=False)),
+            )
+            if work_root is not None:
+                try:
+                    job_log = work_root / job_id / CLI_LOG_FILE
+                    bundle.writestr(f"jobs/{job_id}.log", sanitize_text(read_log_tail(job_log)))
+                except OSError as e:
+                    logger.debug(f"No CLI log for job {job_id}: {e}")
+
+    logger.info(f"Debug bundle exported to {path}")
+    return path
@@ -0,0 +1,258 @@
+"""History of completed album downloads."""
+
+import dataclasses
+import json
+import logging
+from dataclasses import dataclass, field
+from datetime import datetime
+from pathlib import Path
+from typing import Any
+from urllib.parse import urlsplit, urlunsplit
+
+from resource_fetcher_gui.gui.core.config_service import get_app_data_dir
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class HistoryEntry:
+    """A finished album download.
+
+    Attributes:
+        url: Album URL the download used.
+        title: Album title, empty if the CLI didn't report it.
+        output_dir: Folder the album was downloaded to.
+        finished_at: ISO 8601 time the download finished.
+        job_id: Identifier of the download job.
+        bytes_downloaded: Size of the downloaded files.
+        completed: Whether the whole album was downloaded. Failed downloads
+            are recorded for their data usage only.
+        config: Download settings the job used, empty for entries recorded
+            by older versions.
+        note: Free-text note attached to the job.
+        metadata: Key/value annotations attached to the job.
+        environment: App, Python and CLI versions and the CLI command the
+            job ran with, empty for entries recorded by older versions.
+        bytes_transferred: Data received over the network, including retries
+            and discarded files, counted against data quotas. 0 for entries
+            recorded by older versions, which count bytes_downloaded instead.
+        track_bytes: Data received for each track number (as a string).
+    """
+
+    url: str
+    title: str
+    output_dir: str
+    finished_at: str
+    job_id: str = ""
+    bytes_downloaded: int = 0
+    completed: bool = True
+    config: dict[str, Any] = field(default_factory=dict)
+    note: str = ""
+    metadata: dict[str, str] = field(default_factory=dict)
+    environment: dict[str, Any] = field(default_factory=dict)
+    bytes_transferred: int = 0
+    track_bytes: dict[str, int] = field(default_factory=dict)
+
+    @property
+    def data_used(self) -> int:
+        """Data the download used, the transferred bytes if they were recorded."""
+        return max(self.bytes_transferred, self.bytes_downloaded)
+
+
+def normalize_url(url: str) -> str:
+    """Normalize an album URL for comparison.
+
+    Scheme and host are lowercased and fragments and trailing slashes are
+    dropped, so "HTTPS://Example.com/album/#top" matches
+    "https://example.com/album".
+
+    Args:
+        url: Album URL.
+
+    Returns:
+        Normalized URL.
+    """
+    parts = urlsplit(url.strip())
+    path = parts.path.rstrip("/")
+    return urlunsplit((parts.scheme.lower(), parts.netloc.lower(), path, parts.query, ""))
+
+
+def create_history_entry(
+    url: str,
+    output_dir: str,
+    title: str = "",
+    job_id: str = "",
+    bytes_downloaded: int = 0,
+    completed: bool = True,
+    config: dict[str, Any] | None = None,
+    note: str = "",
+    metadata: dict[str, str] | None = None,
+    environment: dict[str, Any] | None = None,
+    bytes_transferred: int = 0,
+    track_bytes: dict[str, int] | None = None,
+) -> HistoryEntry:
+    """Create a history entry for a download that just finished.
+
+    Args:
+        url: Album URL the download used.
+        output_dir: Folder the album was downloaded to.
+        title: Album title, if known.
+        job_id: Identifier of the download job.
+        bytes_downloaded: Size of the downloaded files.
+        completed: Whether the whole album was downloaded.
+        config: Download settings the job used.
+        note: Free-text note attached to the job.
+        metadata: Key/value annotations attached to the job.
+        environment: Versions and CLI command the job ran with.
+        bytes_transferred: Data received over the network.
+        track_bytes: Data received for each track number.
+
+    Returns:
+        HistoryEntry stamped with the current time.
+    """
+    return HistoryEntry(
+        url=url,
+        title=title,
+        output_dir=output_dir,
+        finished_at=datetime.now().isoformat(timespec="seconds"),
+        job_id=job_id,
+        bytes_downloaded=bytes_downloaded,
+        completed=completed,
+        config=config or {},
+        note=note,
+        metadata=metadata or {},
+        environment=environment or {},
+        bytes_transferred=bytes_transferred,
+        track_bytes=track_bytes or {},
+    )
+
+
+class DownloadHistory:
+    """Persistent history of completed downloads in a JSON Lines file."""
+
+    def __init__(self, history_path: Path | None = None) -> None:
+        """Initialize download history.
+
+        Args:
+            history_path: Optional custom path to the history file.
+                          If not provided, uses history.jsonl in the app data directory.
+        """
+        self.history_path = history_path or get_app_data_dir() / "history.jsonl"
+        logger.debug(f"DownloadHistory initialized with path: {self.history_path}")
+
+    def record(self, entry: HistoryEntry) -> None:
+        """Append a completed download to the history.
+
+        Args:
+            entry: History entry to persist.
+        """
+        try:
+            self.history_path.parent.mkdir(parents=True, exist_ok=True)
+            with open(self.history_path, "a", encoding="utf-8") as f:
+                f.write(json.dumps(dataclasses.asdict(entry), ensure_ascii=False) + "\n")
+        except OSError as e:
+            logger.warning(f"Failed to write download history: {e}")
+
+    def entries(self) -> list[HistoryEntry]:
+        """Read all completed downloads.
+
+        Returns:
+            History entries, oldest first.
+        """
+        if not self.history_path.exists():
+            return []
+
+        entries = []
+        with open(self.history_path, encoding="utf-8") as f:
+            for line in f:
+                try:
+                    entries.append(HistoryEntry(**json.loads(line)))
+                except (json.JSONDecodeError, TypeError):
+                    logger.warning(f"Skipping malformed history entry in {self.history_path}")
+        return entries
+
+    def get_job(self, job_id: str) -> HistoryEntry | None:
+        """Find the history entry of a job.
+
+        Args:
+            job_id: Identifier of the download job.
+
+        Returns:
+            The job's latest entry, or None if it wasn't recorded.
+        """
+        matches = [entry for entry in self.entries() if entry.job_id == job_id]
+        return matches[-1] if matches else None
+
+    def find(self, url: str) -> HistoryEntry | None:
+        """Find the most recent completed download of an album.
+
+        Args:
+            url: Album URL.
+
+        Returns:
+            Latest matching history entry, or None if the album was never downloaded.
+        """
+        normalized = normalize_url(url)
+        matches = [
+            entry
+            for entry in self.entries()
+            if entry.completed and normalize_url(entry.url) == normalized
+        ]
+        return matches[-1] if matches else None
+
+    def search(self, query: str) -> list[HistoryEntry]:
+        """Search the history.
+
+        A "key=value" query matches entries with that metadata value. Any
+        other query matches entries whose URL, title, output folder, note or
+        metadata contain it, ignoring case.
+
+        Args:
+            query: Text to search for.
+
+        Returns:
+            Matching entries, oldest first.
+        """
+        query = query.strip()
+        key, separator, value = query.partition("=")
+        if separator and key.strip():
+            return [
+                entry
+                for entry in self.entries()
+                if entry.metadata.get(key.strip()) == value.strip()
+            ]
+
+        text = query.casefold()
+        return [
+            entry
+            for entry in self.entries()
+            if any(
+                text in field_value.casefold()
+                for field_value in (
+                    entry.url,
+                    entry.title,
+                    entry.output_dir,
+                    entry.note,
+                    *entry.metadata.keys(),
+                    *entry.metadata.values(),
+                )
+            )
+        ]
+
+    def bytes_since(self, start: datetime) -> int:
+        """Sum the data used by downloads finished since a time.
+
+        Args:
+            start: Start of the period.
+
+        Returns:
+            Bytes used, including those of failed downloads.
+        """
+        total = 0
+        for entry in self.entries():
+            try:
+                if datetime.fromisoformat(entry.finished_at) >= start:
+                    total += entry.data_used
+            except ValueError:
+                logger.warning(f"Skipping history entry with invalid time: {entry.finished_at}")
+        return total
@@ -0,0 +1,352 @@
+"""Localization of GUI backend messages."""
+
+import locale
+import logging
+import os
+
+logger = logging.getLogger(__name__)
+
+# Locale used when the system locale is not supported
+DEFAULT_LOCALE = "en"
+
+# Message catalog: locale -> message key -> format string
+MESSAGES: dict[str, dict[str, str]] = {
+    "en": {
+        "job_timeout": "Job exceeded time limit of {seconds} seconds",
+        "track_timeout": "Track exceeded time limit of {seconds} seconds",
+        "download_started": "Starting download from: {url}",
+        "download_completed": "Download completed successfully!",
+        "download_timed_out": "Download timed out: {reason}",
+        "download_failed": "Download failed with exit code {exit_code}",
+        "download_failed_songs": "Download completed with errors. {count} songs failed.",
+        "download_stopped": "Download stopped by user",
+        "no_download": "No download in progress",
+        "invalid_url": "Invalid URL: {error}",
+        "cli_not_found": "CLI executable not found. Please build the project first.",
+        "config_updated": "Configuration updated",
+        "time_limit_exceeded": "time limit exceeded",
+        "settings_exported": "Settings exported to {path}",
+        "settings_export_failed": "Failed to export settings: {error}",
+        "debug_bundle_exported": "Debug bundle exported to {path}",
+        "debug_bundle_failed": "Failed to export debug bundle: {error}",
+        "crash_reports_enabled": "Crash reports will be saved on this computer",
+        "crash_reports_disabled": "Crash reports will no longer be saved",
+        "crash_reports_failed": "Failed to access crash reports: {error}",
+        "self_test_running": "Running self-test...",
+        "self_test_passed": "Self-test passed: downloading works",
+        "self_test_failed": "Self-test failed at stage {stage}: {message}",
+        "benchmark_running": "Measuring download speed...",
+        "output_dropped": "{count} lines of CLI output not shown (see the job log)",
+        "benchmark_failed": "Benchmark failed: {error}",
+        "console_exited": "Console command exited with code {code}",
+        "console_failed": "Console command failed to start: {error}",
+        "benchmark_speed": "{streams} connections: {speed:.2f} MB/s",
+        "benchmark_workers_kept": "Parallel songs setting ({workers}) already suits the connection",
+        "benchmark_workers_prompt": "Downloading {workers} songs at once is fastest on this "
+        "connection. Use this setting?",
+        "settings_imported": "Settings imported from {path}",
+        "settings_import_failed": "Failed to import settings: {error}",
+        "backup_created": "Backed up {count} files to {path}",
+        "backup_failed": "Failed to write backup: {error}",
+        "backup_restored": (
+            "Restored {count} files from {path}. Restart the application to use them."
+        ),
+        "backup_restore_failed": "Failed to restore backup: {error}",
+        "backup_restore_busy": "A backup can be restored once the downloads have finished",
+        "backup_credentials": "{name} refers to {path}, which isn't part of the backup",
+        "no_job_log": "No download log available yet",
+        "job_queued": "Added to queue ({count} waiting): {url}",
+        "jobs_queued": "Queued {count} jobs from {path}",
+        "job_file_failed": "Failed to load job file: {error}",
+        "no_finished_job": "No finished download to export",
+        "redownload_queued": "Downloading again to {path}: {url}",
+        "volume_lost": "Output drive disappeared, downloads wait for it to return: {path}",
+        "volume_back": "Output drive is back, resuming downloads: {path}",
+        "history_matches": '{count} downloads in the history match "{query}"',
+        "job_not_found": "No download with job ID {job_id} in the history",
+        "queue_empty": "The queue has no jobs",
+        "migration_done": "Upgraded {target} to version {version} "
+        "({count} files, originals kept as .bak)",
+        "migration_failed": "Upgrading {target} failed, the old files were kept: {error}",
+        "migration_skipped": "{count} {target} files couldn't be upgraded "
+        "and were left as they were: {files}",
+        "queued_jobs_moved": "{count} queued jobs will download to {path}",
+        "waiting_for": "waiting for {jobs}",
+        "cleanup_limits_saved": "Cleanup limits saved",
+        "cleanup_busy": "Old files can be cleaned up once the downloads have finished",
+        "cleanup_done": "Removed {count} old job files ({size:.1f} MB freed)",
+        "maintenance_findings": "Maintenance ({task}) found {count} issues, see the log",
+        "maintenance_failed": "Maintenance ({task}) failed: {error}",
+        "maintenance_running": "Maintenance is already running",
+        "maintenance_run": "{finished_at} {task}: {count} findings",
+        "no_maintenance_runs": "No maintenance has run yet",
+        "failures_exported": "Exported {count} failed tracks to {path}",
+        "failures_export_failed": "Failed to export failed tracks: {error}",
+        "template_saved": "Template saved: {name}",
+        "batch_progress": "Batch: {percent}% ({done}/{total} albums, "
+        "{tracks}/{tracks_total} tracks)",
+        "already_downloaded": "Already downloaded on {date} to {location}",
+        "redownload_prompt": "This album was already downloaded on {date} to:\n{location}\n\n"
+        "Download it again?",
+        "redownload_skipped": "Skipped already downloaded album: {url}",
+        "already_queued": "Album is already queued or downloading: {url}",
+        "merge_progress": "Merging album: {percent}%",
+        "processed_progress": "Processed {done} of {total} downloaded files",
+        "summary_minutes": "{done} of {total} tracks done, about {minutes} minutes remaining",
+        "summary_under_a_minute": "{done} of {total} tracks done, less than a minute remaining",
+        "summary_estimating": "{done} of {total} tracks done, estimating time remaining",
+        "summary_finished": "All {total} tracks done",
+        "bookmarks_found": "Found {count} supported albums in {path}",
+        "bookmarks_failed": "Failed to read bookmarks: {error}",
+        "no_bookmarks": "No supported albums found in {path}",
+        "rate_banned": "The site is refusing requests, queued downloads wait {minutes} min",
+        "rate_ban_over": "Rate limit cooldown over, downloads resumed",
+        "scan_failed": "Scanner rejected {title}, file kept in quarantine: {reason}",
+        "wrong_content_type": "{title} is not an audio file and was discarded: {reason}",
+        "sanity_failed": "{title} failed the sanity check and was discarded: {reason}",
+        "mount_lost": "Output share disconnected, download paused until it reconnects",
+        "mount_restored": "Output share reconnected, download resumed",
+        "artist_albums_found": "Found {count} albums of the artist at {url}",
+        "artist_failed": "Failed to list the albums of the artist: {error}",
+        "artist_followed": "Following {url} ({count} albums so far)",
+        "artist_unfollowed": "No longer following {url}",
+        "new_release": "{count} new albums of {url}",
+        "no_notifications": "No notifications",
+        "undelivered_results": "{count} job results from the last session weren't shown:",
+        "source_rule_saved": "Source rule saved: {pattern}",
+        "source_rule_removed": "Source rule removed: {pattern}",
+        "library_saved": "Library saved: {name}",
+        "library_removed": "Library removed: {name}",
+        "library_active": "New jobs download into: {name}",
+        "no_library": "the output directory",
+        "concurrency_slowed": "Slowing down {source} (level {level}): {reason}",
+        "concurrency_restored": "{source} back to normal speed: {reason}",
+        "custom_sources_hint": "Put source definitions (*.json) into {path} and restart",
+        "custom_sources_failed": "Failed to open the custom sources folder: {error}",
+        "interaction_title": "Input Required",
+        "interaction_required": "{prompt}\n\nPage: {url}",
+        "interaction_not_delivered": "The job no longer waits for input",
+        "speed_limit_saved": "Speed limit window saved: {window}",
+        "speed_limit_removed": "Speed limit window removed: {window}",
+        "speed_limit_changed": "Speed limit window active: {window}",
+        "speed_limit_ended": "No speed limit window active",
+        "quota_saved": "Data quotas saved",
+        "sounds_saved": "Sounds saved",
+        "sounds_enabled": "Sounds will be played when downloads end",
+        "sounds_disabled": "No sounds will be played",
+        "quota_exceeded": "{period} data quota reached ({used}/{limit} MB), paused until {resets}",
+        "quota_resumed": "Data quota allows downloads again, resuming",
+        "quota_day": "Daily",
+        "session_restored": "Restored {count} unfinished jobs from the last session",
+        "quota_month": "Monthly",
+        "app_paths_title": "File Locations",
+        "app_path_config": "Settings",
+        "app_path_data": "Data",
+        "app_path_cache": "Cache",
+        "app_path_logs": "Logs",
+    },
+    "zh": {
+        "job_timeout": "任务超过时间限制 {seconds} 秒",
+        "track_timeout": "单曲超过时间限制 {seconds} 秒",
+        "download_started": "开始下载: {url}",
+        "download_completed": "下载成功完成!",
+        "download_timed_out": "下载超时: {reason}",
+        "download_failed": "下载失败, 退出码 {exit_code}",
+        "download_failed_songs": "下载完成但有错误, {count} 首歌曲失败.",
+        "download_stopped": "用户已停止下载",
+        "no_download": "当前没有进行中的下载",
+        "invalid_url": "无效的链接: {error}",
+        "cli_not_found": "未找到 CLI 可执行文件, 请先构建项目.",
+        "config_updated": "配置已更新",
+        "time_limit_exceeded": "超过时间限制",
+        "settings_exported": "设置已导出到 {path}",
+        "settings_export_failed": "导出设置失败: {error}",
+        "debug_bundle_exported": "调试包已导出到 {path}",
+        "debug_bundle_failed": "导出调试包失败: {error}",
+        "crash_reports_enabled": "崩溃报告将保存在本机",
+        "crash_reports_disabled": "不再保存崩溃报告",
+        "crash_reports_failed": "无法访问崩溃报告: {error}",
+        "self_test_running": "正在运行自检...",
+        "self_test_passed": "自检通过: 下载正常",
+        "self_test_failed": "自检在 {stage} 阶段失败: {message}",
+        "benchmark_running": "正在测量下载速度...",
+        "output_dropped": "{count} 行命令行输出未显示 (见任务日志)",
+        "benchmark_failed": "测速失败: {error}",
+        "console_exited": "控制台命令已退出, 退出码 {code}",
+        "console_failed": "控制台命令启动失败: {error}",
+        "benchmark_speed": "{streams} 个连接: {speed:.2f} MB/s",
+        "benchmark_workers_kept": "并行歌曲数设置 ({workers}) 已适合当前网络",
+        "benchmark_workers_prompt": "同时下载 {workers} 首歌曲在当前网络下最快。是否使用此设置?",
+        "settings_imported": "已从 {path} 导入设置",
+        "settings_import_failed": "导入设置失败: {error}",
+        "backup_created": "已将 {count} 个文件备份到 {path}",
+        "backup_failed": "写入备份失败: {error}",
+        "backup_restored": "已从 {path} 恢复 {count} 个文件, 请重启应用以使用它们",
+        "backup_restore_failed": "恢复备份失败: {error}",
+        "backup_restore_busy": "下载完成后才能恢复备份",
+        "backup_credentials": "{name} 指向 {path}, 该文件不在备份中",
+        "no_job_log": "暂无下载日志",
+        "job_queued": "已加入队列 (等待 {count} 个): {url}",
+        "jobs_queued": "已从 {path} 加入 {count} 个任务",
+        "job_file_failed": "加载任务文件失败: {error}",
+        "no_finished_job": "没有可导出的已完成下载",
+        "redownload_queued": "正在重新下载到 {path}: {url}",
+        "volume_lost": "输出驱动器已断开, 下载将等待其重新连接: {path}",
+        "volume_back": "输出驱动器已重新连接, 继续下载: {path}",
+        "history_matches": '历史记录中有 {count} 个下载匹配 "{query}"',
+        "job_not_found": "历史记录中没有任务 ID 为 {job_id} 的下载",
+        "queue_empty": "队列中没有任务",
+        "migration_done": "已将 {target} 升级到版本 {version} ({count} 个文件, 原文件已保存为 .bak)",
+        "migration_failed": "升级 {target} 失败, 已保留旧文件: {error}",
+        "migration_skipped": "{count} 个 {target} 文件无法升级, 已保持原样: {files}",
+        "queued_jobs_moved": "{count} 个排队任务将下载到 {path}",
+        "waiting_for": "等待 {jobs}",
+        "cleanup_limits_saved": "清理限制已保存",
+        "cleanup_busy": "下载完成后才能清理旧文件",
+        "cleanup_done": "已删除 {count} 个旧任务文件 (释放 {size:.1f} MB)",
+        "maintenance_findings": "维护任务 ({task}) 发现 {count} 个问题, 详见日志",
+        "maintenance_failed": "维护任务 ({task}) 失败: {error}",
+        "maintenance_running": "维护任务正在运行",
+        "maintenance_run": "{finished_at} {task}: {count} 个发现",
+        "no_maintenance_runs": "尚未运行过维护任务",
+        "failures_exported": "已将 {count} 首失败歌曲导出到 {path}",
+        "failures_export_failed": "导出失败歌曲失败: {error}",
+        "template_saved": "模板已保存: {name}",
+        "batch_progress": "批量: {percent}% ({done}/{total} 张专辑, {tracks}/{tracks_total} 首)",
+        "already_downloaded": "已于 {date} 下载到 {location}",
+        "redownload_prompt": "该专辑已于 {date} 下载到:\n{location}\n\n是否重新下载?",
+        "redownload_skipped": "已跳过已下载的专辑: {url}",
+        "already_queued": "该专辑已在队列中或正在下载: {url}",
+        "merge_progress": "正在合并专辑: {percent}%",
+        "processed_progress": "已处理 {done}/{total} 个已下载的文件",
+        "summary_minutes": "已完成 {done}/{total} 首, 大约还需 {minutes} 分钟",
+        "summary_under_a_minute": "已完成 {done}/{total} 首, 还需不到一分钟",
+        "summary_estimating": "已完成 {done}/{total} 首, 正在估算剩余时间",
+        "summary_finished": "全部 {total} 首已完成",
+        "bookmarks_found": "在 {path} 中找到 {count} 个支持的专辑",
+        "bookmarks_failed": "读取书签失败: {error}",
+        "no_bookmarks": "在 {path} 中未找到支持的专辑",
+        "rate_banned": "网站暂时拒绝请求, 队列中的下载将等待 {minutes} 分钟",
+        "rate_ban_over": "限流冷却结束, 下载已继续",
+        "scan_failed": "扫描未通过: {title}, 文件已保留在隔离区: {reason}",
+        "wrong_content_type": "{title} 不是音频文件, 已丢弃: {reason}",
+        "sanity_failed": "{title} 未通过完整性检查, 已丢弃: {reason}",
+        "mount_lost": "输出共享已断开, 下载已暂停, 等待重新连接",
+        "mount_restored": "输出共享已重新连接, 下载已继续",
+        "artist_albums_found": "在 {url} 中找到该歌手的 {count} 个专辑",
+        "artist_failed": "获取歌手专辑列表失败: {error}",
+        "artist_followed": "已关注 {url} (目前 {count} 个专辑)",
+        "artist_unfollowed": "已取消关注 {url}",
+        "new_release": "{url} 有 {count} 个新专辑",
+        "no_notifications": "没有通知",
+        "undelivered_results": "上次运行有 {count} 个任务结果未显示:",
+        "source_rule_saved": "来源规则已保存: {pattern}",
+        "source_rule_removed": "来源规则已删除: {pattern}",
+        "library_saved": "媒体库已保存: {name}",
+        "library_removed": "媒体库已删除: {name}",
+        "library_active": "新任务将下载到: {name}",
+        "no_library": "输出目录",
+        "concurrency_slowed": "已降低 {source} 的下载速度 (级别 {level}): {reason}",
+        "concurrency_restored": "{source} 已恢复正常速度: {reason}",
+        "custom_sources_hint": "将来源定义 (*.json) 放入 {path} 后重新启动",
+        "custom_sources_failed": "无法打开自定义来源文件夹: {error}",
+        "interaction_title": "需要输入",
+        "interaction_required": "{prompt}\n\n页面: {url}",
+        "interaction_not_delivered": "任务已不再等待输入",
+        "speed_limit_saved": "限速时段已保存: {window}",
+        "speed_limit_removed": "限速时段已删除: {window}",
+        "speed_limit_changed": "当前限速时段: {window}",
+        "speed_limit_ended": "当前没有限速时段",
+        "quota_saved": "流量配额已保存",
+        "sounds_saved": "提示音已保存",
+        "sounds_enabled": "下载结束时将播放提示音",
+        "sounds_disabled": "将不再播放提示音",
+        "quota_exceeded": "已达到{period}流量配额 ({used} / {limit} MB), 下载暂停至 {resets}",
+        "quota_resumed": "流量配额允许继续下载, 正在恢复",
+        "quota_day": "每日",
+        "session_restored": "已恢复上次未完成的 {count} 个任务",
+        "quota_month": "每月",
+        "app_paths_title": "文件位置",
+        "app_path_config": "设置",
+        "app_path_data": "数据",
+        "app_path_cache": "缓存",
+        "app_path_logs": "日志",
+    },
+}
+
+_locale_override: str | None = None
+
+
+def _normalize(value: str) -> str | None:
+    """Map a locale name such as "zh_CN.UTF-8" to a supported catalog locale.
+
+    Args:
+        value: Locale name.
+
+    Returns:
+        Supported locale code, or None if the language is not supported.
+    """
+    language = value.split(".")[0].split("_")[0].split("-")[0].lower()
+    return language if language in MESSAGES else None
+
+
+def get_locale() -> str:
+    """Get the locale used for backend messages.
+
+    Uses the override set with set_locale(), then the LC_ALL, LC_MESSAGES,
+    LANGUAGE and LANG environment variables, then the system default locale.
+
+    Returns:
+        Supported locale code (e.g. "en" or "zh").
+    """
+    if _locale_override:
+        return _locale_override
+
+    candidates = [os.environ.get(name, "") for name in ("LC_ALL", "LC_MESSAGES", "LANGUAGE")]
+    candidates.append(os.environ.get("LANG", ""))
+    try:
+        candidates.append(locale.getlocale()[0] or "")
+    except ValueError:
+        pass
+
+    for candidate in candidates:
+        # LANGUAGE may hold a priority list such as "zh_CN:en"
+        for value in candidate.split(":"):
+            if value and value not in ("C", "POSIX") and (code := _normalize(value)):
+                return code
+
+    return DEFAULT_LOCALE
+
+
+def set_locale(code: str | None) -> None:
+    """Override the detected locale.
+
+    Args:
+        code: Supported locale code, or None to use system detection again.
+
+    Raises:
+        ValueError: If the locale is not supported.
+    """
+    global _locale_override
+
+    if code is not None and code not in MESSAGES:
+        raise ValueError(f"Unsupported locale: {code}")
+
+    _locale_override = code
+    logger.debug(f"Locale override set to: {code}")
+
+
+def tr(key: str, **kwargs: object) -> str:
+    """Translate a message key into the current locale.
+
+    Falls back to the default locale, then to the key itself.
+
+    Args:
+        key: Message key in the catalog.
+        **kwargs: Values substituted into the message.
+
+    Returns:
+        Localized message.
+    """
+    template = MESSAGES[get_locale()].get(key) or MESSAGES[DEFAULT_LOCALE].get(key, key)
+    return template.format(**kwargs)
@@ -0,0 +1,335 @@
+"""Declarative batch job files (JSON or YAML)."""
+
+import dataclasses
+import json
+import logging
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_core.utils.manifest import read_manifest
+from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.job_queue import JobQueue, QueuedJob
+
+logger = logging.getLogger(__name__)
+
+# File suffixes parsed as YAML (requires PyYAML)
+YAML_SUFFIXES = (".yaml", ".yml")
+
+# Job keys that are not download settings ("failed_tracks" is written by
+# export_failures for other tools and ignored when loading)
+JOB_KEYS = ("url", "mirrors", "note", "metadata", "failed_tracks", "id", "after")
+
+# Manifest status of tracks that failed to download
+FAILED_STATUS = "failed"
+
+
+@dataclass
+class JobSpec:
+    """A job described in a job file.
+
+    Attributes:
+        url: Album URL to download.
+        config: Download configuration for the job.
+        mirrors: Alternative URLs for the same album.
+        note: Free-text note kept in the download history.
+        metadata: Key/value annotations kept in the download history.
+        name: Optional name other jobs of the file refer to in "after".
+        after: Names of jobs that have to complete before this one runs.
+    """
+
+    url: str
+    config: DownloadConfig
+    mirrors: list[str] = field(default_factory=list)
+    note: str = ""
+    metadata: dict[str, str] = field(default_factory=dict)
+    name: str = ""
+    after: list[str] = field(default_factory=list)
+
+
+def _check_url(url: object, where: str) -> str:
+    """Validate a URL from the job file.
+
+    Args:
+        url: URL value from the job file.
+        where: Location of the URL, for error messages.
+
+    Returns:
+        The URL without surrounding whitespace.
+
+    Raises:
+        ValueError: If the URL is invalid.
+    """
+    if not isinstance(url, str) or not url.strip().startswith(("http://", "https://")):
+        raise ValueError(f"Invalid job file: {where} has an invalid URL: {url}")
+    return url.strip()
+
+
+def _read_job_file(path: Path) -> Any:
+    """Parse a job file as JSON or YAML depending on its suffix.
+
+    Args:
+        path: Job file path.
+
+    Returns:
+        Parsed document.
+
+    Raises:
+        ValueError: If the file can't be parsed.
+    """
+    text = path.read_text(encoding="utf-8")
+
+    if path.suffix.lower() in YAML_SUFFIXES:
+        try:
+            import yaml
+        except ImportError as e:
+            raise ValueError("YAML job files require PyYAML; use JSON instead") from e
+        try:
+            return yaml.safe_load(text)
+        except yaml.YAMLError as e:
+            raise ValueError(f"Invalid job file: {e}") from e
+
+    try:
+        return json.loads(text)
+    except json.JSONDecodeError as e:
+        raise ValueError(f"Invalid job file: {e}") from e
+
+
+def _check_overrides(values: dict[str, Any], where: str) -> None:
+    """Reject option names that are not download settings.
+
+    Args:
+        values: Option values from the job file.
+        where: Location of the values, for error messages.
+
+    Raises:
+        ValueError: If an option is unknown.
+    """
+    unknown = sorted(set(values) - set(DownloadConfig.__dataclass_fields__))
+    if unknown:
+        raise ValueError(f"Unknown options in {where}: {', '.join(unknown)}")
+
+
+def order_jobs(jobs: list[JobSpec]) -> list[JobSpec]:
+    """Sort jobs so every job comes after the jobs it depends on.
+
+    Args:
+        jobs: Jobs in file order.
+
+    Returns:
+        The jobs in file order, except that jobs are moved behind their dependencies.
+
+    Raises:
+        ValueError: If a job depends on an unknown name or dependencies form a cycle.
+    """
+    names = {job.name for job in jobs if job.name}
+    for job in jobs:
+        unknown = [name for name in job.after if name not in names]
+        if unknown:
+            raise ValueError(f"Invalid job file: {job.url} runs after unknown jobs: {unknown}")
+
+    ordered: list[JobSpec] = []
+    done: set[str] = set()
+    remaining = list(jobs)
+    while remaining:
+        ready = [job for job in remaining if set(job.after) <= done]
+        if not ready:
+            cycle = ", ".join(job.name or job.url for job in remaining)
+            raise ValueError(f"Invalid job file: dependencies form a cycle: {cycle}")
+        for job in ready:
+            ordered.append(job)
+            remaining.remove(job)
+            if job.name:
+                done.add(job.name)
+    return ordered
+
+
+def load_job_file(
+    path: Path,
+    base_config: DownloadConfig,
+    config_service: ConfigService | None = None,
+) -> list[JobSpec]:
+    """Load the jobs described by a job file.
+
+    The file contains either a list of jobs or a mapping with a "jobs" list
+    and optional "defaults" applied to every job. Each job is a URL string
+    or a mapping with a "url", optional "mirrors", a "note", "metadata"
+    annotations, an "id" and the ids of jobs it runs "after" (once they
+    have completed successfully), and per-job setting overrides, e.g.::
+
+        {
+          "defaults": {"output_dir": "./downloads", "retries": 5},
+          "jobs": [
+            "https://example.com/album/1.html",
+            {
+              "url": "https://example.com/album/2.html",
+              "mirrors": ["https://mirror.example.com/album/2.html"],
+              "note": "Requested by the choir",
+              "metadata": {"collection": "hymns"},
+              "limit": 10,
+              "id": "hymns"
+            },
+            {
+              "url": "https://example.com/album/3.html",
+              "after": ["hymns"]
+            }
+          ]
+        }
+
+    Args:
+        path: Job file (.json, or .yaml/.yml when PyYAML is installed).
+        base_config: Settings that defaults and overrides are applied to.
+        config_service: Optional config service used to validate settings.
+
+    Returns:
+        Job specifications in file order, each after the jobs it depends on.
+
+    Raises:
+        FileNotFoundError: If the job file doesn't exist.
+        ValueError: If the job file is invalid.
+    """
+    if not path.exists():
+        raise FileNotFoundError(f"Job file not found: {path}")
+
+    config_service = config_service or ConfigService()
+    document = _read_job_file(path)
+
+    defaults: dict[str, Any] = {}
+    if isinstance(document, dict):
+        defaults = document.get("defaults") or {}
+        if not isinstance(defaults, dict):
+            raise ValueError("Invalid job file: defaults must be a mapping")
+        _check_overrides(defaults, "defaults")
+        document = document.get("jobs")
+
+    if not isinstance(document, list) or not document:
+        raise ValueError("Invalid job file: no jobs")
+
+    base = {**dataclasses.asdict(base_config), **defaults}
+    jobs = []
+    for index, entry in enumerate(document, 1):
+        if isinstance(entry, str):
+            entry = {"url": entry}
+        if not isinstance(entry, dict) or "url" not in entry:
+            raise ValueError(f"Invalid job file: job {index} has no URL")
+
+        url = _check_url(entry["url"], f"job {index}")
+        mirrors = entry.get("mirrors") or []
+        if not isinstance(mirrors, list):
+            raise ValueError(f"Invalid job file: mirrors of job {index} must be a list")
+        mirrors = [_check_url(mirror, f"job {index}") for mirror in mirrors]
+        note = entry.get("note") or ""
+        metadata = entry.get("metadata") or {}
+        if not isinstance(note, str):
+            raise ValueError(f"Invalid job file: note of job {index} must be text")
+        if not isinstance(metadata, dict):
+            raise ValueError(f"Invalid job file: metadata of job {index} must be a mapping")
+        metadata = {str(key): str(value) for key, value in metadata.items()}
+        name = entry.get("id") or ""
+        if not isinstance(name, str):
+            raise ValueError(f"Invalid job file: id of job {index} must be text")
+        if name and name in [job.name for job in jobs]:
+            raise ValueError(f"Invalid job file: id {name!r} is used twice")
+        after = entry.get("after") or []
+        if isinstance(after, str):
+            after = [after]
+        if not isinstance(after, list) or not all(isinstance(item, str) for item in after):
+            raise ValueError(f"Invalid job file: after of job {index} must be a list of ids")
+
+        overrides = {key: value for key, value in entry.items() if key not in JOB_KEYS}
+        _check_overrides(overrides, f"job {index}")
+        config = config_service.config_from_dict({**base, **overrides})
+        jobs.append(
+            JobSpec(
+                url=url,
+                config=config,
+                mirrors=mirrors,
+                note=note,
+                metadata=metadata,
+                name=name,
+                after=after,
+            )
+        )
+
+    jobs = order_jobs(jobs)
+    logger.info(f"Loaded {len(jobs)} jobs from {path}")
+    return jobs
+
+
+def run_job_file(
+    path: Path,
+    queue: JobQueue,
+    base_config: DownloadConfig,
+    config_service: ConfigService | None = None,
+) -> list[QueuedJob]:
+    """Load a job file and enqueue all of its jobs.
+
+    Nothing is enqueued if the file is invalid.
+
+    Args:
+        path: Job file path.
+        queue: Queue receiving the jobs.
+        base_config: Settings that defaults and overrides are applied to.
+        config_service: Optional config service used to validate settings.
+
+    Returns:
+        The queued jobs.
+
+    Raises:
+        FileNotFoundError: If the job file doesn't exist.
+        ValueError: If the job file is invalid.
+    """
+    jobs = load_job_file(path, base_config, config_service)
+    queued: list[QueuedJob] = []
+    job_ids: dict[str, str] = {}
+    for job in jobs:
+        depends_on = [job_ids[name] for name in job.after]
+        queued_job = queue.enqueue(
+            job.url, job.config, job.mirrors, job.note, job.metadata, depends_on
+        )
+        queued.append(queued_job)
+        if job.name:
+            job_ids[job.name] = queued_job.job_id
+    return queued
+
+
+def export_failures(job: QueuedJob, path: Path) -> int:
+    """Write a job file retrying the tracks a finished job failed to download.
+
+    The failed tracks are read from the album manifest in the job's output
+    folder. The file is a regular job file, so "Run Job File" downloads just
+    those tracks again into the same folder, e.g. on another network or day.
+    Each job also lists the failed tracks (number, title and URL) for
+    external tools.
+
+    Args:
+        job: Finished job.
+        path: Job file to write (JSON).
+
+    Returns:
+        Number of failed tracks written.
+
+    Raises:
+        FileNotFoundError: If the output folder has no manifest.
+        ValueError: If the manifest belongs to another album or no track failed.
+    """
+    manifest = read_manifest(Path(job.config.output_dir))
+    if manifest.source_url not in (job.url, *job.mirrors):
+        raise ValueError(f"Manifest in {job.config.output_dir} belongs to {manifest.source_url}")
+
+    failed = [track for track in manifest.tracks if track.status == FAILED_STATUS]
+    if not failed:
+        raise ValueError(f"No failed tracks in {job.config.output_dir}")
+
+    entry = {
+        "url": job.url,
+        "mirrors": job.mirrors,
+        "output_dir": job.config.output_dir,
+        "track_range": ",".join(str(track.index) for track in failed),
+        "failed_tracks": [
+            {"index": track.index, "title": track.title, "url": track.url} for track in failed
+        ],
+    }
+    path.write_text(json.dumps({"jobs": [entry]}, ensure_ascii=False, indent=2), encoding="utf-8")
+    logger.info(f"Exported {len(failed)} failed tracks of job {job.job_id} to {path}")
+    return len(failed)
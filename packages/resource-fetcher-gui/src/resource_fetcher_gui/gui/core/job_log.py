@@ -0,0 +1,149 @@
+"""Per-job log events for CLI output."""
+
+import dataclasses
+import json
+import logging
+import re
+from dataclasses import dataclass
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import get_app_data_dir
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class LogEvent:
+    """A single informational line of CLI output.
+
+    Attributes:
+        job_id: Identifier of the job that produced the line.
+        severity: Severity level (debug, info, warning, error).
+        timestamp: ISO 8601 time the line was received.
+        message: The output line.
+    """
+
+    job_id: str
+    severity: str
+    timestamp: str
+    message: str
+
+
+# Python logging format used by the CLI: "<time> - <name> - LEVEL - message"
+LOG_RECORD_PATTERN = re.compile(r" - (DEBUG|INFO|WARNING|ERROR|CRITICAL) - ")
+
+# Error lines printed directly by the CLI
+ERROR_LINE_PATTERN = re.compile(r"(错误|Error):")
+
+
+def classify_severity(line: str) -> str:
+    """Determine the severity of a CLI output line.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        One of "debug", "info", "warning" or "error".
+    """
+    if match := LOG_RECORD_PATTERN.search(line):
+        level = match.group(1).lower()
+        return "error" if level == "critical" else level
+
+    if ERROR_LINE_PATTERN.search(line):
+        return "error"
+
+    return "info"
+
+
+def create_log_event(job_id: str, line: str) -> LogEvent:
+    """Create a log event for a line of CLI output.
+
+    Args:
+        job_id: Identifier of the job that produced the line.
+        line: A single line of CLI output.
+
+    Returns:
+        LogEvent stamped with the current time.
+    """
+    return LogEvent(
+        job_id=job_id,
+        severity=classify_severity(line),
+        timestamp=datetime.now().isoformat(timespec="seconds"),
+        message=line,
+    )
+
+
+class JobLogStore:
+    """Persistent store of log events, one JSON Lines file per job."""
+
+    def __init__(self, log_dir: Path | None = None) -> None:
+        """Initialize log store.
+
+        Args:
+            log_dir: Optional directory for job logs.
+                     If not provided, uses the "logs" folder in the app data directory.
+        """
+        self.log_dir = log_dir or get_app_data_dir() / "logs"
+        logger.debug(f"JobLogStore initialized with directory: {self.log_dir}")
+
+    def path_for(self, job_id: str) -> Path:
+        """Get the log file path for a job.
+
+        Args:
+            job_id: Job identifier.
+
+        Returns:
+            Path to the job's log file.
+        """
+        return self.log_dir / f"{job_id}.jsonl"
+
+    def append(self, event: LogEvent) -> None:
+        """Append an event to its job's log.
+
+        Args:
+            event: Log event to persist.
+        """
+        try:
+            self.log_dir.mkdir(parents=True, exist_ok=True)
+            with open(self.path_for(event.job_id), "a", encoding="utf-8") as f:
+                f.write(json.dumps(dataclasses.asdict(event), ensure_ascii=False) + "\n")
+        except OSError as e:
+            logger.warning(f"Failed to write job log for {event.job_id}: {e}")
+
+    def latest_job_id(self) -> str | None:
+        """Find the job that logged most recently.
+
+        Returns:
+            Identifier of the job whose log was written last, or None if
+            there are no job logs.
+        """
+        if not self.log_dir.is_dir():
+            return None
+
+        paths = list(self.log_dir.glob("*.jsonl"))
+        if not paths:
+            return None
+        return max(paths, key=lambda path: path.stat().st_mtime).stem
+
+    def read(self, job_id: str) -> list[LogEvent]:
+        """Read all events logged for a job.
+
+        Args:
+            job_id: Job identifier.
+
+        Returns:
+            List of log events in the order they were received.
+        """
+        path = self.path_for(job_id)
+        if not path.exists():
+            return []
+
+        events = []
+        with open(path, encoding="utf-8") as f:
+            for line in f:
+                try:
+                    events.append(LogEvent(**json.loads(line)))
+                except (json.JSONDecodeError, TypeError):
+                    logger.warning(f"Skipping malformed log entry in {path}")
+        return events
@@ -0,0 +1,713 @@
+"""Sequential queue of download jobs for GUI."""
+
+import dataclasses
+import logging
+import threading
+import uuid
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from datetime import datetime
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_gui.gui.core.adaptive_concurrency import AdaptiveConcurrency
+from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper, InteractionRequest
+from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.download_history import normalize_url
+from resource_fetcher_gui.gui.core.job_log import LogEvent, create_log_event
+from resource_fetcher_gui.gui.core.output_parser import (
+    parse_downloaded_bytes,
+    parse_failed_tracks,
+    parse_processed_files,
+    parse_rate_ban,
+    parse_track_position,
+    parse_track_transfer,
+    parse_transferred_bytes,
+)
+from resource_fetcher_gui.gui.core.volumes import volume_available, volume_root
+
+logger = logging.getLogger(__name__)
+
+# Job lifecycle states
+JOB_QUEUED = "queued"
+JOB_RUNNING = "running"
+JOB_COMPLETED = "completed"
+JOB_FAILED = "failed"
+JOB_CANCELLED = "cancelled"
+
+# States list_jobs reports for queued jobs: free to run, or waiting for
+# jobs they depend on
+JOB_READY = "ready"
+JOB_BLOCKED = "blocked"
+
+# Finished jobs kept in the session state for display after a restart
+MAX_RECENT_JOBS = 20
+
+
+@dataclass
+class QueuedJob:
+    """A download job in the queue.
+
+    Attributes:
+        job_id: Unique job identifier (also used for the job log).
+        url: Album URL currently used for the download.
+        config: Download configuration for this job.
+        status: Job state (queued, running, completed, failed, cancelled).
+        exit_code: CLI exit code once the job has finished.
+        mirrors: Alternative URLs for the same album, tried in order if the
+                 current URL fails.
+        downloaded_bytes: Data downloaded so far, as reported by the CLI.
+        transferred_bytes: Data received over the network so far, including
+                           retries and discarded files.
+        track_bytes: Data received for each track number (as a string),
+                     added up over reruns and mirrors.
+        current_track: Number of the track being downloaded, 0 before the first.
+        track_count: Number of tracks the CLI is downloading, 0 until known.
+        processed_tracks: Number of downloaded files the CLI has verified,
+                          normalized and checksummed.
+        failed_tracks: Number of tracks that failed in the last run.
+        rate_banned: Whether the site rate limited the last run.
+        note: Free-text note, e.g. who requested the download.
+        metadata: Key/value annotations, e.g. {"collection": "hymns"}.
+        environment: Versions and CLI command the job last ran with.
+        depends_on: IDs of jobs that have to complete successfully before
+                    this one runs.
+    """
+
+    job_id: str
+    url: str
+    config: DownloadConfig
+    status: str = JOB_QUEUED
+    exit_code: int | None = None
+    mirrors: list[str] = field(default_factory=list)
+    downloaded_bytes: int = 0
+    transferred_bytes: int = 0
+    track_bytes: dict[str, int] = field(default_factory=dict)
+    current_track: int = 0
+    track_count: int = 0
+    processed_tracks: int = 0
+    failed_tracks: int = 0
+    rate_banned: bool = False
+    note: str = ""
+    metadata: dict[str, str] = field(default_factory=dict)
+    environment: dict[str, Any] = field(default_factory=dict)
+    depends_on: list[str] = field(default_factory=list)
+
+
+@dataclass
+class JobListing:
+    """A job and its state, as reported by list_jobs.
+
+    Attributes:
+        job: The job.
+        state: "ready" or "blocked" for queued jobs, the job's status otherwise.
+        waiting_for: IDs of unfinished jobs a blocked job depends on.
+    """
+
+    job: QueuedJob
+    state: str
+    waiting_for: list[str] = field(default_factory=list)
+
+
+@dataclass
+class SessionState:
+    """Snapshot of a job queue, saved so it can be rebuilt after a restart.
+
+    Attributes:
+        jobs: Unfinished and recently finished jobs in queue order.
+        paused: Whether the queue was paused.
+        saved_at: ISO 8601 time the snapshot was taken.
+    """
+
+    jobs: list[QueuedJob] = field(default_factory=list)
+    paused: bool = False
+    saved_at: str = ""
+
+
+class JobQueue:
+    """Runs download jobs one after another through a CLIWrapper.
+
+    Jobs start automatically when enqueued while the queue is idle.
+    Callbacks are invoked from the download thread. If can_start refuses a
+    job, the queue pauses until resume is called.
+    """
+
+    def __init__(
+        self,
+        cli_wrapper: CLIWrapper,
+        progress_callback: Callable[[str], None] | None = None,
+        job_started_callback: Callable[[QueuedJob], None] | None = None,
+        job_finished_callback: Callable[[QueuedJob], None] | None = None,
+        log_callback: Callable[[LogEvent], None] | None = None,
+        config_resolver: Callable[[str, DownloadConfig], DownloadConfig] | None = None,
+        interaction_callback: Callable[[InteractionRequest], None] | None = None,
+        start_resolver: Callable[[DownloadConfig], DownloadConfig] | None = None,
+        can_start: Callable[[QueuedJob], bool] | None = None,
+        session_callback: Callable[[SessionState], None] | None = None,
+        volume_lost_callback: Callable[[QueuedJob, Path], None] | None = None,
+        adaptive_concurrency: AdaptiveConcurrency | None = None,
+    ) -> None:
+        """Initialize job queue.
+
+        Args:
+            cli_wrapper: Wrapper used to run each job.
+            progress_callback: Optional callback for each line of CLI output.
+            job_started_callback: Optional callback when a job starts.
+            job_finished_callback: Optional callback when a job finishes or is cancelled.
+            log_callback: Optional callback for each log event of a job.
+            config_resolver: Optional function adjusting a job's configuration
+                for its URL when it is enqueued, e.g. SourceRuleService.resolve.
+            interaction_callback: Optional callback when a job needs input, answered
+                with submit_interaction.
+            start_resolver: Optional function adjusting a job's configuration each
+                time it starts running, e.g. BandwidthSchedule.apply.
+            can_start: Optional check before the next job starts. If it returns
+                False, the job stays queued and the queue pauses.
+            session_callback: Optional callback with the queue's state after
+                every change, e.g. SessionStore.save.
+            volume_lost_callback: Optional callback when a job failed because
+                its output volume disappeared, with the volume's mount point.
+                The job is queued again and the queue pauses until resumed.
+            adaptive_concurrency: Optional per-source slow-down applied when a
+                job starts and fed with each finished run's failures.
+        """
+        self.cli_wrapper = cli_wrapper
+        self.progress_callback = progress_callback
+        self.job_started_callback = job_started_callback
+        self.job_finished_callback = job_finished_callback
+        self.log_callback = log_callback
+        self.config_resolver = config_resolver
+        self.interaction_callback = interaction_callback
+        self.start_resolver = start_resolver
+        self.can_start = can_start
+        self.session_callback = session_callback
+        self.volume_lost_callback = volume_lost_callback
+        self.adaptive_concurrency = adaptive_concurrency
+        self.paused = False
+        self.jobs: list[QueuedJob] = []
+        self.current_job: QueuedJob | None = None
+        # Output volume of each job, recorded when it starts
+        self._volumes: dict[str, Path] = {}
+        self._lock = threading.RLock()
+
+    def enqueue(
+        self,
+        url: str,
+        config: DownloadConfig,
+        mirrors: list[str] | None = None,
+        note: str = "",
+        metadata: dict[str, str] | None = None,
+        depends_on: list[str] | None = None,
+    ) -> QueuedJob:
+        """Add a job to the queue, starting it if the queue is idle.
+
+        A URL that is already queued or running for the same output directory
+        isn't queued twice; the existing job is returned instead. A job with
+        dependencies runs once all of them have completed, and fails without
+        running if one of them fails or is cancelled.
+
+        Args:
+            url: Album URL to download.
+            config: Download configuration for the job.
+            mirrors: Optional alternative URLs for the same album.
+            note: Optional free-text note, kept in the download history.
+            metadata: Optional key/value annotations, kept in the download history.
+            depends_on: Optional IDs of jobs in this queue that have to complete first.
+
+        Returns:
+            The queued job, or the existing job for the URL.
+
+        Raises:
+            ValueError: If a dependency is not a job of this queue.
+        """
+        if self.config_resolver:
+            config = self.config_resolver(url, config)
+        job = QueuedJob(
+            job_id=uuid.uuid4().hex,
+            url=url,
+            config=config,
+            mirrors=list(mirrors or []),
+            note=note,
+            metadata=dict(metadata or {}),
+            depends_on=list(depends_on or []),
+        )
+        with self._lock:
+            existing = self.find_active(url, config.output_dir)
+            if existing is not None:
+                logger.info(f"{url} is already queued as job {existing.job_id}")
+                return existing
+            known = {item.job_id for item in self.jobs}
+            unknown = [job_id for job_id in job.depends_on if job_id not in known]
+            if unknown:
+                raise ValueError(f"Unknown dependencies: {', '.join(unknown)}")
+            self.jobs.append(job)
+            logger.info(f"Job {job.job_id} queued: {url}")
+            if self.current_job is None:
+                self._start_next()
+            else:
+                self._save_session()
+        return job
+
+    def restore(self, state: SessionState) -> int:
+        """Rebuild the queue from a previous session's state.
+
+        Jobs that were running are queued again; finished jobs are kept for
+        display only. The queue starts unless it was paused.
+
+        Args:
+            state: State saved by the previous session.
+
+        Returns:
+            Number of jobs queued again.
+        """
+        with self._lock:
+            for job in state.jobs:
+                if job.status == JOB_RUNNING:
+                    job.status = JOB_QUEUED
+                self.jobs.append(job)
+            self.paused = self.paused or state.paused
+            restored = sum(1 for job in state.jobs if job.status == JOB_QUEUED)
+            logger.info(f"Restored {len(state.jobs)} jobs, {restored} queued")
+            if self.current_job is None:
+                self._start_next()
+        return restored
+
+    def get_session_state(self) -> SessionState:
+        """Get a snapshot of the queue.
+
+        Returns:
+            Copies of the unfinished jobs, the jobs they depend on and the
+            MAX_RECENT_JOBS most recently finished ones, in queue order.
+        """
+        with self._lock:
+            unfinished = [job for job in self.jobs if job.status in (JOB_QUEUED, JOB_RUNNING)]
+            finished = [job for job in self.jobs if job.status not in (JOB_QUEUED, JOB_RUNNING)]
+            kept = {job.job_id for job in finished[-MAX_RECENT_JOBS:]}
+            kept.update(job.job_id for job in unfinished)
+            kept.update(job_id for job in unfinished for job_id in job.depends_on)
+            jobs = [
+                dataclasses.replace(
+                    job,
+                    mirrors=list(job.mirrors),
+                    metadata=dict(job.metadata),
+                    depends_on=list(job.depends_on),
+                    track_bytes=dict(job.track_bytes),
+                )
+                for job in self.jobs
+                if job.job_id in kept
+            ]
+            return SessionState(
+                jobs=jobs,
+                paused=self.paused,
+                saved_at=datetime.now().isoformat(timespec="seconds"),
+            )
+
+    def find_active(self, url: str, output_dir: str) -> QueuedJob | None:
+        """Find a queued or running job downloading a URL into a directory.
+
+        Args:
+            url: Album URL, compared after normalize_url.
+            output_dir: Output directory of the download.
+
+        Returns:
+            The unfinished job, or None if there is none.
+        """
+        normalized = normalize_url(url)
+        with self._lock:
+            return next(
+                (
+                    job
+                    for job in self.jobs
+                    if job.status in (JOB_QUEUED, JOB_RUNNING)
+                    and normalize_url(job.url) == normalized
+                    and job.config.output_dir == output_dir
+                ),
+                None,
+            )
+
+    def list_jobs(self) -> list[JobListing]:
+        """List all jobs with their state.
+
+        Returns:
+            Jobs in queue order. Queued jobs are "blocked" while a job they
+            depend on hasn't completed, "ready" otherwise.
+        """
+        with self._lock:
+            statuses = {job.job_id: job.status for job in self.jobs}
+            listings = []
+            for job in self.jobs:
+                if job.status != JOB_QUEUED:
+                    listings.append(JobListing(job, job.status))
+                    continue
+                waiting = [
+                    job_id for job_id in job.depends_on if statuses.get(job_id) != JOB_COMPLETED
+                ]
+                listings.append(JobListing(job, JOB_BLOCKED if waiting else JOB_READY, waiting))
+            return listings
+
+    def pending(self) -> list[QueuedJob]:
+        """Get jobs waiting to run.
+
+        Returns:
+            Queued jobs in the order they will run.
+        """
+        with self._lock:
+            return [job for job in self.jobs if job.status == JOB_QUEUED]
+
+    def is_active(self) -> bool:
+        """Check if a job is running or waiting to run.
+
+        Returns:
+            True if the queue has unfinished jobs, False otherwise.
+        """
+        with self._lock:
+            return self.current_job is not None or bool(self.pending())
+
+    def update_jobs(self, job_ids: list[str], patch: dict[str, Any]) -> list[QueuedJob]:
+        """Change options of several queued jobs at once.
+
+        All jobs are changed or none: the patch is validated for every job
+        before the first one is changed. Running and finished jobs keep the
+        options they ran with.
+
+        Args:
+            job_ids: Identifiers of queued jobs.
+            patch: DownloadConfig fields and their new values,
+                e.g. {"output_dir": "D:/Music", "low_priority": True}.
+
+        Returns:
+            The changed jobs.
+
+        Raises:
+            ValueError: If a job is unknown or not queued, a field doesn't
+                exist or a value is invalid.
+        """
+        unknown = [key for key in patch if key not in DownloadConfig.__dataclass_fields__]
+        if unknown:
+            raise ValueError(f"Unknown options: {', '.join(unknown)}")
+
+        config_service = ConfigService()
+        with self._lock:
+            jobs = {job.job_id: job for job in self.jobs}
+            configs = []
+            for job_id in job_ids:
+                job = jobs.get(job_id)
+                if job is None:
+                    raise ValueError(f"Unknown job: {job_id}")
+                if job.status != JOB_QUEUED:
+                    raise ValueError(f"Job {job_id} is {job.status}, only queued jobs can change")
+                config = config_service.config_from_dict(
+                    {**dataclasses.asdict(job.config), **patch}
+                )
+                invalid = [key for key, value in patch.items() if getattr(config, key) != value]
+                if invalid:
+                    raise ValueError(f"Invalid value for {', '.join(invalid)}")
+                configs.append((job, config))
+
+            for job, config in configs:
+                job.config = config
+            if configs:
+                logger.info(f"Changed {', '.join(patch)} of {len(configs)} queued jobs")
+                self._save_session()
+        return [job for job, _ in configs]
+
+    def cancel(self, job_id: str) -> bool:
+        """Cancel a queued or running job.
+
+        Args:
+            job_id: Identifier of the job to cancel.
+
+        Returns:
+            True if the job was cancelled, False if it is unknown or already finished.
+        """
+        with self._lock:
+            job = next((job for job in self.jobs if job.job_id == job_id), None)
+            if job is None or job.status not in (JOB_QUEUED, JOB_RUNNING):
+                return False
+
+            running = job.status == JOB_RUNNING
+            job.status = JOB_CANCELLED
+            self._save_session()
+
+        logger.info(f"Job {job_id} cancelled")
+        if running:
+            # The completion callback moves on to the next job
+            self.cli_wrapper.stop_download()
+            return True
+
+        if self.job_finished_callback:
+            self.job_finished_callback(job)
+        self._fail_orphaned_jobs()
+        return True
+
+    def cancel_all(self) -> None:
+        """Cancel the running job and every queued job."""
+        with self._lock:
+            job_ids = [job.job_id for job in self.jobs if job.status == JOB_QUEUED]
+            current = self.current_job
+
+        for job_id in job_ids:
+            self.cancel(job_id)
+        if current is not None:
+            self.cancel(current.job_id)
+
+    def pause(self) -> None:
+        """Keep queued jobs from starting; a running job continues."""
+        with self._lock:
+            self.paused = True
+            self._save_session()
+        logger.info("Job queue paused")
+
+    def resume(self) -> None:
+        """Start queued jobs again after a pause."""
+        with self._lock:
+            if not self.paused:
+                return
+            self.paused = False
+            idle = self.current_job is None
+        logger.info("Job queue resumed")
+        if idle:
+            self._start_next()
+
+    def submit_interaction(self, job_id: str, response: str | None) -> bool:
+        """Answer the input the running job waits for.
+
+        Args:
+            job_id: Job that asked for input.
+            response: The user's answer, or None to decline.
+
+        Returns:
+            True if the answer was delivered, False if the job isn't running.
+        """
+        with self._lock:
+            current = self.current_job
+        if current is None or current.job_id != job_id:
+            return False
+        return self.cli_wrapper.submit_interaction(job_id, response)
+
+    def confirm_track(self, job_id: str, index: int, accept: bool) -> bool:
+        """Answer whether the running job downloads a track.
+
+        Args:
+            job_id: Job that asked.
+            index: Album track number the job asked about.
+            accept: True to download the track, False to skip it.
+
+        Returns:
+            True if the answer was delivered, False if the job isn't running.
+        """
+        with self._lock:
+            current = self.current_job
+        if current is None or current.job_id != job_id:
+            return False
+        return self.cli_wrapper.confirm_track(job_id, index, accept)
+
+    def refresh_speed_limit(self) -> bool:
+        """Pass the running job's speed limit on again, e.g. for a new schedule window.
+
+        The limit is worked out like when the job started, with start_resolver.
+
+        Returns:
+            True if the running job got the limit, False if no job is running.
+        """
+        with self._lock:
+            current = self.current_job
+        if current is None:
+            return False
+        config = self.start_resolver(current.config) if self.start_resolver else current.config
+        return self.cli_wrapper.set_speed_limit(current.job_id, config.max_speed)
+
+    def _fail_orphaned_jobs(self) -> None:
+        """Fail queued jobs that depend on a job that failed, was cancelled or is gone."""
+        orphaned: list[tuple[QueuedJob, str]] = []
+        with self._lock:
+            changed = True
+            while changed:
+                changed = False
+                statuses = {job.job_id: job.status for job in self.jobs}
+                for job in self.jobs:
+                    if job.status != JOB_QUEUED:
+                        continue
+                    failed = next(
+                        (
+                            job_id
+                            for job_id in job.depends_on
+                            if statuses.get(job_id) not in (JOB_QUEUED, JOB_RUNNING, JOB_COMPLETED)
+                        ),
+                        None,
+                    )
+                    if failed is not None:
+                        job.status = JOB_FAILED
+                        orphaned.append((job, failed))
+                        changed = True
+            if orphaned:
+                self._save_session()
+
+        for job, failed in orphaned:
+            self._log(job, f"Dependency {failed} did not complete, job not run")
+            if self.job_finished_callback:
+                self.job_finished_callback(job)
+
+    def _start_next(self) -> None:
+        """Start the next queued job whose dependencies have completed, if any."""
+        self._fail_orphaned_jobs()
+        with self._lock:
+            ready = [listing.job for listing in self.list_jobs() if listing.state == JOB_READY]
+            job = ready[0] if ready else None
+            if job is None:
+                self.current_job = None
+                self._save_session()
+                blocked = len(self.pending())
+                if blocked:
+                    # Only possible if dependencies form a cycle
+                    logger.warning(f"Job queue stopped, {blocked} jobs blocked by dependencies")
+                else:
+                    logger.info("Job queue finished")
+                return
+            if self.paused or (self.can_start and not self.can_start(job)):
+                self.current_job = None
+                self.paused = True
+                self._save_session()
+                logger.info(f"Job queue paused before job {job.job_id}")
+                return
+            self.current_job = job
+            job.status = JOB_RUNNING
+            self._volumes[job.job_id] = volume_root(Path(job.config.output_dir))
+            self._save_session()
+
+        logger.info(f"Starting job {job.job_id}: {job.url}")
+        if self.job_started_callback:
+            self.job_started_callback(job)
+
+        self._run(job)
+
+    def _run(self, job: QueuedJob) -> None:
+        """Run a job with its current URL.
+
+        Args:
+            job: The job to run.
+        """
+        config = self.start_resolver(job.config) if self.start_resolver else job.config
+        if self.adaptive_concurrency:
+            config = self.adaptive_concurrency.apply(job.url, config)
+        job.failed_tracks = 0
+        job.rate_banned = False
+
+        def on_environment(environment: dict[str, Any]) -> None:
+            job.environment = environment
+
+        def on_output(line: str) -> None:
+            downloaded = parse_downloaded_bytes(line)
+            if downloaded is not None:
+                job.downloaded_bytes += downloaded
+            transferred = parse_transferred_bytes(line)
+            if transferred is not None:
+                job.transferred_bytes += transferred
+            track_transfer = parse_track_transfer(line)
+            if track_transfer is not None:
+                number, size = str(track_transfer[0]), track_transfer[1]
+                job.track_bytes[number] = job.track_bytes.get(number, 0) + size
+            position = parse_track_position(line)
+            if position is not None:
+                with self._lock:
+                    job.current_track, job.track_count = position
+                    self._save_session()
+            processed = parse_processed_files(line)
+            if processed is not None:
+                job.processed_tracks = processed
+            failed = parse_failed_tracks(line)
+            if failed is not None:
+                job.failed_tracks = failed
+            if parse_rate_ban(line) is not None:
+                job.rate_banned = True
+            if self.progress_callback:
+                self.progress_callback(line)
+
+        try:
+            self.cli_wrapper.execute_download(
+                job.url,
+                config,
+                progress_callback=on_output,
+                complete_callback=lambda exit_code: self._on_job_complete(job, exit_code),
+                log_callback=self.log_callback,
+                job_id=job.job_id,
+                interaction_callback=self.interaction_callback,
+                environment_callback=on_environment,
+            )
+        except (OSError, ValueError, RuntimeError) as e:
+            logger.error(f"Job {job.job_id} could not start: {e}")
+            self._on_job_complete(job, -1)
+
+    def _save_session(self) -> None:
+        """Pass the queue's current state to the session callback."""
+        if self.session_callback:
+            self.session_callback(self.get_session_state())
+
+    def _log(self, job: QueuedJob, message: str) -> None:
+        """Record a queue message in a job's log.
+
+        Args:
+            job: The job the message is about.
+            message: Message text.
+        """
+        logger.warning(f"Job {job.job_id}: {message}")
+        event = create_log_event(job.job_id, message)
+        event.severity = "warning"
+        if self.cli_wrapper.log_store:
+            self.cli_wrapper.log_store.append(event)
+        if self.log_callback:
+            self.log_callback(event)
+
+    def _on_job_complete(self, job: QueuedJob, exit_code: int) -> None:
+        """Record a finished job and start the next one.
+
+        Args:
+            job: The job that finished.
+            exit_code: CLI exit code.
+        """
+        with self._lock:
+            volume = self._volumes.get(job.job_id)
+        if (
+            job.status == JOB_RUNNING
+            and exit_code != 0
+            and volume is not None
+            and not volume_available(volume)
+        ):
+            with self._lock:
+                job.status = JOB_QUEUED
+                self.current_job = None
+                self.paused = True
+                self._save_session()
+            self._log(job, f"Output volume lost, job waits for it to return: {volume}")
+            if self.volume_lost_callback:
+                self.volume_lost_callback(job, volume)
+            return
+
+        if self.adaptive_concurrency and job.status == JOB_RUNNING:
+            adjustment = self.adaptive_concurrency.record(
+                job.url, job.track_count, job.failed_tracks, job.rate_banned
+            )
+            if adjustment:
+                self._log(job, adjustment.describe())
+
+        with self._lock:
+            job.exit_code = exit_code
+            switch_mirror = job.status == JOB_RUNNING and exit_code != 0 and bool(job.mirrors)
+            if switch_mirror:
+                failed_url, job.url = job.url, job.mirrors.pop(0)
+            elif job.status == JOB_RUNNING:
+                job.status = JOB_COMPLETED if exit_code == 0 else JOB_FAILED
+            if not switch_mirror:
+                self._volumes.pop(job.job_id, None)
+
+        if switch_mirror:
+            self._log(job, f"Source failed (exit code {exit_code}): {failed_url}")
+            self._log(job, f"Switching to mirror: {job.url}")
+            self._run(job)
+            return
+
+        logger.info(f"Job {job.job_id} {job.status} (exit code {exit_code})")
+        if self.job_finished_callback:
+            self.job_finished_callback(job)
+
+        self._start_next()
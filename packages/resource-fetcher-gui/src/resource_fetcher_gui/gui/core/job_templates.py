@@ -0,0 +1,57 @@
+"""Reusable job templates for GUI."""
+
+import logging
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import ConfigService, get_app_config_dir
+from resource_fetcher_gui.gui.core.job_queue import JobQueue, QueuedJob
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+
+logger = logging.getLogger(__name__)
+
+
+class JobTemplateService(ProfileService):
+    """Service for managing job templates.
+
+    A template holds all settings of a job except its URL, e.g. one for
+    podcasts and one for albums. Templates are stored like profiles, in
+    their own file.
+    """
+
+    def __init__(
+        self,
+        templates_path: Path | None = None,
+        config_service: ConfigService | None = None,
+    ) -> None:
+        """Initialize job template service.
+
+        Args:
+            templates_path: Optional custom path to the templates file.
+                            If not provided, uses templates.json in the settings directory.
+            config_service: Optional config service used to validate template values.
+        """
+        super().__init__(
+            templates_path or get_app_config_dir() / "templates.json",
+            config_service,
+        )
+
+    def create_job_from_template(self, template: str, url: str, queue: JobQueue) -> QueuedJob:
+        """Enqueue a job for a URL using a template's settings.
+
+        Args:
+            template: Template name.
+            url: Album URL to download.
+            queue: Queue receiving the job.
+
+        Returns:
+            The queued job.
+
+        Raises:
+            ValueError: If no template with that name exists.
+        """
+        if template not in self.load_profiles():
+            raise ValueError(f"Template not found: {template}")
+
+        config = self.apply_profile(template)
+        logger.info(f"Creating job from template {template}: {url}")
+        return queue.enqueue(url, config)
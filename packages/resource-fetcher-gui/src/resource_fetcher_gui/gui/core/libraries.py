@@ -0,0 +1,286 @@
+"""Named output libraries, each with its own root folder and file settings."""
+
+import dataclasses
+import json
+import logging
+from dataclasses import dataclass, field
+from datetime import date
+from pathlib import Path
+from typing import Any
+from urllib.parse import urlparse
+
+from resource_fetcher_gui.gui.core.config_service import (
+    ConfigService,
+    DownloadConfig,
+    get_app_config_dir,
+)
+
+logger = logging.getLogger(__name__)
+
+# Settings a library may set: how files are named and post-processed
+LIBRARY_SETTINGS = (
+    "filename_replacement",
+    "max_filename_length",
+    "normalize_loudness",
+    "checksum_sidecars",
+    "merge_format",
+    "scan_command",
+)
+
+# Placeholders of folder templates
+TEMPLATE_FIELDS = ("source", "date")
+
+
+@dataclass
+class Library:
+    """A place albums are downloaded to, e.g. "Music NAS" or "Podcasts".
+
+    Attributes:
+        name: Library name.
+        root: Root folder of the library.
+        folder_template: Optional folder below the root each album goes to,
+            with the placeholders {source} (site of the URL) and {date}
+            (day the job was enqueued), e.g. "{source}/{date}".
+        settings: Naming and post-processing settings (LIBRARY_SETTINGS)
+            merged over the job's settings, e.g. {"normalize_loudness": true}.
+    """
+
+    name: str
+    root: str
+    folder_template: str = ""
+    settings: dict[str, Any] = field(default_factory=dict)
+
+    def output_dir(self, url: str, today: date | None = None) -> Path:
+        """Get the folder an album is downloaded to.
+
+        Args:
+            url: Album URL.
+            today: Optional date filled into {date}.
+
+        Returns:
+            The root, or the template's folder below it.
+        """
+        if not self.folder_template:
+            return Path(self.root)
+
+        values = {
+            "source": urlparse(url).hostname or "unknown",
+            "date": (today or date.today()).isoformat(),
+        }
+        return Path(self.root) / self.folder_template.format(**values)
+
+
+def check_folder_template(template: str) -> None:
+    """Check that a folder template only uses known placeholders.
+
+    Args:
+        template: Folder template, e.g. "{source}/{date}".
+
+    Raises:
+        ValueError: If the template is malformed or uses an unknown placeholder.
+    """
+    try:
+        template.format(**{name: name for name in TEMPLATE_FIELDS})
+    except (KeyError, IndexError, ValueError) as e:
+        raise ValueError(f"Invalid folder template {template!r}: {e}") from e
+
+
+class LibraryService:
+    """Service managing the libraries and which one is active.
+
+    A job downloads into the library its configuration names, or into the
+    active library if it names none. Jobs keep their output directory if
+    neither is set.
+    """
+
+    def __init__(
+        self,
+        libraries_path: Path | None = None,
+        config_service: ConfigService | None = None,
+    ) -> None:
+        """Initialize library service.
+
+        Args:
+            libraries_path: Optional custom path to the libraries file.
+                            If not provided, uses libraries.json in the settings directory.
+            config_service: Optional config service used to validate library settings.
+        """
+        self.libraries_path = libraries_path or get_app_config_dir() / "libraries.json"
+        self.config_service = config_service or ConfigService()
+        logger.debug(f"LibraryService initialized with path: {self.libraries_path}")
+
+    def _read(self) -> tuple[list[Library], str | None]:
+        """Read the libraries file.
+
+        Returns:
+            Tuple of (libraries in the order they were added, name of the
+            active library or None). Empty if the file doesn't exist or is invalid.
+        """
+        if not self.libraries_path.exists():
+            return [], None
+
+        try:
+            with open(self.libraries_path, encoding="utf-8") as f:
+                data = json.load(f)
+            libraries = [Library(**item) for item in data["libraries"]]
+        except (OSError, json.JSONDecodeError, KeyError, TypeError) as e:
+            logger.warning(f"Failed to read libraries from {self.libraries_path}: {e}")
+            return [], None
+
+        active = data.get("active")
+        if active not in [library.name for library in libraries]:
+            active = None
+        return libraries, active
+
+    def _write(self, libraries: list[Library], active: str | None) -> None:
+        """Write the libraries file.
+
+        Args:
+            libraries: Libraries in the order they were added.
+            active: Name of the active library or None.
+        """
+        data = {
+            "active": active,
+            "libraries": [dataclasses.asdict(library) for library in libraries],
+        }
+        self.libraries_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.libraries_path, "w", encoding="utf-8") as f:
+            json.dump(data, f, indent=2, ensure_ascii=False)
+
+    def list_libraries(self) -> list[Library]:
+        """List the libraries.
+
+        Returns:
+            Libraries in the order they were added.
+        """
+        return self._read()[0]
+
+    def get_library(self, name: str) -> Library | None:
+        """Find a library by name.
+
+        Args:
+            name: Library name.
+
+        Returns:
+            The library, or None if it doesn't exist.
+        """
+        return next((library for library in self.list_libraries() if library.name == name), None)
+
+    def add_library(
+        self,
+        name: str,
+        root: str,
+        folder_template: str = "",
+        settings: dict[str, Any] | None = None,
+    ) -> Library:
+        """Add a library, replacing any existing library with the same name.
+
+        Args:
+            name: Library name.
+            root: Root folder.
+            folder_template: Optional folder template below the root.
+            settings: Optional naming and post-processing settings.
+
+        Returns:
+            The stored library.
+
+        Raises:
+            ValueError: If the name or root is empty, the template is invalid
+                or a setting isn't a naming or post-processing setting.
+        """
+        name = name.strip()
+        if not name:
+            raise ValueError("Library name cannot be empty")
+        if not root.strip():
+            raise ValueError("Library root folder cannot be empty")
+        folder_template = folder_template.strip()
+        check_folder_template(folder_template)
+        unknown = [setting for setting in settings or {} if setting not in LIBRARY_SETTINGS]
+        if unknown:
+            raise ValueError(f"Settings not allowed in a library: {', '.join(unknown)}")
+
+        library = Library(name, root.strip(), folder_template, dict(settings or {}))
+        libraries, active = self._read()
+        libraries = [item for item in libraries if item.name != name]
+        libraries.append(library)
+        self._write(libraries, active)
+        logger.info(f"Library saved: {name} ({library.root})")
+        return library
+
+    def remove_library(self, name: str) -> bool:
+        """Remove a library. If it was active, no library is active afterwards.
+
+        Args:
+            name: Library name.
+
+        Returns:
+            True if the library was removed, False if it didn't exist.
+        """
+        libraries, active = self._read()
+        remaining = [library for library in libraries if library.name != name]
+        if len(remaining) == len(libraries):
+            return False
+
+        self._write(remaining, None if active == name else active)
+        logger.info(f"Library removed: {name}")
+        return True
+
+    def active_library(self) -> Library | None:
+        """Get the active library.
+
+        Returns:
+            The library jobs download into by default, or None.
+        """
+        libraries, active = self._read()
+        return next((library for library in libraries if library.name == active), None)
+
+    def set_active_library(self, name: str | None) -> None:
+        """Switch the library jobs download into by default.
+
+        Only affects jobs enqueued afterwards.
+
+        Args:
+            name: Library name, or None to use each job's output directory.
+
+        Raises:
+            ValueError: If no library with that name exists.
+        """
+        libraries, _ = self._read()
+        if name is not None and name not in [library.name for library in libraries]:
+            raise ValueError(f"Library not found: {name}")
+
+        self._write(libraries, name)
+        logger.info(f"Active library: {name or '(none)'}")
+
+    def resolve(self, url: str, config: DownloadConfig) -> DownloadConfig:
+        """Apply a job's library to its configuration.
+
+        Args:
+            url: URL about to be downloaded.
+            config: Configuration the job was enqueued with.
+
+        Returns:
+            The configuration with the library's settings merged over it and
+            its output directory in the library; the given configuration if
+            the job has no library and none is active.
+        """
+        if config.library:
+            library = self.get_library(config.library)
+            if library is None:
+                logger.warning(f"Library not found: {config.library}, keeping output directory")
+                return config
+        else:
+            library = self.active_library()
+            if library is None:
+                return config
+
+        if library.settings:
+            # Invalid values fall back to the defaults, as in the config file
+            config = self.config_service.config_from_dict(
+                {**dataclasses.asdict(config), **library.settings}
+            )
+        config = dataclasses.replace(
+            config, output_dir=str(library.output_dir(url)), library=library.name
+        )
+        logger.info(f"Library {library.name} applied to {url}")
+        return config
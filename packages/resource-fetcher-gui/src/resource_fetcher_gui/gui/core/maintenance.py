@@ -0,0 +1,323 @@
+"""Scheduled maintenance of the download library and application data."""
+
+import dataclasses
+import json
+import logging
+import random
+import threading
+import time
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_core.utils.manifest import file_checksum, read_manifest
+
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir, get_app_data_dir
+from resource_fetcher_gui.gui.core.download_history import HistoryEntry
+from resource_fetcher_gui.gui.core.subscriptions import CHECK_INTERVAL
+
+logger = logging.getLogger(__name__)
+
+# Time between checks for due maintenance tasks (seconds)
+MAINTENANCE_CHECK_INTERVAL = 15 * 60
+
+# Seconds in an hour, the unit task intervals are configured in
+HOUR = 60 * 60
+
+# Tracks whose checksum is verified by one checksum sample run
+CHECKSUM_SAMPLE_SIZE = 20
+
+# Oldest runs are dropped beyond this number
+MAX_MAINTENANCE_RUNS = 200
+
+# Maintenance tasks
+LIBRARY_SCAN = "library_scan"
+CHECKSUM_SAMPLE = "checksum_sample"
+CLEANUP = "cleanup"
+SUBSCRIPTIONS = "subscriptions"
+
+# Default hours between runs of each task
+DEFAULT_INTERVALS = {
+    LIBRARY_SCAN: 24,
+    CHECKSUM_SAMPLE: 7 * 24,
+    CLEANUP: 24,
+    SUBSCRIPTIONS: CHECK_INTERVAL // HOUR,
+}
+
+
+@dataclass
+class MaintenanceTask:
+    """A maintenance task and how often it runs.
+
+    Attributes:
+        name: Task name, one of DEFAULT_INTERVALS.
+        interval_hours: Hours between runs.
+        enabled: Whether the task runs at all.
+    """
+
+    name: str
+    interval_hours: int
+    enabled: bool = True
+
+
+@dataclass
+class MaintenanceRun:
+    """A finished run of a maintenance task.
+
+    Attributes:
+        task: Name of the task.
+        started_at: ISO 8601 time the run started.
+        finished_at: ISO 8601 time the run finished.
+        findings: Problems or news the user should look at.
+        error: Why the run failed, empty if it didn't.
+    """
+
+    task: str
+    started_at: str
+    finished_at: str
+    findings: list[str] = field(default_factory=list)
+    error: str = ""
+
+
+def library_folders(entries: list[HistoryEntry]) -> list[Path]:
+    """Get the album folders of completed downloads.
+
+    Args:
+        entries: History entries, oldest first.
+
+    Returns:
+        Each output folder once, in the order of the history.
+    """
+    folders = dict.fromkeys(Path(entry.output_dir) for entry in entries if entry.completed)
+    return list(folders)
+
+
+def scan_library(folders: list[Path]) -> list[str]:
+    """Check that downloaded albums are still complete.
+
+    Folders without a manifest, e.g. from older versions, are only checked
+    for existence. Checksums aren't verified; see verify_checksum_sample.
+
+    Args:
+        folders: Album folders.
+
+    Returns:
+        Missing folders, unreadable manifests and missing or truncated files.
+    """
+    findings = []
+    for folder in folders:
+        if not folder.is_dir():
+            findings.append(f"Album folder missing: {folder}")
+            continue
+
+        try:
+            manifest = read_manifest(folder)
+        except FileNotFoundError:
+            continue
+        except ValueError as e:
+            findings.append(f"Unreadable manifest in {folder}: {e}")
+            continue
+
+        for track in manifest.tracks:
+            if not track.filename:
+                continue
+            path = folder / track.filename
+            try:
+                intact = path.stat().st_size == track.size
+            except OSError:
+                intact = False
+            if not intact:
+                findings.append(f"File missing or incomplete: {path}")
+    return findings
+
+
+def verify_checksum_sample(
+    folders: list[Path],
+    sample_size: int = CHECKSUM_SAMPLE_SIZE,
+    rng: random.Random | None = None,
+) -> list[str]:
+    """Verify the checksums of randomly chosen downloaded files.
+
+    Spreads the cost of detecting silent corruption (bit rot) over many
+    runs instead of hashing the whole library at once.
+
+    Args:
+        folders: Album folders.
+        sample_size: Number of files to verify.
+        rng: Optional random number generator, for reproducible samples.
+
+    Returns:
+        Files whose contents no longer match their manifest.
+    """
+    candidates = []
+    for folder in folders:
+        try:
+            manifest = read_manifest(folder)
+        except (FileNotFoundError, ValueError):
+            continue
+        candidates += [
+            (folder / track.filename, track.sha256)
+            for track in manifest.tracks
+            if track.filename and track.sha256 and (folder / track.filename).is_file()
+        ]
+
+    sample = (rng or random.Random()).sample(candidates, min(sample_size, len(candidates)))
+    findings = []
+    for path, checksum in sample:
+        try:
+            if file_checksum(path) != checksum:
+                findings.append(f"Checksum mismatch: {path}")
+        except OSError as e:
+            findings.append(f"Unreadable file {path}: {e}")
+    logger.info(f"Verified checksums of {len(sample)} files, {len(findings)} mismatches")
+    return findings
+
+
+class MaintenanceScheduler:
+    """Schedule of maintenance tasks and the record of their runs."""
+
+    def __init__(self, tasks_path: Path | None = None, runs_path: Path | None = None) -> None:
+        """Initialize maintenance scheduler.
+
+        Args:
+            tasks_path: Optional custom path to the task schedule.
+                        If not provided, uses maintenance.json in the settings directory.
+            runs_path: Optional custom path to the run record.
+                       If not provided, uses maintenance_runs.json in the app data directory.
+        """
+        self.tasks_path = tasks_path or get_app_config_dir() / "maintenance.json"
+        self.runs_path = runs_path or get_app_data_dir() / "maintenance_runs.json"
+        self._lock = threading.Lock()
+        logger.debug(f"MaintenanceScheduler initialized with path: {self.tasks_path}")
+
+    def load_tasks(self) -> list[MaintenanceTask]:
+        """Read the task schedule.
+
+        Returns:
+            Every known task, with the default interval unless configured otherwise.
+        """
+        tasks = {
+            name: MaintenanceTask(name, interval) for name, interval in DEFAULT_INTERVALS.items()
+        }
+        if not self.tasks_path.exists():
+            return list(tasks.values())
+
+        try:
+            with open(self.tasks_path, encoding="utf-8") as f:
+                for item in json.load(f):
+                    task = MaintenanceTask(**item)
+                    if task.name in tasks and task.interval_hours > 0:
+                        tasks[task.name] = task
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read maintenance schedule from {self.tasks_path}: {e}")
+        return list(tasks.values())
+
+    def save_tasks(self, tasks: list[MaintenanceTask]) -> None:
+        """Write the task schedule.
+
+        Args:
+            tasks: Tasks to configure.
+
+        Raises:
+            ValueError: If a task is unknown or its interval isn't positive.
+        """
+        for task in tasks:
+            if task.name not in DEFAULT_INTERVALS:
+                raise ValueError(f"Unknown maintenance task: {task.name}")
+            if task.interval_hours <= 0:
+                raise ValueError(f"Maintenance interval must be positive: {task.interval_hours}")
+
+        self.tasks_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.tasks_path, "w", encoding="utf-8") as f:
+            json.dump([dataclasses.asdict(task) for task in tasks], f, indent=2)
+        logger.info(f"Maintenance schedule saved: {len(tasks)} tasks")
+
+    def list_maintenance_runs(self, task: str | None = None) -> list[MaintenanceRun]:
+        """List recorded runs.
+
+        Args:
+            task: Only list runs of this task.
+
+        Returns:
+            Runs, newest first.
+        """
+        with self._lock:
+            runs = self._load_runs()
+        return [run for run in runs[::-1] if task in (None, run.task)]
+
+    def due_tasks(self, now: float | None = None) -> list[str]:
+        """Find the enabled tasks whose interval has passed since their last run.
+
+        Args:
+            now: Optional current time (seconds since the epoch).
+
+        Returns:
+            Names of due tasks, tasks that never ran included.
+        """
+        now = now or time.time()
+        last_runs: dict[str, float] = {}
+        for run in self.list_maintenance_runs()[::-1]:
+            last_runs[run.task] = datetime.fromisoformat(run.finished_at).timestamp()
+
+        return [
+            task.name
+            for task in self.load_tasks()
+            if task.enabled
+            and now - last_runs.get(task.name, 0) >= task.interval_hours * HOUR
+        ]
+
+    def run(self, task: str, action: Callable[[], list[str]]) -> MaintenanceRun:
+        """Run a task and record the result.
+
+        Args:
+            task: Task name.
+            action: Function doing the work and returning its findings.
+
+        Returns:
+            The recorded run. Errors are recorded instead of raised.
+        """
+        started_at = datetime.now().isoformat(timespec="seconds")
+        findings: list[str] = []
+        error = ""
+        try:
+            findings = action()
+        except Exception as e:
+            logger.exception(f"Maintenance task {task} failed")
+            error = str(e)
+
+        run = MaintenanceRun(
+            task=task,
+            started_at=started_at,
+            finished_at=datetime.now().isoformat(timespec="seconds"),
+            findings=findings,
+            error=error,
+        )
+        with self._lock:
+            runs = self._load_runs()
+            runs.append(run)
+            self._save_runs(runs[-MAX_MAINTENANCE_RUNS:])
+        logger.info(f"Maintenance task {task} finished with {len(findings)} findings")
+        return run
+
+    def _load_runs(self) -> list[MaintenanceRun]:
+        """Read the run record, oldest first."""
+        if not self.runs_path.exists():
+            return []
+
+        try:
+            with open(self.runs_path, encoding="utf-8") as f:
+                return [MaintenanceRun(**item) for item in json.load(f)]
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read maintenance runs from {self.runs_path}: {e}")
+            return []
+
+    def _save_runs(self, runs: list[MaintenanceRun]) -> None:
+        """Write the run record.
+
+        Args:
+            runs: Runs to store, oldest first.
+        """
+        self.runs_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.runs_path, "w", encoding="utf-8") as f:
+            json.dump([dataclasses.asdict(run) for run in runs], f, indent=2, ensure_ascii=False)
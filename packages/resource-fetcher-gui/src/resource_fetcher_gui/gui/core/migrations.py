@@ -0,0 +1,329 @@
+"""Versioned upgrades of stored settings, history and album manifests at startup."""
+
+import json
+import logging
+import shutil
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_core.utils.manifest import MANIFEST_FILENAME
+
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir, get_app_data_dir
+from resource_fetcher_gui.gui.core.download_history import DownloadHistory
+from resource_fetcher_gui.gui.core.maintenance import library_folders
+
+logger = logging.getLogger(__name__)
+
+# Type of the events reporting a migration
+MIGRATION_FINISHED = "migration-finished"
+
+
+@dataclass
+class Migration:
+    """An upgrade of one file format to the next version.
+
+    Attributes:
+        version: Version the files have after the upgrade.
+        description: What the upgrade changes, for the log.
+        apply: Function upgrading one file in place.
+    """
+
+    version: int
+    description: str
+    apply: Callable[[Path], None]
+
+
+@dataclass
+class MigrationTarget:
+    """A kind of stored data and the upgrades of its format.
+
+    Attributes:
+        name: Name of the data, e.g. "settings".
+        files: Function listing the files holding the data.
+        migrations: Upgrades in version order.
+        atomic: Whether a file failing to upgrade restores all files of the
+            target. Otherwise only that file is restored and skipped, for
+            data kept in many independent files such as album manifests.
+    """
+
+    name: str
+    files: Callable[[], list[Path]]
+    migrations: list[Migration] = field(default_factory=list)
+    atomic: bool = True
+
+    @property
+    def version(self) -> int:
+        """Current format version, 0 if the format has never changed."""
+        return self.migrations[-1].version if self.migrations else 0
+
+
+@dataclass
+class MigrationResult:
+    """The outcome of upgrading one kind of stored data.
+
+    Attributes:
+        target: Name of the data, e.g. "settings".
+        from_version: Version before the upgrade.
+        to_version: Version after the upgrade, from_version if it failed.
+        files: Files that were upgraded.
+        backups: Copies of the files as they were before.
+        error: Why the upgrade failed, empty if it succeeded. The files are
+            restored from the backups and the upgrade is tried again on the
+            next start.
+        skipped: Files of a non-atomic target that couldn't be upgraded, with
+            the reason. They are left as they were.
+        type: Event type, always MIGRATION_FINISHED.
+    """
+
+    target: str
+    from_version: int
+    to_version: int
+    files: list[str] = field(default_factory=list)
+    backups: list[str] = field(default_factory=list)
+    error: str = ""
+    skipped: list[str] = field(default_factory=list)
+    type: str = MIGRATION_FINISHED
+
+
+def rewrite_json(path: Path, transform: Callable[[dict[str, Any]], dict[str, Any]]) -> None:
+    """Replace the contents of a JSON file with a transformed version.
+
+    Args:
+        path: JSON file containing an object.
+        transform: Function returning the new object.
+
+    Raises:
+        ValueError: If the file doesn't contain a JSON object.
+    """
+    with open(path, encoding="utf-8") as f:
+        data = json.load(f)
+    if not isinstance(data, dict):
+        raise ValueError(f"{path} doesn't contain a JSON object")
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(transform(data), f, indent=2, ensure_ascii=False)
+
+
+def settings_log_level(path: Path) -> None:
+    """Replace the legacy verbose flag of the settings with a log level.
+
+    Args:
+        path: Settings file.
+    """
+
+    def transform(data: dict[str, Any]) -> dict[str, Any]:
+        verbose = data.pop("verbose", None)
+        if verbose and "log_level" not in data:
+            data["log_level"] = "debug"
+        return data
+
+    rewrite_json(path, transform)
+
+
+def manifest_version(path: Path) -> None:
+    """Record the format version in manifests written before it existed.
+
+    Args:
+        path: Manifest file.
+    """
+    rewrite_json(path, lambda data: {**data, "version": data.get("version") or 1})
+
+
+def settings_files() -> list[Path]:
+    """List the settings file if it exists.
+
+    Returns:
+        The settings file, or nothing.
+    """
+    path = get_app_config_dir() / "config.json"
+    return [path] if path.exists() else []
+
+
+def history_files() -> list[Path]:
+    """List the download history file if it exists.
+
+    Returns:
+        The history file, or nothing.
+    """
+    path = get_app_data_dir() / "history.jsonl"
+    return [path] if path.exists() else []
+
+
+def manifest_files() -> list[Path]:
+    """List the manifests of albums in the download history.
+
+    Returns:
+        Manifest files of album folders that still have one.
+    """
+    paths = [folder / MANIFEST_FILENAME for folder in library_folders(DownloadHistory().entries())]
+    return [path for path in paths if path.exists()]
+
+
+def default_targets() -> list[MigrationTarget]:
+    """Get the stored data the application upgrades at startup.
+
+    Returns:
+        Targets in the order they are upgraded.
+    """
+    return [
+        MigrationTarget(
+            "settings",
+            settings_files,
+            [Migration(1, "verbose flag replaced by a log level", settings_log_level)],
+        ),
+        # The history format hasn't changed yet; its upgrades go here
+        MigrationTarget("history", history_files),
+        MigrationTarget(
+            "manifests",
+            manifest_files,
+            [Migration(1, "format version recorded", manifest_version)],
+            atomic=False,
+        ),
+    ]
+
+
+class MigrationRunner:
+    """Upgrades stored data to the current format versions.
+
+    The version each kind of data has been upgraded to is kept in a file of
+    its own, so the data files themselves don't need a version field. Files
+    are backed up next to themselves as "<name>.v<version>.bak" before their
+    first change.
+    """
+
+    def __init__(
+        self,
+        targets: list[MigrationTarget] | None = None,
+        versions_path: Path | None = None,
+    ) -> None:
+        """Initialize migration runner.
+
+        Args:
+            targets: Optional data to upgrade. If not provided, uses default_targets.
+            versions_path: Optional custom path to the versions file.
+                           If not provided, uses schema_versions.json in the settings directory.
+        """
+        self.targets = default_targets() if targets is None else targets
+        self.versions_path = versions_path or get_app_config_dir() / "schema_versions.json"
+        logger.debug(f"MigrationRunner initialized with path: {self.versions_path}")
+
+    def load_versions(self) -> dict[str, int]:
+        """Read the versions the data has been upgraded to.
+
+        Returns:
+            Version of each target. Empty if the file doesn't exist or is invalid.
+        """
+        if not self.versions_path.exists():
+            return {}
+
+        try:
+            with open(self.versions_path, encoding="utf-8") as f:
+                return {str(name): int(version) for name, version in json.load(f).items()}
+        except (OSError, json.JSONDecodeError, AttributeError, TypeError, ValueError) as e:
+            logger.warning(f"Failed to read schema versions from {self.versions_path}: {e}")
+            return {}
+
+    def run(self) -> list[MigrationResult]:
+        """Upgrade every target that is behind its current version.
+
+        Returns:
+            The result of each target whose files were upgraded or failed to
+            upgrade, in target order. Targets without files, e.g. on a fresh
+            install, are only marked as current.
+        """
+        versions = self.load_versions()
+        results = []
+        changed = False
+        for target in self.targets:
+            current = versions.get(target.name, 0)
+            if current >= target.version:
+                continue
+
+            result = self._migrate(target, current)
+            if not result.error:
+                versions[target.name] = result.to_version
+                changed = True
+            if result.error or result.files or result.skipped:
+                results.append(result)
+
+        if changed:
+            self._save_versions(versions)
+        return results
+
+    def _migrate(self, target: MigrationTarget, current: int) -> MigrationResult:
+        """Upgrade the files of a target, restoring them all if one fails.
+
+        Files of a non-atomic target that fail are restored and skipped
+        instead, so one damaged file doesn't hold back all others.
+
+        Args:
+            target: Data to upgrade.
+            current: Version the data has now.
+
+        Returns:
+            Result of the upgrade.
+        """
+        result = MigrationResult(target.name, current, current)
+        migrations = [item for item in target.migrations if item.version > current]
+        backups: list[tuple[Path, Path]] = []
+        try:
+            for path in target.files():
+                backup = path.with_name(f"{path.name}.v{current}.bak")
+                try:
+                    shutil.copy2(path, backup)
+                    backups.append((path, backup))
+                    for migration in migrations:
+                        migration.apply(path)
+                except (OSError, ValueError, json.JSONDecodeError) as e:
+                    if target.atomic:
+                        raise
+                    if backups and backups[-1] == (path, backup):
+                        backups.pop()
+                        self._restore(path, backup)
+                        backup.unlink(missing_ok=True)
+                    result.skipped.append(f"{path}: {e}")
+                    logger.warning(f"Skipped upgrading {path}: {e}")
+                    continue
+                result.files.append(str(path))
+        except (OSError, ValueError, json.JSONDecodeError) as e:
+            for path, backup in backups:
+                self._restore(path, backup)
+            result.files.clear()
+            result.error = str(e)
+            logger.error(f"Upgrading {target.name} from version {current} failed: {e}")
+            return result
+
+        result.to_version = target.version
+        result.backups = [str(backup) for _, backup in backups]
+        for migration in migrations:
+            logger.info(
+                f"Upgraded {target.name} to version {migration.version}: {migration.description}"
+            )
+        return result
+
+    @staticmethod
+    def _restore(path: Path, backup: Path) -> None:
+        """Put a file back as it was before the upgrade.
+
+        Args:
+            path: Upgraded file.
+            backup: Copy of the file made before the upgrade.
+        """
+        try:
+            shutil.copy2(backup, path)
+        except OSError as e:
+            logger.error(f"Failed to restore {path} from {backup}: {e}")
+
+    def _save_versions(self, versions: dict[str, int]) -> None:
+        """Write the versions the data has been upgraded to.
+
+        Args:
+            versions: Version of each target.
+        """
+        try:
+            self.versions_path.parent.mkdir(parents=True, exist_ok=True)
+            with open(self.versions_path, "w", encoding="utf-8") as f:
+                json.dump(versions, f, indent=2)
+        except OSError as e:
+            logger.warning(f"Failed to write schema versions: {e}")
@@ -0,0 +1,261 @@
+"""Simulated CLI backend for developing and testing the GUI without the CLI."""
+
+import json
+import logging
+import subprocess
+import threading
+from collections.abc import Iterator
+from datetime import datetime
+from pathlib import Path
+from typing import Any, cast
+
+from resource_fetcher_core.adapters.registry import describe_sources
+from resource_fetcher_core.utils.title_filter import title_selected
+from resource_fetcher_core.utils.track_range import parse_track_range, track_in_range
+
+from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper
+
+# Options the simulated CLI lists in its usage
+MOCK_CLI_OPTIONS = (
+    "--url",
+    "--output",
+    "--normalize",
+    "--merge",
+    "--checksum-sidecars",
+    "--scan-command",
+    "--cookies",
+    "--list-albums",
+    "--list-sources",
+    "--benchmark",
+)
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_log import JobLogStore
+
+logger = logging.getLogger(__name__)
+
+# Exit code reported when the simulated process is terminated (like SIGTERM)
+TERMINATED_EXIT_CODE = -15
+
+# Size reported for each simulated song (bytes)
+MOCK_SONG_SIZE = 4_000_000
+
+
+def mock_song_title(index: int) -> str:
+    """Get the title of a simulated track.
+
+    Args:
+        index: 1-based track number.
+
+    Returns:
+        Track title as shown in the simulated output.
+    """
+    return f"第{index}首 Mock Song {index}"
+
+
+class SimulatedProcess:
+    """Stand-in for a CLI subprocess that emits realistic output.
+
+    Produces the same album header, per-track progress lines, error log
+    records and download summary as the real CLI, pausing between tracks.
+    """
+
+    def __init__(
+        self,
+        song_count: int,
+        delay: float,
+        fail_every: int = 0,
+    ) -> None:
+        """Initialize simulated process.
+
+        Args:
+            song_count: Number of tracks to simulate.
+            delay: Seconds spent on each track.
+            fail_every: Fail every Nth track (0 disables failures).
+        """
+        self.song_count = song_count
+        self.delay = delay
+        self.fail_every = fail_every
+        self.returncode: int | None = None
+        self._stopped = threading.Event()
+        self._finished = threading.Event()
+        self.stdout: Iterator[str] = self._generate_output()
+        # Simulated jobs never ask for input
+        self.stdin = None
+
+    def _generate_output(self) -> Iterator[str]:
+        """Generate CLI output lines, finishing the process when done."""
+        try:
+            yield "=" * 60 + "\n"
+            yield "专辑 (Album): Mock Album\n"
+            yield "来源 (Source): mock\n"
+            yield "礼貌策略 (Politeness): delay=0.0s workers=1\n"
+            yield f"歌曲数 (Songs): {self.song_count}\n"
+            yield "=" * 60 + "\n"
+
+            success = failed = 0
+            for index in range(1, self.song_count + 1):
+                yield f"[{index}/{self.song_count}] {mock_song_title(index)}\n"
+                if self._stopped.wait(self.delay):
+                    return
+
+                if self.fail_every and index % self.fail_every == 0:
+                    failed += 1
+                    timestamp = datetime.now().strftime("%Y-%m-%d %H:%M:%S,000")
+                    yield (
+                        f"{timestamp} - resource_fetcher_cli.cli.main - ERROR - "
+                        f"Failed to download after 3 attempts: mock://song/{index}\n"
+                    )
+                else:
+                    success += 1
+                    yield f"  传输 (Transferred): [{index}] {MOCK_SONG_SIZE} 字节 (bytes)\n"
+                    yield f"  处理 (Processed): {success}/{success}\n"
+
+            elapsed = self.song_count * self.delay
+            speed = success / elapsed if elapsed > 0 else 0
+            yield "\n" + "=" * 60 + "\n"
+            yield "下载完成! Download Summary\n"
+            yield "=" * 60 + "\n"
+            yield f"  成功 (Success): {success}\n"
+            yield f"  失败 (Failed): {failed}\n"
+            yield "  跳过 (Skipped): 0\n"
+            yield f"  总计 (Total): {self.song_count}\n"
+            yield f"  数据量 (Data): {success * MOCK_SONG_SIZE} 字节 (bytes)\n"
+            yield f"  传输量 (Transferred): {success * MOCK_SONG_SIZE} 字节 (bytes)\n"
+            yield f"  耗时 (Time): {elapsed:.1f} 秒\n"
+            yield f"  速度 (Speed): {speed:.2f} 首/秒\n"
+            yield "=" * 60 + "\n"
+
+            if self.returncode is None:
+                self.returncode = 1 if failed else 0
+        finally:
+            self._finished.set()
+
+    def poll(self) -> int | None:
+        """Return the exit code, or None while running."""
+        return self.returncode
+
+    def wait(self, timeout: float | None = None) -> int:
+        """Wait for the simulated process to finish.
+
+        Args:
+            timeout: Optional timeout in seconds.
+
+        Returns:
+            Exit code.
+
+        Raises:
+            subprocess.TimeoutExpired: If the process doesn't finish in time.
+        """
+        if not self._finished.wait(timeout):
+            raise subprocess.TimeoutExpired("mock-cli", timeout or 0)
+        return self.returncode if self.returncode is not None else TERMINATED_EXIT_CODE
+
+    def terminate(self) -> None:
+        """Stop the simulation."""
+        if self.returncode is None:
+            self.returncode = TERMINATED_EXIT_CODE
+        self._stopped.set()
+        self._finished.set()
+
+    def kill(self) -> None:
+        """Stop the simulation."""
+        self.terminate()
+
+
+class MockCLIWrapper(CLIWrapper):
+    """CLI wrapper that runs a SimulatedProcess instead of the real CLI.
+
+    Everything else (callbacks, log events, time limits, stopping) behaves
+    exactly as with the real CLI.
+    """
+
+    def __init__(
+        self,
+        delay: float = 0.5,
+        song_count: int = 10,
+        fail_every: int = 7,
+        log_store: JobLogStore | None = None,
+    ) -> None:
+        """Initialize mock CLI wrapper.
+
+        Args:
+            delay: Seconds spent on each simulated track.
+            song_count: Number of tracks in the simulated album.
+            fail_every: Fail every Nth track (0 disables failures).
+            log_store: Optional store persisting each job's log events.
+        """
+        super().__init__(Path("mock-cli"), log_store=log_store)
+        self.delay = delay
+        self.song_count = song_count
+        self.fail_every = fail_every
+
+    def _validate_cli(self) -> None:
+        """No executable is needed in mock mode."""
+
+    def _run_to_completion(self, cmd: list[str], timeout: float) -> str:
+        """Simulate printing the usage or version, listing albums or sources, or benchmarking.
+
+        Args:
+            cmd: Command that would have been executed.
+            timeout: Maximum seconds the command would have been given.
+
+        Returns:
+            Simulated usage, version, output listing three albums, the bundled
+            sources as JSON, or benchmark results.
+        """
+        if "--help" in cmd:
+            options = " ".join(f"[{option}]" for option in MOCK_CLI_OPTIONS)
+            return f"usage: resource-fetcher {options}\n"
+
+        if "--version" in cmd:
+            return "resource-fetcher 1.0.0 (mock)\n"
+
+        if "--list-sources" in cmd:
+            return json.dumps(describe_sources(), ensure_ascii=False)
+
+        if "--benchmark" in cmd:
+            logger.info(f"Mock mode: simulating benchmark for {' '.join(cmd)}")
+            return (
+                "测速 (Benchmark): 1 个连接 (streams): 1.50 MB/s\n"
+                "测速 (Benchmark): 2 个连接 (streams): 2.90 MB/s\n"
+                "测速 (Benchmark): 4 个连接 (streams): 3.10 MB/s\n"
+                "建议并行数 (Suggested workers): 2\n"
+            )
+
+        logger.info(f"Mock mode: simulating album list for {' '.join(cmd)}")
+        lines = ["专辑数 (Albums): 3"]
+        lines += [
+            f"专辑链接 (Album URL): https://www.izanmei.cc/album/mock-{index}.html"
+            for index in range(1, 4)
+        ]
+        return "\n".join(lines) + "\n"
+
+    def _spawn(
+        self,
+        cmd: list[str],
+        config: DownloadConfig,
+        popen_options: dict[str, Any],
+    ) -> subprocess.Popen[str]:
+        """Start a simulated CLI process.
+
+        Args:
+            cmd: Command that would have been executed.
+            config: Download configuration (track range, title patterns and limit
+                    are honored).
+            popen_options: Ignored.
+
+        Returns:
+            Simulated process behaving like a Popen object.
+        """
+        ranges = parse_track_range(config.track_range) if config.track_range else [(1, None)]
+        include, exclude = config.include_pattern, config.exclude_pattern
+        song_count = sum(
+            track_in_range(index, ranges)
+            and title_selected(mock_song_title(index), include, exclude)
+            for index in range(1, self.song_count + 1)
+        )
+        if config.limit:
+            song_count = min(config.limit, song_count)
+        logger.info(f"Mock mode: simulating {song_count} tracks for {' '.join(cmd)}")
+        process = SimulatedProcess(song_count, self.delay, self.fail_every)
+        return cast("subprocess.Popen[str]", process)
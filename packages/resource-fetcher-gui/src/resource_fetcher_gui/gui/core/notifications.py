@@ -0,0 +1,190 @@
+"""Persistent notification center for important download events."""
+
+import dataclasses
+import json
+import logging
+import threading
+import uuid
+from dataclasses import dataclass
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import get_app_data_dir
+
+logger = logging.getLogger(__name__)
+
+# Oldest notifications are dropped beyond this number
+MAX_NOTIFICATIONS = 200
+
+
+@dataclass
+class Notification:
+    """An event the user should see even if they weren't watching.
+
+    Attributes:
+        notification_id: Unique identifier.
+        severity: Severity level (info, success, warning, error).
+        message: Notification text.
+        created_at: ISO 8601 time the event happened.
+        read: Whether the user has marked the notification as read.
+        delivered: Whether the event has been shown in the GUI. Final job
+            results are stored before the GUI is told, so one that was
+            never shown can be shown on the next start.
+    """
+
+    notification_id: str
+    severity: str
+    message: str
+    created_at: str
+    read: bool = False
+    delivered: bool = True
+
+
+class NotificationStore:
+    """Persistent list of notifications in a JSON file."""
+
+    def __init__(self, notifications_path: Path | None = None) -> None:
+        """Initialize notification store.
+
+        Args:
+            notifications_path: Optional custom path to the notifications file.
+                If not provided, uses notifications.json in the app data directory.
+        """
+        self.notifications_path = notifications_path or get_app_data_dir() / "notifications.json"
+        self._lock = threading.Lock()
+        logger.debug(f"NotificationStore initialized with path: {self.notifications_path}")
+
+    def add(self, severity: str, message: str, delivered: bool = True) -> Notification:
+        """Store a new unread notification.
+
+        Args:
+            severity: Severity level (info, success, warning, error).
+            message: Notification text.
+            delivered: Whether the event is already shown. Pass False to store
+                it first and call mark_delivered once the GUI has shown it.
+
+        Returns:
+            The stored notification.
+        """
+        notification = Notification(
+            notification_id=uuid.uuid4().hex,
+            severity=severity,
+            message=message,
+            created_at=datetime.now().isoformat(timespec="seconds"),
+            delivered=delivered,
+        )
+        with self._lock:
+            notifications = self._load()
+            notifications.append(notification)
+            self._save(notifications[-MAX_NOTIFICATIONS:])
+        return notification
+
+    def list_notifications(self, unread_only: bool = False) -> list[Notification]:
+        """List stored notifications.
+
+        Args:
+            unread_only: Only list notifications not marked as read.
+
+        Returns:
+            Notifications, newest first.
+        """
+        with self._lock:
+            notifications = self._load()
+        if unread_only:
+            notifications = [item for item in notifications if not item.read]
+        return notifications[::-1]
+
+    def unread_count(self) -> int:
+        """Count notifications not marked as read.
+
+        Returns:
+            Number of unread notifications.
+        """
+        return len(self.list_notifications(unread_only=True))
+
+    def mark_read(self, notification_id: str | None = None) -> int:
+        """Mark notifications as read.
+
+        Args:
+            notification_id: Notification to mark, or None to mark all.
+
+        Returns:
+            Number of notifications that were unread before.
+        """
+        with self._lock:
+            notifications = self._load()
+            marked = 0
+            for item in notifications:
+                if not item.read and notification_id in (None, item.notification_id):
+                    item.read = True
+                    marked += 1
+            if marked:
+                self._save(notifications)
+        return marked
+
+    def undelivered(self) -> list[Notification]:
+        """List notifications the GUI hasn't shown yet.
+
+        Returns:
+            Undelivered notifications, oldest first.
+        """
+        with self._lock:
+            notifications = self._load()
+        return [item for item in notifications if not item.delivered]
+
+    def mark_delivered(self, notification_id: str) -> bool:
+        """Acknowledge that the GUI has shown a notification.
+
+        Args:
+            notification_id: Notification that was shown.
+
+        Returns:
+            True if the notification was undelivered before.
+        """
+        with self._lock:
+            notifications = self._load()
+            for item in notifications:
+                if item.notification_id == notification_id and not item.delivered:
+                    item.delivered = True
+                    self._save(notifications)
+                    return True
+        return False
+
+    def clear(self) -> None:
+        """Remove all notifications."""
+        with self._lock:
+            self._save([])
+
+    def _load(self) -> list[Notification]:
+        """Read notifications from file.
+
+        Returns:
+            Notifications, oldest first. Empty if the file doesn't exist or is invalid.
+        """
+        if not self.notifications_path.exists():
+            return []
+
+        try:
+            with open(self.notifications_path, encoding="utf-8") as f:
+                return [Notification(**item) for item in json.load(f)]
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read notifications from {self.notifications_path}: {e}")
+            return []
+
+    def _save(self, notifications: list[Notification]) -> None:
+        """Write notifications to file.
+
+        Args:
+            notifications: Notifications, oldest first.
+        """
+        try:
+            self.notifications_path.parent.mkdir(parents=True, exist_ok=True)
+            with open(self.notifications_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    [dataclasses.asdict(item) for item in notifications],
+                    f,
+                    indent=2,
+                    ensure_ascii=False,
+                )
+        except OSError as e:
+            logger.warning(f"Failed to write notifications: {e}")
@@ -0,0 +1,59 @@
+"""Bounded buffer between the CLI reader thread and the GUI."""
+
+import logging
+import threading
+from collections import deque
+
+logger = logging.getLogger(__name__)
+
+# Lines kept until the GUI collects them; older ones are dropped
+MAX_BUFFERED_LINES = 500
+
+
+class OutputBuffer:
+    """Thread-safe ring buffer of CLI output lines.
+
+    The reader thread adds lines as fast as the CLI prints them; the GUI
+    collects them periodically. If the GUI falls behind, the oldest lines
+    are dropped and counted instead of piling up in memory.
+    """
+
+    def __init__(self, max_lines: int = MAX_BUFFERED_LINES) -> None:
+        """Initialize output buffer.
+
+        Args:
+            max_lines: Maximum number of lines kept between two collections.
+        """
+        self._lines: deque[str] = deque(maxlen=max_lines)
+        self._lock = threading.Lock()
+        self._dropped = 0
+        self.total_dropped = 0
+
+    def put(self, line: str) -> None:
+        """Add a line, dropping the oldest one if the buffer is full.
+
+        Args:
+            line: Line of CLI output.
+        """
+        with self._lock:
+            if len(self._lines) == self._lines.maxlen:
+                self._dropped += 1
+                self.total_dropped += 1
+            self._lines.append(line)
+
+    def drain(self) -> tuple[list[str], int]:
+        """Collect all buffered lines.
+
+        Returns:
+            Buffered lines, oldest first, and the number of lines dropped
+            since the last collection.
+        """
+        with self._lock:
+            lines = list(self._lines)
+            dropped = self._dropped
+            self._lines.clear()
+            self._dropped = 0
+
+        if dropped:
+            logger.debug(f"Dropped {dropped} lines of CLI output")
+        return lines, dropped
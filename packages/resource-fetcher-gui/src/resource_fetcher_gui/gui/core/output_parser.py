@@ -17,12 +17,14 @@ class SongProgress:
         total: Total number of songs.
         title: Song title.
         status: Download status (pending, downloading, success, failed, skipped).
+        reason: Why the song was skipped, if reported by the CLI.
     """
 
     index: int
     total: int
     title: str
     status: str = "downloading"  # pending, downloading, success, failed, skipped
+    reason: str | None = None
 
 
 @dataclass
@@ -33,11 +35,15 @@ class AlbumInfo:
         title: Album title.
         source: Source website.
         song_count: Number of songs in album.
+        politeness: Effective delay ("delay", seconds), concurrency ("workers"),
+            download window ("window", "HH:MM-HH:MM" or None) and whether the
+            source's policy was relaxed ("relaxed"); None if not reported.
     """
 
     title: str
     source: str
     song_count: int
+    politeness: dict[str, Any] | None = None
 
 
 @dataclass
@@ -51,6 +57,9 @@ class DownloadSummary:
         total: Total number of songs.
         elapsed_time: Time elapsed in seconds.
         speed: Download speed (songs per second).
+        downloaded_bytes: Size of the downloaded files in bytes.
+        transferred_bytes: Data received over the network in bytes, including
+            retries and discarded files.
     """
 
     success: int
@@ -59,6 +68,8 @@ class DownloadSummary:
     total: int
     elapsed_time: float
     speed: float
+    downloaded_bytes: int = 0
+    transferred_bytes: int = 0
 
 
 class OutputParser:
@@ -73,12 +84,27 @@ class OutputParser:
         "album_title": r"专辑\s*\(Album\):\s*(.+)",
         "album_source": r"来源\s*\(Source\):\s*(.+)",
         "album_songs": r"歌曲数\s*\(Songs\):\s*(\d+)",
+        "album_politeness": (
+            r"礼貌策略\s*\(Politeness\):\s*delay=([\d.]+)s workers=(\d+)"
+            r"(?: window=(\S+))?( \(relaxed\))?"
+        ),
         "song_progress": r"\[(\d+)/(\d+)\]\s+(.+)",  # [1/10] Song Title
+        "song_skipped": r"跳过\s*\(Skip\):\s*(.+)",  # Reason for skipping the previous song
+        "rate_banned": r"限流\s*\(Rate limited\):.*?(\d+)\s*秒",
+        "scan_failed": r"扫描\s*\(Scan\):\s*未通过\s*\(failed\)\s*(.*)",
+        "sanity_failed": r"检查\s*\(Sanity\):\s*未通过\s*\(failed\)\s*(.*)",
+        "wrong_content_type": r"类型\s*\(Content type\):\s*错误\s*\(wrong-content-type\)\s*(.*)",
+        "merge_progress": r"合并\s*\(Merge\):\s*(\d+)%",
+        "processed": r"处理\s*\(Processed\):\s*(\d+)/(\d+)",
+        "track_transferred": r"传输\s*\(Transferred\):\s*\[(\d+)\]\s*(\d+)",
+        "storage": r"存储\s*\(Storage\):.*\(mount (lost|restored)\)",
         "summary_header": r"下载完成! Download Summary",
         "summary_success": r"\s+成功\s*\(Success\):\s*(\d+)",
         "summary_failed": r"\s+失败\s*\(Failed\):\s*(\d+)",
         "summary_skipped": r"\s+跳过\s*\(Skipped\):\s*(\d+)",
         "summary_total": r"\s+总计\s*\(Total\):\s*(\d+)",
+        "summary_data": r"\s+数据量\s*\(Data\):\s*(\d+)",
+        "summary_transferred": r"\s+传输量\s*\(Transferred\):\s*(\d+)",
         "summary_time": r"\s+耗时\s*\(Time\):\s+([\d.]+)\s+秒",
         "summary_speed": r"\s+速度\s*\(Speed\):\s+([\d.]+)\s+首/秒",
         "error": r"(错误|Error):\s*(.+)",
@@ -87,6 +113,7 @@ class OutputParser:
     def __init__(self) -> None:
         """Initialize output parser."""
         self._current_album: AlbumInfo | None = None
+        self._current_song: SongProgress | None = None
         self._in_summary = False
         self._summary_data: dict[str, Any] = {}
 
@@ -118,6 +145,7 @@ class OutputParser:
         # Check for album title
         if match := self._compiled_patterns["album_title"].search(line):
             title = match.group(1).strip()
+            self._current_album = AlbumInfo(title=title, source="", song_count=0)
             logger.debug(f"Parsed album title: {title}")
             # Don't return yet, wait for complete album info
 
@@ -130,6 +158,19 @@ class OutputParser:
                 self._current_album.source = source
             logger.debug(f"Parsed album source: {source}")
 
+        # Check for the source's politeness policy
+        elif match := self._compiled_patterns["album_politeness"].search(line):
+            politeness = {
+                "delay": float(match.group(1)),
+                "workers": int(match.group(2)),
+                "window": match.group(3),
+                "relaxed": match.group(4) is not None,
+            }
+            if self._current_album is None:
+                self._current_album = AlbumInfo(title="", source="", song_count=0)
+            self._current_album.politeness = politeness
+            logger.debug(f"Parsed politeness policy: {politeness}")
+
         # Check for song count
         elif match := self._compiled_patterns["album_songs"].search(line):
             count = int(match.group(1))
@@ -147,8 +188,68 @@ class OutputParser:
             title = match.group(3).strip()
             progress = SongProgress(index=index, total=total, title=title, status="downloading")
             logger.debug(f"Parsed song progress: [{index}/{total}] {title}")
+            self._current_song = progress
             return progress
 
+        # Check for a skipped song
+        elif match := self._compiled_patterns["song_skipped"].search(line):
+            if self._current_song is None:
+                return None
+            reason = match.group(1).strip()
+            logger.debug(f"Parsed skipped song: {self._current_song.title} ({reason})")
+            return SongProgress(
+                index=self._current_song.index,
+                total=self._current_song.total,
+                title=self._current_song.title,
+                status="skipped",
+                reason=reason,
+            )
+
+        # Check for a site refusing requests for a while
+        elif match := self._compiled_patterns["rate_banned"].search(line):
+            cooldown = int(match.group(1))
+            logger.debug(f"Parsed rate limit, cooldown {cooldown} seconds")
+            return {"type": "rate_banned", "cooldown": cooldown}
+
+        # Check for a song the scanner rejected
+        elif match := self._compiled_patterns["scan_failed"].search(line):
+            reason = match.group(1).strip()
+            title = self._current_song.title if self._current_song else ""
+            logger.debug(f"Parsed scan failure: {title} ({reason})")
+            return {"type": "scan_failed", "title": title, "reason": reason}
+
+        # Check for a song whose file wasn't the expected audio format
+        elif match := self._compiled_patterns["wrong_content_type"].search(line):
+            reason = match.group(1).strip()
+            title = self._current_song.title if self._current_song else ""
+            logger.debug(f"Parsed wrong content type: {title} ({reason})")
+            return {"type": "wrong_content_type", "title": title, "reason": reason}
+
+        # Check for a song that failed the size or silence checks
+        elif match := self._compiled_patterns["sanity_failed"].search(line):
+            reason = match.group(1).strip()
+            title = self._current_song.title if self._current_song else ""
+            logger.debug(f"Parsed sanity check failure: {title} ({reason})")
+            return {"type": "sanity_failed", "title": title, "reason": reason}
+
+        # Check for merge progress
+        elif match := self._compiled_patterns["merge_progress"].search(line):
+            percent = int(match.group(1))
+            logger.debug(f"Parsed merge progress: {percent}%")
+            return {"type": "merge_progress", "percent": percent}
+
+        # Check for downloaded files the post-processing has finished with
+        elif match := self._compiled_patterns["processed"].search(line):
+            done, total = int(match.group(1)), int(match.group(2))
+            logger.debug(f"Parsed post-processing progress: {done}/{total}")
+            return {"type": "processed", "done": done, "total": total}
+
+        # Check for a network share that disconnected or came back
+        elif match := self._compiled_patterns["storage"].search(line):
+            event = f"mount_{match.group(1)}"
+            logger.debug(f"Parsed storage event: {event}")
+            return {"type": event}
+
         # Check for summary header
         elif self._compiled_patterns["summary_header"].search(line):
             logger.debug("Summary section started")
@@ -173,6 +274,14 @@ class OutputParser:
             elif match := self._compiled_patterns["summary_total"].search(line):
                 self._summary_data["total"] = int(match.group(1))
 
+            # Downloaded data
+            elif match := self._compiled_patterns["summary_data"].search(line):
+                self._summary_data["downloaded_bytes"] = int(match.group(1))
+
+            # Transferred data
+            elif match := self._compiled_patterns["summary_transferred"].search(line):
+                self._summary_data["transferred_bytes"] = int(match.group(1))
+
             # Elapsed time
             elif match := self._compiled_patterns["summary_time"].search(line):
                 self._summary_data["elapsed_time"] = float(match.group(1))
@@ -190,6 +299,8 @@ class OutputParser:
                         total=self._summary_data["total"],
                         elapsed_time=self._summary_data.get("elapsed_time", 0.0),
                         speed=self._summary_data.get("speed", 0.0),
+                        downloaded_bytes=self._summary_data.get("downloaded_bytes", 0),
+                        transferred_bytes=self._summary_data.get("transferred_bytes", 0),
                     )
                     logger.debug(f"Parsed download summary: {summary}")
                     self._in_summary = False
@@ -203,6 +314,11 @@ class OutputParser:
 
         return None
 
+    @property
+    def album(self) -> AlbumInfo | None:
+        """Album information parsed so far, or None before the album header."""
+        return self._current_album
+
     def reset(self) -> None:
         """Reset parser state.
 
@@ -210,6 +326,7 @@ class OutputParser:
         Call this before starting a new download.
         """
         self._current_album = None
+        self._current_song = None
         self._in_summary = False
         self._summary_data = {}
         logger.debug("Parser state reset")
@@ -249,3 +366,94 @@ class OutputParser:
                 return result
 
         return None
+
+
+def parse_downloaded_bytes(line: str) -> int | None:
+    """Read the amount of downloaded data from a summary line.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Downloaded bytes if the line reports them, None otherwise.
+    """
+    match = re.match(OutputParser.PATTERNS["summary_data"], line)
+    return int(match.group(1)) if match else None
+
+
+def parse_transferred_bytes(line: str) -> int | None:
+    """Read the amount of data received over the network from a summary line.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Transferred bytes if the line reports them, None otherwise.
+    """
+    match = re.match(OutputParser.PATTERNS["summary_transferred"], line)
+    return int(match.group(1)) if match else None
+
+
+def parse_track_transfer(line: str) -> tuple[int, int] | None:
+    """Read the data a single track used.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Track number and transferred bytes if the line reports them, None otherwise.
+    """
+    match = re.search(OutputParser.PATTERNS["track_transferred"], line)
+    return (int(match.group(1)), int(match.group(2))) if match else None
+
+
+def parse_track_position(line: str) -> tuple[int, int] | None:
+    """Read which track a line of CLI output starts.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Track number and track count if the line starts a track, None otherwise.
+    """
+    match = re.match(OutputParser.PATTERNS["song_progress"], line)
+    return (int(match.group(1)), int(match.group(2))) if match else None
+
+
+def parse_processed_files(line: str) -> int | None:
+    """Read how many downloaded files the CLI has post-processed.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Number of processed files if the line reports it, None otherwise.
+    """
+    match = re.search(OutputParser.PATTERNS["processed"], line)
+    return int(match.group(1)) if match else None
+
+
+def parse_failed_tracks(line: str) -> int | None:
+    """Read the number of failed tracks from a summary line.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Number of failed tracks if the line reports it, None otherwise.
+    """
+    match = re.match(OutputParser.PATTERNS["summary_failed"], line)
+    return int(match.group(1)) if match else None
+
+
+def parse_rate_ban(line: str) -> int | None:
+    """Read the cooldown of a site rate limiting the CLI.
+
+    Args:
+        line: A single line of CLI output.
+
+    Returns:
+        Cooldown in seconds if the line reports a rate limit, None otherwise.
+    """
+    match = re.search(OutputParser.PATTERNS["rate_banned"], line)
+    return int(match.group(1)) if match else None
@@ -0,0 +1,135 @@
+"""Named configuration profiles (presets) for GUI."""
+
+import dataclasses
+import json
+import logging
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_gui.gui.core.config_service import (
+    ConfigService,
+    DownloadConfig,
+    get_app_config_dir,
+)
+
+logger = logging.getLogger(__name__)
+
+
+class ProfileService:
+    """Service for managing named download configuration profiles.
+
+    Profiles are stored together in a single JSON file mapping profile names
+    to configuration values, next to the main configuration file.
+    """
+
+    def __init__(
+        self,
+        profiles_path: Path | None = None,
+        config_service: ConfigService | None = None,
+    ) -> None:
+        """Initialize profile service.
+
+        Args:
+            profiles_path: Optional custom path to the profiles file.
+                           If not provided, uses profiles.json in the settings directory.
+            config_service: Optional config service used to validate profile values.
+        """
+        self.profiles_path = profiles_path or get_app_config_dir() / "profiles.json"
+        self.config_service = config_service or ConfigService()
+        logger.debug(f"ProfileService initialized with path: {self.profiles_path}")
+
+    def load_profiles(self) -> dict[str, dict[str, Any]]:
+        """Load raw profile data from file.
+
+        Returns:
+            Mapping of profile name to configuration values. Empty if the file
+            doesn't exist or is invalid.
+        """
+        if not self.profiles_path.exists():
+            return {}
+
+        try:
+            with open(self.profiles_path, encoding="utf-8") as f:
+                data = json.load(f)
+        except (OSError, json.JSONDecodeError) as e:
+            logger.warning(f"Failed to read profiles from {self.profiles_path}: {e}")
+            return {}
+
+        if not isinstance(data, dict):
+            logger.warning(f"Invalid profiles file {self.profiles_path}, ignoring")
+            return {}
+
+        return {name: values for name, values in data.items() if isinstance(values, dict)}
+
+    def save_profiles(self, profiles: dict[str, dict[str, Any]]) -> None:
+        """Write raw profile data to file.
+
+        Args:
+            profiles: Mapping of profile name to configuration values.
+        """
+        self.profiles_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.profiles_path, "w", encoding="utf-8") as f:
+            json.dump(profiles, f, indent=2, ensure_ascii=False)
+
+    def save_profile(self, name: str, config: DownloadConfig) -> None:
+        """Save a configuration under a profile name, replacing any existing one.
+
+        Args:
+            name: Profile name.
+            config: Configuration to store.
+
+        Raises:
+            ValueError: If the name is empty.
+        """
+        name = name.strip()
+        if not name:
+            raise ValueError("Profile name cannot be empty")
+
+        profiles = self.load_profiles()
+        profiles[name] = dataclasses.asdict(config)
+        self.save_profiles(profiles)
+        logger.info(f"Profile saved: {name}")
+
+    def list_profiles(self) -> list[str]:
+        """List saved profile names.
+
+        Returns:
+            Sorted list of profile names.
+        """
+        return sorted(self.load_profiles())
+
+    def apply_profile(self, name: str) -> DownloadConfig:
+        """Get the configuration stored in a profile.
+
+        Args:
+            name: Profile name.
+
+        Returns:
+            Validated DownloadConfig for the profile.
+
+        Raises:
+            ValueError: If no profile with that name exists.
+        """
+        profiles = self.load_profiles()
+        if name not in profiles:
+            raise ValueError(f"Profile not found: {name}")
+
+        return self.config_service.config_from_dict(profiles[name])
+
+    def delete_profile(self, name: str) -> bool:
+        """Delete a profile.
+
+        Args:
+            name: Profile name.
+
+        Returns:
+            True if the profile was deleted, False if it didn't exist.
+        """
+        profiles = self.load_profiles()
+        if name not in profiles:
+            return False
+
+        del profiles[name]
+        self.save_profiles(profiles)
+        logger.info(f"Profile deleted: {name}")
+        return True
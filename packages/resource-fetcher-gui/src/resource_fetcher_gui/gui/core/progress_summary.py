@@ -0,0 +1,110 @@
+"""Human-readable progress summaries for screen readers and the status line."""
+
+import logging
+import math
+import threading
+import time
+from collections.abc import Callable
+
+from resource_fetcher_gui.gui.core.i18n import tr
+from resource_fetcher_gui.gui.core.output_parser import SongProgress
+
+logger = logging.getLogger(__name__)
+
+# Seconds between two summaries while tracks keep finishing
+SUMMARY_INTERVAL = 15.0
+
+# Track states that count as done
+FINISHED_STATES = ("success", "failed", "skipped")
+
+
+def summarize(done: int, total: int, elapsed: float) -> str:
+    """Describe how far a download is in a sentence.
+
+    The remaining time is estimated from the average time per finished
+    track so far.
+
+    Args:
+        done: Tracks finished (downloaded, failed or skipped).
+        total: Tracks in the album.
+        elapsed: Seconds since the download started.
+
+    Returns:
+        Localized text like "3 of 12 tracks done, about 8 minutes remaining".
+    """
+    if done >= total:
+        return tr("summary_finished", total=total)
+    if done == 0 or elapsed <= 0:
+        return tr("summary_estimating", done=done, total=total)
+
+    remaining = elapsed / done * (total - done)
+    if remaining < 60:
+        return tr("summary_under_a_minute", done=done, total=total)
+    return tr("summary_minutes", done=done, total=total, minutes=math.ceil(remaining / 60))
+
+
+class ProgressSummary:
+    """Turns the stream of track progress into occasional summary sentences.
+
+    A summary is passed on when a track finishes and at least SUMMARY_INTERVAL
+    seconds have passed since the previous one, and always for the first and
+    the last track, so assistive technology isn't flooded with updates. Safe
+    to call from the download thread.
+    """
+
+    def __init__(
+        self,
+        callback: Callable[[str], None],
+        interval: float = SUMMARY_INTERVAL,
+        clock: Callable[[], float] = time.monotonic,
+    ) -> None:
+        """Initialize progress summary.
+
+        Args:
+            callback: Function receiving the localized summaries.
+            interval: Minimum seconds between two summaries.
+            clock: Source of the current time in seconds.
+        """
+        self.callback = callback
+        self.interval = interval
+        self.clock = clock
+        self._lock = threading.Lock()
+        self._finished: set[int] = set()
+        self._started_at = clock()
+        self._last_summary = float("-inf")
+
+    def reset(self) -> None:
+        """Start summarizing a new download."""
+        with self._lock:
+            self._finished.clear()
+            self._started_at = self.clock()
+            self._last_summary = float("-inf")
+
+    def update(self, progress: SongProgress) -> str | None:
+        """Record a track's progress and pass on a summary if one is due.
+
+        Args:
+            progress: Progress reported by the CLI.
+
+        Returns:
+            The summary passed on, or None if none was due.
+        """
+        if progress.status not in FINISHED_STATES:
+            return None
+
+        with self._lock:
+            if progress.index in self._finished:
+                return None
+            self._finished.add(progress.index)
+            done = len(self._finished)
+            now = self.clock()
+            milestone = done == 1 or done >= progress.total
+            if not milestone and now - self._last_summary < self.interval:
+                return None
+            self._last_summary = now
+            elapsed = now - self._started_at
+
+        summary = summarize(done, progress.total, elapsed)
+        logger.debug(f"Progress summary: {summary}")
+        self.callback(summary)
+        return summary
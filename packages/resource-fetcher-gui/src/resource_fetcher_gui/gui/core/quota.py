@@ -0,0 +1,171 @@
+"""Daily and monthly limits on downloaded data."""
+
+import dataclasses
+import json
+import logging
+from dataclasses import dataclass
+from datetime import datetime, timedelta
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir
+from resource_fetcher_gui.gui.core.download_history import DownloadHistory
+
+logger = logging.getLogger(__name__)
+
+# Bytes in a megabyte, the unit quotas are configured in
+MEGABYTE = 1024 * 1024
+
+# How often a paused queue checks whether the quota period rolled over (seconds)
+QUOTA_CHECK_INTERVAL = 60
+
+
+@dataclass
+class QuotaLimits:
+    """Configured data quotas.
+
+    Attributes:
+        daily_mb: Optional limit for each calendar day in MB.
+        monthly_mb: Optional limit for each calendar month in MB.
+    """
+
+    daily_mb: int | None = None
+    monthly_mb: int | None = None
+
+
+@dataclass
+class QuotaExceeded:
+    """A data quota that has been reached.
+
+    Attributes:
+        period: "day" or "month".
+        used_mb: Data downloaded in the period in MB.
+        limit_mb: The period's limit in MB.
+        resets_at: When the next period starts.
+    """
+
+    period: str
+    used_mb: float
+    limit_mb: int
+    resets_at: datetime
+
+
+def period_start(period: str, now: datetime) -> datetime:
+    """Get the start of the quota period containing a time.
+
+    Args:
+        period: "day" or "month".
+        now: Time within the period.
+
+    Returns:
+        Midnight of the day, or of the first day of the month.
+    """
+    start = now.replace(hour=0, minute=0, second=0, microsecond=0)
+    return start.replace(day=1) if period == "month" else start
+
+
+def next_period_start(period: str, now: datetime) -> datetime:
+    """Get the start of the quota period after the one containing a time.
+
+    Args:
+        period: "day" or "month".
+        now: Time within the current period.
+
+    Returns:
+        Midnight of the next day, or of the first day of the next month.
+    """
+    start = period_start(period, now)
+    if period == "day":
+        return start + timedelta(days=1)
+    # Day 28 plus four days is always in the next month
+    return (start.replace(day=28) + timedelta(days=4)).replace(day=1)
+
+
+class QuotaService:
+    """Service checking downloaded data against the configured quotas.
+
+    Usage is read from the download history, so it survives restarts.
+    """
+
+    def __init__(
+        self, history: DownloadHistory | None = None, quota_path: Path | None = None
+    ) -> None:
+        """Initialize quota service.
+
+        Args:
+            history: Optional download history the usage is read from.
+            quota_path: Optional custom path to the quota file.
+                        If not provided, uses quota.json in the settings directory.
+        """
+        self.history = history or DownloadHistory()
+        self.quota_path = quota_path or get_app_config_dir() / "quota.json"
+        logger.debug(f"QuotaService initialized with path: {self.quota_path}")
+
+    def load(self) -> QuotaLimits:
+        """Read the configured quotas.
+
+        Returns:
+            Configured quotas. None are set if the file doesn't exist or is invalid.
+        """
+        if not self.quota_path.exists():
+            return QuotaLimits()
+
+        try:
+            with open(self.quota_path, encoding="utf-8") as f:
+                return QuotaLimits(**json.load(f))
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read quotas from {self.quota_path}: {e}")
+            return QuotaLimits()
+
+    def save(self, limits: QuotaLimits) -> None:
+        """Write the quotas to file.
+
+        Args:
+            limits: Quotas to configure.
+
+        Raises:
+            ValueError: If a limit isn't positive.
+        """
+        for limit in (limits.daily_mb, limits.monthly_mb):
+            if limit is not None and limit <= 0:
+                raise ValueError(f"Data quota must be positive: {limit}")
+
+        self.quota_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.quota_path, "w", encoding="utf-8") as f:
+            json.dump(dataclasses.asdict(limits), f, indent=2)
+        logger.info(f"Data quotas saved: {limits}")
+
+    def usage(self, period: str, now: datetime | None = None) -> int:
+        """Get the data downloaded in the current period.
+
+        Args:
+            period: "day" or "month".
+            now: Optional current time.
+
+        Returns:
+            Bytes used, including retries and discarded files where recorded.
+        """
+        return self.history.bytes_since(period_start(period, now or datetime.now()))
+
+    def exceeded(self, now: datetime | None = None) -> QuotaExceeded | None:
+        """Check whether a quota has been reached.
+
+        Args:
+            now: Optional current time.
+
+        Returns:
+            The reached quota (the daily one if both are), or None.
+        """
+        now = now or datetime.now()
+        limits = self.load()
+        for period, limit in (("day", limits.daily_mb), ("month", limits.monthly_mb)):
+            if limit is None:
+                continue
+            used = self.usage(period, now)
+            if used >= limit * MEGABYTE:
+                return QuotaExceeded(
+                    period=period,
+                    used_mb=used / MEGABYTE,
+                    limit_mb=limit,
+                    resets_at=next_period_start(period, now),
+                )
+        return None
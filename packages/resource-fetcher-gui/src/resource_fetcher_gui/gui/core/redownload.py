@@ -0,0 +1,62 @@
+"""Downloading a past job again."""
+
+import dataclasses
+import logging
+from typing import Any
+
+from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.download_history import DownloadHistory
+from resource_fetcher_gui.gui.core.job_queue import JobQueue, QueuedJob
+
+logger = logging.getLogger(__name__)
+
+
+def redownload(
+    job_id: str,
+    history: DownloadHistory,
+    queue: JobQueue,
+    base_config: DownloadConfig,
+    overrides: dict[str, Any] | None = None,
+    config_service: ConfigService | None = None,
+) -> QueuedJob:
+    """Enqueue a past job again with its original settings.
+
+    Settings the history entry doesn't record (entries of older versions
+    only record the output folder) are taken from the base configuration.
+    The job keeps its note and metadata.
+
+    Args:
+        job_id: Identifier of the past job, as recorded in the history.
+        history: Download history holding the job.
+        queue: Queue receiving the job.
+        base_config: Settings used where the history has none.
+        overrides: Optional settings replacing the original ones,
+            e.g. {"output_dir": "./new"}.
+        config_service: Optional config service used to validate settings.
+
+    Returns:
+        The queued job.
+
+    Raises:
+        ValueError: If the job isn't in the history or an override is unknown.
+    """
+    entry = history.get_job(job_id)
+    if entry is None:
+        raise ValueError(f"Job not found in history: {job_id}")
+
+    overrides = overrides or {}
+    unknown = sorted(set(overrides) - set(DownloadConfig.__dataclass_fields__))
+    if unknown:
+        raise ValueError(f"Unknown options: {', '.join(unknown)}")
+
+    config_service = config_service or ConfigService()
+    config = config_service.config_from_dict(
+        {
+            **dataclasses.asdict(base_config),
+            "output_dir": entry.output_dir,
+            **entry.config,
+            **overrides,
+        }
+    )
+    logger.info(f"Downloading job {job_id} again: {entry.url}")
+    return queue.enqueue(entry.url, config, note=entry.note, metadata=entry.metadata)
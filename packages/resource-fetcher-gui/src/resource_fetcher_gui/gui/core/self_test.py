@@ -0,0 +1,114 @@
+"""End-to-end self-test downloading one known track."""
+
+import logging
+import tempfile
+from dataclasses import dataclass
+from pathlib import Path
+
+from resource_fetcher_core.utils.manifest import MANIFEST_FILENAME, read_manifest, verify_manifest
+from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.output_parser import OutputParser
+
+logger = logging.getLogger(__name__)
+
+# Album whose first track is downloaded by the self-test
+SELF_TEST_URL = "https://www.izanmei.cc/album/hymns-442-1.html"
+
+# Maximum time for the self-test download (seconds)
+SELF_TEST_TIMEOUT = 120
+
+
+@dataclass
+class SelfTestStage:
+    """Result of one stage of the self-test.
+
+    Attributes:
+        name: Stage name (start, download, parse, write, verify).
+        passed: Whether the stage succeeded.
+        message: Details, e.g. why the stage failed.
+    """
+
+    name: str
+    passed: bool
+    message: str = ""
+
+
+def run_self_test(
+    cli_wrapper: CLIWrapper,
+    url: str = SELF_TEST_URL,
+    timeout: float = SELF_TEST_TIMEOUT,
+) -> list[SelfTestStage]:
+    """Download one track into a temporary folder and check every step.
+
+    The stages are: starting the CLI, the download finishing successfully,
+    parsing the CLI output, the file being written and its checksum
+    matching the manifest. The test stops at the first failing stage, so
+    the last stage tells where the problem is. Call it from a background
+    thread.
+
+    Args:
+        cli_wrapper: Wrapper running the CLI.
+        url: Album whose first track is downloaded.
+        timeout: Maximum seconds to wait for the download.
+
+    Returns:
+        Results of the stages that ran, in order.
+    """
+    stages: list[SelfTestStage] = []
+
+    def stage(name: str, passed: bool, message: str = "") -> None:
+        stages.append(SelfTestStage(name, passed, message))
+        logger.info(f"Self-test stage {name}: {'passed' if passed else 'failed'} {message}")
+
+    with tempfile.TemporaryDirectory(prefix="resource-fetcher-self-test-") as tmp:
+        folder = Path(tmp)
+        config = DownloadConfig(output_dir=tmp, limit=1, overwrite=True)
+        lines: list[str] = []
+        exit_codes: list[int] = []
+
+        try:
+            thread = cli_wrapper.execute_download(url, config, lines.append, exit_codes.append)
+        except (OSError, ValueError) as e:
+            stage("start", False, str(e))
+            return stages
+        stage("start", True)
+
+        thread.join(timeout)
+        if thread.is_alive():
+            cli_wrapper.stop_download()
+            stage("download", False, f"No result after {timeout:.0f} seconds")
+            return stages
+        exit_code = exit_codes[0] if exit_codes else None
+        if exit_code != 0:
+            stage("download", False, f"Exit code {exit_code}")
+            return stages
+        stage("download", True)
+
+        summary = OutputParser().parse_summary(lines)
+        if summary is None:
+            stage("parse", False, "No download summary in the CLI output")
+            return stages
+        if summary.success != 1:
+            stage("parse", False, f"{summary.success} of 1 track succeeded")
+            return stages
+        stage("parse", True)
+
+        files = [path for path in folder.iterdir() if path.name != MANIFEST_FILENAME]
+        if not files:
+            stage("write", False, "No file was written")
+            return stages
+        stage("write", True, files[0].name)
+
+        try:
+            manifest = read_manifest(folder)
+        except (FileNotFoundError, ValueError) as e:
+            stage("verify", False, str(e))
+            return stages
+        damaged = verify_manifest(folder, manifest)
+        if damaged:
+            stage("verify", False, f"{len(damaged)} files don't match their checksum")
+        else:
+            stage("verify", True)
+
+    return stages
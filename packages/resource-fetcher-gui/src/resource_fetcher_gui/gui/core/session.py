@@ -0,0 +1,72 @@
+"""Job queue state kept across restarts."""
+
+import dataclasses
+import json
+import logging
+import threading
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import ConfigService, get_app_data_dir
+from resource_fetcher_gui.gui.core.job_queue import QueuedJob, SessionState
+
+logger = logging.getLogger(__name__)
+
+
+class SessionStore:
+    """Stores the job queue's state in a JSON file."""
+
+    def __init__(
+        self, session_path: Path | None = None, config_service: ConfigService | None = None
+    ) -> None:
+        """Initialize session store.
+
+        Args:
+            session_path: Optional custom path to the session file.
+                If not provided, uses session.json in the app data directory.
+            config_service: Optional service validating the stored job configurations.
+        """
+        self.session_path = session_path or get_app_data_dir() / "session.json"
+        self.config_service = config_service or ConfigService()
+        self._lock = threading.Lock()
+        logger.debug(f"SessionStore initialized with path: {self.session_path}")
+
+    def save(self, state: SessionState) -> None:
+        """Write the state to file.
+
+        Args:
+            state: Snapshot of the job queue.
+        """
+        try:
+            with self._lock:
+                self.session_path.parent.mkdir(parents=True, exist_ok=True)
+                with open(self.session_path, "w", encoding="utf-8") as f:
+                    json.dump(dataclasses.asdict(state), f, indent=2, ensure_ascii=False)
+        except OSError as e:
+            logger.warning(f"Failed to write session: {e}")
+
+    def load(self) -> SessionState:
+        """Read the state the previous session saved.
+
+        Returns:
+            Saved state. Empty if the file doesn't exist or is invalid; jobs
+            that can't be read are skipped.
+        """
+        if not self.session_path.exists():
+            return SessionState()
+
+        try:
+            with self._lock, open(self.session_path, encoding="utf-8") as f:
+                data = json.load(f)
+            state = SessionState(paused=bool(data["paused"]), saved_at=str(data["saved_at"]))
+            items = data["jobs"]
+        except (OSError, json.JSONDecodeError, KeyError, TypeError) as e:
+            logger.warning(f"Failed to read session from {self.session_path}: {e}")
+            return SessionState()
+
+        for item in items:
+            try:
+                config = self.config_service.config_from_dict(item["config"])
+                state.jobs.append(QueuedJob(**{**item, "config": config}))
+            except (KeyError, TypeError) as e:
+                logger.warning(f"Skipping invalid session job: {e}")
+        return state
@@ -0,0 +1,94 @@
+"""Export and import of all GUI settings as a single bundle file."""
+
+import dataclasses
+import json
+import logging
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+
+logger = logging.getLogger(__name__)
+
+# Version of the bundle format written by export_config
+BUNDLE_VERSION = 1
+
+
+def export_config(
+    path: Path,
+    config_service: ConfigService,
+    profile_service: ProfileService,
+) -> None:
+    """Write the current settings and profiles to a bundle file.
+
+    Args:
+        path: Destination JSON file.
+        config_service: Service providing the current settings.
+        profile_service: Service providing the saved profiles.
+    """
+    bundle = {
+        "version": BUNDLE_VERSION,
+        "exported_at": datetime.now().isoformat(timespec="seconds"),
+        "settings": dataclasses.asdict(config_service.load_config()),
+        "profiles": profile_service.load_profiles(),
+    }
+
+    path.parent.mkdir(parents=True, exist_ok=True)
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(bundle, f, indent=2, ensure_ascii=False)
+
+    logger.info(f"Settings exported to {path}")
+
+
+def import_config(
+    path: Path,
+    config_service: ConfigService,
+    profile_service: ProfileService,
+) -> DownloadConfig:
+    """Restore settings and profiles from a bundle file.
+
+    Imported profiles are merged into the existing ones, replacing profiles
+    with the same name.
+
+    Args:
+        path: Bundle file written by export_config.
+        config_service: Service receiving the imported settings.
+        profile_service: Service receiving the imported profiles.
+
+    Returns:
+        The imported (validated) settings.
+
+    Raises:
+        FileNotFoundError: If the bundle file doesn't exist.
+        ValueError: If the file is not a valid settings bundle.
+    """
+    if not path.exists():
+        raise FileNotFoundError(f"Settings bundle not found: {path}")
+
+    try:
+        with open(path, encoding="utf-8") as f:
+            bundle = json.load(f)
+    except json.JSONDecodeError as e:
+        raise ValueError(f"Invalid settings bundle: {e}") from e
+
+    if not isinstance(bundle, dict) or not isinstance(bundle.get("settings"), dict):
+        raise ValueError("Invalid settings bundle: missing settings")
+
+    version = bundle.get("version", 0)
+    if not isinstance(version, int) or version > BUNDLE_VERSION:
+        raise ValueError(f"Unsupported settings bundle version: {version}")
+
+    config = config_service.config_from_dict(bundle["settings"])
+    config_service.save_config(config)
+
+    imported_profiles = bundle.get("profiles", {})
+    if isinstance(imported_profiles, dict):
+        profiles = profile_service.load_profiles()
+        profiles.update(
+            {name: values for name, values in imported_profiles.items() if isinstance(values, dict)}
+        )
+        profile_service.save_profiles(profiles)
+
+    logger.info(f"Settings imported from {path}")
+    return config
@@ -0,0 +1,197 @@
+"""Sounds played when downloads finish, with quiet hours."""
+
+import dataclasses
+import json
+import logging
+import shutil
+import subprocess
+import sys
+from collections.abc import Callable
+from dataclasses import dataclass
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.bandwidth import TIME_FORMAT, parse_time
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir
+
+logger = logging.getLogger(__name__)
+
+# Events a sound can be played for
+SOUND_COMPLETED = "completed"
+SOUND_FAILED = "failed"
+
+# Command line players tried in order on Linux and other Unix systems
+UNIX_PLAYERS = ("paplay", "aplay", "afplay")
+
+
+@dataclass
+class SoundSettings:
+    """Configured sounds.
+
+    Attributes:
+        enabled: Whether sounds are played at all.
+        completed_sound: Optional sound file for finished downloads. The
+            system bell is used if not set.
+        failed_sound: Optional sound file for failed downloads. The system
+            bell is used if not set.
+        quiet_start: Optional start of the quiet hours as "HH:MM".
+        quiet_end: Optional end of the quiet hours as "HH:MM", excluded.
+            Quiet hours ending before they start span midnight.
+    """
+
+    enabled: bool = True
+    completed_sound: str | None = None
+    failed_sound: str | None = None
+    quiet_start: str | None = None
+    quiet_end: str | None = None
+
+    def is_quiet(self, now: datetime) -> bool:
+        """Check whether a time falls into the quiet hours.
+
+        Args:
+            now: Time to check.
+
+        Returns:
+            True if quiet hours are set and contain the time.
+        """
+        if not self.quiet_start or not self.quiet_end:
+            return False
+
+        start, end = parse_time(self.quiet_start), parse_time(self.quiet_end)
+        moment = now.time()
+        if start <= end:
+            return start <= moment < end
+        return moment >= start or moment < end
+
+
+def play_sound_file(path: Path) -> bool:
+    """Play a sound file without waiting for it to end.
+
+    Uses winsound on Windows and the first command line player found
+    elsewhere.
+
+    Args:
+        path: Sound file, WAV works everywhere.
+
+    Returns:
+        True if playback started, False if no player is available.
+    """
+    if sys.platform == "win32":
+        import winsound
+
+        winsound.PlaySound(str(path), winsound.SND_FILENAME | winsound.SND_ASYNC)
+        return True
+
+    for name in UNIX_PLAYERS:
+        player = shutil.which(name)
+        if player:
+            subprocess.Popen(
+                [player, str(path)], stdout=subprocess.DEVNULL, stderr=subprocess.DEVNULL
+            )
+            return True
+    return False
+
+
+class SoundService:
+    """Service playing the configured sound for download events.
+
+    During the quiet hours nothing is played; the events are still stored
+    in the notification center by the caller.
+    """
+
+    def __init__(
+        self,
+        bell: Callable[[], None] | None = None,
+        sounds_path: Path | None = None,
+        player: Callable[[Path], bool] = play_sound_file,
+    ) -> None:
+        """Initialize sound service.
+
+        Args:
+            bell: Optional function ringing the system bell, used when no sound
+                file is set or it can't be played.
+            sounds_path: Optional custom path to the sounds file.
+                         If not provided, uses sounds.json in the settings directory.
+            player: Function playing a sound file.
+        """
+        self.bell = bell
+        self.sounds_path = sounds_path or get_app_config_dir() / "sounds.json"
+        self.player = player
+        logger.debug(f"SoundService initialized with path: {self.sounds_path}")
+
+    def load(self) -> SoundSettings:
+        """Read the configured sounds.
+
+        Returns:
+            Configured sounds. Defaults if the file doesn't exist or is invalid.
+        """
+        if not self.sounds_path.exists():
+            return SoundSettings()
+
+        try:
+            with open(self.sounds_path, encoding="utf-8") as f:
+                return SoundSettings(**json.load(f))
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read sounds from {self.sounds_path}: {e}")
+            return SoundSettings()
+
+    def save(self, settings: SoundSettings) -> SoundSettings:
+        """Write the sounds to file.
+
+        Args:
+            settings: Sounds to store.
+
+        Returns:
+            The stored sounds, with quiet hours normalized to "HH:MM".
+
+        Raises:
+            ValueError: If only one end of the quiet hours is set, they start
+                and end at the same time, or a time is invalid.
+        """
+        if bool(settings.quiet_start) != bool(settings.quiet_end):
+            raise ValueError("Quiet hours need both a start and an end time")
+        if settings.quiet_start and settings.quiet_end:
+            start = parse_time(settings.quiet_start).strftime(TIME_FORMAT)
+            end = parse_time(settings.quiet_end).strftime(TIME_FORMAT)
+            if start == end:
+                raise ValueError("Quiet hours can't start and end at the same time")
+            settings = dataclasses.replace(settings, quiet_start=start, quiet_end=end)
+
+        self.sounds_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.sounds_path, "w", encoding="utf-8") as f:
+            json.dump(dataclasses.asdict(settings), f, indent=2)
+        logger.info(f"Sounds saved: {settings}")
+        return settings
+
+    def play(self, event: str, now: datetime | None = None) -> bool:
+        """Play the sound for an event unless sounds are off or it's quiet hours.
+
+        Args:
+            event: SOUND_COMPLETED or SOUND_FAILED.
+            now: Optional time to check. If not provided, uses the current time.
+
+        Returns:
+            True if a sound was played.
+        """
+        settings = self.load()
+        if not settings.enabled:
+            return False
+        try:
+            if settings.is_quiet(now or datetime.now()):
+                logger.debug(f"Quiet hours, no sound for {event}")
+                return False
+        except ValueError as e:
+            logger.warning(f"Ignoring invalid quiet hours: {e}")
+
+        sound = settings.completed_sound if event == SOUND_COMPLETED else settings.failed_sound
+        if sound:
+            try:
+                if self.player(Path(sound)):
+                    return True
+            except (OSError, RuntimeError) as e:
+                logger.warning(f"Failed to play {sound}: {e}")
+
+        if self.bell is None:
+            return False
+        self.bell()
+        return True
@@ -0,0 +1,242 @@
+"""Per-source defaults chosen by URL pattern."""
+
+import dataclasses
+import fnmatch
+import json
+import logging
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+from resource_fetcher_core.utils.title_filter import REGEX_PREFIX
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig, get_app_config_dir
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class SourceRule:
+    """Defaults for every URL matching a pattern.
+
+    Attributes:
+        pattern: URL glob ("*izanmei.cc/album/*"), or a regular expression
+            searched in the URL when prefixed with "re:". Case-insensitive.
+        output_dir: Optional output directory for matching URLs.
+        profile: Optional profile whose settings matching URLs are downloaded with.
+        overrides: Settings merged over the profile or current settings,
+            e.g. {"delay": 2.0, "retries": 5} for a source that needs politeness.
+    """
+
+    pattern: str
+    output_dir: str | None = None
+    profile: str | None = None
+    overrides: dict[str, Any] = field(default_factory=dict)
+
+    def matches(self, url: str) -> bool:
+        """Check whether a URL matches the rule's pattern.
+
+        Args:
+            url: URL to check.
+
+        Returns:
+            True if the URL matches.
+        """
+        if self.pattern.startswith(REGEX_PREFIX):
+            expression = self.pattern[len(REGEX_PREFIX) :]
+            return re.search(expression, url, re.IGNORECASE) is not None
+        return fnmatch.fnmatch(url.casefold(), self.pattern.casefold())
+
+
+def parse_overrides(text: str) -> dict[str, Any]:
+    """Parse settings written as "name=value" pairs separated by spaces.
+
+    Values are read as JSON where possible ("2" is a number, "true" a
+    boolean) and as text otherwise.
+
+    Args:
+        text: Settings, e.g. "delay=2 retries=5".
+
+    Returns:
+        Mapping of setting name to value.
+
+    Raises:
+        ValueError: If a pair has no "=" or names an unknown setting.
+    """
+    overrides: dict[str, Any] = {}
+    for pair in text.split():
+        name, separator, value = pair.partition("=")
+        if not separator:
+            raise ValueError(f"Invalid setting {pair!r}, expected name=value")
+        if name not in DownloadConfig.__dataclass_fields__:
+            raise ValueError(f"Unknown setting: {name}")
+        try:
+            overrides[name] = json.loads(value)
+        except json.JSONDecodeError:
+            overrides[name] = value
+    return overrides
+
+
+class SourceRuleService:
+    """Service mapping sources to their own output directory and profile.
+
+    Rules are tried in order; the first matching rule applies. They are
+    resolved when a job is enqueued and take precedence over the settings
+    the job was enqueued with.
+    """
+
+    def __init__(
+        self,
+        rules_path: Path | None = None,
+        profile_service: ProfileService | None = None,
+    ) -> None:
+        """Initialize source rule service.
+
+        Args:
+            rules_path: Optional custom path to the rules file.
+                        If not provided, uses source_rules.json in the settings directory.
+            profile_service: Optional service providing the profiles rules refer to.
+        """
+        self.rules_path = rules_path or get_app_config_dir() / "source_rules.json"
+        self.profile_service = profile_service or ProfileService()
+        logger.debug(f"SourceRuleService initialized with path: {self.rules_path}")
+
+    def load(self) -> list[SourceRule]:
+        """Read the rules from file.
+
+        Returns:
+            Rules in the order they are tried. Empty if the file doesn't exist
+            or is invalid.
+        """
+        if not self.rules_path.exists():
+            return []
+
+        try:
+            with open(self.rules_path, encoding="utf-8") as f:
+                return [SourceRule(**item) for item in json.load(f)]
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read source rules from {self.rules_path}: {e}")
+            return []
+
+    def save(self, rules: list[SourceRule]) -> None:
+        """Write the rules to file.
+
+        Args:
+            rules: Rules in the order they are tried.
+        """
+        self.rules_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.rules_path, "w", encoding="utf-8") as f:
+            json.dump([dataclasses.asdict(rule) for rule in rules], f, indent=2, ensure_ascii=False)
+
+    def add_rule(
+        self,
+        pattern: str,
+        output_dir: str | None = None,
+        profile: str | None = None,
+        overrides: dict[str, Any] | None = None,
+    ) -> SourceRule:
+        """Add a rule, replacing any existing rule with the same pattern.
+
+        Args:
+            pattern: URL glob or "re:"-prefixed regular expression.
+            output_dir: Optional output directory for matching URLs.
+            profile: Optional profile for matching URLs.
+            overrides: Optional settings for matching URLs.
+
+        Returns:
+            The stored rule.
+
+        Raises:
+            ValueError: If the pattern is empty or invalid, a setting is unknown,
+                or the rule sets nothing.
+        """
+        pattern = pattern.strip()
+        if not pattern:
+            raise ValueError("Source pattern cannot be empty")
+        if pattern.startswith(REGEX_PREFIX):
+            try:
+                re.compile(pattern[len(REGEX_PREFIX) :])
+            except re.error as e:
+                raise ValueError(f"Invalid source pattern: {pattern!r} ({e})") from e
+        unknown = [
+            name for name in overrides or {} if name not in DownloadConfig.__dataclass_fields__
+        ]
+        if unknown:
+            raise ValueError(f"Unknown settings: {', '.join(unknown)}")
+        if not output_dir and not profile and not overrides:
+            raise ValueError("A source rule needs an output directory, a profile or settings")
+
+        rule = SourceRule(pattern, output_dir or None, profile or None, dict(overrides or {}))
+        rules = [item for item in self.load() if item.pattern != pattern]
+        rules.append(rule)
+        self.save(rules)
+        logger.info(f"Source rule saved: {pattern}")
+        return rule
+
+    def remove_rule(self, pattern: str) -> bool:
+        """Remove the rule with a pattern.
+
+        Args:
+            pattern: Pattern of the rule.
+
+        Returns:
+            True if the rule was removed, False if it didn't exist.
+        """
+        rules = self.load()
+        remaining = [rule for rule in rules if rule.pattern != pattern]
+        if len(remaining) == len(rules):
+            return False
+
+        self.save(remaining)
+        logger.info(f"Source rule removed: {pattern}")
+        return True
+
+    def match(self, url: str) -> SourceRule | None:
+        """Find the rule applying to a URL.
+
+        Args:
+            url: URL about to be downloaded.
+
+        Returns:
+            The first matching rule, or None.
+        """
+        for rule in self.load():
+            try:
+                if rule.matches(url):
+                    return rule
+            except re.error as e:
+                logger.warning(f"Ignoring invalid source rule {rule.pattern}: {e}")
+        return None
+
+    def resolve(self, url: str, config: DownloadConfig) -> DownloadConfig:
+        """Apply the matching rule to a job's configuration.
+
+        Args:
+            url: URL about to be downloaded.
+            config: Configuration the job was enqueued with.
+
+        Returns:
+            The profile's configuration if the rule names one (otherwise the
+            given one) with the rule's settings and output directory merged
+            over it; the given configuration if no rule matches.
+        """
+        rule = self.match(url)
+        if rule is None:
+            return config
+
+        if rule.profile:
+            try:
+                config = self.profile_service.apply_profile(rule.profile)
+            except ValueError as e:
+                logger.warning(f"Source rule {rule.pattern}: {e}")
+        if rule.overrides:
+            # Invalid values fall back to the defaults, as in the config file
+            config = self.profile_service.config_service.config_from_dict(
+                {**dataclasses.asdict(config), **rule.overrides}
+            )
+        if rule.output_dir:
+            config = dataclasses.replace(config, output_dir=rule.output_dir)
+
+        logger.info(f"Source rule {rule.pattern} applied to {url}")
+        return config
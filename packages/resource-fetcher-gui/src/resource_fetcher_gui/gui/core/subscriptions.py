@@ -0,0 +1,181 @@
+"""Followed artists and detection of their new releases."""
+
+import dataclasses
+import json
+import logging
+import subprocess
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import get_app_config_dir
+from resource_fetcher_gui.gui.core.download_history import normalize_url
+
+logger = logging.getLogger(__name__)
+
+# Time between checks of all followed artists (seconds)
+CHECK_INTERVAL = 6 * 60 * 60
+
+
+@dataclass
+class Subscription:
+    """A followed artist page.
+
+    Attributes:
+        url: Artist page URL.
+        known_albums: Album URLs already seen on the artist page.
+        auto_download: Enqueue new albums for download as soon as they are found.
+        template: Job template used for automatic downloads, empty for the
+            current settings.
+    """
+
+    url: str
+    known_albums: list[str] = field(default_factory=list)
+    auto_download: bool = False
+    template: str = ""
+
+
+@dataclass
+class NewRelease:
+    """Albums that appeared on a followed artist page since the last check.
+
+    Attributes:
+        subscription: Subscription the albums were found for.
+        album_urls: URLs of the new albums in page order.
+    """
+
+    subscription: Subscription
+    album_urls: list[str]
+
+
+class SubscriptionService:
+    """Persistent list of followed artists in a JSON file."""
+
+    def __init__(self, subscriptions_path: Path | None = None) -> None:
+        """Initialize subscription service.
+
+        Args:
+            subscriptions_path: Optional custom path to the subscriptions file.
+                If not provided, uses subscriptions.json in the settings directory.
+        """
+        self.subscriptions_path = subscriptions_path or get_app_config_dir() / "subscriptions.json"
+        logger.debug(f"SubscriptionService initialized with path: {self.subscriptions_path}")
+
+    def load(self) -> list[Subscription]:
+        """Load all subscriptions.
+
+        Returns:
+            Subscriptions in the order they were added. Empty if the file
+            doesn't exist or is invalid.
+        """
+        if not self.subscriptions_path.exists():
+            return []
+
+        try:
+            with open(self.subscriptions_path, encoding="utf-8") as f:
+                data = json.load(f)
+            return [Subscription(**item) for item in data]
+        except (OSError, json.JSONDecodeError, TypeError) as e:
+            logger.warning(f"Failed to read subscriptions from {self.subscriptions_path}: {e}")
+            return []
+
+    def save(self, subscriptions: list[Subscription]) -> None:
+        """Write all subscriptions to file.
+
+        Args:
+            subscriptions: Subscriptions to store.
+        """
+        self.subscriptions_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(self.subscriptions_path, "w", encoding="utf-8") as f:
+            json.dump(
+                [dataclasses.asdict(item) for item in subscriptions],
+                f,
+                indent=2,
+                ensure_ascii=False,
+            )
+
+    def follow(
+        self,
+        url: str,
+        known_albums: list[str],
+        auto_download: bool = False,
+        template: str = "",
+    ) -> Subscription:
+        """Follow an artist page, replacing an existing subscription to it.
+
+        Args:
+            url: Artist page URL.
+            known_albums: Albums currently on the page, not reported as new.
+            auto_download: Enqueue new albums for download automatically.
+            template: Job template for automatic downloads, empty for the
+                current settings.
+
+        Returns:
+            The stored subscription.
+        """
+        subscription = Subscription(
+            url=url.strip(),
+            known_albums=list(known_albums),
+            auto_download=auto_download,
+            template=template,
+        )
+        subscriptions = [
+            item for item in self.load() if normalize_url(item.url) != normalize_url(url)
+        ]
+        subscriptions.append(subscription)
+        self.save(subscriptions)
+        logger.info(f"Following {url} ({len(known_albums)} known albums)")
+        return subscription
+
+    def unfollow(self, url: str) -> bool:
+        """Stop following an artist page.
+
+        Args:
+            url: Artist page URL.
+
+        Returns:
+            True if a subscription was removed, False if there was none.
+        """
+        subscriptions = self.load()
+        remaining = [
+            item for item in subscriptions if normalize_url(item.url) != normalize_url(url)
+        ]
+        if len(remaining) == len(subscriptions):
+            return False
+
+        self.save(remaining)
+        logger.info(f"Unfollowed {url}")
+        return True
+
+    def check(self, list_albums: Callable[[str], list[str]]) -> list[NewRelease]:
+        """Look for new albums on all followed artist pages.
+
+        New albums are remembered, so each one is reported only once.
+        Artists whose page can't be listed are skipped until the next check.
+
+        Args:
+            list_albums: Function returning the album URLs of an artist page,
+                e.g. CLIWrapper.list_albums.
+
+        Returns:
+            New releases, one per artist with new albums.
+        """
+        subscriptions = self.load()
+        releases = []
+        for subscription in subscriptions:
+            try:
+                albums = list_albums(subscription.url)
+            except (OSError, ValueError, subprocess.TimeoutExpired) as e:
+                logger.warning(f"Failed to check {subscription.url}: {e}")
+                continue
+
+            known = {normalize_url(album) for album in subscription.known_albums}
+            new_albums = [album for album in albums if normalize_url(album) not in known]
+            if new_albums:
+                logger.info(f"{len(new_albums)} new albums on {subscription.url}")
+                subscription.known_albums.extend(new_albums)
+                releases.append(NewRelease(subscription, new_albums))
+
+        if releases:
+            self.save(subscriptions)
+        return releases
@@ -0,0 +1,82 @@
+"""Coalescing of high-frequency progress updates."""
+
+import logging
+import threading
+import time
+from collections.abc import Callable
+from typing import Generic, TypeVar
+
+logger = logging.getLogger(__name__)
+
+T = TypeVar("T")
+
+# Default maximum number of updates passed on per second
+DEFAULT_MAX_RATE = 10.0
+
+
+class ProgressThrottle(Generic[T]):
+    """Passes on at most max_rate updates per second, latest value wins.
+
+    Updates arriving faster are coalesced: only the most recent one is
+    delivered once the interval has passed, so the final state is never
+    lost. Safe to call from any thread; the callback runs either in the
+    caller's thread or in a timer thread.
+    """
+
+    def __init__(self, callback: Callable[[T], None], max_rate: float = DEFAULT_MAX_RATE) -> None:
+        """Initialize progress throttle.
+
+        Args:
+            callback: Function receiving the coalesced updates.
+            max_rate: Maximum number of updates per second.
+
+        Raises:
+            ValueError: If max_rate is not positive.
+        """
+        if max_rate <= 0:
+            raise ValueError(f"max_rate must be positive: {max_rate}")
+
+        self.callback = callback
+        self.interval = 1.0 / max_rate
+        self.coalesced = 0
+        self._lock = threading.Lock()
+        self._emit_lock = threading.Lock()
+        self._last_emit = float("-inf")
+        self._pending: tuple[T] | None = None
+        self._timer: threading.Timer | None = None
+
+    def __call__(self, value: T) -> None:
+        """Submit an update.
+
+        Args:
+            value: The latest progress value.
+        """
+        with self._lock:
+            if self._pending is not None:
+                self.coalesced += 1
+            self._pending = (value,)
+            if self._timer is not None:
+                return
+            wait = self._last_emit + self.interval - time.monotonic()
+            if wait > 0:
+                self._timer = threading.Timer(wait, self.flush)
+                self._timer.daemon = True
+                self._timer.start()
+                return
+
+        self.flush()
+
+    def flush(self) -> None:
+        """Deliver the pending update right away, if there is one."""
+        # Deliveries are serialized so an older value can't overtake a newer one
+        with self._emit_lock:
+            with self._lock:
+                if self._timer is not None:
+                    self._timer.cancel()
+                    self._timer = None
+                pending, self._pending = self._pending, None
+                if pending is None:
+                    return
+                self._last_emit = time.monotonic()
+
+            self.callback(pending[0])
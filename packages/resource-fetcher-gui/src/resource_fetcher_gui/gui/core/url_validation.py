@@ -0,0 +1,105 @@
+"""Instant checks of pasted URLs before a download starts."""
+
+import fnmatch
+import logging
+from dataclasses import dataclass
+from urllib.parse import urlsplit
+
+from resource_fetcher_core.adapters.registry import get_adapter, list_supported_sites
+from resource_fetcher_core.utils.http import clean_url
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class SupportedSource:
+    """A site supported by the installed CLI.
+
+    Attributes:
+        name: Site name.
+        url_patterns: Globs of the URLs the CLI accepts for the site.
+        capabilities: What the CLI can download from the site (album, artist).
+    """
+
+    name: str
+    url_patterns: list[str]
+    capabilities: list[str]
+
+    def matches(self, url: str) -> bool:
+        """Check whether a URL belongs to the site.
+
+        Args:
+            url: URL to check.
+
+        Returns:
+            True if any of the site's patterns matches, ignoring case.
+        """
+        return any(
+            fnmatch.fnmatch(url.casefold(), pattern.casefold()) for pattern in self.url_patterns
+        )
+
+
+@dataclass
+class UrlValidation:
+    """Result of checking a URL.
+
+    Attributes:
+        url: Normalized URL, or the stripped input if it isn't valid.
+        valid: Whether the input is a well-formed http(s) URL.
+        source: Name of the supported site, or None if the site isn't supported.
+        kind: What the URL points to (album, song, playlist, artist), if known.
+        error: Why the URL is invalid or unsupported.
+    """
+
+    url: str
+    valid: bool
+    source: str | None = None
+    kind: str | None = None
+    error: str = ""
+
+    @property
+    def supported(self) -> bool:
+        """Whether the URL belongs to a supported site."""
+        return self.source is not None
+
+
+def validate_url(url: str, sources: list[SupportedSource] | None = None) -> UrlValidation:
+    """Check a URL without any network access.
+
+    Well-formed URLs of supported sites are normalized: tracking parameters
+    and fragments are removed and the site's canonical host is used.
+
+    Args:
+        url: URL as entered or pasted by the user.
+        sources: Sites supported by the installed CLI. If not provided, the
+            site adapters bundled with the GUI decide.
+
+    Returns:
+        Validation result.
+    """
+    url = url.strip()
+    if not url:
+        return UrlValidation(url, False, error="URL cannot be empty")
+
+    parts = urlsplit(url)
+    if parts.scheme.lower() not in ("http", "https"):
+        return UrlValidation(url, False, error="URL must start with http:// or https://")
+    if not parts.netloc:
+        return UrlValidation(url, False, error="URL has no host name")
+
+    adapter = get_adapter(url)
+    if sources is None:
+        source = adapter.name if adapter else None
+        names = list_supported_sites()
+    else:
+        source = next((item.name for item in sources if item.matches(url)), None)
+        names = [item.name for item in sources]
+    if source is None:
+        sites = ", ".join(names)
+        return UrlValidation(url, True, error=f"Not a supported site (supported: {sites})")
+
+    # The CLI may support sites the bundled adapters don't know yet
+    normalized = adapter.canonicalize_url(url) if adapter else clean_url(url)
+    kind = adapter.content_kind(normalized) if adapter else None
+    logger.debug(f"Validated URL {normalized}: {source} {kind or 'unknown'}")
+    return UrlValidation(normalized, True, source, kind)
@@ -0,0 +1,39 @@
+"""Detection of output volumes that disappear, e.g. ejected USB drives."""
+
+import os
+from pathlib import Path
+
+# How often a lost volume is checked for its return (seconds)
+VOLUME_CHECK_INTERVAL = 5
+
+
+def volume_root(path: Path) -> Path:
+    """Find the mount point of the volume a path is on.
+
+    Missing folders are skipped, so it also works for output folders the
+    CLI hasn't created yet.
+
+    Args:
+        path: File or folder, which doesn't need to exist.
+
+    Returns:
+        The volume's mount point, e.g. "/media/user/USB" or "E:\\".
+    """
+    path = Path(os.path.abspath(path))
+    while not path.exists() and path.parent != path:
+        path = path.parent
+    while not os.path.ismount(path) and path.parent != path:
+        path = path.parent
+    return path
+
+
+def volume_available(root: Path) -> bool:
+    """Check whether a volume is mounted.
+
+    Args:
+        root: Mount point returned by volume_root.
+
+    Returns:
+        True if the mount point exists and still has a volume mounted.
+    """
+    return root.exists() and os.path.ismount(root)
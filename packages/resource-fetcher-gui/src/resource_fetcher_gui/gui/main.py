@@ -1,28 +1,172 @@
 """Main entry point for GUI application."""
 
+import argparse
+import json
 import logging
 import sys
+from pathlib import Path
 
-# Setup logging
-logging.basicConfig(
-    level=logging.INFO,
-    format="%(asctime)s - %(name)s - %(levelname)s - %(message)s",
-    handlers=[
-        logging.FileHandler("gui.log", encoding="utf-8"),
-        logging.StreamHandler(),
-    ],
+from resource_fetcher_gui.gui.core.config_service import (
+    get_log_dir,
+    is_portable,
+    migrate_legacy_app_dir,
+    set_portable,
 )
+from resource_fetcher_gui.gui.core.migrations import MigrationRunner
+
+# --conformance value testing the simulated CLI
+MOCK_CLI = "mock"
+
 logger = logging.getLogger(__name__)
 
 
-def main() -> int:
+def setup_logging() -> None:
+    """Log to the console and to gui.log in the log directory."""
+    log_dir = get_log_dir()
+    log_dir.mkdir(parents=True, exist_ok=True)
+    logging.basicConfig(
+        level=logging.INFO,
+        format="%(asctime)s - %(name)s - %(levelname)s - %(message)s",
+        handlers=[
+            logging.FileHandler(log_dir / "gui.log", encoding="utf-8"),
+            logging.StreamHandler(),
+        ],
+    )
+
+
+def parse_args(argv: list[str] | None = None) -> argparse.Namespace:
+    """Parse GUI command line arguments.
+
+    Args:
+        argv: Arguments to parse (default: sys.argv[1:]).
+
+    Returns:
+        Parsed arguments.
+    """
+    parser = argparse.ArgumentParser(
+        prog="resource-fetcher-gui",
+        description="Resource Fetcher GUI",
+    )
+    parser.add_argument(
+        "--url",
+        help="Album URL to open on startup",
+    )
+    parser.add_argument(
+        "--output",
+        metavar="DIR",
+        help="Output directory for the download opened with --url",
+    )
+    parser.add_argument(
+        "--autostart",
+        action="store_true",
+        help="Start downloading the --url immediately",
+    )
+    parser.add_argument(
+        "--mock",
+        action="store_true",
+        help="Use a simulated CLI backend (for development without the CLI build)",
+    )
+    parser.add_argument(
+        "--mock-delay",
+        type=float,
+        default=0.5,
+        metavar="SECONDS",
+        help="Seconds spent on each simulated track in mock mode (default: 0.5)",
+    )
+    parser.add_argument(
+        "--portable",
+        action="store_true",
+        help="Keep settings, history and logs beside the executable (or create portable.flag)",
+    )
+    parser.add_argument(
+        "--backup",
+        metavar="FILE",
+        help="Write settings, history, subscriptions and profiles to a backup file and exit",
+    )
+    parser.add_argument(
+        "--restore",
+        metavar="FILE",
+        help="Restore the state saved with --backup and exit",
+    )
+    # Developer and custom CLI build check: print a JSON conformance report and exit
+    parser.add_argument("--conformance", metavar="CLI", help=argparse.SUPPRESS)
+    args = parser.parse_args(argv)
+    if (args.output or args.autostart) and not args.url:
+        parser.error("--output and --autostart require --url")
+    if args.backup and args.restore:
+        parser.error("--backup and --restore can't be used together")
+    return args
+
+
+def run_conformance(cli: str) -> int:
+    """Check a CLI build against the output protocol and print the report as JSON.
+
+    Args:
+        cli: Path of the CLI executable, or "mock" for the simulated CLI.
+
+    Returns:
+        Exit code (0 if the CLI conforms, 1 otherwise).
+    """
+    from resource_fetcher_gui.gui.core.conformance import run_protocol_conformance
+
+    report = run_protocol_conformance(None if cli == MOCK_CLI else Path(cli))
+    print(json.dumps(report.to_dict(), indent=2, ensure_ascii=False))
+    return 0 if report.passed else 1
+
+
+def run_backup(backup: str | None, restore: str | None) -> int:
+    """Write or restore a backup of the application state and print the result.
+
+    Args:
+        backup: Optional backup file to write.
+        restore: Optional backup file to restore.
+
+    Returns:
+        Exit code (0 on success, 1 otherwise).
+    """
+    from resource_fetcher_gui.gui.core.backup import create_backup, restore_backup
+
+    try:
+        if backup:
+            info = create_backup(Path(backup))
+            print(f"Backed up {len(info.files)} files to {backup}")
+        else:
+            info = restore_backup(Path(str(restore)))
+            print(f"Restored {len(info.files)} files from {restore}")
+    except (OSError, ValueError) as e:
+        print(f"Error: {e}")
+        return 1
+
+    for name, path in info.credentials.items():
+        print(f"Note: {name} refers to {path}, which isn't part of the backup")
+    return 0
+
+
+def main(argv: list[str] | None = None) -> int:
     """Main entry point for GUI application.
 
+    Args:
+        argv: Command line arguments (default: sys.argv[1:]).
+
     Returns:
         Exit code (0 for success, non-zero for error).
     """
+    args = parse_args(argv)
+    if args.portable:
+        set_portable(True)
+    setup_logging()
+    if args.conformance:
+        return run_conformance(args.conformance)
+    if args.backup or args.restore:
+        migrate_legacy_app_dir()
+        return run_backup(args.backup, args.restore)
+
     try:
         logger.info("Starting Resource Fetcher GUI...")
+        if is_portable():
+            logger.info("Running in portable mode")
+        migrate_legacy_app_dir()
+        migrations = MigrationRunner().run()
 
         # Check if ttkbootstrap is available
         try:
@@ -42,7 +186,12 @@ def main() -> int:
         logger.info("GUI widgets imported successfully")
 
         # Create and run main window
-        app = MainWindow(theme="cosmo")
+        app = MainWindow(theme="cosmo", mock=args.mock, mock_delay=args.mock_delay)
+        if migrations:
+            app.after_idle(app.report_migrations, migrations)
+        if args.url:
+            # Wait until the window is ready before starting the download
+            app.after_idle(app.open_url, args.url, args.output, args.autostart)
         app.run()
 
         return 0
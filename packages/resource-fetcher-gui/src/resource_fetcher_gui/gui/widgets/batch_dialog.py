@@ -0,0 +1,102 @@
+"""Batch download dialog for GUI."""
+
+import logging
+import tkinter as tk
+from collections.abc import Callable
+from tkinter import scrolledtext, ttk
+
+from resource_fetcher_gui.gui.core.batch import POLICY_PARALLEL, POLICY_SEQUENTIAL
+
+logger = logging.getLogger(__name__)
+
+
+class BatchDialog(tk.Toplevel):
+    """Dialog for entering many album URLs and a batch policy."""
+
+    def __init__(
+        self,
+        master: tk.Misc,
+        on_start: Callable[[list[str], str, int], None],
+        urls: list[str] | None = None,
+    ) -> None:
+        """Initialize batch dialog.
+
+        Args:
+            master: Parent window.
+            on_start: Callback with the URLs, policy and maximum parallel
+                      downloads when the user starts the batch.
+            urls: Optional album URLs to prefill, e.g. imported from bookmarks.
+        """
+        super().__init__(master)
+        self.title("Batch Download")
+        self.geometry("600x400")
+        self.transient(master)
+
+        self.on_start = on_start
+
+        self._create_widgets()
+        if urls:
+            self.urls_text.insert("1.0", "\n".join(urls))
+        logger.debug("BatchDialog initialized")
+
+    def _create_widgets(self) -> None:
+        """Create batch dialog widgets."""
+        container = ttk.Frame(self, padding=10)
+        container.pack(fill=tk.BOTH, expand=True)
+
+        ttk.Label(container, text="Album URLs (one per line):").pack(anchor=tk.W)
+        self.urls_text = scrolledtext.ScrolledText(container, height=12, wrap=tk.NONE)
+        self.urls_text.pack(fill=tk.BOTH, expand=True, pady=(5, 10))
+
+        options_frame = ttk.Frame(container)
+        options_frame.pack(fill=tk.X)
+
+        ttk.Label(options_frame, text="Policy:").pack(side=tk.LEFT)
+        self.policy_var = tk.StringVar(value=POLICY_SEQUENTIAL)
+        ttk.Combobox(
+            options_frame,
+            textvariable=self.policy_var,
+            values=[POLICY_SEQUENTIAL, POLICY_PARALLEL],
+            state="readonly",
+            width=12,
+        ).pack(side=tk.LEFT, padx=(5, 15))
+
+        ttk.Label(options_frame, text="Parallel:").pack(side=tk.LEFT)
+        self.parallel_var = tk.IntVar(value=2)
+        ttk.Spinbox(
+            options_frame,
+            from_=1,
+            to=8,
+            textvariable=self.parallel_var,
+            width=5,
+        ).pack(side=tk.LEFT, padx=(5, 0))
+
+        ttk.Button(
+            options_frame,
+            text="Start",
+            command=self._on_start_clicked,
+            width=10,
+        ).pack(side=tk.RIGHT)
+
+    def get_urls(self) -> list[str]:
+        """Get the entered URLs.
+
+        Returns:
+            Non-empty lines of the URL text, without surrounding whitespace.
+        """
+        lines = self.urls_text.get("1.0", tk.END).splitlines()
+        return [line.strip() for line in lines if line.strip()]
+
+    def _on_start_clicked(self) -> None:
+        """Start the batch and close the dialog."""
+        urls = self.get_urls()
+        if not urls:
+            return
+
+        try:
+            max_parallel = int(self.parallel_var.get())
+        except (tk.TclError, ValueError):
+            max_parallel = 1
+
+        self.on_start(urls, self.policy_var.get(), max_parallel)
+        self.destroy()
@@ -6,7 +6,16 @@ from collections.abc import Callable
 from pathlib import Path
 from tkinter import ttk
 
-from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_core.utils.audio import MERGE_FORMATS
+
+from resource_fetcher_gui.gui.core.capabilities import Capabilities
+from resource_fetcher_gui.gui.core.config_service import (
+    CONFIRM_DEFAULTS,
+    LOG_LEVELS,
+    DownloadConfig,
+)
+from resource_fetcher_gui.gui.core.libraries import LibraryService
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
 
 logger = logging.getLogger(__name__)
 
@@ -18,16 +27,22 @@ class ConfigWidget(ttk.LabelFrame):
         self,
         master: tk.Widget,
         on_config_change: Callable[[DownloadConfig], None] | None = None,
+        profile_service: ProfileService | None = None,
+        library_service: LibraryService | None = None,
     ) -> None:
         """Initialize configuration widget.
 
         Args:
             master: Parent widget.
             on_config_change: Callback when configuration changes.
+            profile_service: Optional service for saving and applying profiles.
+            library_service: Optional service providing the libraries to choose from.
         """
         super().__init__(master, text="Configuration", padding=10)
 
         self.on_config_change = on_config_change
+        self.profile_service = profile_service
+        self.library_service = library_service
         self._config = DownloadConfig()
 
         self._create_widgets()
@@ -59,13 +74,20 @@ class ConfigWidget(ttk.LabelFrame):
         )
         browse_btn.pack(side=tk.LEFT)
 
-        # Limit
-        ttk.Label(left_frame, text="Download Limit (optional):").grid(
+        # Limit and track range
+        ttk.Label(left_frame, text="Download Limit / Track Range (optional):").grid(
             row=2, column=0, sticky=tk.W, pady=2
         )
+        selection_frame = ttk.Frame(left_frame)
+        selection_frame.grid(row=3, column=0, sticky=tk.W, pady=(0, 10))
+
         self.limit_var = tk.StringVar(value=str(self._config.limit) if self._config.limit else "")
-        limit_entry = ttk.Entry(left_frame, textvariable=self.limit_var, width=15)
-        limit_entry.grid(row=3, column=0, sticky=tk.W, pady=(0, 10))
+        limit_entry = ttk.Entry(selection_frame, textvariable=self.limit_var, width=10)
+        limit_entry.pack(side=tk.LEFT, padx=(0, 5))
+
+        # e.g. "5-12,15"
+        self.track_range_var = tk.StringVar(value=self._config.track_range or "")
+        ttk.Entry(selection_frame, textvariable=self.track_range_var, width=15).pack(side=tk.LEFT)
 
         # Timeout
         ttk.Label(left_frame, text="Timeout (seconds):").grid(row=4, column=0, sticky=tk.W, pady=2)
@@ -91,6 +113,131 @@ class ConfigWidget(ttk.LabelFrame):
         )
         retries_spinbox.grid(row=7, column=0, sticky=tk.W, pady=(0, 10))
 
+        # Time limits
+        ttk.Label(left_frame, text="Time Limits (job / track, seconds, optional):").grid(
+            row=8, column=0, sticky=tk.W, pady=2
+        )
+        limits_frame = ttk.Frame(left_frame)
+        limits_frame.grid(row=9, column=0, sticky=tk.W, pady=(0, 10))
+
+        self.job_timeout_var = tk.StringVar(value=self._format_optional(self._config.job_timeout))
+        ttk.Entry(limits_frame, textvariable=self.job_timeout_var, width=10).pack(
+            side=tk.LEFT, padx=(0, 5)
+        )
+        self.track_timeout_var = tk.StringVar(
+            value=self._format_optional(self._config.track_timeout)
+        )
+        ttk.Entry(limits_frame, textvariable=self.track_timeout_var, width=10).pack(side=tk.LEFT)
+
+        # Title blacklist
+        ttk.Label(left_frame, text="Skip Titles Containing (comma-separated):").grid(
+            row=10, column=0, sticky=tk.W, pady=2
+        )
+        self.skip_titles_var = tk.StringVar(value=", ".join(self._config.skip_titles))
+        ttk.Entry(left_frame, textvariable=self.skip_titles_var, width=30).grid(
+            row=11, column=0, sticky=tk.W, pady=(0, 10)
+        )
+
+        # Title patterns, globs or "re:" regular expressions
+        ttk.Label(left_frame, text="Include / Exclude Titles (glob or re:regex, optional):").grid(
+            row=12, column=0, sticky=tk.W, pady=2
+        )
+        patterns_frame = ttk.Frame(left_frame)
+        patterns_frame.grid(row=13, column=0, sticky=tk.W, pady=(0, 10))
+
+        self.include_var = tk.StringVar(value=self._config.include_pattern or "")
+        ttk.Entry(patterns_frame, textvariable=self.include_var, width=15).pack(
+            side=tk.LEFT, padx=(0, 5)
+        )
+        self.exclude_var = tk.StringVar(value=self._config.exclude_pattern or "")
+        ttk.Entry(patterns_frame, textvariable=self.exclude_var, width=15).pack(side=tk.LEFT)
+
+        # Browser cookies for sources that need a login
+        ttk.Label(left_frame, text="Cookies File (cookies.txt, optional):").grid(
+            row=14, column=0, sticky=tk.W, pady=2
+        )
+        cookies_frame = ttk.Frame(left_frame)
+        cookies_frame.grid(row=15, column=0, sticky=tk.EW, pady=(0, 10))
+
+        self.cookies_var = tk.StringVar(value=self._config.cookies_file or "")
+        ttk.Entry(cookies_frame, textvariable=self.cookies_var).pack(
+            side=tk.LEFT, fill=tk.X, expand=True, padx=(0, 5)
+        )
+        ttk.Button(
+            cookies_frame,
+            text="Browse...",
+            command=self._browse_cookies_file,
+            width=10,
+        ).pack(side=tk.LEFT)
+
+        # Scanner checking downloads before they leave quarantine
+        ttk.Label(left_frame, text="Scan Command (optional):").grid(
+            row=16, column=0, sticky=tk.W, pady=2
+        )
+        self.scan_command_var = tk.StringVar(value=self._config.scan_command or "")
+        self.scan_command_entry = ttk.Entry(left_frame, textvariable=self.scan_command_var)
+        self.scan_command_entry.grid(row=17, column=0, sticky=tk.EW, pady=(0, 10))
+
+        # Local folder for partial files, e.g. when the output is on a slow share
+        ttk.Label(left_frame, text="Temp Folder (optional):").grid(
+            row=18, column=0, sticky=tk.W, pady=2
+        )
+        temp_frame = ttk.Frame(left_frame)
+        temp_frame.grid(row=19, column=0, sticky=tk.EW, pady=(0, 10))
+
+        self.temp_dir_var = tk.StringVar(value=self._config.temp_dir or "")
+        ttk.Entry(temp_frame, textvariable=self.temp_dir_var).pack(
+            side=tk.LEFT, fill=tk.X, expand=True, padx=(0, 5)
+        )
+        ttk.Button(
+            temp_frame,
+            text="Browse...",
+            command=self._browse_temp_dir,
+            width=10,
+        ).pack(side=tk.LEFT)
+
+        # Library, replacing the output directory
+        self.library_var = tk.StringVar(value=self._config.library or "")
+        if self.library_service:
+            ttk.Label(left_frame, text="Library (empty for the active one):").grid(
+                row=20, column=0, sticky=tk.W, pady=2
+            )
+            self.library_combo = ttk.Combobox(
+                left_frame,
+                textvariable=self.library_var,
+                state="readonly",
+                width=20,
+                postcommand=self.refresh_libraries,
+            )
+            self.library_combo.grid(row=21, column=0, sticky=tk.W, pady=(0, 10))
+            self.refresh_libraries()
+
+        self.relax_politeness_var = tk.BooleanVar(value=self._config.relax_politeness)
+        self.relax_politeness_check = ttk.Checkbutton(
+            left_frame,
+            text="Ignore the source's recommended delay and hours",
+            variable=self.relax_politeness_var,
+        )
+        self.relax_politeness_check.grid(row=22, column=0, sticky=tk.W, pady=(0, 5))
+
+        sanity_frame = ttk.Frame(left_frame)
+        sanity_frame.grid(row=23, column=0, sticky=tk.W, pady=(0, 5))
+        ttk.Label(sanity_frame, text="Fail tracks under (bytes):").pack(side=tk.LEFT, padx=(0, 5))
+        self.min_track_size_var = tk.StringVar(
+            value=self._format_optional(self._config.min_track_size)
+        )
+        self.min_track_size_entry = ttk.Entry(
+            sanity_frame, textvariable=self.min_track_size_var, width=8
+        )
+        self.min_track_size_entry.pack(side=tk.LEFT)
+        self.detect_silence_var = tk.BooleanVar(value=self._config.detect_silence)
+        self.detect_silence_check = ttk.Checkbutton(
+            sanity_frame,
+            text="or silent (ffmpeg)",
+            variable=self.detect_silence_var,
+        )
+        self.detect_silence_check.pack(side=tk.LEFT, padx=5)
+
         # Delay
         ttk.Label(right_frame, text="Delay (seconds):").grid(row=0, column=0, sticky=tk.W, pady=2)
         self.delay_var = tk.DoubleVar(value=self._config.delay)
@@ -104,26 +251,123 @@ class ConfigWidget(ttk.LabelFrame):
         )
         delay_spinbox.grid(row=1, column=0, sticky=tk.W, pady=(0, 10))
 
+        workers_frame = ttk.Frame(right_frame)
+        workers_frame.grid(row=2, column=0, sticky=tk.W, pady=(0, 10))
+        ttk.Label(workers_frame, text="Parallel songs:").pack(side=tk.LEFT, padx=(0, 5))
+        self.workers_var = tk.IntVar(value=self._config.workers)
+        ttk.Spinbox(
+            workers_frame,
+            from_=1,
+            to=8,
+            textvariable=self.workers_var,
+            width=5,
+        ).pack(side=tk.LEFT)
+        ttk.Label(workers_frame, text="Max speed (KB/s):").pack(side=tk.LEFT, padx=(10, 5))
+        self.max_speed_var = tk.StringVar(value=self._format_optional(self._config.max_speed))
+        ttk.Entry(workers_frame, textvariable=self.max_speed_var, width=8).pack(side=tk.LEFT)
+
         # Checkboxes
+        existing_frame = ttk.Frame(right_frame)
+        existing_frame.grid(row=3, column=0, sticky=tk.W, pady=(0, 5))
         self.overwrite_var = tk.BooleanVar(value=self._config.overwrite)
-        overwrite_check = ttk.Checkbutton(
-            right_frame,
+        ttk.Checkbutton(
+            existing_frame,
             text="Overwrite existing files",
             variable=self.overwrite_var,
+        ).pack(side=tk.LEFT, padx=(0, 10))
+        self.sync_only_var = tk.BooleanVar(value=self._config.sync_only)
+        ttk.Checkbutton(
+            existing_frame,
+            text="Only new or incomplete tracks",
+            variable=self.sync_only_var,
+        ).pack(side=tk.LEFT)
+
+        log_level_frame = ttk.Frame(right_frame)
+        log_level_frame.grid(row=4, column=0, sticky=tk.W, pady=(0, 5))
+        ttk.Label(log_level_frame, text="Log Level:").pack(side=tk.LEFT, padx=(0, 5))
+        self.log_level_var = tk.StringVar(value=self._config.log_level)
+        ttk.Combobox(
+            log_level_frame,
+            textvariable=self.log_level_var,
+            values=LOG_LEVELS,
+            state="readonly",
+            width=10,
+        ).pack(side=tk.LEFT)
+
+        self.low_priority_var = tk.BooleanVar(value=self._config.low_priority)
+        low_priority_check = ttk.Checkbutton(
+            right_frame,
+            text="Run at low priority",
+            variable=self.low_priority_var,
         )
-        overwrite_check.grid(row=2, column=0, sticky=tk.W, pady=(0, 5))
+        low_priority_check.grid(row=5, column=0, sticky=tk.W, pady=(0, 5))
 
-        self.verbose_var = tk.BooleanVar(value=self._config.verbose)
-        verbose_check = ttk.Checkbutton(
+        self.normalize_var = tk.BooleanVar(value=self._config.normalize_loudness)
+        self.normalize_check = ttk.Checkbutton(
             right_frame,
-            text="Verbose output",
-            variable=self.verbose_var,
+            text="Normalize loudness (ffmpeg)",
+            variable=self.normalize_var,
         )
-        verbose_check.grid(row=3, column=0, sticky=tk.W, pady=(0, 10))
+        self.normalize_check.grid(row=6, column=0, sticky=tk.W, pady=(0, 5))
+
+        self.checksum_sidecars_var = tk.BooleanVar(value=self._config.checksum_sidecars)
+        self.checksum_sidecars_check = ttk.Checkbutton(
+            right_frame,
+            text="Write .sha256 file per track",
+            variable=self.checksum_sidecars_var,
+        )
+        self.checksum_sidecars_check.grid(row=7, column=0, sticky=tk.W, pady=(0, 5))
+
+        confirm_frame = ttk.Frame(right_frame)
+        confirm_frame.grid(row=8, column=0, sticky=tk.W, pady=(0, 5))
+        self.confirm_tracks_var = tk.BooleanVar(value=self._config.confirm_tracks)
+        self.confirm_tracks_check = ttk.Checkbutton(
+            confirm_frame,
+            text="Ask before each track, after",
+            variable=self.confirm_tracks_var,
+        )
+        self.confirm_tracks_check.pack(side=tk.LEFT)
+        self.confirm_timeout_var = tk.IntVar(value=self._config.confirm_timeout)
+        ttk.Spinbox(
+            confirm_frame, from_=5, to=3600, textvariable=self.confirm_timeout_var, width=5
+        ).pack(side=tk.LEFT, padx=5)
+        ttk.Label(confirm_frame, text="s:").pack(side=tk.LEFT, padx=(0, 5))
+        self.confirm_default_var = tk.StringVar(value=self._config.confirm_default)
+        ttk.Combobox(
+            confirm_frame,
+            textvariable=self.confirm_default_var,
+            values=CONFIRM_DEFAULTS,
+            state="readonly",
+            width=8,
+        ).pack(side=tk.LEFT)
+
+        merge_frame = ttk.Frame(right_frame)
+        merge_frame.grid(row=9, column=0, sticky=tk.W, pady=(0, 10))
+        ttk.Label(merge_frame, text="Merge into chaptered file:").pack(side=tk.LEFT, padx=(0, 5))
+        self.merge_format_var = tk.StringVar(value=self._config.merge_format or "")
+        self.merge_combo = ttk.Combobox(
+            merge_frame,
+            textvariable=self.merge_format_var,
+            values=["", *sorted(MERGE_FORMATS)],
+            state="readonly",
+            width=6,
+        )
+        self.merge_combo.pack(side=tk.LEFT)
+
+        names_frame = ttk.Frame(right_frame)
+        names_frame.grid(row=10, column=0, sticky=tk.W, pady=(0, 10))
+        ttk.Label(names_frame, text="File names: replace with").pack(side=tk.LEFT, padx=(0, 5))
+        self.replacement_var = tk.StringVar(value=self._config.filename_replacement)
+        ttk.Entry(names_frame, textvariable=self.replacement_var, width=3).pack(side=tk.LEFT)
+        ttk.Label(names_frame, text="max length").pack(side=tk.LEFT, padx=5)
+        self.max_name_length_var = tk.StringVar(
+            value=self._format_optional(self._config.max_filename_length)
+        )
+        ttk.Entry(names_frame, textvariable=self.max_name_length_var, width=5).pack(side=tk.LEFT)
 
         # Buttons
         btn_frame = ttk.Frame(right_frame)
-        btn_frame.grid(row=4, column=0, sticky=tk.W)
+        btn_frame.grid(row=11, column=0, sticky=tk.W)
 
         reset_btn = ttk.Button(btn_frame, text="Reset", command=self.reset, width=10)
         reset_btn.pack(side=tk.LEFT, padx=(0, 5))
@@ -136,6 +380,74 @@ class ConfigWidget(ttk.LabelFrame):
         )
         apply_btn.pack(side=tk.LEFT)
 
+        # Profiles
+        if self.profile_service:
+            profile_frame = ttk.Frame(right_frame)
+            profile_frame.grid(row=11, column=0, sticky=tk.W, pady=(10, 0))
+
+            ttk.Label(profile_frame, text="Profile:").pack(side=tk.LEFT, padx=(0, 5))
+            self.profile_combo = ttk.Combobox(
+                profile_frame,
+                values=self.profile_service.list_profiles(),
+                state="readonly",
+                width=15,
+            )
+            self.profile_combo.pack(side=tk.LEFT, padx=(0, 5))
+            self.profile_combo.bind("<<ComboboxSelected>>", self._on_profile_selected)
+
+            save_profile_btn = ttk.Button(
+                profile_frame,
+                text="Save As...",
+                command=self._save_profile,
+                width=10,
+            )
+            save_profile_btn.pack(side=tk.LEFT)
+
+    def _on_profile_selected(self, event: object) -> None:
+        """Apply the selected profile.
+
+        Args:
+            event: Event object (unused).
+        """
+        name = self.profile_combo.get()
+        if not name or not self.profile_service:
+            return
+
+        try:
+            self.set_config(self.profile_service.apply_profile(name))
+        except ValueError as e:
+            logger.warning(f"Failed to apply profile: {e}")
+            return
+
+        self._apply_config()
+        logger.info(f"Profile applied: {name}")
+
+    def _save_profile(self) -> None:
+        """Save the current configuration as a named profile."""
+        from tkinter import simpledialog
+
+        if not self.profile_service:
+            return
+
+        name = simpledialog.askstring("Save Profile", "Profile name:", parent=self)
+        if not name or not name.strip():
+            return
+
+        self.profile_service.save_profile(name, self.get_config())
+        self.refresh_profiles()
+        self.profile_combo.set(name.strip())
+
+    def refresh_profiles(self) -> None:
+        """Reload the list of profiles from the profile service."""
+        if self.profile_service:
+            self.profile_combo["values"] = self.profile_service.list_profiles()
+
+    def refresh_libraries(self) -> None:
+        """Reload the list of libraries from the library service."""
+        if self.library_service:
+            names = [library.name for library in self.library_service.list_libraries()]
+            self.library_combo["values"] = ["", *names]
+
     def _browse_output_dir(self) -> None:
         """Browse for output directory."""
         from tkinter import filedialog
@@ -154,6 +466,33 @@ class ConfigWidget(ttk.LabelFrame):
             self.output_var.set(directory)
             logger.debug(f"Selected output directory: {directory}")
 
+    def _browse_temp_dir(self) -> None:
+        """Browse for the folder partial files are written to."""
+        from tkinter import filedialog
+
+        directory = filedialog.askdirectory(
+            title="Select Temp Folder",
+            initialdir=self.temp_dir_var.get() or str(Path.home()),
+            mustexist=True,
+        )
+
+        if directory:
+            self.temp_dir_var.set(directory)
+            logger.debug(f"Selected temp folder: {directory}")
+
+    def _browse_cookies_file(self) -> None:
+        """Browse for a cookies file exported from a browser."""
+        from tkinter import filedialog
+
+        filename = filedialog.askopenfilename(
+            title="Select Cookies File",
+            filetypes=[("Cookies files", "*.txt"), ("All files", "*.*")],
+        )
+
+        if filename:
+            self.cookies_var.set(filename)
+            logger.debug(f"Selected cookies file: {filename}")
+
     def _apply_config(self) -> None:
         """Apply configuration changes."""
         self._config = self.get_config()
@@ -167,19 +506,72 @@ class ConfigWidget(ttk.LabelFrame):
         Returns:
             Current DownloadConfig.
         """
-        limit_str = self.limit_var.get().strip()
-        limit = int(limit_str) if limit_str else None
+        limit = self._parse_optional(self.limit_var.get())
 
         return DownloadConfig(
             output_dir=self.output_var.get(),
             limit=limit,
             overwrite=self.overwrite_var.get(),
+            sync_only=self.sync_only_var.get(),
             timeout=self.timeout_var.get(),
             retries=self.retries_var.get(),
             delay=self.delay_var.get(),
-            verbose=self.verbose_var.get(),
+            workers=self.workers_var.get(),
+            max_speed=self._parse_optional(self.max_speed_var.get()),
+            log_level=self.log_level_var.get(),
+            low_priority=self.low_priority_var.get(),
+            normalize_loudness=self.normalize_var.get(),
+            checksum_sidecars=self.checksum_sidecars_var.get(),
+            merge_format=self.merge_format_var.get() or None,
+            filename_replacement=self.replacement_var.get(),
+            max_filename_length=self._parse_optional(self.max_name_length_var.get()),
+            job_timeout=self._parse_optional(self.job_timeout_var.get()),
+            track_timeout=self._parse_optional(self.track_timeout_var.get()),
+            track_range=self.track_range_var.get().strip() or None,
+            skip_titles=[
+                pattern.strip()
+                for pattern in self.skip_titles_var.get().split(",")
+                if pattern.strip()
+            ],
+            include_pattern=self.include_var.get().strip() or None,
+            exclude_pattern=self.exclude_var.get().strip() or None,
+            cookies_file=self.cookies_var.get().strip() or None,
+            scan_command=self.scan_command_var.get().strip() or None,
+            temp_dir=self.temp_dir_var.get().strip() or None,
+            confirm_tracks=self.confirm_tracks_var.get(),
+            confirm_timeout=self.confirm_timeout_var.get(),
+            confirm_default=self.confirm_default_var.get(),
+            library=self.library_var.get() or None,
+            relax_politeness=self.relax_politeness_var.get(),
+            min_track_size=self._parse_optional(self.min_track_size_var.get()),
+            detect_silence=self.detect_silence_var.get(),
         )
 
+    @staticmethod
+    def _parse_optional(value: str) -> int | None:
+        """Parse an optional integer entry.
+
+        Args:
+            value: Entry text.
+
+        Returns:
+            Parsed integer, or None if the entry is empty.
+        """
+        value = value.strip()
+        return int(value) if value else None
+
+    @staticmethod
+    def _format_optional(value: int | None) -> str:
+        """Format an optional integer for an entry.
+
+        Args:
+            value: Integer value or None.
+
+        Returns:
+            String representation, empty for None.
+        """
+        return str(value) if value else ""
+
     def set_config(self, config: DownloadConfig) -> None:
         """Set configuration.
 
@@ -188,12 +580,87 @@ class ConfigWidget(ttk.LabelFrame):
         """
         self._config = config
         self.output_var.set(config.output_dir)
-        self.limit_var.set(str(config.limit) if config.limit else "")
+        self.limit_var.set(self._format_optional(config.limit))
         self.timeout_var.set(config.timeout)
         self.retries_var.set(config.retries)
         self.delay_var.set(config.delay)
+        self.workers_var.set(config.workers)
+        self.max_speed_var.set(self._format_optional(config.max_speed))
         self.overwrite_var.set(config.overwrite)
-        self.verbose_var.set(config.verbose)
+        self.sync_only_var.set(config.sync_only)
+        self.log_level_var.set(config.log_level)
+        self.low_priority_var.set(config.low_priority)
+        self.normalize_var.set(config.normalize_loudness)
+        self.checksum_sidecars_var.set(config.checksum_sidecars)
+        self.merge_format_var.set(config.merge_format or "")
+        self.replacement_var.set(config.filename_replacement)
+        self.max_name_length_var.set(self._format_optional(config.max_filename_length))
+        self.job_timeout_var.set(self._format_optional(config.job_timeout))
+        self.track_timeout_var.set(self._format_optional(config.track_timeout))
+        self.track_range_var.set(config.track_range or "")
+        self.skip_titles_var.set(", ".join(config.skip_titles))
+        self.include_var.set(config.include_pattern or "")
+        self.exclude_var.set(config.exclude_pattern or "")
+        self.cookies_var.set(config.cookies_file or "")
+        self.scan_command_var.set(config.scan_command or "")
+        self.temp_dir_var.set(config.temp_dir or "")
+        self.confirm_tracks_var.set(config.confirm_tracks)
+        self.confirm_timeout_var.set(config.confirm_timeout)
+        self.confirm_default_var.set(config.confirm_default)
+        self.library_var.set(config.library or "")
+        self.relax_politeness_var.set(config.relax_politeness)
+        self.min_track_size_var.set(self._format_optional(config.min_track_size))
+        self.detect_silence_var.set(config.detect_silence)
+
+    def set_capabilities(self, capabilities: Capabilities) -> None:
+        """Disable the options the current install can't run.
+
+        Unavailable options are also switched off, so downloads don't fail on them.
+
+        Args:
+            capabilities: Features available in the current install.
+        """
+        toggles = [
+            (capabilities.normalize, self.normalize_check, self.normalize_var),
+            (
+                capabilities.supports("--checksum-sidecars"),
+                self.checksum_sidecars_check,
+                self.checksum_sidecars_var,
+            ),
+            (
+                capabilities.supports("--confirm-tracks"),
+                self.confirm_tracks_check,
+                self.confirm_tracks_var,
+            ),
+            (
+                capabilities.supports("--relax-politeness"),
+                self.relax_politeness_check,
+                self.relax_politeness_var,
+            ),
+            (
+                capabilities.silence_detection,
+                self.detect_silence_check,
+                self.detect_silence_var,
+            ),
+        ]
+        for available, check, var in toggles:
+            check.configure(state=tk.NORMAL if available else tk.DISABLED)
+            if not available:
+                var.set(False)
+
+        self.merge_combo.configure(state="readonly" if capabilities.merge else tk.DISABLED)
+        if not capabilities.merge:
+            self.merge_format_var.set("")
+
+        scan = capabilities.supports("--scan-command")
+        self.scan_command_entry.configure(state=tk.NORMAL if scan else tk.DISABLED)
+        if not scan:
+            self.scan_command_var.set("")
+
+        min_size = capabilities.supports("--min-size")
+        self.min_track_size_entry.configure(state=tk.NORMAL if min_size else tk.DISABLED)
+        if not min_size:
+            self.min_track_size_var.set("")
 
     def reset(self) -> None:
         """Reset to default configuration."""
@@ -0,0 +1,123 @@
+"""Advanced console dialog for running CLI commands from the GUI."""
+
+import logging
+import shlex
+import tkinter as tk
+from collections.abc import Callable
+from tkinter import scrolledtext, ttk
+
+logger = logging.getLogger(__name__)
+
+
+class ConsoleDialog(tk.Toplevel):
+    """Dialog for running a CLI invocation and watching its output."""
+
+    def __init__(
+        self,
+        master: tk.Misc,
+        on_run: Callable[[list[str]], None],
+        on_stop: Callable[[], None],
+    ) -> None:
+        """Initialize console dialog.
+
+        Args:
+            master: Parent window.
+            on_run: Callback with the CLI arguments when the user runs a command.
+            on_stop: Callback when the user stops the running command.
+        """
+        super().__init__(master)
+        self.title("Advanced Console")
+        self.geometry("700x450")
+        self.transient(master)
+
+        self.on_run = on_run
+        self.on_stop = on_stop
+
+        self._create_widgets()
+        self.set_running(False)
+        self.protocol("WM_DELETE_WINDOW", self._on_close)
+        logger.debug("ConsoleDialog initialized")
+
+    def _create_widgets(self) -> None:
+        """Create console dialog widgets."""
+        container = ttk.Frame(self, padding=10)
+        container.pack(fill=tk.BOTH, expand=True)
+
+        ttk.Label(container, text="CLI arguments:").pack(anchor=tk.W)
+
+        command_frame = ttk.Frame(container)
+        command_frame.pack(fill=tk.X, pady=(5, 10))
+
+        self.args_var = tk.StringVar()
+        args_entry = ttk.Entry(command_frame, textvariable=self.args_var)
+        args_entry.pack(side=tk.LEFT, fill=tk.X, expand=True)
+        args_entry.bind("<Return>", lambda event: self._on_run_clicked())
+
+        self.stop_button = ttk.Button(
+            command_frame,
+            text="Stop",
+            command=self.on_stop,
+            width=8,
+        )
+        self.stop_button.pack(side=tk.RIGHT, padx=(5, 0))
+
+        self.run_button = ttk.Button(
+            command_frame,
+            text="Run",
+            command=self._on_run_clicked,
+            width=8,
+        )
+        self.run_button.pack(side=tk.RIGHT, padx=(5, 0))
+
+        self.output_text = scrolledtext.ScrolledText(container, height=18, wrap=tk.NONE)
+        self.output_text.pack(fill=tk.BOTH, expand=True)
+        self.output_text.configure(state=tk.DISABLED)
+
+    def get_args(self) -> list[str]:
+        """Get the entered CLI arguments.
+
+        Returns:
+            Arguments split like a shell would, without the executable.
+
+        Raises:
+            ValueError: If the quoting is unbalanced.
+        """
+        return shlex.split(self.args_var.get())
+
+    def append_output(self, line: str) -> None:
+        """Add a line to the output and scroll to it.
+
+        Args:
+            line: Line of CLI output.
+        """
+        self.output_text.configure(state=tk.NORMAL)
+        self.output_text.insert(tk.END, line.rstrip("\n") + "\n")
+        self.output_text.see(tk.END)
+        self.output_text.configure(state=tk.DISABLED)
+
+    def set_running(self, running: bool) -> None:
+        """Enable the buttons that fit whether a command is running.
+
+        Args:
+            running: Whether a command is running.
+        """
+        self.run_button.configure(state=tk.DISABLED if running else tk.NORMAL)
+        self.stop_button.configure(state=tk.NORMAL if running else tk.DISABLED)
+
+    def _on_run_clicked(self) -> None:
+        """Run the entered command."""
+        try:
+            args = self.get_args()
+        except ValueError as e:
+            self.append_output(f"Invalid arguments: {e}")
+            return
+        if not args:
+            return
+
+        self.append_output(f"$ {shlex.join(args)}")
+        self.on_run(args)
+
+    def _on_close(self) -> None:
+        """Stop the running command and close the dialog."""
+        self.on_stop()
+        self.destroy()
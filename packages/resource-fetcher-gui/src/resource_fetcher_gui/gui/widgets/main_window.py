@@ -1,23 +1,137 @@
 """Main application window for Resource Fetcher GUI."""
 
+import dataclasses
+import json
 import logging
+import math
+import os
+import subprocess
 import sys
+import tempfile
+import threading
+import time
 import tkinter as tk
 import tkinter.ttk as ttk
+import webbrowser
+from collections.abc import Callable
 from pathlib import Path
+from tkinter import filedialog, messagebox, scrolledtext, simpledialog
+from types import TracebackType
 
 import ttkbootstrap as bootstrap
 
-from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper
-from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.adaptive_concurrency import (
+    AdaptiveConcurrency,
+    ConcurrencyAdjustment,
+)
+from resource_fetcher_gui.gui.core.backup import BackupInfo, create_backup, restore_backup
+from resource_fetcher_gui.gui.core.bandwidth import (
+    GOVERNOR_INTERVAL,
+    BandwidthGovernor,
+    BandwidthSchedule,
+    BandwidthWindow,
+)
+from resource_fetcher_gui.gui.core.batch import BatchDownload, BatchProgress
+from resource_fetcher_gui.gui.core.bookmarks import find_supported_bookmarks
+from resource_fetcher_gui.gui.core.capabilities import Capabilities, get_capabilities
+from resource_fetcher_gui.gui.core.cleanup import (
+    MEGABYTE,
+    STAGED_FILE_PATTERN,
+    CleanupService,
+    CleanupTarget,
+    RetentionPolicy,
+)
+from resource_fetcher_gui.gui.core.cli_wrapper import (
+    CONFIRM_TRACK,
+    JOB_TIMEOUT_EXIT_CODE,
+    BenchmarkResult,
+    CLIWrapper,
+    InteractionRequest,
+)
+from resource_fetcher_gui.gui.core.config_service import (
+    ConfigService,
+    DownloadConfig,
+    get_app_cache_dir,
+    get_app_config_dir,
+    get_app_paths,
+)
+from resource_fetcher_gui.gui.core.crash_reports import CrashReporter
+from resource_fetcher_gui.gui.core.debug_bundle import export_debug_bundle
+from resource_fetcher_gui.gui.core.download_history import DownloadHistory, create_history_entry
+from resource_fetcher_gui.gui.core.i18n import tr
+from resource_fetcher_gui.gui.core.job_file import export_failures, run_job_file
+from resource_fetcher_gui.gui.core.job_log import JobLogStore
+from resource_fetcher_gui.gui.core.job_queue import (
+    JOB_CANCELLED,
+    JOB_COMPLETED,
+    JOB_FAILED,
+    JobQueue,
+    QueuedJob,
+)
+from resource_fetcher_gui.gui.core.job_templates import JobTemplateService
+from resource_fetcher_gui.gui.core.libraries import LibraryService
+from resource_fetcher_gui.gui.core.maintenance import (
+    CHECKSUM_SAMPLE,
+    CLEANUP,
+    LIBRARY_SCAN,
+    MAINTENANCE_CHECK_INTERVAL,
+    SUBSCRIPTIONS,
+    MaintenanceRun,
+    MaintenanceScheduler,
+    library_folders,
+    scan_library,
+    verify_checksum_sample,
+)
+from resource_fetcher_gui.gui.core.migrations import MigrationResult
+from resource_fetcher_gui.gui.core.mock_cli import MockCLIWrapper
+from resource_fetcher_gui.gui.core.notifications import Notification, NotificationStore
+from resource_fetcher_gui.gui.core.output_buffer import OutputBuffer
 from resource_fetcher_gui.gui.core.output_parser import OutputParser, SongProgress
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+from resource_fetcher_gui.gui.core.progress_summary import ProgressSummary
+from resource_fetcher_gui.gui.core.quota import (
+    QUOTA_CHECK_INTERVAL,
+    QuotaExceeded,
+    QuotaLimits,
+    QuotaService,
+)
+from resource_fetcher_gui.gui.core.redownload import redownload
+from resource_fetcher_gui.gui.core.self_test import SelfTestStage, run_self_test
+from resource_fetcher_gui.gui.core.session import SessionStore
+from resource_fetcher_gui.gui.core.settings_bundle import export_config, import_config
+from resource_fetcher_gui.gui.core.sounds import (
+    SOUND_COMPLETED,
+    SOUND_FAILED,
+    SoundService,
+    SoundSettings,
+)
+from resource_fetcher_gui.gui.core.source_rules import SourceRuleService, parse_overrides
+from resource_fetcher_gui.gui.core.subscriptions import NewRelease, SubscriptionService
+from resource_fetcher_gui.gui.core.throttle import ProgressThrottle
+from resource_fetcher_gui.gui.core.volumes import VOLUME_CHECK_INTERVAL, volume_available
+from resource_fetcher_gui.gui.widgets.batch_dialog import BatchDialog
+from resource_fetcher_gui.gui.widgets.console_dialog import ConsoleDialog
 from resource_fetcher_gui.gui.widgets.config_widget import ConfigWidget
 from resource_fetcher_gui.gui.widgets.progress_widget import ProgressWidget
-from resource_fetcher_gui.gui.widgets.status_bar import StatusBar
+from resource_fetcher_gui.gui.widgets.status_bar import LogLevel, StatusBar
 from resource_fetcher_gui.gui.widgets.url_input_widget import URLInputWidget
 
 logger = logging.getLogger(__name__)
 
+APP_TITLE = "Resource Fetcher GUI"
+
+# How often buffered CLI output is shown in the log (milliseconds)
+OUTPUT_FLUSH_INTERVAL = 100
+
+# Number of recent maintenance runs listed by "Show Maintenance Runs"
+MAINTENANCE_RUNS_SHOWN = 20
+
+# Maximum number of progress updates shown per second
+PROGRESS_MAX_RATE = 10.0
+
+# Number of recent downloads offered in the "Download Again" menu
+REDOWNLOAD_MENU_SIZE = 10
+
 
 class MainWindow(bootstrap.Window):
     """Main application window.
@@ -26,34 +140,104 @@ class MainWindow(bootstrap.Window):
     Uses ttkbootstrap for modern theming.
     """
 
-    def __init__(self, theme: str = "cosmo") -> None:
+    def __init__(
+        self,
+        theme: str = "cosmo",
+        mock: bool = False,
+        mock_delay: float = 0.5,
+    ) -> None:
         """Initialize main window.
 
         Args:
             theme: ttkbootstrap theme name (default: "cosmo").
+            mock: Use a simulated CLI instead of the built executable.
+            mock_delay: Seconds spent on each simulated track in mock mode.
         """
         super().__init__(themename=theme)
 
         # Window configuration
-        self.title("Resource Fetcher GUI")
+        self.title(APP_TITLE)
         self.geometry("800x600")
         self.minsize(600, 400)
 
+        # Report crashes if the user opted in
+        self.crash_reporter = CrashReporter()
+        self.crash_reporter.install()
+
         # Initialize services
         self.config_service = ConfigService()
+        self.profile_service = ProfileService(config_service=self.config_service)
+        self.job_log_store = JobLogStore()
+        self.template_service = JobTemplateService(config_service=self.config_service)
+        self.download_history = DownloadHistory()
+        self.quota_service = QuotaService(self.download_history)
+        self.quota_exceeded: QuotaExceeded | None = None
+        self.cleanup_service = CleanupService()
+        # Monotonic time until which a rate-limiting site asked to wait
+        self.rate_ban_until: float | None = None
+        # Mount point of an output volume that disappeared during a job
+        self.lost_volume: Path | None = None
+        self.subscription_service = SubscriptionService()
+        self.maintenance = MaintenanceScheduler()
+        self._maintenance_running = False
+        self.notification_store = NotificationStore()
+        self.sound_service = SoundService(bell=self.bell)
+        self.source_rules = SourceRuleService(profile_service=self.profile_service)
+        self.library_service = LibraryService(config_service=self.config_service)
+        self.bandwidth_schedule = BandwidthSchedule()
+        self.session_store = SessionStore(config_service=self.config_service)
+        self.bandwidth_governor = BandwidthGovernor(
+            self.bandwidth_schedule, self._on_speed_limit_window_changed
+        )
+        # Per-source slow-down shared by the queue and batches
+        self.adaptive_concurrency = AdaptiveConcurrency(self._on_concurrency_adjusted)
         self.cli_wrapper: CLIWrapper | None = None
+        # Optional features of the install, None until detected
+        self.capabilities: Capabilities | None = None
+        self.job_queue: JobQueue | None = None
+        self.batch: BatchDownload | None = None
         self.output_parser = OutputParser()
+        self.output_buffer = OutputBuffer()
+        self.merge_status = ProgressThrottle(self._show_merge_status, PROGRESS_MAX_RATE)
+        # Occasional spoken-style summaries of the running download
+        self.progress_summary = ProgressSummary(self._show_progress_summary)
+
+        # Definitions of sites the CLI doesn't support out of the box
+        self.custom_sources_dir = get_app_config_dir() / "sources"
+
+        # Working directories of jobs, kept after a failure for debugging
+        self.job_work_dir = get_app_cache_dir() / "jobs"
 
         # Determine CLI path
-        cli_path = Path("dist/resource-fetcher.exe")
+        self.cli_path = Path("dist/resource-fetcher.exe")
         if sys.platform != "win32":
-            cli_path = Path("dist/resource-fetcher")
+            self.cli_path = Path("dist/resource-fetcher")
+        self.mock = mock
+        self.mock_delay = mock_delay
 
-        if cli_path.exists():
-            self.cli_wrapper = CLIWrapper(cli_path)
-            logger.info(f"CLI wrapper initialized with: {cli_path}")
+        if mock:
+            self.cli_wrapper = self._create_cli_wrapper()
+            logger.info(f"Mock CLI wrapper initialized (delay: {mock_delay}s)")
+        elif self.cli_path.exists():
+            self.cli_wrapper = self._create_cli_wrapper()
+            logger.info(f"CLI wrapper initialized with: {self.cli_path}")
         else:
-            logger.warning(f"CLI not found at {cli_path} (will be available after build)")
+            logger.warning(f"CLI not found at {self.cli_path} (will be available after build)")
+
+        if self.cli_wrapper:
+            self.job_queue = JobQueue(
+                self.cli_wrapper,
+                progress_callback=self._on_progress,
+                job_started_callback=self._on_job_started,
+                job_finished_callback=self._on_job_finished,
+                config_resolver=self._resolve_config,
+                interaction_callback=self._on_interaction_required,
+                start_resolver=self.bandwidth_schedule.apply,
+                can_start=self._can_start_job,
+                session_callback=self.session_store.save,
+                volume_lost_callback=self._on_volume_lost,
+                adaptive_concurrency=self.adaptive_concurrency,
+            )
 
         # Center window on screen
         self.center_window()
@@ -62,8 +246,85 @@ class MainWindow(bootstrap.Window):
         self._create_widgets()
         self._load_config()
 
+        # Show job results the previous session stored but never showed
+        self._deliver_pending_notifications()
+
+        # Remove old job logs and leftovers before any job starts
+        self._run_cleanup()
+
+        # Queue the jobs the previous session didn't finish
+        self._restore_session()
+
+        # Check URLs against the sites the installed CLI supports
+        self._load_supported_sources()
+
+        # Disable options the install can't run, e.g. normalizing without ffmpeg
+        self._load_capabilities()
+
+        # Show CLI output in batches from the main thread
+        self.after(OUTPUT_FLUSH_INTERVAL, self._flush_output)
+
+        # Scan the library, clean up and look for new releases from time to time
+        self.after(MAINTENANCE_CHECK_INTERVAL * 1000, self._on_maintenance_timer)
+
+        # Follow the speed limit schedule
+        self.after(GOVERNOR_INTERVAL * 1000, self._on_speed_limit_timer)
+
+        # Resume downloads paused by a data quota once the period rolls over
+        self.after(QUOTA_CHECK_INTERVAL * 1000, self._on_quota_timer)
+
         logger.info(f"MainWindow initialized with theme: {theme}")
 
+    def _load_supported_sources(self) -> None:
+        """Ask the CLI for its supported sites in the background."""
+        if not self.cli_wrapper:
+            return
+        cli_wrapper = self.cli_wrapper
+
+        def load() -> None:
+            try:
+                sources = cli_wrapper.list_supported_sources()
+            except (OSError, ValueError, subprocess.TimeoutExpired) as e:
+                logger.warning(f"Using the bundled site list: {e}")
+                return
+            self.after(0, lambda: self.url_input.set_sources(sources))
+
+        threading.Thread(target=load, daemon=True).start()
+
+    def _load_capabilities(self) -> None:
+        """Detect the optional features of the install in the background."""
+        cli_wrapper = self.cli_wrapper
+
+        def load() -> None:
+            capabilities = get_capabilities(cli_wrapper)
+            self.after(0, lambda: self._apply_capabilities(capabilities))
+
+        threading.Thread(target=load, daemon=True).start()
+
+    def _apply_capabilities(self, capabilities: Capabilities) -> None:
+        """Disable the controls of features the install lacks.
+
+        Args:
+            capabilities: Features available in the current install.
+        """
+        self.capabilities = capabilities
+        self.config_widget.set_capabilities(capabilities)
+
+    def _create_cli_wrapper(self) -> CLIWrapper:
+        """Create a CLI wrapper for the configured backend.
+
+        Returns:
+            Mock wrapper in mock mode, otherwise a wrapper for the built CLI.
+        """
+        if self.mock:
+            return MockCLIWrapper(delay=self.mock_delay, log_store=self.job_log_store)
+        return CLIWrapper(
+            self.cli_path,
+            log_store=self.job_log_store,
+            sources_dir=self.custom_sources_dir,
+            work_root=self.job_work_dir,
+        )
+
     def center_window(self) -> None:
         """Center window on screen."""
         self.update_idletasks()
@@ -75,8 +336,96 @@ class MainWindow(bootstrap.Window):
 
         self.geometry(f"{width}x{height}+{x_offset}+{y_offset}")
 
+    def _create_menu(self) -> None:
+        """Create the menu bar."""
+        menubar = tk.Menu(self)
+
+        file_menu = tk.Menu(menubar, tearoff=False)
+        file_menu.add_command(label="Batch Download...", command=self._on_batch_download)
+        file_menu.add_command(label="Import Bookmarks...", command=self._on_import_bookmarks)
+        file_menu.add_command(label="Download Artist...", command=self._on_download_artist)
+        file_menu.add_command(label="Follow Artist...", command=self._on_follow_artist)
+        self.subscription_menu = tk.Menu(
+            file_menu, tearoff=False, postcommand=self._fill_subscription_menu
+        )
+        file_menu.add_cascade(label="Unfollow Artist", menu=self.subscription_menu)
+        file_menu.add_command(label="Check New Releases", command=self._check_subscriptions)
+        file_menu.add_command(label="Run Job File...", command=self._on_run_job_file)
+        file_menu.add_command(label="Export Failed Tracks...", command=self._on_export_failures)
+        self.redownload_menu = tk.Menu(
+            file_menu, tearoff=False, postcommand=self._fill_redownload_menu
+        )
+        file_menu.add_cascade(label="Download Again", menu=self.redownload_menu)
+        file_menu.add_command(label="Search History...", command=self._on_search_history)
+        file_menu.add_command(label="Show Job Details...", command=self._on_show_job_details)
+        file_menu.add_command(label="Show Queue", command=self._on_show_queue)
+        file_menu.add_command(label="Move Queued Jobs To...", command=self._on_move_queued_jobs)
+        file_menu.add_command(label="Save as Template...", command=self._on_save_template)
+        self.template_menu = tk.Menu(file_menu, tearoff=False, postcommand=self._fill_template_menu)
+        file_menu.add_cascade(label="Download with Template", menu=self.template_menu)
+        file_menu.add_command(label="Add Source Rule...", command=self._on_add_source_rule)
+        self.source_rule_menu = tk.Menu(
+            file_menu, tearoff=False, postcommand=self._fill_source_rule_menu
+        )
+        file_menu.add_cascade(label="Remove Source Rule", menu=self.source_rule_menu)
+        file_menu.add_command(label="Add Library...", command=self._on_add_library)
+        self.library_menu = tk.Menu(file_menu, tearoff=False, postcommand=self._fill_library_menu)
+        file_menu.add_cascade(label="Switch Library", menu=self.library_menu)
+        self.remove_library_menu = tk.Menu(
+            file_menu, tearoff=False, postcommand=self._fill_remove_library_menu
+        )
+        file_menu.add_cascade(label="Remove Library", menu=self.remove_library_menu)
+        file_menu.add_command(
+            label="Open Custom Sources Folder", command=self._on_open_custom_sources
+        )
+        file_menu.add_command(label="Add Speed Limit Window...", command=self._on_add_speed_limit)
+        file_menu.add_command(label="Set Data Quota...", command=self._on_set_quota)
+        file_menu.add_command(label="Set Cleanup Limits...", command=self._on_set_cleanup_limits)
+        file_menu.add_command(label="Set Sounds...", command=self._on_set_sounds)
+        self.sounds_var = tk.BooleanVar(value=self.sound_service.load().enabled)
+        file_menu.add_checkbutton(
+            label="Play Sounds", variable=self.sounds_var, command=self._on_sounds_toggled
+        )
+        file_menu.add_command(label="Clean Up Old Files", command=self._on_clean_up)
+        file_menu.add_command(label="Run Maintenance Now", command=self._on_run_maintenance)
+        file_menu.add_command(label="Show Maintenance Runs", command=self._on_show_maintenance_runs)
+        self.speed_limit_menu = tk.Menu(
+            file_menu, tearoff=False, postcommand=self._fill_speed_limit_menu
+        )
+        file_menu.add_cascade(label="Remove Speed Limit Window", menu=self.speed_limit_menu)
+        file_menu.add_separator()
+        file_menu.add_command(label="Export Settings...", command=self._on_export_settings)
+        file_menu.add_command(label="Import Settings...", command=self._on_import_settings)
+        file_menu.add_command(label="Back Up Everything...", command=self._on_create_backup)
+        file_menu.add_command(label="Restore Backup...", command=self._on_restore_backup)
+        file_menu.add_command(label="Export Debug Bundle...", command=self._on_export_debug_bundle)
+        file_menu.add_separator()
+        file_menu.add_command(label="Exit", command=self.destroy)
+        menubar.add_cascade(label="File", menu=file_menu)
+
+        help_menu = tk.Menu(menubar, tearoff=False)
+        self.crash_reports_var = tk.BooleanVar(value=self.crash_reporter.enabled)
+        help_menu.add_checkbutton(
+            label="Save Crash Reports",
+            variable=self.crash_reports_var,
+            command=self._on_crash_reports_toggled,
+        )
+        help_menu.add_command(
+            label="Open Crash Reports Folder", command=self._on_open_crash_reports
+        )
+        help_menu.add_command(label="Show File Locations", command=self._on_show_app_paths)
+        help_menu.add_separator()
+        help_menu.add_command(label="Run Self-Test", command=self._on_run_self_test)
+        help_menu.add_command(label="Benchmark Connection...", command=self._on_benchmark)
+        help_menu.add_command(label="Advanced Console...", command=self._on_open_console)
+        menubar.add_cascade(label="Help", menu=help_menu)
+
+        self.config(menu=menubar)
+
     def _create_widgets(self) -> None:
         """Create all GUI widgets."""
+        self._create_menu()
+
         # Main container with padding
         main_container = ttk.Frame(self, padding=10)
         main_container.pack(fill=tk.BOTH, expand=True)
@@ -92,6 +441,8 @@ class MainWindow(bootstrap.Window):
         self.config_widget = ConfigWidget(
             main_container,
             on_config_change=self._on_config_changed,
+            profile_service=self.profile_service,
+            library_service=self.library_service,
         )
         self.config_widget.pack(fill=tk.X, pady=(0, 10))
 
@@ -116,6 +467,22 @@ class MainWindow(bootstrap.Window):
         )
         self.stop_btn.pack(side=tk.LEFT, padx=(0, 5))
 
+        self.view_log_btn = ttk.Button(
+            control_frame,
+            text="View Log",
+            command=self._on_view_log_clicked,
+            width=15,
+        )
+        self.view_log_btn.pack(side=tk.LEFT, padx=(0, 5))
+
+        self.notifications_btn = ttk.Button(
+            control_frame,
+            command=self._on_notifications_clicked,
+            width=18,
+        )
+        self.notifications_btn.pack(side=tk.LEFT, padx=(0, 5))
+        self._update_notifications_button()
+
         # Progress Widget
         self.progress_widget = ProgressWidget(main_container)
         self.progress_widget.pack(fill=tk.BOTH, expand=True, pady=(0, 10))
@@ -131,8 +498,17 @@ class MainWindow(bootstrap.Window):
         """Load configuration from file."""
         config = self.config_service.load_config()
         self.config_widget.set_config(config)
+        self._apply_log_level(config)
         logger.info("Configuration loaded")
 
+    def _apply_log_level(self, config: DownloadConfig) -> None:
+        """Apply the configured log level to the GUI's own logging.
+
+        Args:
+            config: Configuration providing the log level.
+        """
+        logging.getLogger().setLevel(config.log_level.upper())
+
     def _on_url_changed(self, url: str) -> None:
         """Handle URL change.
 
@@ -150,8 +526,9 @@ class MainWindow(bootstrap.Window):
         Args:
             config: New configuration.
         """
+        self._apply_log_level(config)
         logger.info("Configuration changed")
-        self.status_bar.log_info("Configuration updated")
+        self.status_bar.log_info(tr("config_updated"))
 
     def _on_download_clicked(self) -> None:
         """Handle download button click."""
@@ -159,88 +536,1802 @@ class MainWindow(bootstrap.Window):
         is_valid, error_msg = self.url_input.validate()
 
         if not is_valid:
-            self.status_bar.error(f"Invalid URL: {error_msg}")
+            self.status_bar.error(tr("invalid_url", error=error_msg))
             return
 
-        if not self.cli_wrapper:
-            self.status_bar.error("CLI executable not found. Please build the project first.")
+        if not self.job_queue:
+            self.status_bar.error(tr("cli_not_found"))
             return
 
         config = self.config_widget.get_config()
+        if self.job_queue.find_active(url, config.output_dir):
+            self.status_bar.log_info(tr("already_queued", url=url))
+            return
+
+        if not self._confirm_redownload(url):
+            return
 
         # Add to history
         self.url_input.add_to_history(url)
 
-        # Clear previous progress
-        self.progress_widget.clear()
-        self.output_parser.reset()
+        self._set_busy_state()
 
-        # Update UI state
-        self.download_btn.config(state=tk.DISABLED)
-        self.stop_btn.config(state=tk.NORMAL)
-        for child in self.config_widget.winfo_children():
+        # Start download in background thread
+        self.job_queue.enqueue(url, config, note=self.url_input.get_note())
+
+    def _on_run_job_file(self) -> None:
+        """Enqueue all jobs from a job file chosen by the user."""
+        if not self.job_queue:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        path = filedialog.askopenfilename(
+            title="Run Job File",
+            filetypes=[("Job files", "*.json *.yaml *.yml"), ("All files", "*.*")],
+        )
+        if not path:
+            return
+
+        try:
+            jobs = run_job_file(
+                Path(path), self.job_queue, self.config_widget.get_config(), self.config_service
+            )
+        except (OSError, ValueError) as e:
+            self.status_bar.error(tr("job_file_failed", error=e))
+            return
+
+        self._set_busy_state()
+        self.status_bar.log_info(tr("jobs_queued", count=len(jobs), path=path))
+
+    def _on_export_failures(self) -> None:
+        """Export the failed tracks of the last finished job as a job file."""
+        finished = [
+            job
+            for job in (self.job_queue.jobs if self.job_queue else [])
+            if job.status in (JOB_COMPLETED, JOB_FAILED)
+        ]
+        if not finished:
+            self.status_bar.warning(tr("no_finished_job"))
+            return
+
+        path = filedialog.asksaveasfilename(
+            title="Export Failed Tracks",
+            defaultextension=".json",
+            initialfile="failed-tracks.json",
+            filetypes=[("JSON files", "*.json")],
+        )
+        if not path:
+            return
+
+        try:
+            count = export_failures(finished[-1], Path(path))
+            self.status_bar.success(tr("failures_exported", count=count, path=path))
+        except (OSError, ValueError) as e:
+            self.status_bar.error(tr("failures_export_failed", error=e))
+
+    def _fill_redownload_menu(self) -> None:
+        """Rebuild the submenu of recent downloads that can be repeated."""
+        self.redownload_menu.delete(0, tk.END)
+        entries = [entry for entry in self.download_history.entries() if entry.completed]
+        recent = [entry for entry in reversed(entries) if entry.job_id][:REDOWNLOAD_MENU_SIZE]
+        if not recent:
+            self.redownload_menu.add_command(label="(no downloads)", state=tk.DISABLED)
+
+        for entry in recent:
+            self.redownload_menu.add_command(
+                label=f"{entry.title or entry.url} ({entry.finished_at})",
+                command=lambda job_id=entry.job_id: self._on_redownload(job_id),
+            )
+
+    def _on_search_history(self) -> None:
+        """Show the history entries matching a search in the log."""
+        query = simpledialog.askstring(
+            "Search History", "Text, or key=value for metadata:", parent=self
+        )
+        if not query or not query.strip():
+            return
+
+        entries = self.download_history.search(query)
+        self.status_bar.log_info(tr("history_matches", count=len(entries), query=query.strip()))
+        for entry in entries:
+            details = [entry.job_id, entry.finished_at, entry.title or entry.url, entry.output_dir]
+            if entry.note:
+                details.append(entry.note)
+            details.extend(f"{key}={value}" for key, value in entry.metadata.items())
+            self.status_bar.log_info("  " + " | ".join(details))
+
+    def _on_show_job_details(self) -> None:
+        """Show the versions, options and data usage of a past job in the log."""
+        job_id = simpledialog.askstring("Show Job Details", "Job ID:", parent=self)
+        if not job_id or not job_id.strip():
+            return
+
+        entry = self.download_history.get_job(job_id.strip())
+        if entry is None:
+            self.status_bar.warning(tr("job_not_found", job_id=job_id.strip()))
+            return
+
+        details = {
+            "url": entry.url,
+            "finished_at": entry.finished_at,
+            "bytes_downloaded": entry.bytes_downloaded,
+            "bytes_transferred": entry.bytes_transferred,
+            "track_bytes": entry.track_bytes,
+            **entry.environment,
+        }
+        self.status_bar.log_info(json.dumps(details, indent=2, ensure_ascii=False))
+
+    def _on_show_queue(self) -> None:
+        """Show the queue's jobs and whether they wait for other jobs in the log."""
+        listings = self.job_queue.list_jobs() if self.job_queue else []
+        if not listings:
+            self.status_bar.log_info(tr("queue_empty"))
+
+        for listing in listings:
+            details = [listing.job.job_id, listing.state, listing.job.url]
+            if listing.waiting_for:
+                details.append(tr("waiting_for", jobs=", ".join(listing.waiting_for)))
+            self.status_bar.log_info("  " + " | ".join(details))
+
+    def _on_move_queued_jobs(self) -> None:
+        """Change the output folder of every job that hasn't started yet."""
+        pending = self.job_queue.pending() if self.job_queue else []
+        if not pending:
+            self.status_bar.log_info(tr("queue_empty"))
+            return
+
+        folder = filedialog.askdirectory(title="Move Queued Jobs To")
+        if not folder:
+            return
+
+        try:
+            jobs = self.job_queue.update_jobs(
+                [job.job_id for job in pending], {"output_dir": folder}
+            )
+        except ValueError as e:
+            # A job may have started while the dialog was open
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("queued_jobs_moved", count=len(jobs), path=folder))
+
+    def _on_redownload(self, job_id: str) -> None:
+        """Download a past job again with its settings into a new folder.
+
+        Args:
+            job_id: Identifier of the past job.
+        """
+        if not self.job_queue:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        folder = filedialog.askdirectory(title="Download Again To")
+        if not folder:
+            return
+
+        try:
+            job = redownload(
+                job_id,
+                self.download_history,
+                self.job_queue,
+                self.config_widget.get_config(),
+                {"output_dir": folder},
+                self.config_service,
+            )
+        except ValueError as e:
+            self.status_bar.error(str(e))
+            return
+
+        self._set_busy_state()
+        self.status_bar.log_info(tr("redownload_queued", url=job.url, path=folder))
+
+    def _on_batch_download(self) -> None:
+        """Open the batch download dialog."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        BatchDialog(self, on_start=self._start_batch)
+
+    def _on_import_bookmarks(self) -> None:
+        """Offer the supported albums in a bookmarks file for batch download."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        path = filedialog.askopenfilename(
+            title="Import Bookmarks",
+            filetypes=[("Bookmarks", "*.html *.htm *.json"), ("All files", "*.*")],
+        )
+        if not path:
+            return
+
+        try:
+            candidates = find_supported_bookmarks(Path(path), self.url_input.sources)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(tr("bookmarks_failed", error=e))
+            return
+
+        if not candidates:
+            self.status_bar.warning(tr("no_bookmarks", path=path))
+            return
+
+        self.status_bar.log_info(tr("bookmarks_found", count=len(candidates), path=path))
+        BatchDialog(
+            self,
+            on_start=self._start_batch,
+            urls=[candidate.url for candidate in candidates],
+        )
+
+    def _on_download_artist(self) -> None:
+        """Offer all albums of an artist for batch download."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        url = simpledialog.askstring("Download Artist", "Artist page URL:", parent=self)
+        if not url or not url.strip():
+            return
+        url = url.strip()
+
+        self.status_bar.set_status(f"Listing albums of {url}...")
+        cli_wrapper = self.cli_wrapper
+
+        def list_albums() -> None:
             try:
-                child.config(state=tk.DISABLED)
-            except Exception:
-                pass
+                urls = cli_wrapper.list_albums(url)
+            except (OSError, ValueError, subprocess.TimeoutExpired) as e:
+                message = tr("artist_failed", error=e)
+                self.after(0, lambda: self.status_bar.error(message))
+                return
+            self.after(0, lambda: self._offer_artist_albums(url, urls))
 
-        self.status_bar.log_info(f"Starting download from: {url}")
+        threading.Thread(target=list_albums, daemon=True).start()
 
-        # Start download in background thread
-        self.cli_wrapper.execute_download(
-            url,
-            config,
-            progress_callback=self._on_progress,
-            complete_callback=self._on_download_complete,
+    def _offer_artist_albums(self, url: str, urls: list[str]) -> None:
+        """Open the batch dialog with the albums of an artist.
+
+        Args:
+            url: Artist page URL.
+            urls: Album URLs found on the artist page.
+        """
+        self.status_bar.log_info(tr("artist_albums_found", count=len(urls), url=url))
+        BatchDialog(self, on_start=self._start_batch, urls=urls)
+
+    def _on_follow_artist(self) -> None:
+        """Follow an artist page to be notified of new albums."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        url = simpledialog.askstring("Follow Artist", "Artist page URL:", parent=self)
+        if not url or not url.strip():
+            return
+        url = url.strip()
+
+        auto_download = messagebox.askyesno(
+            "Follow Artist", "Download new albums automatically?", parent=self
         )
+        template = ""
+        if auto_download and self.template_service.list_profiles():
+            template = (
+                simpledialog.askstring(
+                    "Follow Artist",
+                    "Template for new albums (empty for the current settings):",
+                    parent=self,
+                )
+                or ""
+            ).strip()
 
-    def _on_progress(self, line: str) -> None:
-        """Handle CLI output progress.
+        cli_wrapper = self.cli_wrapper
+
+        def follow() -> None:
+            try:
+                urls = cli_wrapper.list_albums(url)
+            except (OSError, ValueError, subprocess.TimeoutExpired) as e:
+                message = tr("artist_failed", error=e)
+                self.after(0, lambda: self.status_bar.error(message))
+                return
+            self.subscription_service.follow(url, urls, auto_download, template)
+            message = tr("artist_followed", url=url, count=len(urls))
+            self.after(0, lambda: self.status_bar.log_info(message))
+
+        threading.Thread(target=follow, daemon=True).start()
+
+    def _fill_subscription_menu(self) -> None:
+        """Rebuild the unfollow submenu from the followed artists."""
+        self.subscription_menu.delete(0, tk.END)
+        subscriptions = self.subscription_service.load()
+        if not subscriptions:
+            self.subscription_menu.add_command(label="(no artists)", state=tk.DISABLED)
+
+        for subscription in subscriptions:
+            self.subscription_menu.add_command(
+                label=subscription.url,
+                command=lambda url=subscription.url: self._on_unfollow_artist(url),
+            )
+
+    def _on_unfollow_artist(self, url: str) -> None:
+        """Stop following an artist page.
 
         Args:
-            line: Line of CLI output.
+            url: Artist page URL.
         """
-        # Parse output
-        result = self.output_parser.parse_line(line)
+        if self.subscription_service.unfollow(url):
+            self.status_bar.log_info(tr("artist_unfollowed", url=url))
 
-        if isinstance(result, SongProgress):
-            # Update progress widget from main thread
-            self.after(0, lambda: self.progress_widget.update_progress(result))
-            self.after(0, lambda: self.progress_widget.scroll_to_bottom())
+    def _check_subscriptions(self) -> None:
+        """Check followed artists for new albums in the background."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
 
-        # Also log the line
-        self.status_bar.log_info(line.strip())
+        cli_wrapper = self.cli_wrapper
 
-    def _on_download_complete(self, exit_code: int) -> None:
-        """Handle download completion.
+        def check() -> None:
+            releases = self.subscription_service.check(cli_wrapper.list_albums)
+            self.after(0, lambda: self._on_new_releases(releases))
+
+        threading.Thread(target=check, daemon=True).start()
+
+    def _on_new_releases(self, releases: list[NewRelease]) -> None:
+        """Announce new albums and enqueue those of auto-downloading subscriptions.
 
         Args:
-            exit_code: Process exit code.
+            releases: New releases found by the last check.
         """
-        if exit_code == 0:
-            self.status_bar.success("Download completed successfully!")
-        else:
-            failed = self.progress_widget.get_failed_songs()
-            if failed:
-                self.status_bar.error(
-                    f"Download completed with errors. {len(failed)} songs failed."
+        for release in releases:
+            subscription = release.subscription
+            self._notify(
+                LogLevel.SUCCESS,
+                tr("new_release", count=len(release.album_urls), url=subscription.url),
+            )
+            if not (subscription.auto_download and self.job_queue):
+                continue
+
+            try:
+                for url in release.album_urls:
+                    if subscription.template:
+                        self.template_service.create_job_from_template(
+                            subscription.template, url, self.job_queue
+                        )
+                    else:
+                        self.job_queue.enqueue(url, self.config_widget.get_config())
+            except ValueError as e:
+                self.status_bar.error(str(e))
+                continue
+            self._set_busy_state()
+
+    def _start_batch(self, urls: list[str], policy: str, max_parallel: int) -> None:
+        """Start a batch download.
+
+        Args:
+            urls: Album URLs to download.
+            policy: Batch policy.
+            max_parallel: Maximum number of albums downloading at once.
+        """
+        try:
+            self.batch = BatchDownload(
+                self._create_cli_wrapper,
+                policy=policy,
+                max_parallel=max_parallel,
+                progress_callback=ProgressThrottle(self._on_batch_progress, PROGRESS_MAX_RATE),
+                job_finished_callback=self._on_batch_job_finished,
+                config_resolver=self._resolve_config,
+                interaction_callback=self._on_interaction_required,
+                start_resolver=self.bandwidth_schedule.apply,
+                can_start=self._can_start_job,
+                adaptive_concurrency=self.adaptive_concurrency,
+            )
+            self.batch.start(urls, self.config_widget.get_config())
+        except ValueError as e:
+            self.status_bar.error(str(e))
+            self.batch = None
+            return
+
+        self._set_busy_state()
+
+    def _on_batch_job_finished(self, job: QueuedJob) -> None:
+        """Report an album of a batch that finished (called from download threads).
+
+        Args:
+            job: The album's job.
+        """
+        notification = self._record_job_result(job)
+        self.after(0, lambda: self._on_download_complete(job, notification))
+
+    def _on_interaction_required(self, request: InteractionRequest) -> None:
+        """Handle a job waiting for input (called from download threads).
+
+        Args:
+            request: What the job needs.
+        """
+        self.after(0, lambda: self._answer_interaction(request))
+
+    def _answer_interaction(self, request: InteractionRequest) -> None:
+        """Ask the user for the input a job waits for and pass it on.
+
+        Args:
+            request: What the job needs.
+        """
+        if request.kind == CONFIRM_TRACK:
+            accept = messagebox.askyesno(tr("interaction_title"), request.prompt, parent=self)
+            owners = [owner for owner in (self.job_queue, self.batch) if owner]
+            delivered = any(
+                owner.confirm_track(request.job_id, request.index, accept) for owner in owners
+            )
+            if not delivered:
+                self.status_bar.warning(tr("interaction_not_delivered"))
+            return
+
+        if request.url:
+            webbrowser.open(request.url)
+
+        message = tr("interaction_required", prompt=request.prompt, url=request.url or "-")
+        confirmed = messagebox.askokcancel(tr("interaction_title"), message, parent=self)
+        response = "done" if confirmed else None
+
+        owners = [owner for owner in (self.job_queue, self.batch) if owner]
+        if not any(owner.submit_interaction(request.job_id, response) for owner in owners):
+            self.status_bar.warning(tr("interaction_not_delivered"))
+
+    def _on_batch_progress(self, progress: BatchProgress) -> None:
+        """Show aggregate batch progress (called from download threads).
+
+        Args:
+            progress: Current batch progress.
+        """
+
+        def update() -> None:
+            done = progress.completed_jobs + progress.failed_jobs
+            self.status_bar.set_status(
+                tr(
+                    "batch_progress",
+                    percent=progress.percent,
+                    done=done,
+                    total=progress.total_jobs,
+                    tracks=progress.tracks_started,
+                    tracks_total=progress.tracks_total,
                 )
-                for title in failed:
-                    self.status_bar.error(f"  - {title}")
-            else:
-                self.status_bar.error(f"Download failed with exit code {exit_code}")
+            )
+            # Show overall progress in the taskbar entry as well
+            self.title(f"{progress.percent}% - {APP_TITLE}")
+            if progress.finished:
+                self.batch = None
+                self.title(APP_TITLE)
+                self._reset_ui_state()
 
-        self._reset_ui_state()
+        self.after(0, update)
 
-    def _on_stop_clicked(self) -> None:
-        """Handle stop button click."""
-        if self.cli_wrapper and self.cli_wrapper.is_running():
-            self.cli_wrapper.stop_download()
-            self.status_bar.warning("Download stopped by user")
-        else:
-            self.status_bar.warning("No download in progress")
+    def _on_save_template(self) -> None:
+        """Save the current settings (without the URL) as a job template."""
+        name = simpledialog.askstring("Save Template", "Template name:", parent=self)
+        if not name or not name.strip():
+            return
 
-        self._reset_ui_state()
+        self.template_service.save_profile(name, self.config_widget.get_config())
+        self.status_bar.log_info(tr("template_saved", name=name.strip()))
+
+    def _fill_template_menu(self) -> None:
+        """Rebuild the template submenu from the saved templates."""
+        self.template_menu.delete(0, tk.END)
+        names = self.template_service.list_profiles()
+        if not names:
+            self.template_menu.add_command(label="(no templates)", state=tk.DISABLED)
+
+        for name in names:
+            self.template_menu.add_command(
+                label=name, command=lambda name=name: self._on_download_with_template(name)
+            )
+
+    def _on_download_with_template(self, template: str) -> None:
+        """Enqueue the current URL using a template's settings.
+
+        Args:
+            template: Template name.
+        """
+        url = self.url_input.get_url()
+        is_valid, error_msg = self.url_input.validate()
+        if not is_valid:
+            self.status_bar.error(tr("invalid_url", error=error_msg))
+            return
+
+        if not self.job_queue:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        if not self._confirm_redownload(url):
+            return
+
+        try:
+            self.template_service.create_job_from_template(template, url, self.job_queue)
+        except ValueError as e:
+            self.status_bar.error(str(e))
+            return
+
+        self.url_input.add_to_history(url)
+        self._set_busy_state()
+
+    def _on_add_source_rule(self) -> None:
+        """Give URLs matching a pattern their own output directory and profile."""
+        pattern = simpledialog.askstring(
+            "Add Source Rule",
+            'URL pattern, e.g. "*izanmei.cc/album/*" (or "re:" and a regular expression):',
+            parent=self,
+        )
+        if not pattern or not pattern.strip():
+            return
+
+        output_dir = filedialog.askdirectory(title="Output directory (cancel to keep)")
+        profile = ""
+        if self.profile_service.list_profiles():
+            profile = (
+                simpledialog.askstring(
+                    "Add Source Rule",
+                    "Profile for matching URLs (empty for the current settings):",
+                    parent=self,
+                )
+                or ""
+            ).strip()
+        settings = simpledialog.askstring(
+            "Add Source Rule",
+            "Settings for matching URLs, e.g. \"delay=2 retries=5\" (empty for none):",
+            parent=self,
+        )
+
+        try:
+            overrides = parse_overrides(settings or "")
+            self.source_rules.add_rule(pattern, output_dir or None, profile or None, overrides)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("source_rule_saved", pattern=pattern.strip()))
+
+    def _fill_source_rule_menu(self) -> None:
+        """Rebuild the source rule submenu from the saved rules."""
+        self.source_rule_menu.delete(0, tk.END)
+        rules = self.source_rules.load()
+        if not rules:
+            self.source_rule_menu.add_command(label="(no rules)", state=tk.DISABLED)
+
+        for rule in rules:
+            settings = " ".join(f"{name}={value}" for name, value in rule.overrides.items())
+            target = " / ".join(
+                item for item in (rule.output_dir, rule.profile, settings) if item
+            )
+            self.source_rule_menu.add_command(
+                label=f"{rule.pattern} → {target}",
+                command=lambda pattern=rule.pattern: self._on_remove_source_rule(pattern),
+            )
+
+    def _on_remove_source_rule(self, pattern: str) -> None:
+        """Remove a source rule.
+
+        Args:
+            pattern: Pattern of the rule.
+        """
+        if self.source_rules.remove_rule(pattern):
+            self.status_bar.log_info(tr("source_rule_removed", pattern=pattern))
+
+    def _resolve_config(self, url: str, config: DownloadConfig) -> DownloadConfig:
+        """Apply the job's library, then the matching source rule, to a job's settings.
+
+        Args:
+            url: URL about to be downloaded.
+            config: Configuration the job was enqueued with.
+
+        Returns:
+            The configuration the job runs with.
+        """
+        return self.source_rules.resolve(url, self.library_service.resolve(url, config))
+
+    def _on_add_library(self) -> None:
+        """Add a library with its own root folder and file settings."""
+        name = simpledialog.askstring("Add Library", 'Library name, e.g. "Music NAS":', parent=self)
+        if not name or not name.strip():
+            return
+
+        root = filedialog.askdirectory(title="Library root folder")
+        if not root:
+            return
+        template = simpledialog.askstring(
+            "Add Library",
+            'Folder below the root for each album, e.g. "{source}/{date}" (empty for the root):',
+            parent=self,
+        )
+        settings = simpledialog.askstring(
+            "Add Library",
+            'Naming and post-processing settings, e.g. "normalize_loudness=true" (empty for none):',
+            parent=self,
+        )
+
+        try:
+            overrides = parse_overrides(settings or "")
+            self.library_service.add_library(name, root, template or "", overrides)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("library_saved", name=name.strip()))
+
+    def _fill_library_menu(self) -> None:
+        """Rebuild the library submenu, marking the active library."""
+        self.library_menu.delete(0, tk.END)
+        active = self.library_service.active_library()
+        self.library_menu.add_command(
+            label=("● " if active is None else "") + "(output directory)",
+            command=lambda: self._on_set_active_library(None),
+        )
+        for library in self.library_service.list_libraries():
+            marker = "● " if active and active.name == library.name else ""
+            self.library_menu.add_command(
+                label=f"{marker}{library.name} → {library.root}",
+                command=lambda name=library.name: self._on_set_active_library(name),
+            )
+
+    def _on_set_active_library(self, name: str | None) -> None:
+        """Switch the library new jobs download into.
+
+        Args:
+            name: Library name, or None for the output directory.
+        """
+        try:
+            self.library_service.set_active_library(name)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("library_active", name=name or tr("no_library")))
+
+    def _fill_remove_library_menu(self) -> None:
+        """Rebuild the library removal submenu from the saved libraries."""
+        self.remove_library_menu.delete(0, tk.END)
+        libraries = self.library_service.list_libraries()
+        if not libraries:
+            self.remove_library_menu.add_command(label="(no libraries)", state=tk.DISABLED)
+
+        for library in libraries:
+            self.remove_library_menu.add_command(
+                label=library.name,
+                command=lambda name=library.name: self._on_remove_library(name),
+            )
+
+    def _on_remove_library(self, name: str) -> None:
+        """Remove a library.
+
+        Args:
+            name: Library name.
+        """
+        if self.library_service.remove_library(name):
+            self.status_bar.log_info(tr("library_removed", name=name))
+
+    def _on_add_speed_limit(self) -> None:
+        """Limit the download speed during a time window."""
+        times = simpledialog.askstring(
+            "Add Speed Limit Window",
+            'Time window, e.g. "09:00-18:00" (may span midnight):',
+            parent=self,
+        )
+        if not times or not times.strip():
+            return
+        speed = simpledialog.askstring(
+            "Add Speed Limit Window",
+            "Speed limit in KB/s (empty for unlimited):",
+            parent=self,
+        )
+        if speed is None:
+            return
+
+        start, _, end = times.partition("-")
+        try:
+            max_speed = int(speed) if speed.strip() else None
+            window = self.bandwidth_schedule.add_window(start, end, max_speed)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("speed_limit_saved", window=window.describe()))
+        self.bandwidth_governor.check()
+
+    def _fill_speed_limit_menu(self) -> None:
+        """Rebuild the speed limit submenu from the saved windows."""
+        self.speed_limit_menu.delete(0, tk.END)
+        windows = self.bandwidth_schedule.load()
+        if not windows:
+            self.speed_limit_menu.add_command(label="(no windows)", state=tk.DISABLED)
+
+        for window in windows:
+            self.speed_limit_menu.add_command(
+                label=window.describe(),
+                command=lambda window=window: self._on_remove_speed_limit(window),
+            )
+
+    def _on_remove_speed_limit(self, window: BandwidthWindow) -> None:
+        """Remove a speed limit window.
+
+        Args:
+            window: The window to remove.
+        """
+        if self.bandwidth_schedule.remove_window(window.start, window.end):
+            self.status_bar.log_info(tr("speed_limit_removed", window=window.describe()))
+            self.bandwidth_governor.check()
+
+    def _on_speed_limit_timer(self) -> None:
+        """Check for a change of the active speed limit window and schedule the next check."""
+        self.bandwidth_governor.check()
+        self.after(GOVERNOR_INTERVAL * 1000, self._on_speed_limit_timer)
+
+    def _on_speed_limit_window_changed(self, window: BandwidthWindow | None) -> None:
+        """Announce the speed limit that applies from now on and pass it to running jobs.
+
+        Args:
+            window: The active window, or None if no window applies.
+        """
+        if window is None:
+            self.status_bar.log_info(tr("speed_limit_ended"))
+        else:
+            self.status_bar.log_info(tr("speed_limit_changed", window=window.describe()))
+        for owner in (self.job_queue, self.batch):
+            if owner:
+                owner.refresh_speed_limit()
+
+    def _on_set_quota(self) -> None:
+        """Limit the data downloaded per day and month."""
+        limits = self.quota_service.load()
+        answers = []
+        for prompt, current in (
+            ("Daily data quota in MB (empty for none):", limits.daily_mb),
+            ("Monthly data quota in MB (empty for none):", limits.monthly_mb),
+        ):
+            answer = simpledialog.askstring(
+                "Set Data Quota", prompt, initialvalue=str(current or ""), parent=self
+            )
+            if answer is None:
+                return
+            answers.append(answer.strip())
+
+        try:
+            daily, monthly = (int(answer) if answer else None for answer in answers)
+            self.quota_service.save(QuotaLimits(daily, monthly))
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("quota_saved"))
+        self._resume_if_quota_allows()
+
+    def _on_set_sounds(self) -> None:
+        """Choose the sounds played when downloads end and the quiet hours."""
+        settings = self.sound_service.load()
+        quiet_hours = f"{settings.quiet_start}-{settings.quiet_end}" if settings.quiet_start else ""
+        answers = []
+        for prompt, current in (
+            (
+                "Sound file for finished downloads (empty for the system bell):",
+                settings.completed_sound,
+            ),
+            (
+                "Sound file for failed downloads (empty for the system bell):",
+                settings.failed_sound,
+            ),
+            ('Quiet hours without sounds, e.g. "22:00-07:00" (empty for none):', quiet_hours),
+        ):
+            answer = simpledialog.askstring(
+                "Set Sounds", prompt, initialvalue=current or "", parent=self
+            )
+            if answer is None:
+                return
+            answers.append(answer.strip())
+
+        completed, failed, quiet = answers
+        start, _, end = quiet.partition("-")
+        try:
+            self.sound_service.save(
+                SoundSettings(
+                    enabled=settings.enabled,
+                    completed_sound=completed or None,
+                    failed_sound=failed or None,
+                    quiet_start=start.strip() or None,
+                    quiet_end=end.strip() or None,
+                )
+            )
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("sounds_saved"))
+
+    def _on_sounds_toggled(self) -> None:
+        """Store whether sounds are played."""
+        settings = dataclasses.replace(self.sound_service.load(), enabled=self.sounds_var.get())
+        try:
+            self.sound_service.save(settings)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("sounds_enabled" if settings.enabled else "sounds_disabled"))
+
+    def _on_set_cleanup_limits(self) -> None:
+        """Limit how long job logs and leftover job files are kept."""
+        policy = self.cleanup_service.load()
+        answers = []
+        for prompt, current in (
+            ("Remove job files older than days (empty to keep):", policy.max_age_days),
+            ("Maximum size of each job folder in MB (empty for none):", policy.max_size_mb),
+        ):
+            answer = simpledialog.askstring(
+                "Set Cleanup Limits", prompt, initialvalue=str(current or ""), parent=self
+            )
+            if answer is None:
+                return
+            answers.append(answer.strip())
+
+        try:
+            max_age, max_size = (int(answer) if answer else None for answer in answers)
+            self.cleanup_service.save(RetentionPolicy(max_age, max_size))
+        except (OSError, ValueError) as e:
+            self.status_bar.error(str(e))
+            return
+        self.status_bar.log_info(tr("cleanup_limits_saved"))
+
+    def _on_clean_up(self) -> None:
+        """Apply the cleanup limits now."""
+        if self.batch or (self.job_queue and self.job_queue.is_active()):
+            self.status_bar.warning(tr("cleanup_busy"))
+            return
+        self._run_cleanup()
+
+    def _run_cleanup(self) -> None:
+        """Remove the job logs and leftover job files the cleanup limits don't keep."""
+        report = self.cleanup_service.run_cleanup(self._cleanup_targets())
+        if report.removed:
+            self.status_bar.log_info(
+                tr("cleanup_done", count=len(report.removed), size=report.freed_bytes / MEGABYTE)
+            )
+
+    def _cleanup_targets(self) -> list[CleanupTarget]:
+        """List the folders the cleanup limits apply to.
+
+        Returns:
+            Job logs, job working directories and the CLI's staged files.
+        """
+        targets = [
+            CleanupTarget(self.job_log_store.log_dir, "*.jsonl"),
+            CleanupTarget(self.job_work_dir),
+        ]
+        # Without a temp folder, files for network shares are staged in the
+        # job's working directory, or the system's temp folder if it couldn't
+        # be created
+        staging_dirs = {Path(tempfile.gettempdir())}
+        temp_dir = self.config_service.load_config().temp_dir
+        if temp_dir:
+            staging_dirs.add(Path(temp_dir))
+        targets.extend(
+            CleanupTarget(folder, STAGED_FILE_PATTERN) for folder in sorted(staging_dirs)
+        )
+        return targets
+
+    def _on_maintenance_timer(self) -> None:
+        """Run the due maintenance tasks and schedule the next check."""
+        self._run_maintenance(self.maintenance.due_tasks())
+        self.after(MAINTENANCE_CHECK_INTERVAL * 1000, self._on_maintenance_timer)
+
+    def _on_run_maintenance(self) -> None:
+        """Run all enabled maintenance tasks now."""
+        if self._maintenance_running:
+            self.status_bar.warning(tr("maintenance_running"))
+            return
+        self._run_maintenance([task.name for task in self.maintenance.load_tasks() if task.enabled])
+
+    def _run_maintenance(self, tasks: list[str]) -> None:
+        """Run maintenance tasks one after another in the background.
+
+        Args:
+            tasks: Names of the tasks to run.
+        """
+        if self._maintenance_running:
+            return
+
+        actions = self._maintenance_actions()
+        busy = bool(self.batch or (self.job_queue and self.job_queue.is_active()))
+        # Cleanup could remove the files of a running job; it runs once idle
+        tasks = [task for task in tasks if task in actions and not (busy and task == CLEANUP)]
+        if not tasks:
+            return
+
+        self._maintenance_running = True
+
+        def run() -> None:
+            runs = [self.maintenance.run(task, actions[task]) for task in tasks]
+            self.after(0, lambda: self._on_maintenance_done(runs))
+
+        threading.Thread(target=run, daemon=True).start()
+
+    def _maintenance_actions(self) -> dict[str, Callable[[], list[str]]]:
+        """Map the maintenance tasks to the functions doing them.
+
+        Called from the main thread; the functions run in the background.
+
+        Returns:
+            Function returning the findings of each task this install can run.
+        """
+        history = self.download_history
+
+        def cleanup() -> list[str]:
+            report = self.cleanup_service.run_cleanup(targets)
+            if report.removed:
+                message = tr(
+                    "cleanup_done", count=len(report.removed), size=report.freed_bytes / MEGABYTE
+                )
+                self.after(0, lambda: self.status_bar.log_info(message))
+            return []
+
+        targets = self._cleanup_targets()
+        actions: dict[str, Callable[[], list[str]]] = {
+            LIBRARY_SCAN: lambda: scan_library(library_folders(history.entries())),
+            CHECKSUM_SAMPLE: lambda: verify_checksum_sample(library_folders(history.entries())),
+            CLEANUP: cleanup,
+        }
+
+        cli_wrapper = self.cli_wrapper
+        if cli_wrapper:
+
+            def check_subscriptions() -> list[str]:
+                releases = self.subscription_service.check(cli_wrapper.list_albums)
+                self.after(0, lambda: self._on_new_releases(releases))
+                return [
+                    tr("new_release", count=len(release.album_urls), url=release.subscription.url)
+                    for release in releases
+                ]
+
+            actions[SUBSCRIPTIONS] = check_subscriptions
+        return actions
+
+    def _on_maintenance_done(self, runs: list[MaintenanceRun]) -> None:
+        """Report the results of maintenance runs.
+
+        New releases are announced by _on_new_releases, so only problems
+        found by the other tasks are notified here.
+
+        Args:
+            runs: Finished runs.
+        """
+        self._maintenance_running = False
+        for run in runs:
+            if run.error:
+                self._notify(
+                    LogLevel.WARNING, tr("maintenance_failed", task=run.task, error=run.error)
+                )
+            elif run.findings and run.task != SUBSCRIPTIONS:
+                self._notify(
+                    LogLevel.WARNING,
+                    tr("maintenance_findings", task=run.task, count=len(run.findings)),
+                )
+                for finding in run.findings:
+                    self.status_bar.log_info(f"  {finding}")
+
+    def _on_show_maintenance_runs(self) -> None:
+        """Show the recent maintenance runs and their findings in the log."""
+        runs = self.maintenance.list_maintenance_runs()[:MAINTENANCE_RUNS_SHOWN]
+        if not runs:
+            self.status_bar.log_info(tr("no_maintenance_runs"))
+
+        for run in runs:
+            self.status_bar.log_info(
+                tr(
+                    "maintenance_run",
+                    finished_at=run.finished_at,
+                    task=run.task,
+                    count=len(run.findings),
+                )
+            )
+            for line in [*run.findings, run.error]:
+                if line:
+                    self.status_bar.log_info(f"  {line}")
+
+    def _can_start_job(self, job: QueuedJob) -> bool:
+        """Check the data quotas before a job starts (called from download threads).
+
+        Args:
+            job: The job about to start.
+
+        Returns:
+            False if a quota has been reached, which pauses the queue.
+        """
+        if self.rate_ban_until is not None and time.monotonic() < self.rate_ban_until:
+            logger.warning(f"Waiting for the rate limit cooldown, job {job.job_id} waits")
+            return False
+
+        lost_volume = self.lost_volume
+        if lost_volume is not None and not volume_available(lost_volume):
+            logger.warning(f"Waiting for volume {lost_volume}, job {job.job_id} waits")
+            return False
+
+        exceeded = self.quota_service.exceeded()
+        if exceeded is None:
+            return True
+
+        logger.warning(f"Data quota reached, job {job.job_id} waits: {exceeded}")
+        self.after(0, lambda: self._on_quota_exceeded(exceeded))
+        return False
+
+    def _on_quota_exceeded(self, exceeded: QuotaExceeded) -> None:
+        """Announce that downloads are paused by a data quota.
+
+        Args:
+            exceeded: The reached quota.
+        """
+        if self.quota_exceeded == exceeded:
+            # Another lane of a batch reached the same quota
+            return
+        self.quota_exceeded = exceeded
+        self._notify(
+            LogLevel.WARNING,
+            tr(
+                "quota_exceeded",
+                period=tr(f"quota_{exceeded.period}"),
+                used=f"{exceeded.used_mb:.0f}",
+                limit=exceeded.limit_mb,
+                resets=exceeded.resets_at.strftime("%Y-%m-%d %H:%M"),
+            ),
+        )
+
+    def _on_rate_banned(self, cooldown: int) -> None:
+        """Hold queued downloads for the cooldown a rate-limiting site asked for.
+
+        Args:
+            cooldown: Suggested cooldown in seconds.
+        """
+        until = time.monotonic() + cooldown
+        if self.rate_ban_until is not None and until <= self.rate_ban_until:
+            return
+        self.rate_ban_until = until
+        self._notify(LogLevel.WARNING, tr("rate_banned", minutes=math.ceil(cooldown / 60)))
+        self.after(cooldown * 1000, lambda: self._end_rate_ban(until))
+
+    def _end_rate_ban(self, until: float) -> None:
+        """Resume downloads held by a rate limit once its cooldown is over.
+
+        Args:
+            until: End of the cooldown this call was scheduled for.
+        """
+        if self.rate_ban_until != until:
+            # A later rate limit extended the cooldown
+            return
+        self.rate_ban_until = None
+        self.status_bar.log_info(tr("rate_ban_over"))
+        if self.job_queue:
+            self.job_queue.resume()
+        if self.batch:
+            self.batch.resume()
+
+    def _on_volume_lost(self, job: QueuedJob, volume: Path) -> None:
+        """Wait for an output volume that disappeared (called from the download thread).
+
+        Args:
+            job: The job that was queued again.
+            volume: Mount point of the lost volume.
+        """
+
+        def update() -> None:
+            already_waiting = self.lost_volume is not None
+            self.lost_volume = volume
+            self._notify(LogLevel.WARNING, tr("volume_lost", path=volume))
+            if not already_waiting:
+                self.after(VOLUME_CHECK_INTERVAL * 1000, self._check_lost_volume)
+
+        self.after(0, update)
+
+    def _on_concurrency_adjusted(self, adjustment: ConcurrencyAdjustment) -> None:
+        """Show a source being slowed down or sped up (called from download threads).
+
+        Args:
+            adjustment: The change of the source's speed.
+        """
+        if adjustment.level == 0:
+            message = tr("concurrency_restored", source=adjustment.source, reason=adjustment.reason)
+            self.after(0, lambda: self.status_bar.log_info(message))
+        else:
+            message = tr(
+                "concurrency_slowed",
+                source=adjustment.source,
+                level=adjustment.level,
+                reason=adjustment.reason,
+            )
+            self.after(0, lambda: self.status_bar.warning(message))
+
+    def _check_lost_volume(self) -> None:
+        """Resume downloads once the lost output volume is back."""
+        if self.lost_volume is None:
+            return
+        if not volume_available(self.lost_volume):
+            self.after(VOLUME_CHECK_INTERVAL * 1000, self._check_lost_volume)
+            return
+
+        self.status_bar.log_info(tr("volume_back", path=self.lost_volume))
+        self.lost_volume = None
+        if self.job_queue:
+            self.job_queue.resume()
+
+    def _on_quota_timer(self) -> None:
+        """Resume paused downloads if allowed and schedule the next check."""
+        self._resume_if_quota_allows()
+        self.after(QUOTA_CHECK_INTERVAL * 1000, self._on_quota_timer)
+
+    def _resume_if_quota_allows(self) -> None:
+        """Resume downloads paused by a quota that no longer applies."""
+        if self.quota_exceeded is None or self.quota_service.exceeded() is not None:
+            return
+
+        self.quota_exceeded = None
+        self.status_bar.log_info(tr("quota_resumed"))
+        if self.job_queue:
+            self.job_queue.resume()
+        if self.batch:
+            self.batch.resume()
+
+    def _confirm_redownload(self, url: str) -> bool:
+        """Ask before downloading an album that was already downloaded.
+
+        Args:
+            url: Album URL about to be downloaded.
+
+        Returns:
+            True if the album is new or the user confirmed downloading it again.
+        """
+        previous = self.download_history.find(url)
+        if previous is None:
+            return True
+
+        self.status_bar.warning(
+            tr("already_downloaded", date=previous.finished_at, location=previous.output_dir)
+        )
+        confirmed = messagebox.askyesno(
+            "Already Downloaded",
+            tr("redownload_prompt", date=previous.finished_at, location=previous.output_dir),
+            parent=self,
+        )
+        if not confirmed:
+            self.status_bar.log_info(tr("redownload_skipped", url=url))
+        return confirmed
+
+    def _on_job_started(self, job: QueuedJob) -> None:
+        """Handle the start of a queued job (called from the download thread).
+
+        Args:
+            job: The job that started.
+        """
+
+        def update() -> None:
+            self.progress_widget.clear()
+            self.output_parser.reset()
+            self.progress_summary.reset()
+            self.status_bar.log_info(tr("download_started", url=job.url))
+
+        self.after(0, update)
+
+    def _on_job_finished(self, job: QueuedJob) -> None:
+        """Handle the end of a queued job (called from the download thread).
+
+        Args:
+            job: The job that finished or was cancelled.
+        """
+        reason = self.cli_wrapper.timeout_reason if self.cli_wrapper else None
+        album = self.output_parser.album
+        title = album.title if album else ""
+        notification = self._record_job_result(job, reason, title)
+        self.after(0, lambda: self._on_download_complete(job, notification, title))
+
+    def _record_job_result(
+        self, job: QueuedJob, timeout_reason: str | None = None, album_title: str = ""
+    ) -> Notification | None:
+        """Store the final result of a job before the GUI is told about it.
+
+        Called from the download thread, so the result survives even if the
+        window is closed before it could show it.
+
+        Args:
+            job: The finished job.
+            timeout_reason: Reason reported by the CLI wrapper if the job timed out.
+            album_title: Album title reported by the CLI, if known.
+
+        Returns:
+            The stored notification, or None for cancelled jobs.
+        """
+        if job.status == JOB_CANCELLED:
+            # Already reported when the user stopped the download
+            return None
+
+        # Recorded before the next job starts, which checks the data quotas
+        completed = job.status == JOB_COMPLETED
+        if completed or job.downloaded_bytes or job.transferred_bytes:
+            self.download_history.record(
+                create_history_entry(
+                    job.url,
+                    job.config.output_dir,
+                    album_title,
+                    job.job_id,
+                    job.downloaded_bytes,
+                    completed,
+                    dataclasses.asdict(job.config),
+                    job.note,
+                    job.metadata,
+                    job.environment,
+                    job.transferred_bytes,
+                    job.track_bytes,
+                )
+            )
+
+        exit_code = job.exit_code
+        if exit_code == 0:
+            level, message = LogLevel.SUCCESS, tr("download_completed")
+        elif exit_code == JOB_TIMEOUT_EXIT_CODE:
+            reason = timeout_reason or tr("time_limit_exceeded")
+            level, message = LogLevel.ERROR, tr("download_timed_out", reason=reason)
+        else:
+            level, message = LogLevel.ERROR, tr("download_failed", exit_code=exit_code)
+
+        return self.notification_store.add(
+            level.value.lower(), f"{message} ({album_title or job.url})", delivered=False
+        )
+
+    def report_migrations(self, results: list[MigrationResult]) -> None:
+        """Show the upgrades of stored data done at startup.
+
+        Args:
+            results: Results of the upgrades, from MigrationRunner.run.
+        """
+        for result in results:
+            if result.error:
+                message = tr("migration_failed", target=result.target, error=result.error)
+                self._notify(LogLevel.ERROR, message)
+                continue
+            if result.skipped:
+                message = tr(
+                    "migration_skipped",
+                    target=result.target,
+                    count=len(result.skipped),
+                    files="; ".join(result.skipped),
+                )
+                self._notify(LogLevel.WARNING, message)
+            if not result.files:
+                continue
+            self.status_bar.log_info(
+                tr(
+                    "migration_done",
+                    target=result.target,
+                    version=result.to_version,
+                    count=len(result.files),
+                )
+            )
+
+    def open_url(self, url: str, output_dir: str | None = None, autostart: bool = False) -> None:
+        """Prefill a download, e.g. from launch arguments.
+
+        Args:
+            url: Album URL to download.
+            output_dir: Optional output directory overriding the configured one.
+            autostart: Start the download immediately if the URL is valid.
+        """
+        self.url_input.set_url(url)
+
+        if output_dir:
+            config = dataclasses.replace(self.config_widget.get_config(), output_dir=output_dir)
+            self.config_widget.set_config(config)
+
+        logger.info(f"Opened URL: {url} (autostart: {autostart})")
+        if autostart:
+            self._on_download_clicked()
+
+    def _on_progress(self, line: str) -> None:
+        """Handle CLI output progress.
+
+        Args:
+            line: Line of CLI output.
+        """
+        # Parse output
+        result = self.output_parser.parse_line(line)
+
+        if isinstance(result, SongProgress):
+            # Update progress widget from main thread
+            self.after(0, lambda: self.progress_widget.update_progress(result))
+            self.after(0, lambda: self.progress_widget.scroll_to_bottom())
+            self.progress_summary.update(result)
+        elif isinstance(result, dict) and result.get("type") == "merge_progress":
+            self.merge_status(tr("merge_progress", percent=result["percent"]))
+        elif isinstance(result, dict) and result.get("type") == "processed":
+            self.merge_status(tr("processed_progress", done=result["done"], total=result["total"]))
+        elif isinstance(result, dict) and result.get("type") == "scan_failed":
+            message = tr("scan_failed", title=result["title"], reason=result["reason"])
+            self.after(0, lambda: self._notify(LogLevel.WARNING, message))
+        elif isinstance(result, dict) and result.get("type") == "wrong_content_type":
+            message = tr("wrong_content_type", title=result["title"], reason=result["reason"])
+            self.after(0, lambda: self._notify(LogLevel.WARNING, message))
+        elif isinstance(result, dict) and result.get("type") == "sanity_failed":
+            message = tr("sanity_failed", title=result["title"], reason=result["reason"])
+            self.after(0, lambda: self._notify(LogLevel.WARNING, message))
+        elif isinstance(result, dict) and result.get("type") == "rate_banned":
+            cooldown = result["cooldown"]
+            self.after(0, lambda: self._on_rate_banned(cooldown))
+        elif isinstance(result, dict) and result.get("type") == "mount_lost":
+            self.after(0, lambda: self._notify(LogLevel.WARNING, tr("mount_lost")))
+        elif isinstance(result, dict) and result.get("type") == "mount_restored":
+            self.after(0, lambda: self._notify(LogLevel.SUCCESS, tr("mount_restored")))
+
+        # Also log the line (collected by _flush_output)
+        self.output_buffer.put(line.strip())
+
+    def _show_merge_status(self, message: str) -> None:
+        """Show the merge progress in the status line.
+
+        Args:
+            message: Localized merge progress message.
+        """
+        self.after(0, lambda: self.status_bar.set_status(message))
+
+    def _show_progress_summary(self, summary: str) -> None:
+        """Show a progress summary in the status line.
+
+        Args:
+            summary: Localized summary of the running download.
+        """
+        self.after(0, lambda: self.status_bar.set_status(summary))
+
+    def _flush_output(self) -> None:
+        """Show buffered CLI output in the log and schedule the next flush."""
+        lines, dropped = self.output_buffer.drain()
+        if dropped:
+            self.status_bar.warning(tr("output_dropped", count=dropped))
+        for line in lines:
+            self.status_bar.log_info(line)
+        self.after(OUTPUT_FLUSH_INTERVAL, self._flush_output)
+
+    def _on_download_complete(
+        self, job: QueuedJob, notification: Notification | None, album_title: str = ""
+    ) -> None:
+        """Handle download completion.
+
+        Args:
+            job: The finished job.
+            notification: The job's stored result, or None if it was cancelled.
+            album_title: Album title reported by the CLI, if known.
+        """
+        if notification:
+            self._deliver_notification(notification)
+            self.sound_service.play(SOUND_COMPLETED if job.exit_code == 0 else SOUND_FAILED)
+            failed = self.progress_widget.get_failed_songs()
+            if job.exit_code != 0 and failed:
+                self.status_bar.error(tr("download_failed_songs", count=len(failed)))
+                for title in failed:
+                    self.status_bar.error(f"  - {title}")
+
+        if not (self.job_queue and self.job_queue.is_active()) and not self.batch:
+            self._reset_ui_state()
+
+    def _on_stop_clicked(self) -> None:
+        """Handle stop button click."""
+        if self.batch or (self.job_queue and self.job_queue.is_active()):
+            if self.batch:
+                self.batch.cancel()
+            if self.job_queue:
+                self.job_queue.cancel_all()
+            self.status_bar.warning(tr("download_stopped"))
+        else:
+            self.status_bar.warning(tr("no_download"))
+
+        self._reset_ui_state()
+
+    def _on_export_settings(self) -> None:
+        """Export settings and profiles to a file chosen by the user."""
+        path = filedialog.asksaveasfilename(
+            title="Export Settings",
+            defaultextension=".json",
+            filetypes=[("JSON files", "*.json")],
+        )
+        if not path:
+            return
+
+        try:
+            export_config(Path(path), self.config_service, self.profile_service)
+            self.status_bar.success(tr("settings_exported", path=path))
+        except OSError as e:
+            self.status_bar.error(tr("settings_export_failed", error=e))
+
+    def _on_export_debug_bundle(self) -> None:
+        """Export logs, diagnostics and settings for a bug report."""
+        path = filedialog.asksaveasfilename(
+            title="Export Debug Bundle",
+            defaultextension=".zip",
+            initialfile="resource-fetcher-debug.zip",
+            filetypes=[("Zip files", "*.zip")],
+        )
+        if not path:
+            return
+
+        try:
+            export_debug_bundle(
+                Path(path),
+                self.config_service,
+                self.job_log_store,
+                self.cli_path,
+                work_root=self.job_work_dir,
+            )
+            self.status_bar.success(tr("debug_bundle_exported", path=path))
+        except OSError as e:
+            self.status_bar.error(tr("debug_bundle_failed", error=e))
+
+    def _on_import_settings(self) -> None:
+        """Import settings and profiles from a file chosen by the user."""
+        path = filedialog.askopenfilename(
+            title="Import Settings",
+            filetypes=[("JSON files", "*.json")],
+        )
+        if not path:
+            return
+
+        try:
+            config = import_config(Path(path), self.config_service, self.profile_service)
+        except (OSError, ValueError) as e:
+            self.status_bar.error(tr("settings_import_failed", error=e))
+            return
+
+        self.config_widget.set_config(config)
+        self.config_widget.refresh_profiles()
+        self._apply_log_level(config)
+        self.status_bar.success(tr("settings_imported", path=path))
+
+    def _on_create_backup(self) -> None:
+        """Back up the whole application state to a file chosen by the user."""
+        path = filedialog.asksaveasfilename(
+            title="Back Up Everything",
+            defaultextension=".zip",
+            initialfile="resource-fetcher-backup.zip",
+            filetypes=[("Zip files", "*.zip")],
+        )
+        if not path:
+            return
+
+        try:
+            info = create_backup(Path(path))
+        except OSError as e:
+            self.status_bar.error(tr("backup_failed", error=e))
+            return
+
+        self._report_credentials(info)
+        self.status_bar.success(tr("backup_created", count=len(info.files), path=path))
+
+    def _on_restore_backup(self) -> None:
+        """Restore the application state from a backup chosen by the user."""
+        # Running jobs would write to the replaced history and settings
+        if self.batch or (self.job_queue and self.job_queue.is_active()):
+            self.status_bar.warning(tr("backup_restore_busy"))
+            return
+
+        path = filedialog.askopenfilename(
+            title="Restore Backup",
+            filetypes=[("Zip files", "*.zip")],
+        )
+        if not path:
+            return
+
+        try:
+            info = restore_backup(Path(path))
+        except (OSError, ValueError) as e:
+            self.status_bar.error(tr("backup_restore_failed", error=e))
+            return
+
+        self._report_credentials(info)
+        message = tr("backup_restored", count=len(info.files), path=path)
+        self.status_bar.success(message)
+        messagebox.showinfo("Restore Backup", message, parent=self)
+
+    def _report_credentials(self, info: BackupInfo) -> None:
+        """Remind the user of credential files that have to be copied by hand.
+
+        Args:
+            info: Backup that was written or restored.
+        """
+        for name, path in info.credentials.items():
+            self.status_bar.warning(tr("backup_credentials", name=name, path=path))
+
+    def _notify(self, level: LogLevel, message: str, subject: str = "") -> None:
+        """Show an important event and keep it in the notification center.
+
+        Args:
+            level: Severity of the event.
+            message: Message shown in the status bar.
+            subject: Optional album or URL the event is about, added to the
+                stored notification.
+        """
+        self.status_bar.log(level, message)
+        self.notification_store.add(
+            level.value.lower(), f"{message} ({subject})" if subject else message
+        )
+        self._update_notifications_button()
+
+    def _deliver_notification(self, notification: Notification) -> None:
+        """Show a stored notification and acknowledge that it was shown.
+
+        Args:
+            notification: Notification stored with delivered=False.
+        """
+        self.status_bar.log(LogLevel(notification.severity.upper()), notification.message)
+        self.notification_store.mark_delivered(notification.notification_id)
+        self._update_notifications_button()
+
+    def _deliver_pending_notifications(self) -> None:
+        """Show job results that were stored but never shown, e.g. after a crash."""
+        pending = self.notification_store.undelivered()
+        if not pending:
+            return
+
+        self.status_bar.warning(tr("undelivered_results", count=len(pending)))
+        for notification in pending:
+            self._deliver_notification(notification)
+
+    def _restore_session(self) -> None:
+        """Rebuild the job queue saved by the previous session."""
+        if not self.job_queue:
+            return
+
+        state = self.session_store.load()
+        if not state.jobs:
+            return
+
+        restored = self.job_queue.restore(state)
+        if restored:
+            self._set_busy_state()
+            self.status_bar.log_info(tr("session_restored", count=restored))
+
+    def _update_notifications_button(self) -> None:
+        """Show the number of unread notifications on the button."""
+        unread = self.notification_store.unread_count()
+        label = f"Notifications ({unread})" if unread else "Notifications"
+        self.notifications_btn.config(text=label)
+
+    def _on_notifications_clicked(self) -> None:
+        """Show the notification center."""
+        window = tk.Toplevel(self)
+        window.title("Notifications")
+        window.geometry("700x400")
+
+        button_frame = ttk.Frame(window, padding=5)
+        button_frame.pack(side=tk.BOTTOM, fill=tk.X)
+
+        text = scrolledtext.ScrolledText(window, font=("Consolas", 9), wrap=tk.WORD)
+        text.pack(fill=tk.BOTH, expand=True)
+        text.tag_config("unread", font=("Consolas", 9, "bold"))
+
+        def fill() -> None:
+            text.config(state=tk.NORMAL)
+            text.delete(1.0, tk.END)
+            notifications = self.notification_store.list_notifications()
+            if not notifications:
+                text.insert(tk.END, tr("no_notifications") + "\n")
+            for item in notifications:
+                line = f"[{item.created_at}] [{item.severity.upper()}] {item.message}"
+                text.insert(tk.END, line + "\n", () if item.read else ("unread",))
+            text.config(state=tk.DISABLED)
+            self._update_notifications_button()
+
+        def mark_all_read() -> None:
+            self.notification_store.mark_read()
+            fill()
+
+        def clear() -> None:
+            self.notification_store.clear()
+            fill()
+
+        ttk.Button(button_frame, text="Mark All Read", command=mark_all_read).pack(
+            side=tk.LEFT, padx=(0, 5)
+        )
+        ttk.Button(button_frame, text="Clear", command=clear).pack(side=tk.LEFT)
+        fill()
+
+    def _on_crash_reports_toggled(self) -> None:
+        """Store the user's choice about crash reports."""
+        enabled = self.crash_reports_var.get()
+        try:
+            self.crash_reporter.set_enabled(enabled)
+        except OSError as e:
+            self.status_bar.error(tr("crash_reports_failed", error=e))
+            return
+        key = "crash_reports_enabled" if enabled else "crash_reports_disabled"
+        self.status_bar.log_info(tr(key))
+
+    def _on_open_crash_reports(self) -> None:
+        """Open the crash reports folder in the file manager."""
+        try:
+            self._open_folder(self.crash_reporter.crash_dir)
+        except OSError as e:
+            self.status_bar.error(tr("crash_reports_failed", error=e))
+
+    def _on_show_app_paths(self) -> None:
+        """Show where settings, data, cache and logs are kept."""
+        lines = [f"{tr(f'app_path_{name}')}: {path}" for name, path in get_app_paths().items()]
+        messagebox.showinfo(tr("app_paths_title"), "\n".join(lines), parent=self)
+
+    def _on_open_custom_sources(self) -> None:
+        """Open the folder for custom source definitions in the file manager."""
+        try:
+            self._open_folder(self.custom_sources_dir)
+        except OSError as e:
+            self.status_bar.error(tr("custom_sources_failed", error=e))
+            return
+        self.status_bar.log_info(tr("custom_sources_hint", path=self.custom_sources_dir))
+
+    def _open_folder(self, folder: Path) -> None:
+        """Create a folder if needed and open it in the file manager.
+
+        Args:
+            folder: Folder to open.
+
+        Raises:
+            OSError: If the folder can't be created or opened.
+        """
+        folder.mkdir(parents=True, exist_ok=True)
+        if sys.platform == "win32":
+            os.startfile(folder)
+        elif sys.platform == "darwin":
+            subprocess.Popen(["open", str(folder)])
+        else:
+            subprocess.Popen(["xdg-open", str(folder)])
+
+    def _on_run_self_test(self) -> None:
+        """Download a known track in the background to check the setup."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        self.status_bar.set_status(tr("self_test_running"))
+        # A separate wrapper, so a running download isn't affected
+        cli_wrapper = self._create_cli_wrapper()
+
+        def self_test() -> None:
+            stages = run_self_test(cli_wrapper)
+            self.after(0, lambda: self._on_self_test_finished(stages))
+
+        threading.Thread(target=self_test, daemon=True).start()
+
+    def _on_self_test_finished(self, stages: list[SelfTestStage]) -> None:
+        """Report the result of each self-test stage.
+
+        Args:
+            stages: Stages that ran, in order.
+        """
+        for stage in stages:
+            result = "OK" if stage.passed else "FAILED"
+            detail = f" - {stage.message}" if stage.message else ""
+            self.status_bar.log_info(f"  {stage.name}: {result}{detail}")
+
+        failed = next((stage for stage in stages if not stage.passed), None)
+        if failed:
+            self.status_bar.error(
+                tr("self_test_failed", stage=failed.name, message=failed.message)
+            )
+        else:
+            self.status_bar.success(tr("self_test_passed"))
+
+    def _on_benchmark(self) -> None:
+        """Measure the download speed and offer the suggested parallel songs."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        url = simpledialog.askstring(
+            "Benchmark Connection", "URL of a test file to download:", parent=self
+        )
+        if not url or not url.strip():
+            return
+        url = url.strip()
+
+        self.status_bar.set_status(tr("benchmark_running"))
+        cli_wrapper = self._create_cli_wrapper()
+
+        def benchmark() -> None:
+            try:
+                result = cli_wrapper.benchmark_connection(url)
+            except (OSError, ValueError, subprocess.TimeoutExpired) as e:
+                message = tr("benchmark_failed", error=e)
+                self.after(0, lambda: self.status_bar.error(message))
+                return
+            self.after(0, lambda: self._on_benchmark_finished(result))
+
+        threading.Thread(target=benchmark, daemon=True).start()
+
+    def _on_benchmark_finished(self, result: BenchmarkResult) -> None:
+        """Show benchmark results and offer the suggested parallel songs.
+
+        Args:
+            result: Speeds measured by the CLI.
+        """
+        for streams, speed in sorted(result.speeds.items()):
+            self.status_bar.log_info(tr("benchmark_speed", streams=streams, speed=speed))
+
+        config = self.config_widget.get_config()
+        if result.suggested_workers == config.workers:
+            self.status_bar.success(tr("benchmark_workers_kept", workers=config.workers))
+            return
+
+        if messagebox.askyesno(
+            "Benchmark Connection",
+            tr("benchmark_workers_prompt", workers=result.suggested_workers),
+            parent=self,
+        ):
+            config = dataclasses.replace(config, workers=result.suggested_workers)
+            self.config_widget.set_config(config)
+            self._on_config_changed(config)
+
+    def _on_open_console(self) -> None:
+        """Open a console running CLI commands composed by the user."""
+        if not self.cli_wrapper:
+            self.status_bar.error(tr("cli_not_found"))
+            return
+
+        # A separate wrapper, so the console can't stop or clash with the queue
+        cli_wrapper = self._create_cli_wrapper()
+
+        def show_line(line: str) -> None:
+            def append() -> None:
+                if dialog.winfo_exists():
+                    dialog.append_output(line)
+
+            self.after(0, append)
+
+        def finished(exit_code: int) -> None:
+            def report() -> None:
+                if dialog.winfo_exists():
+                    dialog.append_output(tr("console_exited", code=exit_code))
+                    dialog.set_running(False)
+
+            self.after(0, report)
+
+        def run(args: list[str]) -> None:
+            try:
+                cli_wrapper.execute_command(
+                    args, progress_callback=show_line, complete_callback=finished
+                )
+            except (OSError, ValueError, RuntimeError) as e:
+                dialog.append_output(tr("console_failed", error=e))
+                return
+            dialog.set_running(True)
+
+        dialog = ConsoleDialog(self, on_run=run, on_stop=cli_wrapper.stop_download)
+
+    def report_callback_exception(
+        self, exc: type[BaseException], val: BaseException, tb: TracebackType | None
+    ) -> None:
+        """Report exceptions raised in Tk callbacks.
+
+        Args:
+            exc: Exception class.
+            val: The exception.
+            tb: Traceback of the exception.
+        """
+        logger.error("Unhandled exception in GUI callback", exc_info=(exc, val, tb))
+        self.crash_reporter.write_report(exc, val, tb)
+
+    def _on_view_log_clicked(self) -> None:
+        """Show the log of the most recent download job."""
+        job_id = self.cli_wrapper.current_job_id if self.cli_wrapper else None
+        if not job_id:
+            self.status_bar.warning(tr("no_job_log"))
+            return
+
+        window = tk.Toplevel(self)
+        window.title(f"Download Log - {job_id}")
+        window.geometry("700x400")
+
+        log_text = scrolledtext.ScrolledText(window, font=("Consolas", 9), wrap=tk.WORD)
+        log_text.pack(fill=tk.BOTH, expand=True)
+        for event in self.job_log_store.read(job_id):
+            line = f"[{event.timestamp}] [{event.severity.upper()}] {event.message}"
+            log_text.insert(tk.END, line + "\n")
+        log_text.config(state=tk.DISABLED)
+
+    def _set_busy_state(self) -> None:
+        """Disable editing while jobs are running."""
+        self.download_btn.config(state=tk.DISABLED)
+        self.stop_btn.config(state=tk.NORMAL)
+        for child in self.config_widget.winfo_children():
+            try:
+                child.config(state=tk.DISABLED)
+            except Exception:
+                pass
 
     def _reset_ui_state(self) -> None:
         """Reset UI to ready state."""
@@ -8,6 +8,9 @@ from tkinter import scrolledtext, ttk
 
 logger = logging.getLogger(__name__)
 
+# Lines kept in the log area; the oldest are removed beyond this
+MAX_LOG_LINES = 2000
+
 
 class LogLevel(Enum):
     """Log levels for status bar."""
@@ -101,6 +104,12 @@ class StatusBar(ttk.Frame):
         """
         self.log_text.config(state=tk.NORMAL)
         self.log_text.insert(tk.END, message + "\n", level.value)
+
+        # Keep memory flat during long verbose runs
+        lines = int(self.log_text.index("end-1c").split(".")[0]) - 1
+        if lines > MAX_LOG_LINES:
+            self.log_text.delete("1.0", f"{lines - MAX_LOG_LINES + 1}.0")
+
         self.log_text.see(tk.END)
         self.log_text.config(state=tk.DISABLED)
 
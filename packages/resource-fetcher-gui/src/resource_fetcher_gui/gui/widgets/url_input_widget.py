@@ -7,6 +7,8 @@ from tkinter import ttk
 
 import pyperclip
 
+from resource_fetcher_gui.gui.core.url_validation import SupportedSource, validate_url
+
 logger = logging.getLogger(__name__)
 
 
@@ -28,6 +30,7 @@ class URLInputWidget(ttk.LabelFrame):
 
         self.on_url_change = on_url_change
         self.url_history: list[str] = []
+        self.sources: list[SupportedSource] | None = None
 
         self._create_widgets()
         logger.debug("URLInputWidget initialized")
@@ -57,6 +60,12 @@ class URLInputWidget(ttk.LabelFrame):
         )
         paste_btn.pack(side=tk.LEFT)
 
+        # Detected source and content kind, or why the URL can't be used
+        self.detected_var = tk.StringVar()
+        ttk.Label(self, textvariable=self.detected_var, foreground="gray").pack(
+            fill=tk.X, pady=(3, 0)
+        )
+
         # History dropdown
         history_frame = ttk.Frame(self)
         history_frame.pack(fill=tk.X, pady=(5, 0))
@@ -72,6 +81,17 @@ class URLInputWidget(ttk.LabelFrame):
         self.history_combo.pack(side=tk.LEFT, fill=tk.X, expand=True, padx=(5, 0))
         self.history_combo.bind("<<ComboboxSelected>>", self._on_history_selected)
 
+        # Note kept with the download in the history
+        note_frame = ttk.Frame(self)
+        note_frame.pack(fill=tk.X, pady=(5, 0))
+
+        ttk.Label(note_frame, text="Note:").pack(side=tk.LEFT)
+
+        self.note_var = tk.StringVar()
+        ttk.Entry(note_frame, textvariable=self.note_var).pack(
+            side=tk.LEFT, fill=tk.X, expand=True, padx=(5, 0)
+        )
+
     def _on_url_changed(self, *args: object) -> None:
         """Handle URL text change.
 
@@ -79,9 +99,35 @@ class URLInputWidget(ttk.LabelFrame):
             *args: Event arguments (unused).
         """
         url = self.url_var.get().strip()
+        self._show_detected(url)
         if self.on_url_change:
             self.on_url_change(url)
 
+    def _show_detected(self, url: str) -> None:
+        """Show what the URL points to below the entry.
+
+        Args:
+            url: Current URL text.
+        """
+        if not url:
+            self.detected_var.set("")
+            return
+
+        result = validate_url(url, self.sources)
+        if result.supported:
+            self.detected_var.set(f"{result.source}: {result.kind or 'page'}")
+        else:
+            self.detected_var.set(result.error)
+
+    def set_sources(self, sources: list[SupportedSource]) -> None:
+        """Check URLs against the sites the installed CLI supports.
+
+        Args:
+            sources: Sites reported by the CLI.
+        """
+        self.sources = sources
+        self._show_detected(self.url_var.get().strip())
+
     def _paste_from_clipboard(self) -> None:
         """Paste URL from clipboard."""
         try:
@@ -107,9 +153,17 @@ class URLInputWidget(ttk.LabelFrame):
         """Get current URL.
 
         Returns:
-            Current URL string.
+            Current URL, normalized if it belongs to a supported site.
+        """
+        return validate_url(self.url_var.get(), self.sources).url
+
+    def get_note(self) -> str:
+        """Get the note for the download.
+
+        Returns:
+            Note text without surrounding whitespace.
         """
-        return self.url_var.get().strip()
+        return self.note_var.get().strip()
 
     def set_url(self, url: str) -> None:
         """Set URL.
@@ -143,12 +197,8 @@ class URLInputWidget(ttk.LabelFrame):
         Returns:
             Tuple of (is_valid, error_message).
         """
-        url = self.get_url()
-
-        if not url:
-            return False, "URL cannot be empty"
-
-        if not url.startswith(("http://", "https://")):
-            return False, "URL must start with http:// or https://"
+        result = validate_url(self.url_var.get(), self.sources)
+        if not result.valid:
+            return False, result.error
 
         return True, ""
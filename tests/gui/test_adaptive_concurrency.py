@@ -0,0 +1,85 @@
+"""Unit tests for adaptive per-source concurrency."""
+
+from resource_fetcher_gui.gui.core.adaptive_concurrency import (
+    CONCURRENCY_ADJUSTED,
+    DELAY_STEP,
+    MAX_LEVEL,
+    RAMP_UP_AFTER,
+    AdaptiveConcurrency,
+    ConcurrencyAdjustment,
+    source_of,
+)
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+
+URL = "https://www.izanmei.cc/album/hymns-442-1.html"
+OTHER_URL = "https://example.com/album/1"
+
+
+class TestAdaptiveConcurrency:
+    """Test slowing sources down and speeding them up again."""
+
+    def test_source_of(self) -> None:
+        """Test that sources are identified by host name."""
+        assert source_of("https://WWW.Izanmei.cc/album/1") == "www.izanmei.cc"
+        assert source_of("not a url") == "not a url"
+
+    def test_full_speed_keeps_config(self) -> None:
+        """Test that jobs of sources without problems run unchanged."""
+        adaptive = AdaptiveConcurrency()
+        config = DownloadConfig(workers=4, delay=0.5)
+
+        assert adaptive.record(URL, track_count=10, failed_tracks=1, rate_banned=False) is None
+        assert adaptive.apply(URL, config) is config
+
+    def test_failures_slow_source_down(self) -> None:
+        """Test that a high failure rate halves workers and lengthens the delay."""
+        adjustments: list[ConcurrencyAdjustment] = []
+        adaptive = AdaptiveConcurrency(adjustments.append)
+
+        adjustment = adaptive.record(URL, track_count=10, failed_tracks=5, rate_banned=False)
+
+        assert adjustment == ConcurrencyAdjustment("www.izanmei.cc", 1, "5 of 10 tracks failed")
+        assert adjustment.type == CONCURRENCY_ADJUSTED
+        assert adjustments == [adjustment]
+        config = adaptive.apply(URL, DownloadConfig(workers=4, delay=0.5))
+        assert config.workers == 2
+        assert config.delay == 0.5 + DELAY_STEP
+        assert adaptive.level(OTHER_URL) == 0
+
+    def test_rate_limit_slows_down_to_max_level(self) -> None:
+        """Test that rate limits raise the level up to its maximum."""
+        adaptive = AdaptiveConcurrency()
+
+        for _ in range(MAX_LEVEL + 2):
+            adaptive.record(URL, track_count=0, failed_tracks=0, rate_banned=True)
+
+        assert adaptive.level(URL) == MAX_LEVEL
+        assert adaptive.apply(URL, DownloadConfig(workers=4)).workers == 1
+
+    def test_clean_jobs_ramp_up(self) -> None:
+        """Test that a slowed source speeds up a step after enough clean jobs."""
+        adaptive = AdaptiveConcurrency()
+        adaptive.record(URL, track_count=0, failed_tracks=0, rate_banned=True)
+        adaptive.record(URL, track_count=0, failed_tracks=0, rate_banned=True)
+
+        results = [
+            adaptive.record(URL, track_count=10, failed_tracks=0, rate_banned=False)
+            for _ in range(RAMP_UP_AFTER)
+        ]
+
+        assert results[:-1] == [None] * (RAMP_UP_AFTER - 1)
+        assert results[-1] is not None
+        assert results[-1].level == 1
+        assert adaptive.level(URL) == 1
+
+    def test_problem_resets_clean_jobs(self) -> None:
+        """Test that a problem in between restarts the count of clean jobs."""
+        adaptive = AdaptiveConcurrency()
+        adaptive.record(URL, track_count=0, failed_tracks=0, rate_banned=True)
+        for _ in range(RAMP_UP_AFTER - 1):
+            adaptive.record(URL, track_count=10, failed_tracks=0, rate_banned=False)
+
+        adaptive.record(URL, track_count=0, failed_tracks=0, rate_banned=True)
+        adaptive.record(URL, track_count=10, failed_tracks=0, rate_banned=False)
+
+        assert adaptive.level(URL) == 2
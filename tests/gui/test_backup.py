@@ -0,0 +1,104 @@
+"""Unit tests for backup and restore of the application state."""
+
+import json
+import zipfile
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.backup import (
+    BACKUP_MANIFEST,
+    BACKUP_VERSION,
+    create_backup,
+    read_backup_info,
+    restore_backup,
+)
+
+
+class TestBackup:
+    """Test writing and restoring backups."""
+
+    @pytest.fixture
+    def machine(self, tmp_path: Path) -> tuple[Path, Path]:
+        """Create settings and data directories with some state."""
+        config_dir, data_dir = tmp_path / "old" / "config", tmp_path / "old" / "data"
+        config_dir.mkdir(parents=True)
+        data_dir.mkdir(parents=True)
+        settings = {"output_dir": "/music", "cookies_file": "/secrets/cookies.txt"}
+        (config_dir / "config.json").write_text(json.dumps(settings), encoding="utf-8")
+        (config_dir / "subscriptions.json").write_text("[]", encoding="utf-8")
+        (data_dir / "history.jsonl").write_text('{"url": "u"}\n', encoding="utf-8")
+        (data_dir / "session.json").write_text("{}", encoding="utf-8")
+        return config_dir, data_dir
+
+    def test_backup_contains_state(self, tmp_path: Path, machine: tuple[Path, Path]) -> None:
+        """Test that state files are archived and the cookies file only referenced."""
+        path = tmp_path / "backup.zip"
+
+        info = create_backup(path, *machine)
+
+        assert info.files == [
+            "config/config.json",
+            "config/subscriptions.json",
+            "data/history.jsonl",
+        ]
+        assert info.credentials == {"cookies_file": "/secrets/cookies.txt"}
+        with zipfile.ZipFile(path) as archive:
+            assert sorted(archive.namelist()) == sorted([*info.files, BACKUP_MANIFEST])
+        assert read_backup_info(path) == info
+
+    def test_restore_on_another_machine(self, tmp_path: Path, machine: tuple[Path, Path]) -> None:
+        """Test that a backup recreates the state and leaves other files alone."""
+        path = tmp_path / "backup.zip"
+        create_backup(path, *machine)
+        config_dir, data_dir = tmp_path / "new" / "config", tmp_path / "new" / "data"
+        config_dir.mkdir(parents=True)
+        (config_dir / "config.json").write_text("{}", encoding="utf-8")
+        (config_dir / "profiles.json").write_text('{"Keep": {}}', encoding="utf-8")
+
+        info = restore_backup(path, config_dir, data_dir)
+
+        assert len(info.files) == 3
+        assert json.loads((config_dir / "config.json").read_text())["output_dir"] == "/music"
+        assert (config_dir / "profiles.json").read_text() == '{"Keep": {}}'
+        assert (data_dir / "history.jsonl").read_text() == '{"url": "u"}\n'
+        assert not (data_dir / "session.json").exists()
+
+    def test_restore_rejects_unknown_files(self, tmp_path: Path) -> None:
+        """Test that nothing is restored from an archive naming other files."""
+        path = tmp_path / "backup.zip"
+        manifest = {"version": BACKUP_VERSION, "files": ["config/config.json", "../evil"]}
+        with zipfile.ZipFile(path, "w") as archive:
+            archive.writestr(BACKUP_MANIFEST, json.dumps(manifest))
+            archive.writestr("config/config.json", "{}")
+            archive.writestr("../evil", "x")
+
+        with pytest.raises(ValueError, match="unknown files"):
+            restore_backup(path, tmp_path / "config", tmp_path / "data")
+        assert not (tmp_path / "config").exists()
+
+    @pytest.mark.parametrize(
+        "manifest",
+        [
+            None,
+            "not json",
+            json.dumps({"version": BACKUP_VERSION + 1}),
+            json.dumps({"files": [["config/config.json"]]}),
+            json.dumps({"files": "config/config.json"}),
+        ],
+    )
+    def test_invalid_backup(self, tmp_path: Path, manifest: str | None) -> None:
+        """Test that archives without a readable, supported manifest are rejected."""
+        path = tmp_path / "backup.zip"
+        with zipfile.ZipFile(path, "w") as archive:
+            archive.writestr("other.txt", "x")
+            if manifest is not None:
+                archive.writestr(BACKUP_MANIFEST, manifest)
+
+        with pytest.raises(ValueError):
+            read_backup_info(path)
+
+    def test_missing_backup(self, tmp_path: Path) -> None:
+        """Test that a missing backup file is reported."""
+        with pytest.raises(FileNotFoundError):
+            restore_backup(tmp_path / "missing.zip", tmp_path, tmp_path)
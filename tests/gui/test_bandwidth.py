@@ -0,0 +1,122 @@
+"""Unit tests for BandwidthSchedule and BandwidthGovernor."""
+
+from datetime import datetime, time
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.bandwidth import (
+    BandwidthGovernor,
+    BandwidthSchedule,
+    BandwidthWindow,
+)
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+
+NOON = datetime(2026, 10, 16, 12, 0)
+NIGHT = datetime(2026, 10, 16, 23, 30)
+
+
+class TestBandwidthWindow:
+    """Test time windows."""
+
+    def test_daytime_window(self) -> None:
+        """Test that the start is included and the end excluded."""
+        window = BandwidthWindow("09:00", "18:00", 1024)
+
+        assert window.contains(time(9, 0))
+        assert window.contains(time(17, 59))
+        assert not window.contains(time(18, 0))
+
+    def test_window_spanning_midnight(self) -> None:
+        """Test that a window ending before it starts spans midnight."""
+        window = BandwidthWindow("22:00", "06:00")
+
+        assert window.contains(time(23, 0))
+        assert window.contains(time(5, 59))
+        assert not window.contains(time(12, 0))
+
+    def test_describe(self) -> None:
+        """Test the display text of limited and unlimited windows."""
+        assert BandwidthWindow("09:00", "18:00", 1024).describe() == "09:00-18:00 1024 KB/s"
+        assert BandwidthWindow("22:00", "06:00").describe() == "22:00-06:00 unlimited"
+
+
+class TestBandwidthSchedule:
+    """Test the stored speed limit schedule."""
+
+    @pytest.fixture
+    def schedule(self, tmp_path: Path) -> BandwidthSchedule:
+        """Create a schedule backed by a temporary file."""
+        return BandwidthSchedule(tmp_path / "bandwidth_schedule.json")
+
+    def test_active_window(self, schedule: BandwidthSchedule) -> None:
+        """Test that the window containing the time applies."""
+        schedule.add_window("9:00", "18:00", 1024)
+        schedule.add_window("22:00", "06:00", None)
+
+        assert schedule.active_window(NOON) == BandwidthWindow("09:00", "18:00", 1024)
+        assert schedule.active_window(NIGHT) == BandwidthWindow("22:00", "06:00", None)
+        assert schedule.active_window(datetime(2026, 10, 16, 20, 0)) is None
+
+    def test_apply_stricter_limit(self, schedule: BandwidthSchedule) -> None:
+        """Test that the stricter of the job's and the window's limit applies."""
+        schedule.add_window("09:00", "18:00", 1024)
+
+        assert schedule.apply(DownloadConfig(), NOON).max_speed == 1024
+        assert schedule.apply(DownloadConfig(max_speed=4096), NOON).max_speed == 1024
+        assert schedule.apply(DownloadConfig(max_speed=256), NOON).max_speed == 256
+
+    def test_apply_outside_limited_windows(self, schedule: BandwidthSchedule) -> None:
+        """Test that the job's own limit applies outside limited windows."""
+        schedule.add_window("22:00", "06:00", None)
+        config = DownloadConfig(max_speed=512)
+
+        assert schedule.apply(config, NIGHT) is config
+        assert schedule.apply(config, NOON) is config
+
+    def test_add_replaces_same_times(self, schedule: BandwidthSchedule) -> None:
+        """Test that adding a window with existing times replaces it."""
+        schedule.add_window("09:00", "18:00", 1024)
+        schedule.add_window("09:00", "18:00", 2048)
+
+        assert schedule.load() == [BandwidthWindow("09:00", "18:00", 2048)]
+
+    def test_remove_window(self, schedule: BandwidthSchedule) -> None:
+        """Test removing a window by its times."""
+        schedule.add_window("09:00", "18:00", 1024)
+
+        assert schedule.remove_window("09:00", "18:00")
+        assert not schedule.remove_window("09:00", "18:00")
+        assert schedule.load() == []
+
+    def test_invalid_windows_are_rejected(self, schedule: BandwidthSchedule) -> None:
+        """Test that invalid times, empty windows and non-positive limits are rejected."""
+        with pytest.raises(ValueError):
+            schedule.add_window("9am", "18:00", 1024)
+        with pytest.raises(ValueError):
+            schedule.add_window("09:00", "09:00", 1024)
+        with pytest.raises(ValueError):
+            schedule.add_window("09:00", "18:00", 0)
+
+    def test_invalid_file(self, tmp_path: Path, schedule: BandwidthSchedule) -> None:
+        """Test that an invalid schedule file is treated as empty."""
+        (tmp_path / "bandwidth_schedule.json").write_text("[{", encoding="utf-8")
+
+        assert schedule.load() == []
+
+
+class TestBandwidthGovernor:
+    """Test reporting of window changes."""
+
+    def test_window_change_is_reported_once(self, tmp_path: Path) -> None:
+        """Test that the callback is called only when the active window changes."""
+        schedule = BandwidthSchedule(tmp_path / "bandwidth_schedule.json")
+        schedule.add_window("09:00", "18:00", 1024)
+        changes: list[BandwidthWindow | None] = []
+        governor = BandwidthGovernor(schedule, changes.append)
+        governor.active = None
+
+        assert governor.check(NOON)
+        assert not governor.check(NOON)
+        assert governor.check(NIGHT)
+        assert changes == [BandwidthWindow("09:00", "18:00", 1024), None]
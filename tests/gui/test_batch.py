@@ -0,0 +1,128 @@
+"""Unit tests for BatchDownload."""
+
+import threading
+
+import pytest
+
+from resource_fetcher_gui.gui.core.batch import (
+    POLICY_INTERLEAVED,
+    POLICY_PARALLEL,
+    BatchDownload,
+    BatchProgress,
+)
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_queue import JOB_CANCELLED, JOB_COMPLETED, QueuedJob
+from resource_fetcher_gui.gui.core.mock_cli import MockCLIWrapper
+
+URLS = [f"http://example.com/album/{index}" for index in range(1, 5)]
+
+
+class BatchRecorder:
+    """Records batch callbacks and waits for the batch to finish."""
+
+    def __init__(self) -> None:
+        self.progress: list[BatchProgress] = []
+        self.started: list[QueuedJob] = []
+        self.done = threading.Event()
+
+    def on_progress(self, progress: BatchProgress) -> None:
+        self.progress.append(progress)
+        if progress.finished:
+            self.done.set()
+
+
+class TestBatchDownload:
+    """Test BatchDownload functionality."""
+
+    def test_sequential_batch(self) -> None:
+        """Test that a sequential batch downloads albums one at a time."""
+        recorder = BatchRecorder()
+        batch = BatchDownload(
+            lambda: MockCLIWrapper(delay=0, song_count=3, fail_every=0),
+            progress_callback=recorder.on_progress,
+            job_started_callback=recorder.started.append,
+        )
+
+        batch.start(URLS, DownloadConfig())
+
+        assert recorder.done.wait(timeout=5)
+        assert len(batch.lanes) == 1
+        assert [job.url for job in recorder.started] == URLS
+        assert max(progress.running_jobs for progress in recorder.progress) == 1
+        final = recorder.progress[-1]
+        assert (final.completed_jobs, final.failed_jobs, final.running_jobs) == (4, 0, 0)
+        assert (final.tracks_started, final.tracks_total) == (12, 12)
+        percents = [progress.percent for progress in recorder.progress]
+        assert percents == sorted(percents)
+        assert final.percent == 100
+
+    def test_parallel_batch_uses_lanes(self) -> None:
+        """Test that a parallel batch runs albums in several lanes."""
+        recorder = BatchRecorder()
+        batch = BatchDownload(
+            lambda: MockCLIWrapper(delay=0.05, song_count=2, fail_every=0),
+            policy=POLICY_PARALLEL,
+            max_parallel=2,
+            progress_callback=recorder.on_progress,
+        )
+
+        batch.start(URLS, DownloadConfig())
+
+        assert recorder.done.wait(timeout=5)
+        assert len(batch.lanes) == 2
+        assert max(progress.running_jobs for progress in recorder.progress) == 2
+        assert all(job.status == JOB_COMPLETED for job in batch.jobs)
+        assert sorted(job.url for job in batch.jobs) == URLS
+
+    def test_cancel_batch(self) -> None:
+        """Test that cancelling stops running albums and drops waiting ones."""
+        recorder = BatchRecorder()
+        batch = BatchDownload(
+            lambda: MockCLIWrapper(delay=30, song_count=2),
+            progress_callback=recorder.on_progress,
+        )
+        batch.start(URLS, DownloadConfig())
+
+        batch.cancel()
+
+        assert recorder.done.wait(timeout=5)
+        assert [job.status for job in batch.jobs] == [JOB_CANCELLED]
+        assert recorder.progress[-1].failed_jobs == 4
+
+    def test_percent_weights_albums_by_tracks(self) -> None:
+        """Test that unseen albums count as the average album seen so far."""
+        # One album of 30 tracks done, one of 10 tracks on its sixth track,
+        # one album not started yet (estimated at 20 tracks)
+        progress = BatchProgress(
+            total_jobs=3,
+            completed_jobs=1,
+            running_jobs=1,
+            tracks_started=36,
+            tracks_total=40,
+            albums_seen=2,
+        )
+
+        assert progress.percent == 60
+
+    def test_percent_before_track_counts(self) -> None:
+        """Test that progress falls back to finished albums without track counts."""
+        assert BatchProgress(total_jobs=4, failed_jobs=1).percent == 25
+        assert BatchProgress(total_jobs=4, failed_jobs=4).percent == 100
+
+    @pytest.mark.parametrize(
+        "policy, max_parallel, message",
+        [
+            (POLICY_INTERLEAVED, 2, "per-track selection"),
+            ("random", 2, "Unknown batch policy"),
+            (POLICY_PARALLEL, 0, "max_parallel"),
+        ],
+    )
+    def test_invalid_options(self, policy: str, max_parallel: int, message: str) -> None:
+        """Test that unsupported policies and lane counts are rejected."""
+        with pytest.raises(ValueError, match=message):
+            BatchDownload(MockCLIWrapper, policy=policy, max_parallel=max_parallel)
+
+    def test_empty_batch(self) -> None:
+        """Test that a batch needs at least one URL."""
+        with pytest.raises(ValueError, match="no URLs"):
+            BatchDownload(MockCLIWrapper).start([], DownloadConfig())
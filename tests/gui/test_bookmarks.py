@@ -0,0 +1,94 @@
+"""Unit tests for the bookmarks import."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.bookmarks import (
+    BookmarkCandidate,
+    find_supported_bookmarks,
+    read_bookmarks,
+)
+from resource_fetcher_gui.gui.core.url_validation import SupportedSource
+
+HTML_BOOKMARKS = """<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<DL><p>
+    <DT><H3>Music</H3>
+    <DL><p>
+        <DT><A HREF="https://www.izanmei.cc/album/1.html" ADD_DATE="1">Hymns 1</A>
+        <DT><A HREF="https://example.com/">Example</A>
+        <DT><A HREF="https://www.izanmei.cc/album/1.html">Hymns 1 again</A>
+    </DL><p>
+</DL><p>
+"""
+
+
+class TestReadBookmarks:
+    """Test reading bookmark exports."""
+
+    def test_html_export(self, tmp_path: Path) -> None:
+        """Test that links and titles are read from a Netscape bookmark file."""
+        path = tmp_path / "bookmarks.html"
+        path.write_text(HTML_BOOKMARKS, encoding="utf-8")
+
+        bookmarks = read_bookmarks(path)
+
+        assert bookmarks[0] == BookmarkCandidate("https://www.izanmei.cc/album/1.html", "Hymns 1")
+        assert len(bookmarks) == 3
+
+    def test_chrome_json(self, tmp_path: Path) -> None:
+        """Test that nested folders of Chrome's Bookmarks file are searched."""
+        document = {
+            "roots": {
+                "bookmark_bar": {
+                    "type": "folder",
+                    "children": [
+                        {"type": "url", "name": "Hymns", "url": "https://www.izanmei.cc/a.html"}
+                    ],
+                }
+            }
+        }
+        path = tmp_path / "Bookmarks"
+        path.write_text(json.dumps(document), encoding="utf-8")
+
+        assert read_bookmarks(path) == [BookmarkCandidate("https://www.izanmei.cc/a.html", "Hymns")]
+
+    def test_firefox_json(self, tmp_path: Path) -> None:
+        """Test that Firefox JSON backups use the uri and title keys."""
+        document = {"children": [{"title": "Hymns", "uri": "https://www.izanmei.cc/b.html"}]}
+        path = tmp_path / "bookmarks.json"
+        path.write_text(json.dumps(document), encoding="utf-8")
+
+        assert read_bookmarks(path) == [BookmarkCandidate("https://www.izanmei.cc/b.html", "Hymns")]
+
+    def test_invalid_json(self, tmp_path: Path) -> None:
+        """Test that malformed JSON raises ValueError."""
+        path = tmp_path / "bookmarks.json"
+        path.write_text("{ broken", encoding="utf-8")
+
+        with pytest.raises(ValueError):
+            read_bookmarks(path)
+
+
+class TestFindSupportedBookmarks:
+    """Test filtering bookmarks by supported source."""
+
+    def test_keeps_supported_urls_once(self, tmp_path: Path) -> None:
+        """Test that unsupported sites and duplicate URLs are dropped."""
+        path = tmp_path / "bookmarks.html"
+        path.write_text(HTML_BOOKMARKS, encoding="utf-8")
+
+        candidates = find_supported_bookmarks(path)
+
+        assert candidates == [BookmarkCandidate("https://www.izanmei.cc/album/1.html", "Hymns 1")]
+
+    def test_custom_sources_of_cli(self, tmp_path: Path) -> None:
+        """Test that the CLI's source list, with custom sites, decides what is kept."""
+        path = tmp_path / "bookmarks.html"
+        path.write_text(HTML_BOOKMARKS, encoding="utf-8")
+        sources = [SupportedSource("example", ["https://example.com/*"], ["album"])]
+
+        candidates = find_supported_bookmarks(path, sources)
+
+        assert candidates == [BookmarkCandidate("https://example.com/", "Example")]
@@ -0,0 +1,61 @@
+"""Unit tests for detecting optional features."""
+
+import subprocess
+from unittest.mock import MagicMock, patch
+
+from resource_fetcher_gui.gui.core.capabilities import Capabilities, get_capabilities
+from resource_fetcher_gui.gui.core.mock_cli import MockCLIWrapper
+
+
+class TestCapabilities:
+    """Test Capabilities functionality."""
+
+    def test_ffmpeg_features_need_ffmpeg_and_cli_option(self) -> None:
+        """Test that normalizing and merging need both ffmpeg and the CLI option."""
+        options = frozenset({"--normalize", "--merge"})
+
+        assert Capabilities(cli=True, cli_options=options, ffmpeg=True).normalize
+        assert not Capabilities(cli=True, cli_options=options, ffmpeg=False).merge
+        assert not Capabilities(cli=True, ffmpeg=True).normalize
+
+    def test_to_dict_lists_features(self) -> None:
+        """Test that the features are described by name."""
+        capabilities = Capabilities(cli=True, cli_options=frozenset({"--scan-command"}))
+
+        features = capabilities.to_dict()
+
+        assert features["scan_command"] is True
+        assert features["checksum_sidecars"] is False
+        assert features["normalize"] is False
+
+
+class TestGetCapabilities:
+    """Test get_capabilities function."""
+
+    def test_reads_cli_options_and_tools(self) -> None:
+        """Test that the CLI's options and installed tools are detected."""
+        with patch(
+            "resource_fetcher_gui.gui.core.capabilities.shutil.which",
+            side_effect=lambda name: f"/usr/bin/{name}" if name == "ffmpeg" else None,
+        ):
+            capabilities = get_capabilities(MockCLIWrapper(delay=0))
+
+        assert capabilities.cli
+        assert capabilities.supports("--checksum-sidecars")
+        assert capabilities.ffmpeg
+        assert not capabilities.ffprobe
+        assert capabilities.normalize
+
+    def test_cli_that_does_not_start(self) -> None:
+        """Test that CLI features are unavailable if the CLI can't be started."""
+        wrapper = MagicMock()
+        wrapper.list_cli_options.side_effect = subprocess.TimeoutExpired("cli", 30)
+
+        capabilities = get_capabilities(wrapper)
+
+        assert not capabilities.cli
+        assert not capabilities.supports("--merge")
+
+    def test_no_cli(self) -> None:
+        """Test detection without a CLI."""
+        assert not get_capabilities(None).cli
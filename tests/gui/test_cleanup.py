@@ -0,0 +1,116 @@
+"""Unit tests for the cleanup of old job files."""
+
+import os
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.cleanup import (
+    DAY,
+    MEGABYTE,
+    STAGED_FILE_PATTERN,
+    CleanupService,
+    CleanupTarget,
+    RetentionPolicy,
+)
+
+NOW = 1_800_000_000.0
+
+
+def write_file(path: Path, size: int, age_days: float) -> Path:
+    """Write a file of a size, last changed a number of days before NOW."""
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_bytes(b"x" * size)
+    modified = NOW - age_days * DAY
+    os.utime(path, (modified, modified))
+    return path
+
+
+class TestCleanupService:
+    """Test applying the retention policy."""
+
+    def test_default_policy(self, tmp_path: Path) -> None:
+        """Test that items are kept for 30 days without a size limit."""
+        assert CleanupService(tmp_path / "retention.json").load() == RetentionPolicy(30, None)
+
+    def test_save_and_load(self, tmp_path: Path) -> None:
+        """Test that a saved policy is read back."""
+        service = CleanupService(tmp_path / "retention.json")
+
+        service.save(RetentionPolicy(max_age_days=7, max_size_mb=100))
+
+        assert service.load() == RetentionPolicy(7, 100)
+
+    def test_limits_must_be_positive(self, tmp_path: Path) -> None:
+        """Test that zero limits are rejected."""
+        with pytest.raises(ValueError, match="positive"):
+            CleanupService(tmp_path / "retention.json").save(RetentionPolicy(max_age_days=0))
+
+    def test_removes_old_items(self, tmp_path: Path) -> None:
+        """Test that old logs and job folders are removed and reported."""
+        logs = tmp_path / "logs"
+        old_log = write_file(logs / "job-1.jsonl", 100, age_days=40)
+        write_file(logs / "job-2.jsonl", 100, age_days=1)
+        write_file(logs / "notes.txt", 100, age_days=40)
+        old_job = tmp_path / "jobs" / "job-1"
+        write_file(old_job / "downloader.log", 50, age_days=40)
+        os.utime(old_job, (NOW - 40 * DAY, NOW - 40 * DAY))
+        service = CleanupService(tmp_path / "retention.json")
+
+        report = service.run_cleanup(
+            [
+                CleanupTarget(logs, "*.jsonl"),
+                CleanupTarget(tmp_path / "jobs"),
+                CleanupTarget(tmp_path / "missing"),
+            ],
+            now=NOW,
+        )
+
+        assert sorted(report.removed) == sorted([old_log, old_job])
+        assert report.freed_bytes == 150
+        assert sorted(path.name for path in logs.iterdir()) == ["job-2.jsonl", "notes.txt"]
+
+    def test_recently_changed_folder_is_kept(self, tmp_path: Path) -> None:
+        """Test that a folder counts as changed when anything in it changed."""
+        job = tmp_path / "jobs" / "job-1"
+        write_file(job / "downloader.log", 50, age_days=1)
+        os.utime(job, (NOW - 40 * DAY, NOW - 40 * DAY))
+
+        report = CleanupService(tmp_path / "retention.json").run_cleanup(
+            [CleanupTarget(tmp_path / "jobs")], now=NOW
+        )
+
+        assert report.removed == []
+
+    def test_size_limit_removes_oldest_first(self, tmp_path: Path) -> None:
+        """Test that the oldest items are removed until the folder fits."""
+        service = CleanupService(tmp_path / "retention.json")
+        service.save(RetentionPolicy(max_age_days=None, max_size_mb=1))
+        temp = tmp_path / "temp"
+        oldest = write_file(temp / "resource-fetcher-staged-1.mp3", MEGABYTE, age_days=3)
+        write_file(temp / "resource-fetcher-staged-2.mp3", MEGABYTE // 2, age_days=2)
+        write_file(temp / "resource-fetcher-staged-3.mp3", MEGABYTE // 2, age_days=1)
+
+        report = service.run_cleanup([CleanupTarget(temp, STAGED_FILE_PATTERN)], now=NOW)
+
+        assert report.removed == [oldest]
+        assert report.freed_bytes == MEGABYTE
+
+    def test_only_staged_files_are_removed(self, tmp_path: Path) -> None:
+        """Test that other programs' files in a shared temp folder are kept."""
+        temp = tmp_path / "temp"
+        staged = write_file(temp / "resource-fetcher-staged-a1b2.mp3", 100, age_days=40)
+        merge = temp / "resource-fetcher-staged-c3d4"
+        write_file(merge / "files.txt", 100, age_days=40)
+        os.utime(merge, (NOW - 40 * DAY, NOW - 40 * DAY))
+        foreign = temp / "tmpXYZ"
+        write_file(foreign / "data.bin", 100, age_days=40)
+        os.utime(foreign, (NOW - 40 * DAY, NOW - 40 * DAY))
+        write_file(temp / "tmp5678.mp3", 100, age_days=40)
+
+        report = CleanupService(tmp_path / "retention.json").run_cleanup(
+            [CleanupTarget(temp, STAGED_FILE_PATTERN)], now=NOW
+        )
+
+        assert sorted(report.removed) == sorted([staged, merge])
+        assert sorted(path.name for path in temp.iterdir()) == ["tmp5678.mp3", "tmpXYZ"]
@@ -1,12 +1,23 @@
 """Unit tests for CLIWrapper."""
 
+import subprocess
+import sys
+import threading
 from pathlib import Path
+from typing import Any
 from unittest.mock import MagicMock, patch
 
 import pytest
 
-from resource_fetcher_gui.gui.core.cli_wrapper import CLIWrapper
+from resource_fetcher_gui.gui.core import cli_wrapper, i18n
+from resource_fetcher_gui.gui.core.cli_wrapper import (
+    CONFIRM_TRACK,
+    JOB_TIMEOUT_EXIT_CODE,
+    CLIWrapper,
+    InteractionRequest,
+)
 from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_log import JobLogStore, LogEvent
 
 
 class TestCLIWrapper:
@@ -82,6 +93,303 @@ class TestCLIWrapper:
         assert "--limit" in cmd
         assert "10" in cmd
 
+    def test_build_command_with_track_range(self, valid_cli_file: Path) -> None:
+        """Test building command with a track range."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(track_range="5-12,15")
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--range") + 1] == "5-12,15"
+
+    def test_build_command_with_skip_titles(self, valid_cli_file: Path) -> None:
+        """Test building command with a title blacklist."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(skip_titles=["remix", "instrumental"])
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        start = cmd.index("--skip-title")
+        assert cmd[start : start + 4] == ["--skip-title", "remix", "--skip-title", "instrumental"]
+
+    def test_build_command_with_title_patterns(self, valid_cli_file: Path) -> None:
+        """Test building command with include and exclude patterns."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(include_pattern="*grace*", exclude_pattern="re:live$")
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--include") + 1] == "*grace*"
+        assert cmd[cmd.index("--exclude") + 1] == "re:live$"
+
+    def test_execute_download_with_invalid_title_pattern(self, valid_cli_file: Path) -> None:
+        """Test execute_download raises ValueError for a malformed regex."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(exclude_pattern="re:(")
+
+        with pytest.raises(ValueError, match="Invalid title pattern"):
+            wrapper.execute_download("http://example.com", config)
+
+    def test_execute_download_with_invalid_track_range(self, valid_cli_file: Path) -> None:
+        """Test execute_download raises ValueError for an invalid track range."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(track_range="12-5")
+
+        with pytest.raises(ValueError, match="Invalid track range"):
+            wrapper.execute_download("http://example.com", config)
+
+    def test_build_command_with_file_naming(self, valid_cli_file: Path) -> None:
+        """Test building command with a replacement text and maximum name length."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(filename_replacement="-", max_filename_length=80)
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--replace-char") + 1] == "-"
+        assert cmd[cmd.index("--max-name-length") + 1] == "80"
+
+    def test_execute_download_with_invalid_replacement(self, valid_cli_file: Path) -> None:
+        """Test execute_download raises ValueError for a replacement with illegal characters."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(filename_replacement="?")
+
+        with pytest.raises(ValueError, match="illegal characters"):
+            wrapper.execute_download("http://example.com", config)
+
+    def test_build_command_with_max_speed(self, valid_cli_file: Path) -> None:
+        """Test building command with a download speed limit."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        unlimited = wrapper._build_command("http://example.com", DownloadConfig())
+        limited = wrapper._build_command("http://example.com", DownloadConfig(max_speed=512))
+
+        assert "--max-speed" not in unlimited
+        assert limited[limited.index("--max-speed") + 1] == "512"
+
+    def test_build_command_with_cookies_file(self, valid_cli_file: Path) -> None:
+        """Test building command with a cookies file."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(cookies_file="/home/user/cookies.txt")
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--cookies") + 1] == "/home/user/cookies.txt"
+
+    def test_execute_download_with_invalid_cookies_file(
+        self, valid_cli_file: Path, tmp_path: Path
+    ) -> None:
+        """Test execute_download raises ValueError for a file not in cookies.txt format."""
+        wrapper = CLIWrapper(valid_cli_file)
+        cookies = tmp_path / "cookies.txt"
+        cookies.write_text("not cookies", encoding="utf-8")
+        config = DownloadConfig(cookies_file=str(cookies))
+
+        with pytest.raises(ValueError, match="Invalid cookies file"):
+            wrapper.execute_download("http://example.com", config)
+
+    def test_list_albums_parses_cli_output(self, valid_cli_file: Path) -> None:
+        """Test that album URLs are read from the CLI's listing output."""
+        wrapper = CLIWrapper(valid_cli_file)
+        output = (
+            "专辑数 (Albums): 2\n"
+            "专辑链接 (Album URL): https://example.com/album/1.html\n"
+            "专辑链接 (Album URL): https://example.com/album/2.html\n"
+        )
+
+        with patch.object(wrapper, "_run_to_completion", return_value=output) as run:
+            urls = wrapper.list_albums("https://example.com/artist.html")
+
+        assert urls == ["https://example.com/album/1.html", "https://example.com/album/2.html"]
+        assert run.call_args.args[0][-1] == "--list-albums"
+
+    def test_list_albums_without_albums(self, valid_cli_file: Path) -> None:
+        """Test that list_albums raises ValueError when no album was found."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        with patch.object(wrapper, "_run_to_completion", return_value="专辑数 (Albums): 0\n"):
+            with pytest.raises(ValueError, match="No albums found"):
+                wrapper.list_albums("https://example.com/artist.html")
+
+    def test_custom_sources_dir_is_passed(self, valid_cli_file: Path, tmp_path: Path) -> None:
+        """Test that the custom sources folder is passed to the CLI once it exists."""
+        sources_dir = tmp_path / "sources"
+        wrapper = CLIWrapper(valid_cli_file, sources_dir=sources_dir)
+
+        assert "--sources-dir" not in wrapper._build_command("http://example.com", DownloadConfig())
+
+        sources_dir.mkdir()
+        cmd = wrapper._build_command("http://example.com", DownloadConfig())
+        assert cmd[-2:] == ["--sources-dir", str(sources_dir)]
+
+    def test_list_supported_sources_is_cached(self, valid_cli_file: Path) -> None:
+        """Test that the CLI's source list is parsed once and then cached."""
+        wrapper = CLIWrapper(valid_cli_file)
+        output = (
+            '[{"name": "Izanmei", "url_patterns": ["*izanmei.cc*"], '
+            '"capabilities": ["album", "artist"]}]'
+        )
+
+        with patch.object(wrapper, "_run_to_completion", return_value=output) as run:
+            sources = wrapper.list_supported_sources()
+            assert wrapper.list_supported_sources() is sources
+
+        assert run.call_count == 1
+        assert run.call_args.args[0][1:] == ["--list-sources", "--json"]
+        assert sources[0].name == "Izanmei"
+        assert sources[0].capabilities == ["album", "artist"]
+        assert sources[0].matches("https://WWW.izanmei.cc/album/1.html")
+
+    def test_list_supported_sources_with_old_cli(self, valid_cli_file: Path) -> None:
+        """Test that a CLI without --list-sources raises ValueError."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        with patch.object(wrapper, "_run_to_completion", return_value="usage: resource-fetcher"):
+            with pytest.raises(ValueError, match="sources"):
+                wrapper.list_supported_sources()
+
+    def test_list_cli_options_is_cached(self, valid_cli_file: Path) -> None:
+        """Test that the options in the CLI's usage are read once and then cached."""
+        wrapper = CLIWrapper(valid_cli_file)
+        output = (
+            "usage: resource-fetcher [-h] [--url URL] [--normalize]\n"
+            "  --scan-command COMMAND  Check each downloaded file\n"
+        )
+
+        with patch.object(wrapper, "_run_to_completion", return_value=output) as run:
+            options = wrapper.list_cli_options()
+            assert wrapper.list_cli_options() is options
+
+        assert run.call_count == 1
+        assert run.call_args.args[0][1:] == ["--help"]
+        assert options == {"--url", "--normalize", "--scan-command"}
+
+    def test_snapshot_environment(self, valid_cli_file: Path) -> None:
+        """Test that a job's snapshot has the versions and the full CLI command."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(output_dir="music", normalize_loudness=True)
+
+        with patch.object(wrapper, "_run_to_completion", return_value="resource-fetcher 1.0.0\n"):
+            snapshot = wrapper.snapshot_environment("http://example.com", config)
+            wrapper.snapshot_environment("http://example.com", config)
+
+        assert snapshot["cli_version"] == "resource-fetcher 1.0.0"
+        assert snapshot["python_version"] == sys.version.split()[0]
+        assert snapshot["command"] == wrapper._build_command("http://example.com", config)
+        assert "--normalize" in snapshot["command"]
+
+    @patch("subprocess.Popen")
+    def test_environment_snapshot_taken_on_download_thread(
+        self, mock_popen: MagicMock, valid_cli_file: Path
+    ) -> None:
+        """Test that asking the CLI for its version doesn't block the caller."""
+        mock_process = MagicMock()
+        mock_process.wait.return_value = 0
+        mock_process.stdout = iter([])
+        mock_popen.return_value = mock_process
+        threads: list[threading.Thread] = []
+        snapshots: list[dict[str, Any]] = []
+
+        def snapshot(url: str, config: DownloadConfig) -> dict[str, Any]:
+            threads.append(threading.current_thread())
+            return {"cli_version": "resource-fetcher 1.0.0"}
+
+        wrapper = CLIWrapper(valid_cli_file)
+        with patch.object(wrapper, "snapshot_environment", side_effect=snapshot):
+            thread = wrapper.execute_download(
+                "http://example.com", DownloadConfig(), environment_callback=snapshots.append
+            )
+            thread.join(timeout=5)
+
+        assert threads == [thread]
+        assert snapshots[0]["cli_version"] == "resource-fetcher 1.0.0"
+
+    @patch("subprocess.Popen")
+    def test_stop_before_cli_starts(self, mock_popen: MagicMock, valid_cli_file: Path) -> None:
+        """Test that a download stopped while the snapshot is taken never starts the CLI."""
+        snapshot_started = threading.Event()
+        release = threading.Event()
+        exit_codes: list[int] = []
+
+        def snapshot(url: str, config: DownloadConfig) -> dict[str, Any]:
+            snapshot_started.set()
+            release.wait(timeout=5)
+            return {}
+
+        wrapper = CLIWrapper(valid_cli_file)
+        with patch.object(wrapper, "snapshot_environment", side_effect=snapshot):
+            thread = wrapper.execute_download(
+                "http://example.com",
+                DownloadConfig(),
+                complete_callback=exit_codes.append,
+                environment_callback=lambda environment: None,
+            )
+            assert snapshot_started.wait(timeout=5)
+            assert wrapper.stop_download()
+            release.set()
+            thread.join(timeout=5)
+
+        mock_popen.assert_not_called()
+        assert exit_codes == [-1]
+
+    def test_stop_while_cli_starts(self, valid_cli_file: Path) -> None:
+        """Test that a download stopped while the CLI is started is terminated."""
+        terminated = threading.Event()
+        process = MagicMock(stdout=[])
+        process.wait.side_effect = lambda: terminated.wait(timeout=5) and -15
+        stops: list[bool] = []
+        stoppers: list[threading.Thread] = []
+        wrapper = CLIWrapper(valid_cli_file)
+
+        def spawn(*args: Any) -> MagicMock:
+            # The stop arrives before the started process is recorded
+            stopper = threading.Thread(target=lambda: stops.append(wrapper.stop_download()))
+            stopper.start()
+            stopper.join(timeout=0.2)
+            stoppers.append(stopper)
+            return process
+
+        with (
+            patch.object(wrapper, "_spawn", side_effect=spawn),
+            patch.object(
+                wrapper, "_terminate", side_effect=lambda p: terminated.set()
+            ) as terminate,
+        ):
+            thread = wrapper.execute_download("http://example.com", DownloadConfig())
+            thread.join(timeout=5)
+            stoppers[0].join(timeout=5)
+
+        assert stops == [True]
+        terminate.assert_called_once_with(process)
+
+    def test_cli_version_unavailable(self, tmp_path: Path) -> None:
+        """Test that a missing CLI gives an empty version instead of an error."""
+        wrapper = CLIWrapper(tmp_path / "missing")
+
+        assert wrapper.get_cli_version() == ""
+
+    def test_benchmark_connection_parses_cli_output(self, valid_cli_file: Path) -> None:
+        """Test that speeds and the suggested workers are read from the CLI output."""
+        wrapper = CLIWrapper(valid_cli_file)
+        output = (
+            "测速 (Benchmark): 1 个连接 (streams): 1.50 MB/s\n"
+            "测速 (Benchmark): 2 个连接 (streams): 2.90 MB/s\n"
+            "建议并行数 (Suggested workers): 2\n"
+        )
+
+        with patch.object(wrapper, "_run_to_completion", return_value=output):
+            result = wrapper.benchmark_connection("https://example.com/test.mp3")
+
+        assert result.speeds == {1: 1.5, 2: 2.9}
+        assert result.suggested_workers == 2
+
+    def test_benchmark_connection_failure(self, valid_cli_file: Path) -> None:
+        """Test that a failed benchmark raises ValueError."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        with patch.object(wrapper, "_run_to_completion", return_value="错误: timeout\n"):
+            with pytest.raises(ValueError, match="Benchmark"):
+                wrapper.benchmark_connection("https://example.com/test.mp3")
+
     def test_build_command_with_overwrite(self, valid_cli_file: Path) -> None:
         """Test building command with overwrite flag."""
         wrapper = CLIWrapper(valid_cli_file)
@@ -91,14 +399,114 @@ class TestCLIWrapper:
 
         assert "--overwrite" in cmd
 
-    def test_build_command_with_verbose(self, valid_cli_file: Path) -> None:
-        """Test building command with verbose flag."""
+    def test_build_command_with_sync_only(self, valid_cli_file: Path) -> None:
+        """Test building command that downloads only new or incomplete tracks."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(sync_only=True)
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert "--sync" in cmd
+
+    def test_build_command_with_normalize_loudness(self, valid_cli_file: Path) -> None:
+        """Test building command with loudness normalization."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        assert "--normalize" in wrapper._build_command(
+            "http://example.com", DownloadConfig(normalize_loudness=True)
+        )
+        assert "--normalize" not in wrapper._build_command("http://example.com", DownloadConfig())
+
+    def test_build_command_with_checksum_sidecars(self, valid_cli_file: Path) -> None:
+        """Test building command with per-file checksum sidecars."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(checksum_sidecars=True)
+
+        assert "--checksum-sidecars" in wrapper._build_command("http://example.com", config)
+        assert "--checksum-sidecars" not in wrapper._build_command(
+            "http://example.com", DownloadConfig()
+        )
+
+    def test_build_command_with_confirm_tracks(self, valid_cli_file: Path) -> None:
+        """Test building command that asks before each track."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(confirm_tracks=True, confirm_timeout=30, confirm_default="skip")
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--confirm-timeout") + 1] == "30"
+        assert cmd[cmd.index("--confirm-default") + 1] == "skip"
+        assert "--confirm-tracks" not in wrapper._build_command(
+            "http://example.com", DownloadConfig()
+        )
+
+    def test_build_command_with_relaxed_politeness(self, valid_cli_file: Path) -> None:
+        """Test building command that ignores the source's politeness policy."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(relax_politeness=True)
+
+        assert "--relax-politeness" in wrapper._build_command("http://example.com", config)
+        assert "--relax-politeness" not in wrapper._build_command(
+            "http://example.com", DownloadConfig()
+        )
+
+    def test_build_command_with_sanity_checks(self, valid_cli_file: Path) -> None:
+        """Test building command with the minimum size and silence checks."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(min_track_size=4096, detect_silence=True)
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--min-size") + 1] == "4096"
+        assert "--detect-silence" in cmd
+        default = wrapper._build_command("http://example.com", DownloadConfig())
+        assert "--min-size" not in default
+        assert "--detect-silence" not in default
+
+    def test_build_command_with_scan_command(self, valid_cli_file: Path) -> None:
+        """Test building command with a scanner command."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(scan_command="clamscan --no-summary")
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        assert cmd[cmd.index("--scan-command") + 1] == "clamscan --no-summary"
+
+    def test_build_command_with_temp_dir(self, valid_cli_file: Path) -> None:
+        """Test building command with a temp folder."""
         wrapper = CLIWrapper(valid_cli_file)
-        config = DownloadConfig(verbose=True)
+        config = DownloadConfig(temp_dir="/fast/tmp")
 
         cmd = wrapper._build_command("http://example.com", config)
 
-        assert "--verbose" in cmd
+        assert cmd[cmd.index("--temp-dir") + 1] == "/fast/tmp"
+
+    def test_build_command_with_merge_format(self, valid_cli_file: Path) -> None:
+        """Test building command with a merge format."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        cmd = wrapper._build_command("http://example.com", DownloadConfig(merge_format="mka"))
+
+        assert cmd[cmd.index("--merge") + 1] == "mka"
+
+    def test_build_command_with_workers(self, valid_cli_file: Path) -> None:
+        """Test building command with parallel song downloads."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        cmd = wrapper._build_command("http://example.com", DownloadConfig(workers=4))
+
+        assert cmd[cmd.index("--workers") + 1] == "4"
+        assert "--workers" not in wrapper._build_command("http://example.com", DownloadConfig())
+
+    def test_build_command_with_log_level(self, valid_cli_file: Path) -> None:
+        """Test building command with a non-default log level."""
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(log_level="debug")
+
+        cmd = wrapper._build_command("http://example.com", config)
+
+        index = cmd.index("--log-level")
+        assert cmd[index + 1] == "debug"
 
     def test_build_command_without_optional_flags(self, valid_cli_file: Path) -> None:
         """Test building command without optional flags."""
@@ -110,7 +518,68 @@ class TestCLIWrapper:
         # These should NOT be in command
         assert "--limit" not in cmd
         assert "--overwrite" not in cmd
-        assert "--verbose" not in cmd
+        assert "--log-level" not in cmd
+
+    def test_priority_options_default(self, valid_cli_file: Path) -> None:
+        """Test that normal priority adds no prefix or Popen options."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        prefix, options = wrapper._priority_options(DownloadConfig())
+
+        assert prefix == []
+        assert options == {}
+
+    def test_priority_options_low_priority_posix(
+        self, valid_cli_file: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that low priority wraps the child with ionice and nice on Linux."""
+        monkeypatch.setattr("sys.platform", "linux")
+        monkeypatch.setattr("shutil.which", lambda name: f"/usr/bin/{name}")
+        wrapper = CLIWrapper(valid_cli_file)
+
+        prefix, options = wrapper._priority_options(DownloadConfig(low_priority=True))
+
+        assert prefix == ["/usr/bin/ionice", "-c", "3", "/usr/bin/nice", "-n", "10"]
+        assert options == {}
+
+    def test_priority_options_low_priority_without_ionice(
+        self, valid_cli_file: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that missing ionice still lowers CPU priority."""
+        monkeypatch.setattr("sys.platform", "linux")
+        monkeypatch.setattr("shutil.which", lambda name: "/bin/nice" if name == "nice" else None)
+        wrapper = CLIWrapper(valid_cli_file)
+
+        prefix, options = wrapper._priority_options(DownloadConfig(low_priority=True))
+
+        assert prefix == ["/bin/nice", "-n", "10"]
+        assert "preexec_fn" not in options
+
+    def test_child_options_force_utf8(
+        self, valid_cli_file: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that the CLI runs in UTF-8 mode with the parent's environment."""
+        monkeypatch.setattr("sys.platform", "linux")
+        monkeypatch.setenv("RF_TEST_VARIABLE", "kept")
+        wrapper = CLIWrapper(valid_cli_file)
+
+        options = wrapper._child_options()
+
+        assert options["env"]["PYTHONUTF8"] == "1"
+        assert options["env"]["PYTHONIOENCODING"] == "utf-8"
+        assert options["env"]["RF_TEST_VARIABLE"] == "kept"
+        assert "creationflags" not in options
+
+    def test_child_options_hide_console_on_windows(
+        self, valid_cli_file: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that Windows adds CREATE_NO_WINDOW to the priority flags."""
+        monkeypatch.setattr("sys.platform", "win32")
+        wrapper = CLIWrapper(valid_cli_file)
+
+        options = wrapper._child_options({"creationflags": 0x4000})
+
+        assert options["creationflags"] == 0x4000 | cli_wrapper.CREATE_NO_WINDOW
 
     @patch("subprocess.Popen")
     def test_execute_download_starts_process(
@@ -149,6 +618,190 @@ class TestCLIWrapper:
         # Wait a bit for thread to complete
         thread.join(timeout=1)
 
+    @pytest.mark.parametrize(("exit_code", "kept"), [(0, False), (1, True)])
+    @patch("subprocess.Popen")
+    def test_job_runs_in_work_dir(
+        self,
+        mock_popen: MagicMock,
+        exit_code: int,
+        kept: bool,
+        valid_cli_file: Path,
+        tmp_path: Path,
+    ) -> None:
+        """Test that a job runs in its own directory, removed only on success."""
+        mock_process = MagicMock()
+        mock_process.wait.return_value = exit_code
+        mock_process.stdout = iter([])
+        mock_popen.return_value = mock_process
+
+        wrapper = CLIWrapper(valid_cli_file, work_root=tmp_path / "jobs")
+        thread = wrapper.execute_download(
+            "http://example.com", DownloadConfig(output_dir="music"), job_id="job-1"
+        )
+        thread.join(timeout=5)
+
+        work_dir = tmp_path / "jobs" / "job-1"
+        options = mock_popen.call_args.kwargs
+        assert options["cwd"] == str(work_dir)
+        assert options["env"]["TMPDIR"] == str(work_dir)
+        args = mock_popen.call_args.args[0]
+        assert args[args.index("--output") + 1] == str(Path("music").absolute())
+        assert work_dir.exists() is kept
+
+    @patch("subprocess.Popen")
+    def test_execute_command_streams_output(
+        self, mock_popen: MagicMock, valid_cli_file: Path, tmp_path: Path
+    ) -> None:
+        """Test that a console command runs like a job and streams its output."""
+        mock_process = MagicMock()
+        mock_process.wait.return_value = 0
+        mock_process.stdout = iter(["Source: example\n", "\n", "Done\n"])
+        mock_popen.return_value = mock_process
+        log_store = JobLogStore(tmp_path / "logs")
+        lines: list[str] = []
+        exit_codes: list[int] = []
+
+        wrapper = CLIWrapper(valid_cli_file, log_store=log_store, work_root=tmp_path / "jobs")
+        thread = wrapper.execute_command(
+            ["--list-sources", "--verbose"], lines.append, exit_codes.append, job_id="job-1"
+        )
+        thread.join(timeout=5)
+
+        args = mock_popen.call_args.args[0]
+        assert args == [str(valid_cli_file.absolute()), "--list-sources", "--verbose"]
+        options = mock_popen.call_args.kwargs
+        assert "cwd" not in options
+        assert options["env"]["TMPDIR"] == str(tmp_path / "jobs" / "job-1")
+        assert lines == ["Source: example", "", "Done"]
+        assert exit_codes == [0]
+        assert [event.message for event in log_store.read("job-1")] == [
+            "Source: example",
+            "Done",
+        ]
+        assert not wrapper.is_running()
+
+    def test_execute_command_rejects_empty_or_concurrent(self, valid_cli_file: Path) -> None:
+        """Test that a console command needs arguments and a free wrapper."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        with pytest.raises(ValueError, match="No CLI arguments"):
+            wrapper.execute_command([])
+
+        wrapper.current_process = MagicMock()
+        wrapper.current_process.poll.return_value = None
+        with pytest.raises(RuntimeError, match="still running"):
+            wrapper.execute_command(["--help"])
+
+    def test_no_process_starts_while_one_is_starting(self, valid_cli_file: Path) -> None:
+        """Test that commands and downloads are rejected before the CLI has started."""
+        wrapper = CLIWrapper(valid_cli_file)
+        release = threading.Event()
+        wrapper.current_thread = threading.Thread(target=release.wait, args=(5,))
+        wrapper.current_thread.start()
+
+        try:
+            with pytest.raises(RuntimeError, match="still running"):
+                wrapper.execute_command(["--help"])
+            with pytest.raises(RuntimeError, match="still running"):
+                wrapper.execute_download("http://example.com", DownloadConfig())
+        finally:
+            release.set()
+            wrapper.current_thread.join(timeout=5)
+
+    @patch("subprocess.Popen")
+    def test_interaction_request_is_relayed(
+        self, mock_popen: MagicMock, valid_cli_file: Path
+    ) -> None:
+        """Test that an interaction line becomes a request instead of output."""
+        mock_process = MagicMock()
+        mock_process.wait.return_value = 0
+        mock_process.stdout = iter(
+            [
+                '交互 (Interaction): {"kind": "captcha", "prompt": "Verify", "url": ""}\n',
+                "[1/1] Song\n",
+            ]
+        )
+        mock_popen.return_value = mock_process
+        requests: list[InteractionRequest] = []
+        lines: list[str] = []
+
+        wrapper = CLIWrapper(valid_cli_file)
+        thread = wrapper.execute_download(
+            "http://example.com",
+            DownloadConfig(),
+            lines.append,
+            job_id="job-1",
+            interaction_callback=requests.append,
+        )
+        thread.join(timeout=1)
+
+        assert requests == [InteractionRequest("job-1", "captcha", "Verify")]
+        assert lines == ["[1/1] Song"]
+        assert mock_popen.call_args.kwargs["stdin"] == subprocess.PIPE
+
+    @patch("subprocess.Popen")
+    def test_interaction_declined_without_callback(
+        self, mock_popen: MagicMock, valid_cli_file: Path
+    ) -> None:
+        """Test that a request nobody can answer is declined so the job doesn't hang."""
+        mock_process = MagicMock()
+        mock_process.wait.return_value = 1
+        mock_process.stdout = iter(['交互 (Interaction): {"kind": "captcha", "prompt": "x"}\n'])
+        mock_popen.return_value = mock_process
+
+        wrapper = CLIWrapper(valid_cli_file)
+        thread = wrapper.execute_download("http://example.com", DownloadConfig())
+        thread.join(timeout=1)
+
+        mock_process.stdin.close.assert_called_once()
+
+    def test_submit_interaction(self, valid_cli_file: Path) -> None:
+        """Test that answers are written to the running job's stdin, one line each."""
+        wrapper = CLIWrapper(valid_cli_file)
+        wrapper.current_process = MagicMock()
+        wrapper.current_job_id = "job-1"
+
+        assert wrapper.submit_interaction("job-1", "12\n34")
+        wrapper.current_process.stdin.write.assert_called_once_with("12 34\n")
+        assert not wrapper.submit_interaction("job-2", "1234")
+
+    def test_confirm_track(self, valid_cli_file: Path) -> None:
+        """Test that track confirmations name the track they answer."""
+        wrapper = CLIWrapper(valid_cli_file)
+        wrapper.current_process = MagicMock()
+        wrapper.current_job_id = "job-1"
+
+        assert wrapper.confirm_track("job-1", 3, True)
+        assert wrapper.confirm_track("job-1", 4, False)
+        written = [c.args[0] for c in wrapper.current_process.stdin.write.call_args_list]
+        assert written == ["3 yes\n", "4 no\n"]
+
+    def test_set_speed_limit(self, valid_cli_file: Path) -> None:
+        """Test that speed limit changes are written to the running job's stdin."""
+        wrapper = CLIWrapper(valid_cli_file)
+        wrapper.current_process = MagicMock()
+        wrapper.current_job_id = "job-1"
+
+        assert wrapper.set_speed_limit("job-1", 256)
+        assert wrapper.set_speed_limit("job-1", None)
+        assert not wrapper.set_speed_limit("job-2", 256)
+        written = [c.args[0] for c in wrapper.current_process.stdin.write.call_args_list]
+        assert written == ["speed-limit 256\n", "speed-limit 0\n"]
+
+    def test_parse_confirm_track_request(self) -> None:
+        """Test that a track confirmation request keeps its track number."""
+        message = '{"kind": "confirm-track", "prompt": "Download track 3?", "index": 3}'
+
+        request = CLIWrapper._parse_interaction("job-1", message)
+
+        assert request == InteractionRequest("job-1", CONFIRM_TRACK, "Download track 3?", index=3)
+
+    def test_submit_interaction_without_process(self, valid_cli_file: Path) -> None:
+        """Test that answers for a job that isn't running are rejected."""
+        wrapper = CLIWrapper(valid_cli_file)
+
+        assert not wrapper.submit_interaction("job-1", "1234")
+
     @patch("subprocess.Popen")
     def test_stop_download_terminates_process(
         self, mock_popen: MagicMock, valid_cli_file: Path
@@ -250,3 +903,85 @@ class TestCLIWrapper:
         result = wrapper.wait_for_completion()
 
         assert result is True
+
+    def test_watch_timeouts_terminates_after_job_timeout(
+        self, valid_cli_file: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that the watchdog terminates a job exceeding its time limit."""
+        monkeypatch.setattr(cli_wrapper, "WATCHDOG_INTERVAL", 0.01)
+        monkeypatch.setattr(i18n, "_locale_override", "en")
+        mock_process = MagicMock()
+        wrapper = CLIWrapper(valid_cli_file)
+        config = DownloadConfig(job_timeout=1)
+
+        wrapper._watch_timeouts(mock_process, config, threading.Event())
+
+        mock_process.terminate.assert_called_once()
+        assert wrapper.timeout_reason is not None
+        assert "Job exceeded time limit" in wrapper.timeout_reason
+
+    def test_watch_timeouts_stops_when_finished(self, valid_cli_file: Path) -> None:
+        """Test that the watchdog exits without terminating a finished job."""
+        mock_process = MagicMock()
+        wrapper = CLIWrapper(valid_cli_file)
+        finished = threading.Event()
+        finished.set()
+
+        wrapper._watch_timeouts(mock_process, DownloadConfig(job_timeout=1), finished)
+
+        mock_process.terminate.assert_not_called()
+        assert wrapper.timeout_reason is None
+
+    def test_track_timeout_reports_error_and_exit_code(
+        self, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that a stalled track is killed and reported as a timeout."""
+        if sys.platform == "win32":
+            pytest.skip("Uses a POSIX shebang script as fake CLI")
+
+        monkeypatch.setattr(cli_wrapper, "WATCHDOG_INTERVAL", 0.05)
+        monkeypatch.setattr(i18n, "_locale_override", "en")
+        cli_path = tmp_path / "resource-fetcher"
+        cli_path.write_text(
+            f"#!{sys.executable}\nimport time\nprint('[1/2] Song', flush=True)\ntime.sleep(30)\n"
+        )
+        cli_path.chmod(0o755)
+
+        lines: list[str] = []
+        exit_codes: list[int] = []
+        wrapper = CLIWrapper(cli_path)
+        thread = wrapper.execute_download(
+            "http://example.com",
+            DownloadConfig(track_timeout=1),
+            lines.append,
+            exit_codes.append,
+        )
+        thread.join(timeout=10)
+
+        assert exit_codes == [JOB_TIMEOUT_EXIT_CODE]
+        assert lines[0] == "[1/2] Song"
+        assert lines[-1].startswith("Error: Track exceeded time limit")
+
+    @patch("subprocess.Popen")
+    def test_execute_download_emits_log_events(
+        self, mock_popen: MagicMock, valid_cli_file: Path, tmp_path: Path
+    ) -> None:
+        """Test that informational lines become persisted log events."""
+        mock_process = MagicMock()
+        mock_process.wait.return_value = 0
+        mock_process.stdout = iter(["专辑 (Album): Test\n", "[1/1] Song\n", "Error: boom\n"])
+        mock_popen.return_value = mock_process
+
+        store = JobLogStore(log_dir=tmp_path / "logs")
+        wrapper = CLIWrapper(valid_cli_file, log_store=store)
+        events: list[LogEvent] = []
+
+        thread = wrapper.execute_download(
+            "http://example.com", DownloadConfig(), log_callback=events.append, job_id="job-1"
+        )
+        thread.join(timeout=5)
+
+        assert wrapper.current_job_id == "job-1"
+        assert [event.message for event in events] == ["专辑 (Album): Test", "Error: boom"]
+        assert [event.severity for event in events] == ["info", "error"]
+        assert store.read("job-1") == events
@@ -3,7 +3,22 @@
 import json
 from pathlib import Path
 
-from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+import pytest
+
+from resource_fetcher_gui.gui.core import config_service
+from resource_fetcher_gui.gui.core.config_service import (
+    PORTABLE_FLAG,
+    ConfigService,
+    DownloadConfig,
+    get_app_cache_dir,
+    get_app_config_dir,
+    get_app_data_dir,
+    get_app_paths,
+    get_log_dir,
+    is_portable,
+    migrate_legacy_app_dir,
+    set_portable,
+)
 
 
 class TestDownloadConfig:
@@ -19,7 +34,10 @@ class TestDownloadConfig:
         assert config.timeout == 60
         assert config.retries == 3
         assert config.delay == 0.5
-        assert config.verbose is False
+        assert config.log_level == "info"
+        assert config.low_priority is False
+        assert config.job_timeout is None
+        assert config.track_timeout is None
 
     def test_custom_values(self) -> None:
         """Test configuration with custom values."""
@@ -30,7 +48,7 @@ class TestDownloadConfig:
             timeout=120,
             retries=5,
             delay=1.0,
-            verbose=True,
+            log_level="debug",
         )
 
         assert config.output_dir == "/tmp/music"
@@ -39,7 +57,7 @@ class TestDownloadConfig:
         assert config.timeout == 120
         assert config.retries == 5
         assert config.delay == 1.0
-        assert config.verbose is True
+        assert config.log_level == "debug"
 
 
 class TestConfigService:
@@ -119,7 +137,7 @@ class TestConfigService:
             "timeout": 120,
             "retries": 5,
             "delay": 1.5,
-            "verbose": True,
+            "log_level": "WARNING",
         }
 
         # Write JSON file
@@ -136,7 +154,7 @@ class TestConfigService:
         assert config.timeout == 120
         assert config.retries == 5
         assert config.delay == 1.5
-        assert config.verbose is True
+        assert config.log_level == "warning"
 
     def test_load_config_with_invalid_json(self, tmp_path: Path) -> None:
         """Test loading invalid JSON returns defaults."""
@@ -232,7 +250,7 @@ class TestConfigService:
             timeout=180,
             retries=7,
             delay=2.5,
-            verbose=False,
+            log_level="error",
         )
 
         # Save
@@ -248,7 +266,7 @@ class TestConfigService:
         assert loaded.timeout == original.timeout
         assert loaded.retries == original.retries
         assert loaded.delay == original.delay
-        assert loaded.verbose == original.verbose
+        assert loaded.log_level == original.log_level
 
     def test_load_config_with_zero_retries(self, tmp_path: Path) -> None:
         """Test that zero retries is accepted (non-negative)."""
@@ -263,6 +281,134 @@ class TestConfigService:
         # Zero retries should be valid
         assert config.retries == 0
 
+    def test_load_config_with_low_priority(self, tmp_path: Path) -> None:
+        """Test that low_priority is loaded and coerced to bool."""
+        config_path = tmp_path / "low_priority.json"
+        config_path.write_text(json.dumps({"low_priority": 1}), encoding="utf-8")
+
+        service = ConfigService(config_path=config_path)
+        config = service.load_config()
+
+        assert config.low_priority is True
+
+    def test_load_config_with_time_limits(self, tmp_path: Path) -> None:
+        """Test that job and track time limits are loaded and validated."""
+        config_path = tmp_path / "time_limits.json"
+        config_path.write_text(
+            json.dumps({"job_timeout": "3600", "track_timeout": 0}), encoding="utf-8"
+        )
+
+        service = ConfigService(config_path=config_path)
+        config = service.load_config()
+
+        assert config.job_timeout == 3600
+        # Non-positive limits are ignored
+        assert config.track_timeout is None
+
+    def test_load_config_migrates_legacy_verbose(self, tmp_path: Path) -> None:
+        """Test that the legacy verbose flag maps to the debug log level."""
+        config_path = tmp_path / "legacy_verbose.json"
+        config_path.write_text(json.dumps({"verbose": True}), encoding="utf-8")
+
+        service = ConfigService(config_path=config_path)
+        config = service.load_config()
+
+        assert config.log_level == "debug"
+
+    def test_load_config_with_invalid_log_level(self, tmp_path: Path) -> None:
+        """Test that an unknown log level falls back to the default."""
+        config_path = tmp_path / "invalid_log_level.json"
+        config_path.write_text(json.dumps({"log_level": "chatty"}), encoding="utf-8")
+
+        service = ConfigService(config_path=config_path)
+        config = service.load_config()
+
+        assert config.log_level == ConfigService.DEFAULT_CONFIG.log_level
+
+    def test_load_config_with_invalid_confirm_options(self, tmp_path: Path) -> None:
+        """Test that invalid track confirmation options fall back to the defaults."""
+        config_path = tmp_path / "invalid_confirm.json"
+        config_path.write_text(
+            json.dumps({"confirm_timeout": 0, "confirm_default": "maybe"}), encoding="utf-8"
+        )
+
+        config = ConfigService(config_path=config_path).load_config()
+
+        assert config.confirm_timeout == ConfigService.DEFAULT_CONFIG.confirm_timeout
+        assert config.confirm_default == ConfigService.DEFAULT_CONFIG.confirm_default
+
+    @pytest.mark.parametrize("track_range, expected", [(" 5-12,15 ", "5-12,15"), ("12-5", None)])
+    def test_load_config_with_track_range(
+        self, tmp_path: Path, track_range: str, expected: str | None
+    ) -> None:
+        """Test that track ranges are normalized and invalid ones are ignored."""
+        config_path = tmp_path / "track_range.json"
+        config_path.write_text(json.dumps({"track_range": track_range}), encoding="utf-8")
+
+        service = ConfigService(config_path=config_path)
+        config = service.load_config()
+
+        assert config.track_range == expected
+
+    def test_load_config_with_skip_titles(self, tmp_path: Path) -> None:
+        """Test that blank blacklist entries are dropped and invalid values ignored."""
+        config_path = tmp_path / "skip_titles.json"
+        config_path.write_text(json.dumps({"skip_titles": [" remix ", ""]}), encoding="utf-8")
+        service = ConfigService(config_path=config_path)
+
+        assert service.load_config().skip_titles == ["remix"]
+
+        config_path.write_text(json.dumps({"skip_titles": "remix"}), encoding="utf-8")
+
+        assert service.load_config().skip_titles == []
+
+    def test_load_config_with_title_patterns(self, tmp_path: Path) -> None:
+        """Test that valid title patterns are kept and malformed ones ignored."""
+        config_path = tmp_path / "patterns.json"
+        config_data = {"include_pattern": "*grace*", "exclude_pattern": "re:("}
+        config_path.write_text(json.dumps(config_data), encoding="utf-8")
+
+        config = ConfigService(config_path=config_path).load_config()
+
+        assert config.include_pattern == "*grace*"
+        assert config.exclude_pattern is None
+
+    @pytest.mark.parametrize("merge_format, expected", [("M4B", "m4b"), ("mp3", None)])
+    def test_load_config_with_merge_format(
+        self, tmp_path: Path, merge_format: str, expected: str | None
+    ) -> None:
+        """Test that merge formats are normalized and unknown ones ignored."""
+        config_path = tmp_path / "merge.json"
+        config_path.write_text(json.dumps({"merge_format": merge_format}), encoding="utf-8")
+
+        config = ConfigService(config_path=config_path).load_config()
+
+        assert config.merge_format == expected
+
+    @pytest.mark.parametrize("workers, expected", [("4", 4), (0, 1)])
+    def test_load_config_with_workers(
+        self, tmp_path: Path, workers: str | int, expected: int
+    ) -> None:
+        """Test that parallel song counts are coerced and non-positive ones ignored."""
+        config_path = tmp_path / "workers.json"
+        config_path.write_text(json.dumps({"workers": workers}), encoding="utf-8")
+
+        config = ConfigService(config_path=config_path).load_config()
+
+        assert config.workers == expected
+
+    def test_load_config_with_file_naming(self, tmp_path: Path) -> None:
+        """Test that illegal replacements and non-positive name lengths are ignored."""
+        config_path = tmp_path / "naming.json"
+        config_path.write_text(
+            json.dumps({"filename_replacement": "/", "max_filename_length": -1}), encoding="utf-8"
+        )
+
+        config = ConfigService(config_path=config_path).load_config()
+
+        assert config.filename_replacement == "_"
+        assert config.max_filename_length is None
+
     def test_load_config_with_zero_delay(self, tmp_path: Path) -> None:
         """Test that zero delay is accepted (non-negative)."""
         config_path = tmp_path / "zero_delay.json"
@@ -275,3 +421,103 @@ class TestConfigService:
 
         # Zero delay should be valid
         assert config.delay == 0
+
+
+class TestPortableMode:
+    """Test keeping application data beside the executable."""
+
+    @pytest.fixture(autouse=True)
+    def executable_dir(self, tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+        """Run as if the executable were in a temporary folder."""
+        monkeypatch.setattr(config_service, "_portable_override", False)
+        monkeypatch.setattr(config_service, "get_executable_dir", lambda: tmp_path)
+        return tmp_path
+
+    def test_installed_mode_by_default(self, executable_dir: Path) -> None:
+        """Test that without the flag file data goes to the user's data directory."""
+        assert not is_portable()
+        assert get_app_config_dir().name == "resource_fetcher"
+        assert get_app_data_dir().name == "resource_fetcher"
+        assert get_log_dir() == Path()
+
+    def test_flag_file_enables_portable_mode(self, executable_dir: Path) -> None:
+        """Test that a portable.flag file beside the executable enables portable mode."""
+        (executable_dir / PORTABLE_FLAG).touch()
+
+        assert is_portable()
+        assert get_app_config_dir() == executable_dir / "data"
+        assert get_app_data_dir() == executable_dir / "data"
+        assert get_app_cache_dir() == executable_dir / "data" / "cache"
+        assert get_log_dir() == executable_dir / "data"
+
+    def test_set_portable(self, executable_dir: Path) -> None:
+        """Test that portable mode can be enabled without the flag file."""
+        set_portable(True)
+
+        assert get_app_data_dir() == executable_dir / "data"
+        assert ConfigService().config_path == executable_dir / "data" / "config.json"
+
+
+class TestXdgDirectories:
+    """Test the XDG base directories on Linux."""
+
+    @pytest.fixture
+    def home(self, tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+        """Run as a Linux user with a temporary home and no XDG variables."""
+        home = tmp_path / "home"
+        monkeypatch.setattr(config_service.sys, "platform", "linux")
+        monkeypatch.setattr(config_service, "_portable_override", False)
+        monkeypatch.setattr(config_service, "get_executable_dir", lambda: tmp_path)
+        monkeypatch.setattr(Path, "home", lambda: home)
+        for variable in ("XDG_CONFIG_HOME", "XDG_DATA_HOME", "XDG_CACHE_HOME"):
+            monkeypatch.delenv(variable, raising=False)
+        return home
+
+    def test_default_directories(self, home: Path) -> None:
+        """Test the XDG defaults when no variables are set."""
+        assert get_app_config_dir() == home / ".config" / "resource_fetcher"
+        assert get_app_data_dir() == home / ".local" / "share" / "resource_fetcher"
+        assert get_app_cache_dir() == home / ".cache" / "resource_fetcher"
+
+    def test_environment_variables(
+        self, home: Path, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that absolute XDG variables are used and relative ones ignored."""
+        monkeypatch.setenv("XDG_CONFIG_HOME", str(tmp_path / "config"))
+        monkeypatch.setenv("XDG_DATA_HOME", "relative")
+
+        assert get_app_config_dir() == tmp_path / "config" / "resource_fetcher"
+        assert get_app_data_dir() == home / ".local" / "share" / "resource_fetcher"
+
+    def test_app_paths(self, home: Path) -> None:
+        """Test that all locations are reported."""
+        paths = get_app_paths()
+
+        assert set(paths) == {"config", "data", "cache", "logs"}
+        assert paths["data"] == get_app_data_dir()
+
+    def test_migrate_legacy_dir(self, home: Path) -> None:
+        """Test that data moves to the data directory and settings stay in place."""
+        legacy = home / ".config" / "resource_fetcher"
+        (legacy / "logs").mkdir(parents=True)
+        (legacy / "history.jsonl").write_text("{}\n", encoding="utf-8")
+        (legacy / "config.json").write_text("{}", encoding="utf-8")
+        data_dir = get_app_data_dir()
+
+        moved = migrate_legacy_app_dir()
+
+        assert moved == [data_dir / "history.jsonl", data_dir / "logs"]
+        assert (data_dir / "history.jsonl").read_text(encoding="utf-8") == "{}\n"
+        assert (legacy / "config.json").exists()
+        assert migrate_legacy_app_dir() == []
+
+    def test_migrate_keeps_existing_files(self, home: Path) -> None:
+        """Test that files already at the new location aren't overwritten."""
+        legacy = home / ".config" / "resource_fetcher"
+        legacy.mkdir(parents=True)
+        (legacy / "history.jsonl").write_text("old", encoding="utf-8")
+        get_app_data_dir().mkdir(parents=True)
+        (get_app_data_dir() / "history.jsonl").write_text("new", encoding="utf-8")
+
+        assert migrate_legacy_app_dir() == []
+        assert (get_app_data_dir() / "history.jsonl").read_text(encoding="utf-8") == "new"
@@ -0,0 +1,94 @@
+"""Unit tests for the CLI protocol conformance check."""
+
+import json
+from pathlib import Path
+from typing import Any
+from unittest.mock import patch
+
+import pytest
+
+from resource_fetcher_gui.gui.core.conformance import (
+    CONFORMANCE_TRACKS,
+    run_protocol_conformance,
+    validate_event,
+)
+from resource_fetcher_gui.gui.core.output_parser import AlbumInfo, DownloadSummary, SongProgress
+from resource_fetcher_gui.gui.main import main
+
+
+class TestValidateEvent:
+    """Test validation of single events."""
+
+    @pytest.mark.parametrize(
+        "event",
+        [
+            SongProgress(index=1, total=2, title="Song"),
+            AlbumInfo(title="Album", source="izanmei", song_count=2),
+            DownloadSummary(success=1, failed=0, skipped=1, total=2, elapsed_time=1, speed=1),
+            {"type": "processed", "done": 1, "total": 2},
+            {"type": "mount_lost"},
+        ],
+    )
+    def test_valid_events(self, event: Any) -> None:
+        """Test that events as the CLI prints them are valid."""
+        assert validate_event(event) == []
+
+    @pytest.mark.parametrize(
+        "event, problem",
+        [
+            (SongProgress(index=3, total=2, title="Song"), "outside"),
+            (SongProgress(index=1, total=2, title="Song", status="lost"), "status"),
+            (AlbumInfo(title="", source="", song_count=1, politeness={"delay": 1}), "politeness"),
+            (
+                DownloadSummary(success=2, failed=1, skipped=0, total=2, elapsed_time=1, speed=1),
+                "exceed",
+            ),
+            ({"type": "teleported"}, "unknown event type"),
+            ({"type": "merge_progress"}, "missing field percent"),
+            ({"type": "merge_progress", "percent": 140}, "outside 0-100"),
+            ({"type": "rate_banned", "cooldown": True}, "should be int"),
+            ({"type": "error", "message": "x", "code": 2}, "unexpected field code"),
+        ],
+    )
+    def test_invalid_events(self, event: Any, problem: str) -> None:
+        """Test that malformed events are reported."""
+        problems = validate_event(event)
+
+        assert len(problems) == 1
+        assert problem in problems[0]
+
+
+class TestRunProtocolConformance:
+    """Test driving a CLI through the conformance scenario."""
+
+    def test_mock_conforms(self) -> None:
+        """Test that the simulated CLI passes every check."""
+        report = run_protocol_conformance(None, timeout=10)
+
+        assert report.passed, report.to_dict()
+        assert [check.name for check in report.checks] == [
+            "version",
+            "start",
+            "exit",
+            "album",
+            "summary",
+        ]
+        # Header, a progress and a processed event per track, and the summary
+        assert report.events == 1 + 2 * CONFORMANCE_TRACKS + 1
+
+    def test_missing_cli(self, tmp_path: Path) -> None:
+        """Test that a CLI that can't be started fails the report."""
+        report = run_protocol_conformance(tmp_path / "missing", timeout=10)
+
+        assert not report.passed
+        assert report.checks[-1].name == "start"
+        assert not report.checks[-1].passed
+
+    def test_command_line(self, capsys: pytest.CaptureFixture[str]) -> None:
+        """Test that --conformance prints the report as JSON."""
+        with patch("resource_fetcher_gui.gui.main.setup_logging"):
+            assert main(["--conformance", "mock"]) == 0
+
+        report = json.loads(capsys.readouterr().out)
+        assert report["cli"] == "mock"
+        assert report["passed"] is True
@@ -0,0 +1,78 @@
+"""Unit tests for CrashReporter."""
+
+import sys
+import threading
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from resource_fetcher_gui.gui.core.crash_reports import CrashReporter
+
+
+@pytest.fixture
+def reporter(tmp_path: Path) -> CrashReporter:
+    """Crash reporter writing into a temporary folder."""
+    return CrashReporter(tmp_path / "crashes", tmp_path / "crash_reports.json")
+
+
+def raise_error() -> None:
+    """Raise an exception to report."""
+    raise RuntimeError("boom")
+
+
+class TestCrashReporter:
+    """Test writing crash reports."""
+
+    def test_disabled_by_default(self, reporter: CrashReporter) -> None:
+        """Test that nothing is written without consent."""
+        try:
+            raise_error()
+        except RuntimeError as e:
+            assert reporter.write_report(RuntimeError, e, e.__traceback__) is None
+
+        assert reporter.enabled is False
+        assert reporter.list_reports() == []
+
+    def test_write_report(self, reporter: CrashReporter) -> None:
+        """Test that the report contains the traceback."""
+        reporter.set_enabled(True)
+
+        try:
+            raise_error()
+        except RuntimeError as e:
+            path = reporter.write_report(RuntimeError, e, e.__traceback__)
+
+        assert path is not None
+        text = path.read_text(encoding="utf-8")
+        assert "RuntimeError: boom" in text
+        assert "raise_error" in text
+        assert reporter.list_reports() == [path]
+
+    def test_old_reports_are_pruned(self, reporter: CrashReporter) -> None:
+        """Test that only the most recent reports are kept."""
+        reporter.set_enabled(True)
+        error = RuntimeError("boom")
+
+        with patch("resource_fetcher_gui.gui.core.crash_reports.MAX_CRASH_REPORTS", 2):
+            for _ in range(3):
+                reporter.write_report(RuntimeError, error, None)
+
+        assert len(reporter.list_reports()) == 2
+
+    def test_install_reports_thread_exceptions(self, reporter: CrashReporter) -> None:
+        """Test that exceptions escaping a thread are reported."""
+        reporter.set_enabled(True)
+        previous_hooks = (sys.excepthook, threading.excepthook)
+        try:
+            with patch("threading.excepthook"):
+                reporter.install()
+                thread = threading.Thread(target=raise_error, name="worker")
+                thread.start()
+                thread.join()
+        finally:
+            sys.excepthook, threading.excepthook = previous_hooks
+
+        reports = reporter.list_reports()
+        assert len(reports) == 1
+        assert "Thread: worker" in reports[0].read_text(encoding="utf-8")
@@ -0,0 +1,84 @@
+"""Unit tests for the debug bundle export."""
+
+import json
+import zipfile
+from pathlib import Path
+from unittest.mock import patch
+
+from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.debug_bundle import (
+    export_debug_bundle,
+    read_log_tail,
+    sanitize_text,
+)
+from resource_fetcher_gui.gui.core.job_log import JobLogStore, LogEvent
+
+
+class TestDebugBundle:
+    """Test exporting debug bundles."""
+
+    def test_sanitize_text_hides_home(self, tmp_path: Path) -> None:
+        """Test that the home directory is replaced by "~"."""
+        with patch("pathlib.Path.home", return_value=Path("/home/alice")):
+            assert sanitize_text("saved to /home/alice/Music") == "saved to ~/Music"
+
+    def test_read_log_tail_starts_at_full_line(self, tmp_path: Path) -> None:
+        """Test that a cut log starts with a complete line."""
+        path = tmp_path / "gui.log"
+        path.write_text("first line\nsecond line\nthird line\n", encoding="utf-8")
+
+        assert read_log_tail(path, max_bytes=20) == "third line\n"
+
+    def test_export_contents(self, tmp_path: Path) -> None:
+        """Test that diagnostics, settings, logs and the last job are bundled."""
+        config_service = ConfigService(config_path=tmp_path / "config.json")
+        config_service.save_config(DownloadConfig(output_dir=str(tmp_path / "music")))
+        job_log_store = JobLogStore(log_dir=tmp_path / "logs")
+        job_log_store.append(LogEvent("job-1", "error", "2026-01-01T10:00:00", "failed"))
+        log_file = tmp_path / "gui.log"
+        log_file.write_text("started\n", encoding="utf-8")
+
+        with patch("pathlib.Path.home", return_value=tmp_path):
+            path = export_debug_bundle(
+                tmp_path / "debug.zip",
+                config_service,
+                job_log_store,
+                tmp_path / "resource-fetcher",
+                log_files=(log_file, tmp_path / "missing.log"),
+            )
+
+        with zipfile.ZipFile(path) as bundle:
+            assert sorted(bundle.namelist()) == [
+                "diagnostics.json",
+                "jobs/job-1.json",
+                "logs/gui.log",
+                "settings.json",
+            ]
+            diagnostics = json.loads(bundle.read("diagnostics.json"))
+            settings = json.loads(bundle.read("settings.json"))
+            events = json.loads(bundle.read("jobs/job-1.json"))
+
+        assert diagnostics["cli_found"] is False
+        assert settings["output_dir"] == str(Path("~") / "music")
+        assert events[0]["message"] == "failed"
+
+    def test_export_includes_kept_job_log(self, tmp_path: Path) -> None:
+        """Test that the CLI log kept in a failed job's directory is bundled."""
+        config_service = ConfigService(config_path=tmp_path / "config.json")
+        job_log_store = JobLogStore(log_dir=tmp_path / "logs")
+        job_log_store.append(LogEvent("job-1", "error", "2026-01-01T10:00:00", "failed"))
+        work_dir = tmp_path / "jobs" / "job-1"
+        work_dir.mkdir(parents=True)
+        (work_dir / "downloader.log").write_text("boom\n", encoding="utf-8")
+
+        path = export_debug_bundle(
+            tmp_path / "debug.zip",
+            config_service,
+            job_log_store,
+            tmp_path / "resource-fetcher",
+            log_files=(),
+            work_root=tmp_path / "jobs",
+        )
+
+        with zipfile.ZipFile(path) as bundle:
+            assert bundle.read("jobs/job-1.log").decode("utf-8") == "boom\n"
@@ -0,0 +1,142 @@
+"""Unit tests for the download history."""
+
+from datetime import datetime
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.download_history import (
+    DownloadHistory,
+    HistoryEntry,
+    create_history_entry,
+    normalize_url,
+)
+
+
+class TestNormalizeUrl:
+    """Test URL normalization."""
+
+    def test_equivalent_urls_match(self) -> None:
+        """Test that case, fragments and trailing slashes are ignored."""
+        assert normalize_url("HTTPS://Example.com/album/#top") == "https://example.com/album"
+
+    def test_query_is_kept(self) -> None:
+        """Test that different query strings stay different albums."""
+        assert normalize_url("http://a.com/album?id=1") != normalize_url("http://a.com/album?id=2")
+
+
+class TestDownloadHistory:
+    """Test DownloadHistory functionality."""
+
+    def test_record_and_read(self, tmp_path: Path) -> None:
+        """Test that recorded entries are read back in order."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        first = HistoryEntry("http://a.com/1", "One", "./music", "2026-01-01T10:00:00", "job-1")
+        second = create_history_entry("http://a.com/2", "./music", "Two", "job-2")
+
+        history.record(first)
+        history.record(second)
+
+        assert history.entries() == [first, second]
+
+    def test_find_returns_latest_download(self, tmp_path: Path) -> None:
+        """Test that the most recent download of an album is found."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(HistoryEntry("http://a.com/1/", "", "./old", "2026-01-01T10:00:00"))
+        history.record(HistoryEntry("http://a.com/2", "", "./other", "2026-01-02T10:00:00"))
+        history.record(HistoryEntry("http://A.com/1", "", "./new", "2026-01-03T10:00:00"))
+
+        entry = history.find("http://a.com/1")
+
+        assert entry is not None
+        assert entry.output_dir == "./new"
+        assert history.find("http://a.com/3") is None
+
+    def test_get_by_job_id(self, tmp_path: Path) -> None:
+        """Test that a job's entry is found with its settings."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(create_history_entry("http://a.com/1", "./music", job_id="job-1"))
+        history.record(
+            create_history_entry(
+                "http://a.com/2",
+                "./music",
+                job_id="job-2",
+                config={"limit": 3},
+                environment={"cli_version": "resource-fetcher 1.0.0"},
+            )
+        )
+
+        entry = history.get_job("job-2")
+
+        assert entry is not None
+        assert entry.config == {"limit": 3}
+        assert entry.environment == {"cli_version": "resource-fetcher 1.0.0"}
+        assert history.get_job("job-3") is None
+
+    def test_search(self, tmp_path: Path) -> None:
+        """Test searching the text fields and the metadata."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        first = create_history_entry(
+            "http://a.com/1", "./music", "Hymns", note="Requested by Ann", metadata={"year": "1998"}
+        )
+        second = create_history_entry(
+            "http://a.com/2", "./music", "Psalms", metadata={"year": "2001"}
+        )
+        history.record(first)
+        history.record(second)
+
+        assert history.search("ann") == [first]
+        assert history.search("psalms") == [second]
+        assert history.search("year=2001") == [second]
+        assert history.search("1998") == [first]
+        assert history.search("missing") == []
+
+    def test_find_ignores_incomplete_downloads(self, tmp_path: Path) -> None:
+        """Test that failed downloads recorded for their data usage aren't found."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(create_history_entry("http://a.com/1", "./music", completed=False))
+
+        assert history.find("http://a.com/1") is None
+
+    def test_bytes_since(self, tmp_path: Path) -> None:
+        """Test that data of downloads finished since a time is summed."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(HistoryEntry("http://a.com/1", "", "./m", "2026-01-01T10:00:00", "", 100))
+        history.record(HistoryEntry("http://a.com/2", "", "./m", "2026-01-02T10:00:00", "", 200))
+        history.record(
+            HistoryEntry("http://a.com/3", "", "./m", "2026-01-02T11:00:00", "", 50, False)
+        )
+
+        assert history.bytes_since(datetime(2026, 1, 2)) == 250
+        assert history.bytes_since(datetime(2026, 1, 3)) == 0
+
+    def test_bytes_since_counts_transferred_data(self, tmp_path: Path) -> None:
+        """Test that retries and discarded files count where they were recorded."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(
+            create_history_entry(
+                "http://a.com/1",
+                "./m",
+                bytes_downloaded=100,
+                bytes_transferred=180,
+                track_bytes={"1": 120, "2": 60},
+            )
+        )
+
+        assert history.bytes_since(datetime(2000, 1, 1)) == 180
+        assert history.entries()[0].track_bytes == {"1": 120, "2": 60}
+
+    def test_missing_file_is_empty(self, tmp_path: Path) -> None:
+        """Test that a missing history file means no downloads."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+
+        assert history.entries() == []
+        assert history.find("http://a.com/1") is None
+
+    def test_malformed_entries_are_skipped(self, tmp_path: Path) -> None:
+        """Test that corrupt lines don't hide valid entries."""
+        path = tmp_path / "history.jsonl"
+        history = DownloadHistory(path)
+        history.record(HistoryEntry("http://a.com/1", "", "./music", "2026-01-01T10:00:00"))
+        with open(path, "a", encoding="utf-8") as f:
+            f.write("{not json\n")
+
+        assert len(history.entries()) == 1
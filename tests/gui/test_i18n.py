@@ -0,0 +1,83 @@
+"""Unit tests for backend message localization."""
+
+import pytest
+
+from resource_fetcher_gui.gui.core import i18n
+from resource_fetcher_gui.gui.core.i18n import MESSAGES, get_locale, set_locale, tr
+
+
+@pytest.fixture(autouse=True)
+def clean_locale(monkeypatch: pytest.MonkeyPatch):
+    """Clear locale environment variables and overrides for each test."""
+    for name in ("LC_ALL", "LC_MESSAGES", "LANGUAGE", "LANG"):
+        monkeypatch.delenv(name, raising=False)
+    monkeypatch.setattr(i18n.locale, "getlocale", lambda: (None, None))
+    monkeypatch.setattr(i18n, "_locale_override", None)
+    yield
+
+
+class TestGetLocale:
+    """Test locale detection."""
+
+    def test_default_locale(self) -> None:
+        """Test that English is used when nothing is configured."""
+        assert get_locale() == "en"
+
+    def test_detect_from_lang(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test detecting Chinese from LANG."""
+        monkeypatch.setenv("LANG", "zh_CN.UTF-8")
+        assert get_locale() == "zh"
+
+    def test_lc_all_takes_precedence(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that LC_ALL wins over LANG."""
+        monkeypatch.setenv("LANG", "zh_CN.UTF-8")
+        monkeypatch.setenv("LC_ALL", "en_US.UTF-8")
+        assert get_locale() == "en"
+
+    def test_language_priority_list(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that LANGUAGE priority lists skip unsupported languages."""
+        monkeypatch.setenv("LANGUAGE", "fr_FR:zh_TW:en")
+        assert get_locale() == "zh"
+
+    def test_c_locale_falls_back(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that the C locale falls back to the default."""
+        monkeypatch.setenv("LC_ALL", "C")
+        assert get_locale() == "en"
+
+    def test_system_locale(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test falling back to the system locale."""
+        monkeypatch.setattr(i18n.locale, "getlocale", lambda: ("zh_CN", "UTF-8"))
+        assert get_locale() == "zh"
+
+    def test_set_locale_override(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that an explicit override wins over detection."""
+        monkeypatch.setenv("LANG", "en_US.UTF-8")
+        set_locale("zh")
+        assert get_locale() == "zh"
+
+    def test_set_unsupported_locale(self) -> None:
+        """Test that unsupported locales are rejected."""
+        with pytest.raises(ValueError, match="Unsupported locale"):
+            set_locale("xx")
+
+
+class TestTranslate:
+    """Test message translation."""
+
+    def test_translate_with_arguments(self) -> None:
+        """Test formatting a translated message."""
+        set_locale("en")
+        assert tr("job_timeout", seconds=30) == "Job exceeded time limit of 30 seconds"
+
+        set_locale("zh")
+        assert tr("job_timeout", seconds=30) == "任务超过时间限制 30 秒"
+
+    def test_unknown_key_returns_key(self) -> None:
+        """Test that unknown keys are returned unchanged."""
+        assert tr("no_such_message") == "no_such_message"
+
+    def test_catalogs_have_same_keys(self) -> None:
+        """Test that every locale translates every message."""
+        keys = set(MESSAGES["en"])
+        for code, messages in MESSAGES.items():
+            assert set(messages) == keys, code
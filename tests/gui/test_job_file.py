@@ -0,0 +1,231 @@
+"""Unit tests for batch job files."""
+
+import json
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+
+from resource_fetcher_core.utils.manifest import AlbumManifest, ManifestTrack, write_manifest
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_file import (
+    JobSpec,
+    export_failures,
+    load_job_file,
+    run_job_file,
+)
+from resource_fetcher_gui.gui.core.job_queue import QueuedJob
+
+
+def write_json(path: Path, data: object) -> Path:
+    """Write data as JSON and return the path."""
+    path.write_text(json.dumps(data), encoding="utf-8")
+    return path
+
+
+class TestLoadJobFile:
+    """Test loading job files."""
+
+    def test_load_list_of_urls(self, tmp_path: Path) -> None:
+        """Test a plain list of URLs using the base settings."""
+        path = write_json(tmp_path / "jobs.json", ["http://a.com/1", "http://a.com/2"])
+        base = DownloadConfig(output_dir="./music", retries=5)
+
+        jobs = load_job_file(path, base)
+
+        assert jobs == [JobSpec("http://a.com/1", base), JobSpec("http://a.com/2", base)]
+
+    def test_defaults_and_overrides(self, tmp_path: Path) -> None:
+        """Test that per-job overrides take precedence over file defaults."""
+        path = write_json(
+            tmp_path / "jobs.json",
+            {
+                "defaults": {"output_dir": "./albums", "retries": 5},
+                "jobs": [
+                    "http://a.com/1",
+                    {"url": "http://a.com/2", "output_dir": "./other", "limit": 3},
+                ],
+            },
+        )
+
+        jobs = load_job_file(path, DownloadConfig(timeout=120))
+
+        assert jobs[0].config == DownloadConfig(output_dir="./albums", retries=5, timeout=120)
+        assert jobs[1].config == DownloadConfig(
+            output_dir="./other", retries=5, timeout=120, limit=3
+        )
+
+    def test_load_yaml(self, tmp_path: Path) -> None:
+        """Test loading a YAML job file."""
+        pytest.importorskip("yaml")
+        path = tmp_path / "jobs.yaml"
+        path.write_text("jobs:\n  - url: http://a.com/1\n    limit: 2\n", encoding="utf-8")
+
+        jobs = load_job_file(path, DownloadConfig())
+
+        assert jobs == [JobSpec("http://a.com/1", DownloadConfig(limit=2))]
+
+    def test_mirrors(self, tmp_path: Path) -> None:
+        """Test that mirrors are kept separate from the download settings."""
+        path = write_json(
+            tmp_path / "jobs.json",
+            [{"url": "http://a.com/1", "mirrors": ["http://b.com/1", "http://c.com/1"]}],
+        )
+
+        jobs = load_job_file(path, DownloadConfig())
+
+        assert jobs[0].url == "http://a.com/1"
+        assert jobs[0].mirrors == ["http://b.com/1", "http://c.com/1"]
+        assert jobs[0].config == DownloadConfig()
+
+    def test_note_and_metadata(self, tmp_path: Path) -> None:
+        """Test that notes and metadata are kept separate from the download settings."""
+        path = write_json(
+            tmp_path / "jobs.json",
+            [{"url": "http://a.com/1", "note": "For the choir", "metadata": {"year": 1998}}],
+        )
+
+        jobs = load_job_file(path, DownloadConfig())
+
+        assert jobs[0].note == "For the choir"
+        assert jobs[0].metadata == {"year": "1998"}
+        assert jobs[0].config == DownloadConfig()
+
+    def test_missing_file(self, tmp_path: Path) -> None:
+        """Test that a missing job file raises FileNotFoundError."""
+        with pytest.raises(FileNotFoundError):
+            load_job_file(tmp_path / "missing.json", DownloadConfig())
+
+    @pytest.mark.parametrize(
+        "data, message",
+        [
+            ([], "no jobs"),
+            ({"jobs": [{"limit": 3}]}, "job 1 has no URL"),
+            (["ftp://a.com/1"], "invalid URL"),
+            ({"jobs": [{"url": "http://a.com/1", "quality": "high"}]}, "quality"),
+            ([{"url": "http://a.com/1", "mirrors": "http://b.com/1"}], "must be a list"),
+            ([{"url": "http://a.com/1", "mirrors": ["b.com/1"]}], "invalid URL"),
+            ([{"url": "http://a.com/1", "metadata": ["a"]}], "must be a mapping"),
+            ({"defaults": {"bogus": 1}, "jobs": ["http://a.com/1"]}, "bogus"),
+            ([{"url": "http://a.com/1", "after": ["nope"]}], "unknown jobs"),
+            ([{"url": "http://a.com/1", "id": "a", "after": "a"}], "cycle"),
+            ([{"url": "http://a.com/1", "id": "a"}, {"url": "http://a.com/2", "id": "a"}], "twice"),
+            ([{"url": "http://a.com/1", "after": [1]}], "list of ids"),
+        ],
+    )
+    def test_invalid_job_file(self, tmp_path: Path, data: object, message: str) -> None:
+        """Test that invalid job files are rejected with a clear error."""
+        path = write_json(tmp_path / "jobs.json", data)
+
+        with pytest.raises(ValueError, match=message):
+            load_job_file(path, DownloadConfig())
+
+    def test_invalid_json(self, tmp_path: Path) -> None:
+        """Test that malformed JSON raises ValueError."""
+        path = tmp_path / "jobs.json"
+        path.write_text("{not json", encoding="utf-8")
+
+        with pytest.raises(ValueError, match="Invalid job file"):
+            load_job_file(path, DownloadConfig())
+
+
+class TestRunJobFile:
+    """Test enqueueing job files."""
+
+    def test_run_job_file_enqueues_all_jobs(self, tmp_path: Path) -> None:
+        """Test that every job in the file is enqueued in order."""
+        path = write_json(tmp_path / "jobs.json", ["http://a.com/1", "http://a.com/2"])
+        queue = MagicMock()
+
+        jobs = run_job_file(path, queue, DownloadConfig())
+
+        assert len(jobs) == 2
+        assert [call.args[0] for call in queue.enqueue.call_args_list] == [
+            "http://a.com/1",
+            "http://a.com/2",
+        ]
+        assert queue.enqueue.call_args.args[2] == []
+
+    def test_dependencies(self, tmp_path: Path) -> None:
+        """Test that jobs are enqueued after their dependencies and wait for them."""
+        data = [
+            {"url": "http://a.com/convert", "after": ["one", "two"]},
+            {"url": "http://a.com/1", "id": "one"},
+            {"url": "http://a.com/2", "id": "two"},
+        ]
+        path = write_json(tmp_path / "jobs.json", data)
+        queue = MagicMock()
+        queue.enqueue.side_effect = lambda url, *args: QueuedJob(url, url, DownloadConfig())
+
+        jobs = run_job_file(path, queue, DownloadConfig())
+
+        assert [job.url for job in jobs] == [
+            "http://a.com/1",
+            "http://a.com/2",
+            "http://a.com/convert",
+        ]
+        assert queue.enqueue.call_args.args[5] == ["http://a.com/1", "http://a.com/2"]
+
+    def test_invalid_file_enqueues_nothing(self, tmp_path: Path) -> None:
+        """Test that no job is enqueued when a later entry is invalid."""
+        path = write_json(tmp_path / "jobs.json", ["http://a.com/1", {"limit": 1}])
+        queue = MagicMock()
+
+        with pytest.raises(ValueError):
+            run_job_file(path, queue, DownloadConfig())
+
+        queue.enqueue.assert_not_called()
+
+
+class TestExportFailures:
+    """Test exporting the failed tracks of a job."""
+
+    def write_album(self, folder: Path, url: str = "http://a.com/1") -> None:
+        """Write a manifest with one successful and two failed tracks."""
+        tracks = [
+            ManifestTrack(index=1, title="One", url="http://a.com/1.mp3", status="success"),
+            ManifestTrack(index=2, title="Two", url="http://a.com/2.mp3", status="failed"),
+            ManifestTrack(index=4, title="Four", url="http://a.com/4.mp3", status="failed"),
+        ]
+        manifest = AlbumManifest(
+            source_url=url, title="Album", source="test", fetched_at="2024-01-01", tracks=tracks
+        )
+        write_manifest(folder, manifest)
+
+    def test_export_round_trips(self, tmp_path: Path) -> None:
+        """Test that the exported file re-downloads only the failed tracks."""
+        self.write_album(tmp_path / "album")
+        config = DownloadConfig(output_dir=str(tmp_path / "album"))
+        job = QueuedJob("job-1", "http://a.com/1", config)
+        path = tmp_path / "failed.json"
+
+        count = export_failures(job, path)
+
+        assert count == 2
+        data = json.loads(path.read_text(encoding="utf-8"))
+        assert data["jobs"][0]["failed_tracks"][1] == {
+            "index": 4,
+            "title": "Four",
+            "url": "http://a.com/4.mp3",
+        }
+        jobs = load_job_file(path, DownloadConfig(retries=5))
+        assert len(jobs) == 1
+        assert jobs[0].url == "http://a.com/1"
+        assert jobs[0].config.track_range == "2,4"
+        assert jobs[0].config.output_dir == str(tmp_path / "album")
+        assert jobs[0].config.retries == 5
+
+    def test_manifest_of_other_album(self, tmp_path: Path) -> None:
+        """Test that a manifest of another album is not exported."""
+        self.write_album(tmp_path, url="http://a.com/2")
+        job = QueuedJob("job-1", "http://a.com/1", DownloadConfig(output_dir=str(tmp_path)))
+
+        with pytest.raises(ValueError, match="belongs to"):
+            export_failures(job, tmp_path / "failed.json")
+
+    def test_no_manifest(self, tmp_path: Path) -> None:
+        """Test that a job without a manifest can't be exported."""
+        job = QueuedJob("job-1", "http://a.com/1", DownloadConfig(output_dir=str(tmp_path)))
+
+        with pytest.raises(FileNotFoundError):
+            export_failures(job, tmp_path / "failed.json")
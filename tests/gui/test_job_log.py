@@ -0,0 +1,89 @@
+"""Unit tests for per-job log events."""
+
+import os
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.job_log import (
+    JobLogStore,
+    LogEvent,
+    classify_severity,
+    create_log_event,
+)
+
+
+class TestClassifySeverity:
+    """Test severity classification of CLI output lines."""
+
+    def test_python_log_record_levels(self) -> None:
+        """Test that logging records use their level name."""
+        line = "2026-01-01 10:00:00,000 - resource_fetcher_cli - WARNING - Request failed"
+        assert classify_severity(line) == "warning"
+
+        line = "2026-01-01 10:00:00,000 - resource_fetcher_cli - DEBUG - Attempting download"
+        assert classify_severity(line) == "debug"
+
+    def test_critical_maps_to_error(self) -> None:
+        """Test that CRITICAL records are reported as errors."""
+        line = "2026-01-01 10:00:00,000 - root - CRITICAL - Disk full"
+        assert classify_severity(line) == "error"
+
+    def test_error_lines(self) -> None:
+        """Test that plain error lines are reported as errors."""
+        assert classify_severity("错误: Connection refused") == "error"
+        assert classify_severity("Error: Job exceeded time limit") == "error"
+
+    def test_plain_lines_are_info(self) -> None:
+        """Test that other lines default to info."""
+        assert classify_severity("专辑 (Album): Test Album") == "info"
+
+
+class TestJobLogStore:
+    """Test JobLogStore persistence."""
+
+    def test_create_log_event(self) -> None:
+        """Test that events carry the job ID, severity and a timestamp."""
+        event = create_log_event("job-1", "Error: boom")
+
+        assert event.job_id == "job-1"
+        assert event.severity == "error"
+        assert event.message == "Error: boom"
+        assert event.timestamp
+
+    def test_append_and_read(self, tmp_path: Path) -> None:
+        """Test that events are persisted and read back per job."""
+        store = JobLogStore(log_dir=tmp_path / "logs")
+        first = LogEvent("job-1", "info", "2026-01-01T10:00:00", "first")
+        other = LogEvent("job-2", "info", "2026-01-01T10:00:01", "other job")
+        second = LogEvent("job-1", "error", "2026-01-01T10:00:02", "second")
+
+        for event in (first, other, second):
+            store.append(event)
+
+        assert store.read("job-1") == [first, second]
+        assert store.read("job-2") == [other]
+
+    def test_read_unknown_job(self, tmp_path: Path) -> None:
+        """Test that reading an unknown job returns no events."""
+        store = JobLogStore(log_dir=tmp_path)
+
+        assert store.read("missing") == []
+
+    def test_read_skips_malformed_lines(self, tmp_path: Path) -> None:
+        """Test that corrupt entries don't prevent reading the rest."""
+        store = JobLogStore(log_dir=tmp_path)
+        store.append(LogEvent("job-1", "info", "2026-01-01T10:00:00", "ok"))
+        with open(store.path_for("job-1"), "a", encoding="utf-8") as f:
+            f.write("not json\n")
+
+        assert [event.message for event in store.read("job-1")] == ["ok"]
+
+    def test_latest_job_id(self, tmp_path: Path) -> None:
+        """Test that the job whose log was written last is found."""
+        store = JobLogStore(log_dir=tmp_path / "logs")
+        assert store.latest_job_id() is None
+
+        store.append(LogEvent("job-1", "info", "2026-01-01T10:00:00", "first"))
+        store.append(LogEvent("job-2", "info", "2026-01-01T10:00:01", "second"))
+        os.utime(store.path_for("job-1"), (1, 1))
+
+        assert store.latest_job_id() == "job-2"
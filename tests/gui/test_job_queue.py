@@ -0,0 +1,565 @@
+"""Unit tests for JobQueue."""
+
+import threading
+from datetime import datetime
+from pathlib import Path
+from typing import Any
+from unittest.mock import MagicMock
+
+import pytest
+
+from resource_fetcher_gui.gui.core import job_queue
+from resource_fetcher_gui.gui.core.adaptive_concurrency import AdaptiveConcurrency
+from resource_fetcher_gui.gui.core.bandwidth import BandwidthGovernor, BandwidthSchedule
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_log import JobLogStore, LogEvent
+from resource_fetcher_gui.gui.core.job_queue import (
+    JOB_BLOCKED,
+    JOB_CANCELLED,
+    JOB_COMPLETED,
+    JOB_FAILED,
+    JOB_QUEUED,
+    JOB_READY,
+    JOB_RUNNING,
+    MAX_RECENT_JOBS,
+    JobQueue,
+    QueuedJob,
+    SessionState,
+)
+from resource_fetcher_gui.gui.core.mock_cli import MOCK_SONG_SIZE, MockCLIWrapper
+from resource_fetcher_gui.gui.core.volumes import volume_root
+
+
+class FinishedJobs:
+    """Job finished callback that waits for an expected number of jobs."""
+
+    def __init__(self, count: int) -> None:
+        self.jobs: list[QueuedJob] = []
+        self.count = count
+        self.done = threading.Event()
+
+    def __call__(self, job: QueuedJob) -> None:
+        self.jobs.append(job)
+        if len(self.jobs) == self.count:
+            self.done.set()
+
+    def wait(self) -> bool:
+        return self.done.wait(timeout=5)
+
+
+class TestJobQueue:
+    """Test JobQueue functionality."""
+
+    def test_jobs_run_sequentially(self) -> None:
+        """Test that queued jobs run one after another in order."""
+        started: list[str] = []
+        finished = FinishedJobs(2)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=2, fail_every=0),
+            job_started_callback=lambda job: started.append(job.url),
+            job_finished_callback=finished,
+        )
+
+        first = queue.enqueue("http://example.com/1", DownloadConfig())
+        second = queue.enqueue("http://example.com/2", DownloadConfig())
+
+        assert finished.wait()
+        assert started == ["http://example.com/1", "http://example.com/2"]
+        assert finished.jobs == [first, second]
+        assert first.status == second.status == JOB_COMPLETED
+        assert first.exit_code == 0
+        assert first.job_id != second.job_id
+        assert not queue.is_active()
+
+    def test_failed_job_does_not_stop_queue(self) -> None:
+        """Test that a failing job is marked failed and the next job still runs."""
+        finished = FinishedJobs(2)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=2, fail_every=1),
+            job_finished_callback=finished,
+        )
+
+        queue.enqueue("http://example.com/1", DownloadConfig())
+        queue.enqueue("http://example.com/2", DownloadConfig())
+
+        assert finished.wait()
+        assert [job.status for job in finished.jobs] == [JOB_FAILED, JOB_FAILED]
+
+    def test_invalid_url_fails_job(self) -> None:
+        """Test that a job the wrapper refuses to start is marked failed."""
+        finished: list[QueuedJob] = []
+        queue = JobQueue(MockCLIWrapper(delay=0), job_finished_callback=finished.append)
+
+        job = queue.enqueue("not-a-url", DownloadConfig())
+
+        assert job.status == JOB_FAILED
+        assert job.exit_code == -1
+        assert finished == [job]
+
+    def test_cancel_queued_and_running_jobs(self) -> None:
+        """Test cancelling both a waiting job and the running job."""
+        started = threading.Event()
+        finished = FinishedJobs(2)
+        queue = JobQueue(
+            MockCLIWrapper(delay=30, song_count=3),
+            progress_callback=lambda line: started.set(),
+            job_finished_callback=finished,
+        )
+
+        running = queue.enqueue("http://example.com/1", DownloadConfig())
+        waiting = queue.enqueue("http://example.com/2", DownloadConfig())
+        assert started.wait(timeout=5)
+        assert running.status == JOB_RUNNING
+        assert queue.pending() == [waiting]
+
+        queue.cancel_all()
+
+        assert finished.wait()
+        assert running.status == waiting.status == JOB_CANCELLED
+        assert not queue.is_active()
+        assert queue.cancel(running.job_id) is False
+
+    def test_failed_source_switches_to_mirror(self, tmp_path: Path) -> None:
+        """Test that a failing URL is retried with the job's mirrors in order."""
+        attempts: list[str] = []
+
+        def execute_download(url: str, config: DownloadConfig, **kwargs: Any) -> None:
+            attempts.append(url)
+            kwargs["complete_callback"](0 if url == "http://mirror2.com/1" else 1)
+
+        wrapper = MagicMock()
+        wrapper.execute_download.side_effect = execute_download
+        wrapper.log_store = JobLogStore(log_dir=tmp_path)
+        events: list[LogEvent] = []
+        finished: list[QueuedJob] = []
+        queue = JobQueue(wrapper, job_finished_callback=finished.append, log_callback=events.append)
+
+        job = queue.enqueue(
+            "http://example.com/1",
+            DownloadConfig(),
+            mirrors=["http://mirror1.com/1", "http://mirror2.com/1", "http://mirror3.com/1"],
+        )
+
+        assert attempts == ["http://example.com/1", "http://mirror1.com/1", "http://mirror2.com/1"]
+        assert job.status == JOB_COMPLETED
+        assert job.url == "http://mirror2.com/1"
+        assert job.mirrors == ["http://mirror3.com/1"]
+        assert finished == [job]
+        assert [event.message for event in events if "Switching" in event.message] == [
+            "Switching to mirror: http://mirror1.com/1",
+            "Switching to mirror: http://mirror2.com/1",
+        ]
+        assert all(event.severity == "warning" for event in events)
+        assert wrapper.log_store.read(job.job_id) == events
+
+    def test_lost_volume_requeues_job(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that a job failing on a lost volume waits for it instead of failing."""
+        exit_codes = iter([1, 0])
+        available = [False]
+        monkeypatch.setattr(job_queue, "volume_available", lambda root: available[0])
+
+        def execute_download(url: str, config: DownloadConfig, **kwargs: Any) -> None:
+            kwargs["complete_callback"](next(exit_codes))
+
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        wrapper.execute_download.side_effect = execute_download
+        lost: list[tuple[QueuedJob, Path]] = []
+        finished: list[QueuedJob] = []
+        queue = JobQueue(
+            wrapper,
+            job_finished_callback=finished.append,
+            volume_lost_callback=lambda job, root: lost.append((job, root)),
+        )
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig(), mirrors=["http://b.com/1"])
+
+        assert lost == [(job, volume_root(Path(job.config.output_dir)))]
+        assert job.status == JOB_QUEUED
+        assert job.url == "http://example.com/1"
+        assert queue.paused
+        assert finished == []
+
+        available[0] = True
+        queue.resume()
+
+        assert job.status == JOB_COMPLETED
+        assert finished == [job]
+
+    def test_job_fails_when_mirrors_exhausted(self) -> None:
+        """Test that a job fails once every mirror has failed."""
+        def execute_download(url: str, config: DownloadConfig, **kwargs: Any) -> None:
+            kwargs["complete_callback"](1)
+
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        wrapper.execute_download.side_effect = execute_download
+        queue = JobQueue(wrapper)
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig(), mirrors=["http://b.com/1"])
+
+        assert wrapper.execute_download.call_count == 2
+        assert job.status == JOB_FAILED
+
+    def test_config_resolved_at_enqueue(self) -> None:
+        """Test that the config resolver adjusts a job's configuration for its URL."""
+        finished = FinishedJobs(1)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=1, fail_every=0),
+            job_finished_callback=finished,
+            config_resolver=lambda url, config: DownloadConfig(output_dir=url.rsplit("/", 1)[1]),
+        )
+
+        job = queue.enqueue("http://example.com/podcasts", DownloadConfig())
+
+        assert finished.wait()
+        assert job.config.output_dir == "podcasts"
+
+    def test_interaction_goes_to_running_job(self) -> None:
+        """Test that input requests are relayed and answers reach only the running job."""
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        callback = MagicMock()
+        queue = JobQueue(wrapper, interaction_callback=callback)
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert wrapper.execute_download.call_args.kwargs["interaction_callback"] is callback
+        assert queue.submit_interaction(job.job_id, "1234")
+        wrapper.submit_interaction.assert_called_once_with(job.job_id, "1234")
+        assert not queue.submit_interaction("other", "1234")
+
+    def test_track_confirmation_goes_to_running_job(self) -> None:
+        """Test that track confirmations reach only the running job."""
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        queue = JobQueue(wrapper)
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert queue.confirm_track(job.job_id, 3, False)
+        wrapper.confirm_track.assert_called_once_with(job.job_id, 3, False)
+        assert not queue.confirm_track("other", 3, True)
+
+    def test_start_resolver_applied_when_job_runs(self) -> None:
+        """Test that the start resolver adjusts the configuration a job runs with."""
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        queue = JobQueue(wrapper, start_resolver=lambda config: DownloadConfig(max_speed=256))
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert wrapper.execute_download.call_args.args[1].max_speed == 256
+        assert job.config.max_speed is None
+
+    def test_window_change_reaches_running_job(self, tmp_path: Path) -> None:
+        """Test that a running job's speed limit follows the bandwidth schedule."""
+        schedule = BandwidthSchedule(tmp_path / "bandwidth_schedule.json")
+        schedule.add_window("09:00", "18:00", 256)
+        now = [datetime(2026, 10, 16, 8, 0)]
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        queue = JobQueue(wrapper, start_resolver=lambda config: schedule.apply(config, now[0]))
+        governor = BandwidthGovernor(schedule, lambda window: queue.refresh_speed_limit())
+        governor.active = None
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig(max_speed=1024))
+        assert wrapper.execute_download.call_args.args[1].max_speed == 1024
+
+        now[0] = datetime(2026, 10, 16, 12, 0)
+        assert governor.check(now[0])
+        wrapper.set_speed_limit.assert_called_once_with(job.job_id, 256)
+
+        now[0] = datetime(2026, 10, 16, 20, 0)
+        assert governor.check(now[0])
+        wrapper.set_speed_limit.assert_called_with(job.job_id, 1024)
+
+    def test_refused_job_pauses_queue(self) -> None:
+        """Test that a job refused by can_start stays queued until the queue resumes."""
+        allowed = threading.Event()
+        finished = FinishedJobs(1)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=1, fail_every=0),
+            job_finished_callback=finished,
+            can_start=lambda job: allowed.is_set(),
+        )
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert queue.paused
+        assert job.status == JOB_QUEUED
+        assert queue.current_job is None
+
+        allowed.set()
+        queue.resume()
+
+        assert finished.wait()
+        assert job.status == JOB_COMPLETED
+        assert not queue.paused
+
+    def test_downloaded_bytes_counted(self) -> None:
+        """Test that the data reported by the CLI is added to the job."""
+        finished = FinishedJobs(1)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=2, fail_every=0), job_finished_callback=finished
+        )
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert finished.wait()
+        assert job.downloaded_bytes == 2 * MOCK_SONG_SIZE
+        assert job.transferred_bytes == 2 * MOCK_SONG_SIZE
+        assert job.track_bytes == {"1": MOCK_SONG_SIZE, "2": MOCK_SONG_SIZE}
+        assert job.processed_tracks == 2
+        assert job.environment["cli_version"] == "resource-fetcher 1.0.0 (mock)"
+        assert "http://example.com/1" in job.environment["command"]
+
+    def test_failures_slow_source_down(self) -> None:
+        """Test that a job with many failed tracks slows down its source."""
+        events: list[LogEvent] = []
+        finished = FinishedJobs(1)
+        adaptive = AdaptiveConcurrency()
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=4, fail_every=2),
+            job_finished_callback=finished,
+            log_callback=events.append,
+            adaptive_concurrency=adaptive,
+        )
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert finished.wait()
+        assert job.failed_tracks == 2
+        assert adaptive.level("http://example.com/2") == 1
+        assert any("slow-down level 1" in event.message for event in events)
+
+    def test_dependencies_decide_order(self) -> None:
+        """Test that jobs wait for their dependencies and fail if one fails."""
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        finished: list[QueuedJob] = []
+        queue = JobQueue(wrapper, job_finished_callback=finished.append)
+
+        first = queue.enqueue("http://example.com/1", DownloadConfig())
+        second = queue.enqueue("http://example.com/2", DownloadConfig(), depends_on=[first.job_id])
+        third = queue.enqueue("http://example.com/3", DownloadConfig(), depends_on=[second.job_id])
+        fourth = queue.enqueue("http://example.com/4", DownloadConfig())
+
+        listings = queue.list_jobs()
+        assert [listing.state for listing in listings] == [
+            JOB_RUNNING,
+            JOB_BLOCKED,
+            JOB_BLOCKED,
+            JOB_READY,
+        ]
+        assert listings[2].waiting_for == [second.job_id]
+
+        queue._on_job_complete(first, 0)
+        assert second.status == JOB_RUNNING
+
+        queue._on_job_complete(second, 1)
+        assert third.status == JOB_FAILED
+        assert third.exit_code is None
+        assert fourth.status == JOB_RUNNING
+        assert finished == [first, second, third]
+
+    def test_cancelled_dependency_fails_dependents(self) -> None:
+        """Test that cancelling a queued job fails the jobs waiting for it."""
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        events: list[LogEvent] = []
+        queue = JobQueue(wrapper, log_callback=events.append)
+        queue.enqueue("http://example.com/1", DownloadConfig())
+        second = queue.enqueue("http://example.com/2", DownloadConfig())
+        third = queue.enqueue("http://example.com/3", DownloadConfig(), depends_on=[second.job_id])
+
+        queue.cancel(second.job_id)
+
+        assert third.status == JOB_FAILED
+        assert second.job_id in events[-1].message
+
+    def test_unknown_dependency(self) -> None:
+        """Test that jobs can only depend on jobs of the same queue."""
+        queue = JobQueue(MagicMock())
+
+        with pytest.raises(ValueError, match="Unknown dependencies"):
+            queue.enqueue("http://example.com/1", DownloadConfig(), depends_on=["nope"])
+
+        assert queue.jobs == []
+
+    def test_session_state_keeps_dependencies(self) -> None:
+        """Test that finished jobs stay in the session while queued jobs depend on them."""
+        wrapper = MagicMock()
+        wrapper.log_store = None
+        queue = JobQueue(wrapper)
+        first = queue.enqueue("http://example.com/1", DownloadConfig())
+        queue.pause()
+        queue.enqueue("http://example.com/2", DownloadConfig(), depends_on=[first.job_id])
+        queue._on_job_complete(first, 0)
+        for index in range(MAX_RECENT_JOBS):
+            queue.jobs.insert(
+                1, QueuedJob(f"old{index}", "http://old.com", DownloadConfig(), JOB_COMPLETED)
+            )
+
+        state = queue.get_session_state()
+
+        assert first.job_id in [job.job_id for job in state.jobs]
+        assert state.jobs[-1].depends_on == [first.job_id]
+
+    def test_session_state_saved_on_changes(self) -> None:
+        """Test that the state is passed on as jobs start, progress and finish."""
+        states: list[SessionState] = []
+        finished = FinishedJobs(1)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=2, fail_every=0),
+            job_finished_callback=finished,
+            session_callback=states.append,
+        )
+
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        assert finished.wait()
+        assert states[0].jobs[0].status == JOB_RUNNING
+        assert any(state.jobs[0].current_track == 2 for state in states)
+        assert states[-1].jobs == [job]
+        assert states[-1].jobs[0] is not job
+        assert (job.current_track, job.track_count) == (2, 2)
+
+    def test_note_and_metadata_are_kept(self) -> None:
+        """Test that a job's note and metadata are copied into the session state."""
+        queue = JobQueue(MockCLIWrapper(delay=0))
+        queue.pause()
+        metadata = {"requested_by": "Ann"}
+
+        job = queue.enqueue("http://a.com/1", DownloadConfig(), note="Gift", metadata=metadata)
+        metadata["requested_by"] = "Bob"
+
+        saved = queue.get_session_state().jobs[0]
+        assert (job.note, job.metadata) == ("Gift", {"requested_by": "Ann"})
+        assert saved.metadata == job.metadata
+        assert saved.metadata is not job.metadata
+
+    def test_session_state_keeps_recent_finished_jobs(self) -> None:
+        """Test that only the most recently finished jobs are kept."""
+        queue = JobQueue(MockCLIWrapper(delay=0))
+        queue.jobs = [
+            QueuedJob(job_id=str(i), url="http://example.com", config=DownloadConfig())
+            for i in range(MAX_RECENT_JOBS + 2)
+        ]
+        for job in queue.jobs[:-1]:
+            job.status = JOB_COMPLETED
+
+        jobs = queue.get_session_state().jobs
+
+        assert [job.job_id for job in jobs] == [str(i) for i in range(1, MAX_RECENT_JOBS + 2)]
+
+    def test_restore_requeues_unfinished_jobs(self) -> None:
+        """Test that interrupted and queued jobs run again and finished ones don't."""
+        finished = FinishedJobs(2)
+        queue = JobQueue(
+            MockCLIWrapper(delay=0, song_count=1, fail_every=0), job_finished_callback=finished
+        )
+        jobs = [
+            QueuedJob(
+                job_id=str(i), url=f"http://example.com/{i}", config=DownloadConfig(), status=status
+            )
+            for i, status in enumerate((JOB_COMPLETED, JOB_RUNNING, JOB_QUEUED))
+        ]
+
+        assert queue.restore(SessionState(jobs=jobs)) == 2
+        assert finished.wait()
+        assert [job.job_id for job in finished.jobs] == ["1", "2"]
+        assert [job.status for job in queue.jobs] == [JOB_COMPLETED] * 3
+
+    def test_restore_paused_queue(self) -> None:
+        """Test that a queue paused in the last session stays paused."""
+        queue = JobQueue(MockCLIWrapper(delay=0))
+        job = QueuedJob(job_id="1", url="http://example.com/1", config=DownloadConfig())
+
+        queue.restore(SessionState(jobs=[job], paused=True))
+
+        assert queue.paused
+        assert job.status == JOB_QUEUED
+
+    def test_duplicate_url_returns_existing_job(self) -> None:
+        """Test that an album already queued or running isn't queued twice."""
+        started = threading.Event()
+        queue = JobQueue(
+            MockCLIWrapper(delay=30, song_count=3),
+            progress_callback=lambda line: started.set(),
+        )
+
+        running = queue.enqueue("http://example.com/1", DownloadConfig())
+        waiting = queue.enqueue("http://example.com/2", DownloadConfig())
+        assert started.wait(timeout=5)
+
+        assert queue.enqueue("HTTP://Example.com/1/", DownloadConfig()) is running
+        assert queue.enqueue("http://example.com/2", DownloadConfig()) is waiting
+        other_folder = queue.enqueue("http://example.com/1", DownloadConfig(output_dir="other"))
+        assert other_folder is not running
+        assert len(queue.jobs) == 3
+
+        queue.cancel_all()
+        assert queue.find_active("http://example.com/1", "./downloads") is None
+
+    def test_update_queued_jobs(self) -> None:
+        """Test changing options of several queued jobs at once."""
+        states: list[SessionState] = []
+        queue = JobQueue(MockCLIWrapper(delay=0), session_callback=states.append)
+        queue.pause()
+        first = queue.enqueue("http://example.com/1", DownloadConfig())
+        second = queue.enqueue("http://example.com/2", DownloadConfig(workers=2))
+        states.clear()
+
+        changed = queue.update_jobs(
+            [first.job_id, second.job_id], {"output_dir": "music", "low_priority": True}
+        )
+
+        assert changed == [first, second]
+        assert first.config.output_dir == second.config.output_dir == "music"
+        assert first.config.low_priority and second.config.low_priority
+        assert second.config.workers == 2
+        assert [job.config.output_dir for job in states[-1].jobs] == ["music", "music"]
+
+    @pytest.mark.parametrize(
+        "patch",
+        [{"quality": "high"}, {"workers": 0}, {"output_dir": "music", "delay": -1.0}],
+    )
+    def test_invalid_update_changes_nothing(self, patch: dict[str, Any]) -> None:
+        """Test that unknown options and invalid values are rejected for all jobs."""
+        queue = JobQueue(MockCLIWrapper(delay=0))
+        queue.pause()
+        job = queue.enqueue("http://example.com/1", DownloadConfig())
+
+        with pytest.raises(ValueError):
+            queue.update_jobs([job.job_id], patch)
+        assert job.config == DownloadConfig()
+
+    def test_update_refuses_started_jobs(self) -> None:
+        """Test that a finished job in the selection keeps queued jobs unchanged."""
+        finished = FinishedJobs(1)
+        queue = JobQueue(MockCLIWrapper(delay=0, song_count=1), job_finished_callback=finished)
+        done = queue.enqueue("http://example.com/1", DownloadConfig())
+        assert finished.wait()
+        queue.pause()
+        waiting = queue.enqueue("http://example.com/2", DownloadConfig())
+
+        with pytest.raises(ValueError, match="only queued jobs"):
+            queue.update_jobs([waiting.job_id, done.job_id], {"output_dir": "music"})
+        assert waiting.config.output_dir == DownloadConfig().output_dir
+
+        with pytest.raises(ValueError, match="Unknown job"):
+            queue.update_jobs(["missing"], {"output_dir": "music"})
+
+    def test_cancel_unknown_job(self) -> None:
+        """Test that cancelling an unknown job returns False."""
+        queue = JobQueue(MockCLIWrapper(delay=0))
+
+        assert queue.cancel("missing") is False
+
+    def test_new_job_is_queued(self) -> None:
+        """Test the initial state of a job."""
+        job = QueuedJob(job_id="job-1", url="http://example.com", config=DownloadConfig())
+
+        assert job.status == JOB_QUEUED
+        assert job.exit_code is None
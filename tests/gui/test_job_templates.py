@@ -0,0 +1,46 @@
+"""Unit tests for JobTemplateService."""
+
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_templates import JobTemplateService
+
+
+class TestJobTemplateService:
+    """Test JobTemplateService functionality."""
+
+    @pytest.fixture
+    def service(self, tmp_path: Path) -> JobTemplateService:
+        """Create a template service backed by a temporary file."""
+        return JobTemplateService(templates_path=tmp_path / "templates.json")
+
+    def test_templates_stored_in_own_file(self, service: JobTemplateService) -> None:
+        """Test that templates are saved to the templates file."""
+        service.save_profile("Podcasts", DownloadConfig(output_dir="/podcasts"))
+
+        assert service.profiles_path.name == "templates.json"
+        assert service.profiles_path.exists()
+        assert service.list_profiles() == ["Podcasts"]
+
+    def test_create_job_from_template(self, service: JobTemplateService) -> None:
+        """Test that a job is enqueued with the template's settings."""
+        config = DownloadConfig(output_dir="/podcasts", retries=5, limit=20)
+        service.save_profile("Podcasts", config)
+        queue = MagicMock()
+
+        job = service.create_job_from_template("Podcasts", "http://example.com/feed", queue)
+
+        queue.enqueue.assert_called_once_with("http://example.com/feed", config)
+        assert job is queue.enqueue.return_value
+
+    def test_create_job_from_missing_template(self, service: JobTemplateService) -> None:
+        """Test that an unknown template raises ValueError without enqueueing."""
+        queue = MagicMock()
+
+        with pytest.raises(ValueError, match="Template not found"):
+            service.create_job_from_template("Missing", "http://example.com", queue)
+
+        queue.enqueue.assert_not_called()
@@ -0,0 +1,116 @@
+"""Unit tests for LibraryService."""
+
+from datetime import date
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.libraries import Library, LibraryService
+
+URL = "https://www.izanmei.cc/album/hymns-442-1.html"
+
+
+class TestLibrary:
+    """Test album folders of a library."""
+
+    def test_root_without_template(self) -> None:
+        """Test that albums go to the root without a folder template."""
+        assert Library("NAS", "/nas/music").output_dir(URL) == Path("/nas/music")
+
+    def test_folder_template(self) -> None:
+        """Test that the template's placeholders are filled from the URL and date."""
+        library = Library("Podcasts", "/podcasts", "{source}/{date}")
+
+        folder = library.output_dir(URL, today=date(2024, 5, 1))
+
+        assert folder == Path("/podcasts/www.izanmei.cc/2024-05-01")
+
+
+class TestLibraryService:
+    """Test managing libraries and applying them to jobs."""
+
+    @pytest.fixture
+    def service(self, tmp_path: Path) -> LibraryService:
+        """Create a library service backed by a temporary file."""
+        return LibraryService(tmp_path / "libraries.json")
+
+    def test_no_library(self, service: LibraryService) -> None:
+        """Test that jobs keep their output directory without libraries."""
+        config = DownloadConfig(output_dir="/downloads")
+
+        assert service.resolve(URL, config) is config
+
+    def test_active_library_is_applied(self, service: LibraryService) -> None:
+        """Test that jobs without a library download into the active one."""
+        service.add_library("NAS", "/nas/music", settings={"normalize_loudness": True})
+        service.set_active_library("NAS")
+
+        config = service.resolve(URL, DownloadConfig(output_dir="/downloads", retries=5))
+
+        assert config.output_dir == str(Path("/nas/music"))
+        assert config.library == "NAS"
+        assert config.normalize_loudness
+        assert config.retries == 5
+
+    def test_job_library_wins(self, service: LibraryService) -> None:
+        """Test that a job's own library takes precedence over the active one."""
+        service.add_library("NAS", "/nas/music")
+        service.add_library("Laptop", "/home/music")
+        service.set_active_library("NAS")
+
+        config = service.resolve(URL, DownloadConfig(library="Laptop"))
+
+        assert config.output_dir == str(Path("/home/music"))
+
+    def test_missing_library_is_ignored(self, service: LibraryService) -> None:
+        """Test that a job naming a removed library keeps its output directory."""
+        config = DownloadConfig(output_dir="/downloads", library="Gone")
+
+        assert service.resolve(URL, config) is config
+
+    def test_switch_and_remove(self, service: LibraryService) -> None:
+        """Test switching the active library and removing it."""
+        service.add_library("NAS", "/nas/music")
+        assert service.active_library() is None
+
+        service.set_active_library("NAS")
+        assert service.active_library() == Library("NAS", "/nas/music")
+
+        assert service.remove_library("NAS")
+        assert service.active_library() is None
+        assert not service.remove_library("NAS")
+
+    def test_unknown_active_library(self, service: LibraryService) -> None:
+        """Test that only existing libraries can be activated."""
+        with pytest.raises(ValueError):
+            service.set_active_library("NAS")
+
+    def test_add_replaces_same_name(self, service: LibraryService) -> None:
+        """Test that adding a library again replaces it and keeps it active."""
+        service.add_library("NAS", "/nas/music")
+        service.set_active_library("NAS")
+
+        service.add_library("NAS", "/mnt/nas", "{source}")
+
+        assert service.list_libraries() == [Library("NAS", "/mnt/nas", "{source}")]
+        assert service.active_library() is not None
+
+    def test_invalid_libraries_are_rejected(self, service: LibraryService) -> None:
+        """Test that empty names, bad templates and unrelated settings are rejected."""
+        with pytest.raises(ValueError):
+            service.add_library(" ", "/nas/music")
+        with pytest.raises(ValueError):
+            service.add_library("NAS", "")
+        with pytest.raises(ValueError):
+            service.add_library("NAS", "/nas/music", "{artist}")
+        with pytest.raises(ValueError):
+            service.add_library("NAS", "/nas/music", settings={"retries": 5})
+        assert service.list_libraries() == []
+
+    def test_invalid_file(self, tmp_path: Path, service: LibraryService) -> None:
+        """Test that an unreadable file means no libraries."""
+        (tmp_path / "libraries.json").write_text("[1, 2]", encoding="utf-8")
+
+        assert service.list_libraries() == []
+        assert service.active_library() is None
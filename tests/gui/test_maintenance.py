@@ -0,0 +1,178 @@
+"""Unit tests for scheduled library maintenance."""
+
+import random
+from datetime import datetime
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_core.utils.manifest import (
+    AlbumManifest,
+    ManifestTrack,
+    file_checksum,
+    write_manifest,
+)
+from resource_fetcher_gui.gui.core.download_history import HistoryEntry
+from resource_fetcher_gui.gui.core.maintenance import (
+    CHECKSUM_SAMPLE,
+    CLEANUP,
+    DEFAULT_INTERVALS,
+    HOUR,
+    LIBRARY_SCAN,
+    MaintenanceScheduler,
+    MaintenanceTask,
+    library_folders,
+    scan_library,
+    verify_checksum_sample,
+)
+
+
+def write_album(folder: Path, contents: dict[str, bytes]) -> None:
+    """Write an album folder with a manifest describing the files as they are now."""
+    folder.mkdir(parents=True, exist_ok=True)
+    tracks = []
+    for index, (filename, data) in enumerate(contents.items(), start=1):
+        path = folder / filename
+        path.write_bytes(data)
+        tracks.append(
+            ManifestTrack(
+                index=index,
+                title=filename,
+                url=f"http://a.com/{index}.mp3",
+                status="success",
+                filename=filename,
+                size=len(data),
+                sha256=file_checksum(path),
+            )
+        )
+    manifest = AlbumManifest(
+        source_url="http://a.com/album", title="Album", source="test", fetched_at="2024-01-01"
+    )
+    manifest.tracks = tracks
+    write_manifest(folder, manifest)
+
+
+class TestLibraryChecks:
+    """Test the checks of downloaded albums."""
+
+    def test_library_folders(self) -> None:
+        """Test that completed downloads are listed once each."""
+        entries = [
+            HistoryEntry("http://a.com/1", "One", "/music/one", "2024-01-01"),
+            HistoryEntry("http://a.com/2", "Two", "/music/two", "2024-01-02", completed=False),
+            HistoryEntry("http://a.com/1", "One", "/music/one", "2024-01-03"),
+        ]
+
+        assert library_folders(entries) == [Path("/music/one")]
+
+    def test_scan_intact_library(self, tmp_path: Path) -> None:
+        """Test that complete albums have no findings."""
+        write_album(tmp_path / "album", {"01.mp3": b"one", "02.mp3": b"two"})
+
+        assert scan_library([tmp_path / "album"]) == []
+
+    def test_scan_finds_missing_and_truncated_files(self, tmp_path: Path) -> None:
+        """Test that missing folders and missing or truncated files are reported."""
+        folder = tmp_path / "album"
+        write_album(folder, {"01.mp3": b"one", "02.mp3": b"two"})
+        (folder / "01.mp3").write_bytes(b"o")
+        (folder / "02.mp3").unlink()
+
+        findings = scan_library([folder, tmp_path / "gone"])
+
+        assert len(findings) == 3
+        assert str(folder / "01.mp3") in findings[0]
+        assert str(folder / "02.mp3") in findings[1]
+        assert "Album folder missing" in findings[2]
+
+    def test_scan_skips_folders_without_manifest(self, tmp_path: Path) -> None:
+        """Test that albums downloaded by older versions aren't reported."""
+        (tmp_path / "old").mkdir()
+
+        assert scan_library([tmp_path / "old"]) == []
+
+    def test_checksum_sample_finds_corruption(self, tmp_path: Path) -> None:
+        """Test that a file with changed contents but the same size is reported."""
+        folder = tmp_path / "album"
+        write_album(folder, {"01.mp3": b"one", "02.mp3": b"two"})
+        (folder / "02.mp3").write_bytes(b"tw0")
+
+        findings = verify_checksum_sample([folder], rng=random.Random(1))
+
+        assert findings == [f"Checksum mismatch: {folder / '02.mp3'}"]
+        assert scan_library([folder]) == []
+
+    def test_checksum_sample_size(self, tmp_path: Path) -> None:
+        """Test that only the sample is verified."""
+        folder = tmp_path / "album"
+        write_album(folder, {f"{i:02}.mp3": b"x" for i in range(10)})
+        for path in folder.glob("*.mp3"):
+            path.write_bytes(b"y")
+
+        assert len(verify_checksum_sample([folder], sample_size=3)) == 3
+
+
+class TestMaintenanceScheduler:
+    """Test the task schedule and run record."""
+
+    @pytest.fixture
+    def scheduler(self, tmp_path: Path) -> MaintenanceScheduler:
+        """Create a scheduler storing its files in a temporary folder."""
+        return MaintenanceScheduler(tmp_path / "maintenance.json", tmp_path / "runs.json")
+
+    def test_default_tasks(self, scheduler: MaintenanceScheduler) -> None:
+        """Test that every task is enabled with its default interval."""
+        tasks = scheduler.load_tasks()
+
+        assert [task.name for task in tasks] == list(DEFAULT_INTERVALS)
+        assert all(task.enabled for task in tasks)
+
+    def test_save_and_load_tasks(self, scheduler: MaintenanceScheduler) -> None:
+        """Test that a saved schedule is read back."""
+        scheduler.save_tasks([MaintenanceTask(CLEANUP, 2, enabled=False)])
+
+        tasks = {task.name: task for task in scheduler.load_tasks()}
+
+        assert tasks[CLEANUP] == MaintenanceTask(CLEANUP, 2, enabled=False)
+        assert tasks[LIBRARY_SCAN].interval_hours == DEFAULT_INTERVALS[LIBRARY_SCAN]
+
+    def test_save_rejects_invalid_tasks(self, scheduler: MaintenanceScheduler) -> None:
+        """Test that unknown tasks and non-positive intervals are rejected."""
+        with pytest.raises(ValueError):
+            scheduler.save_tasks([MaintenanceTask("defrag", 1)])
+        with pytest.raises(ValueError):
+            scheduler.save_tasks([MaintenanceTask(CLEANUP, 0)])
+
+    def test_run_records_findings(self, scheduler: MaintenanceScheduler) -> None:
+        """Test that runs are recorded and listed newest first."""
+        scheduler.run(LIBRARY_SCAN, lambda: [])
+        scheduler.run(CHECKSUM_SAMPLE, lambda: ["Checksum mismatch: a.mp3"])
+
+        runs = scheduler.list_maintenance_runs()
+
+        assert [run.task for run in runs] == [CHECKSUM_SAMPLE, LIBRARY_SCAN]
+        assert runs[0].findings == ["Checksum mismatch: a.mp3"]
+        assert scheduler.list_maintenance_runs(LIBRARY_SCAN) == [runs[1]]
+
+    def test_run_records_error(self, scheduler: MaintenanceScheduler) -> None:
+        """Test that a failing task is recorded instead of raising."""
+
+        def fail() -> list[str]:
+            raise OSError("disk gone")
+
+        run = scheduler.run(CLEANUP, fail)
+
+        assert run.error == "disk gone"
+        assert scheduler.list_maintenance_runs() == [run]
+
+    def test_due_tasks(self, scheduler: MaintenanceScheduler) -> None:
+        """Test that tasks are due once their interval passed since the last run."""
+        scheduler.save_tasks([MaintenanceTask(CHECKSUM_SAMPLE, 1, enabled=False)])
+        assert set(scheduler.due_tasks()) == set(DEFAULT_INTERVALS) - {CHECKSUM_SAMPLE}
+
+        run = scheduler.run(LIBRARY_SCAN, lambda: [])
+        finished = datetime.fromisoformat(run.finished_at).timestamp()
+
+        assert LIBRARY_SCAN not in scheduler.due_tasks(finished + HOUR)
+        interval = DEFAULT_INTERVALS[LIBRARY_SCAN] * HOUR
+        assert LIBRARY_SCAN in scheduler.due_tasks(finished + interval)
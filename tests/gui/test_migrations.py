@@ -0,0 +1,144 @@
+"""Unit tests for startup migrations."""
+
+import json
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.migrations import (
+    Migration,
+    MigrationRunner,
+    MigrationTarget,
+    manifest_version,
+    settings_log_level,
+)
+
+
+def write_json(path: Path, data: object) -> Path:
+    """Write a JSON file for a test."""
+    path.write_text(json.dumps(data), encoding="utf-8")
+    return path
+
+
+def read_json(path: Path) -> object:
+    """Read a JSON file written by a migration."""
+    return json.loads(path.read_text(encoding="utf-8"))
+
+
+class TestMigrationSteps:
+    """Test the shipped format upgrades."""
+
+    def test_settings_verbose_becomes_log_level(self, tmp_path: Path) -> None:
+        """Test that the legacy verbose flag is replaced by a debug log level."""
+        path = write_json(tmp_path / "config.json", {"verbose": True, "workers": 2})
+
+        settings_log_level(path)
+
+        assert read_json(path) == {"workers": 2, "log_level": "debug"}
+
+    def test_settings_keep_explicit_log_level(self, tmp_path: Path) -> None:
+        """Test that an explicit log level wins over the verbose flag."""
+        path = write_json(tmp_path / "config.json", {"verbose": True, "log_level": "warning"})
+
+        settings_log_level(path)
+
+        assert read_json(path) == {"log_level": "warning"}
+
+    def test_manifest_version_recorded(self, tmp_path: Path) -> None:
+        """Test that manifests without a version get version 1."""
+        path = write_json(tmp_path / "manifest.json", {"title": "Album", "tracks": []})
+
+        manifest_version(path)
+
+        assert read_json(path) == {"title": "Album", "tracks": [], "version": 1}
+
+
+class TestMigrationRunner:
+    """Test running upgrades and recording versions."""
+
+    def test_upgrade_backs_up_and_records_version(self, tmp_path: Path) -> None:
+        """Test that files are upgraded after a backup and not upgraded twice."""
+        path = write_json(tmp_path / "config.json", {"verbose": True})
+        target = MigrationTarget(
+            "settings", lambda: [path], [Migration(1, "log level", settings_log_level)]
+        )
+        runner = MigrationRunner([target], tmp_path / "versions.json")
+
+        results = runner.run()
+
+        assert len(results) == 1
+        assert (results[0].from_version, results[0].to_version) == (0, 1)
+        assert results[0].files == [str(path)]
+        assert read_json(tmp_path / "config.json.v0.bak") == {"verbose": True}
+        assert read_json(path) == {"log_level": "debug"}
+        assert runner.load_versions() == {"settings": 1}
+        assert runner.run() == []
+
+    def test_steps_run_in_order_from_recorded_version(self, tmp_path: Path) -> None:
+        """Test that only the upgrades after the recorded version run."""
+        applied: list[int] = []
+        steps = [
+            Migration(version, f"step {version}", lambda path, v=version: applied.append(v))
+            for version in (1, 2, 3)
+        ]
+        path = write_json(tmp_path / "data.json", {})
+        write_json(tmp_path / "versions.json", {"data": 1})
+        runner = MigrationRunner(
+            [MigrationTarget("data", lambda: [path], steps)], tmp_path / "versions.json"
+        )
+
+        results = runner.run()
+
+        assert applied == [2, 3]
+        assert (results[0].from_version, results[0].to_version) == (1, 3)
+        assert (tmp_path / "data.json.v1.bak").exists()
+
+    def test_failure_restores_files(self, tmp_path: Path) -> None:
+        """Test that a failed upgrade restores every file and is retried later."""
+        good = write_json(tmp_path / "good.json", {"verbose": True})
+        bad = tmp_path / "bad.json"
+        bad.write_text("[1, 2]", encoding="utf-8")
+        target = MigrationTarget(
+            "settings", lambda: [good, bad], [Migration(1, "log level", settings_log_level)]
+        )
+        runner = MigrationRunner([target], tmp_path / "versions.json")
+
+        results = runner.run()
+
+        assert "doesn't contain a JSON object" in results[0].error
+        assert results[0].to_version == 0
+        assert read_json(good) == {"verbose": True}
+        assert runner.load_versions() == {}
+
+    def test_bad_file_of_non_atomic_target_is_skipped(self, tmp_path: Path) -> None:
+        """Test that one damaged manifest doesn't hold back the others."""
+        good = write_json(tmp_path / "good.json", {"title": "Album"})
+        bad = tmp_path / "bad.json"
+        bad.write_text("[1, 2]", encoding="utf-8")
+        target = MigrationTarget(
+            "manifests",
+            lambda: [bad, good],
+            [Migration(1, "format version recorded", manifest_version)],
+            atomic=False,
+        )
+        runner = MigrationRunner([target], tmp_path / "versions.json")
+
+        results = runner.run()
+
+        assert results[0].error == ""
+        assert results[0].files == [str(good)]
+        assert len(results[0].skipped) == 1
+        assert results[0].skipped[0].startswith(str(bad))
+        assert bad.read_text(encoding="utf-8") == "[1, 2]"
+        assert not (tmp_path / "bad.json.v0.bak").exists()
+        assert read_json(good) == {"title": "Album", "version": 1}
+        assert runner.load_versions() == {"manifests": 1}
+        assert runner.run() == []
+
+    def test_nothing_to_upgrade_is_marked_current(self, tmp_path: Path) -> None:
+        """Test that a target without files is marked current without a result."""
+        target = MigrationTarget(
+            "settings", lambda: [], [Migration(1, "log level", settings_log_level)]
+        )
+        runner = MigrationRunner([target], tmp_path / "versions.json")
+
+        assert runner.run() == []
+        assert runner.load_versions() == {"settings": 1}
@@ -0,0 +1,174 @@
+"""Unit tests for the mock CLI backend."""
+
+import subprocess
+import threading
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.mock_cli import (
+    TERMINATED_EXIT_CODE,
+    MockCLIWrapper,
+    SimulatedProcess,
+)
+from resource_fetcher_gui.gui.core.output_parser import (
+    AlbumInfo,
+    DownloadSummary,
+    OutputParser,
+    SongProgress,
+)
+
+
+class TestSimulatedProcess:
+    """Test SimulatedProcess output and lifecycle."""
+
+    def test_output_follows_cli_format(self) -> None:
+        """Test that the simulated output can be parsed like real CLI output."""
+        process = SimulatedProcess(song_count=3, delay=0)
+        parser = OutputParser()
+
+        results = [parser.parse_line(line.rstrip("\n")) for line in process.stdout]
+
+        albums = [result for result in results if isinstance(result, AlbumInfo)]
+        assert albums and albums[-1].song_count == 3
+        assert sum(isinstance(result, SongProgress) for result in results) == 3
+        summary = results[-2]
+        assert isinstance(summary, DownloadSummary)
+        assert (summary.success, summary.failed, summary.total) == (3, 0, 3)
+        assert process.wait() == 0
+        assert process.poll() == 0
+
+    def test_failed_tracks_set_exit_code(self) -> None:
+        """Test that failing tracks produce error lines and a non-zero exit code."""
+        process = SimulatedProcess(song_count=4, delay=0, fail_every=2)
+
+        lines = list(process.stdout)
+
+        assert sum(" - ERROR - " in line for line in lines) == 2
+        assert "  失败 (Failed): 2\n" in lines
+        assert process.wait() == 1
+
+    def test_wait_times_out_while_running(self) -> None:
+        """Test that wait raises TimeoutExpired before the output is consumed."""
+        process = SimulatedProcess(song_count=1, delay=0)
+
+        with pytest.raises(subprocess.TimeoutExpired):
+            process.wait(timeout=0.01)
+        assert process.poll() is None
+
+    def test_terminate_stops_output(self) -> None:
+        """Test that terminate ends the simulation early."""
+        process = SimulatedProcess(song_count=3, delay=30)
+        first_lines = [next(process.stdout) for _ in range(7)]
+
+        process.terminate()
+
+        assert first_lines[-1].startswith("[1/3]")
+        assert list(process.stdout) == []
+        assert process.wait(timeout=1) == TERMINATED_EXIT_CODE
+
+
+class TestMockCLIWrapper:
+    """Test MockCLIWrapper functionality."""
+
+    def test_list_albums_runs_without_cli(self) -> None:
+        """Test that the albums of an artist are simulated."""
+        wrapper = MockCLIWrapper(delay=0)
+
+        urls = wrapper.list_albums("http://example.com/artist")
+
+        assert len(urls) == 3
+        assert urls[0] == "https://www.izanmei.cc/album/mock-1.html"
+
+    def test_list_supported_sources_runs_without_cli(self) -> None:
+        """Test that the bundled site adapters are reported as sources."""
+        sources = MockCLIWrapper(delay=0).list_supported_sources()
+
+        assert [source.name for source in sources] == ["Izanmei"]
+
+    def test_list_cli_options_runs_without_cli(self) -> None:
+        """Test that the simulated CLI lists its options."""
+        options = MockCLIWrapper(delay=0).list_cli_options()
+
+        assert {"--normalize", "--merge", "--scan-command"} <= options
+
+    def test_execute_download_runs_without_cli(self) -> None:
+        """Test a full simulated download through the wrapper callbacks."""
+        wrapper = MockCLIWrapper(delay=0, song_count=5, fail_every=0)
+        lines: list[str] = []
+        exit_codes: list[int] = []
+
+        thread = wrapper.execute_download(
+            "http://example.com/album", DownloadConfig(limit=2), lines.append, exit_codes.append
+        )
+        thread.join(timeout=5)
+
+        assert exit_codes == [0]
+        assert "歌曲数 (Songs): 2" in lines
+        assert [line for line in lines if line.startswith("[")] == [
+            "[1/2] 第1首 Mock Song 1",
+            "[2/2] 第2首 Mock Song 2",
+        ]
+
+    def test_execute_download_honors_track_range(self) -> None:
+        """Test that only tracks in the range are simulated."""
+        wrapper = MockCLIWrapper(delay=0, song_count=10, fail_every=0)
+        lines: list[str] = []
+
+        thread = wrapper.execute_download(
+            "http://example.com/album", DownloadConfig(track_range="2-4,9-"), lines.append
+        )
+        thread.join(timeout=5)
+
+        assert "歌曲数 (Songs): 5" in lines
+
+    def test_execute_download_honors_title_patterns(self) -> None:
+        """Test that only tracks passing the title patterns are simulated."""
+        wrapper = MockCLIWrapper(delay=0, song_count=12, fail_every=0)
+        lines: list[str] = []
+        config = DownloadConfig(include_pattern="*Song 1*", exclude_pattern="re:Song 10$")
+
+        thread = wrapper.execute_download("http://example.com/album", config, lines.append)
+        thread.join(timeout=5)
+
+        # Songs 1, 11 and 12
+        assert "歌曲数 (Songs): 3" in lines
+
+    def test_stop_download_terminates_simulation(self) -> None:
+        """Test that stopping a simulated download reports the terminated exit code."""
+        wrapper = MockCLIWrapper(delay=30, song_count=3)
+        exit_codes: list[int] = []
+        started = threading.Event()
+
+        thread = wrapper.execute_download(
+            "http://example.com/album",
+            DownloadConfig(),
+            lambda line: started.set() if line.startswith("[1/3]") else None,
+            exit_codes.append,
+        )
+        assert started.wait(timeout=5)
+
+        wrapper.stop_download()
+        thread.join(timeout=5)
+
+        assert exit_codes == [TERMINATED_EXIT_CODE]
+        assert not wrapper.is_running()
+
+    def test_complete_callback_can_start_next_download(self) -> None:
+        """Test that a download started from the complete callback keeps its process."""
+        wrapper = MockCLIWrapper(delay=0.05, song_count=2, fail_every=0)
+        exit_codes: list[int] = []
+        second_done = threading.Event()
+
+        def start_second(exit_code: int) -> None:
+            exit_codes.append(exit_code)
+            wrapper.execute_download(
+                "http://example.com/2",
+                DownloadConfig(),
+                complete_callback=lambda code: (exit_codes.append(code), second_done.set()),
+            )
+
+        wrapper.execute_download("http://example.com/1", DownloadConfig(), None, start_second)
+
+        assert second_done.wait(timeout=5)
+        assert exit_codes == [0, 0]
@@ -0,0 +1,97 @@
+"""Unit tests for NotificationStore."""
+
+from pathlib import Path
+from unittest.mock import patch
+
+from resource_fetcher_gui.gui.core.notifications import NotificationStore
+
+
+class TestNotificationStore:
+    """Test the persistent notification center."""
+
+    def test_add_and_list_newest_first(self, tmp_path: Path) -> None:
+        """Test that notifications persist and are listed newest first."""
+        store = NotificationStore(tmp_path / "notifications.json")
+        store.add("success", "Download completed")
+        store.add("error", "Download failed")
+
+        notifications = NotificationStore(tmp_path / "notifications.json").list_notifications()
+
+        assert [item.message for item in notifications] == [
+            "Download failed",
+            "Download completed",
+        ]
+        assert notifications[0].severity == "error"
+        assert not notifications[0].read
+
+    def test_mark_read_single(self, tmp_path: Path) -> None:
+        """Test that a single notification can be marked as read."""
+        store = NotificationStore(tmp_path / "notifications.json")
+        first = store.add("info", "first")
+        store.add("info", "second")
+
+        assert store.mark_read(first.notification_id) == 1
+        assert [item.message for item in store.list_notifications(unread_only=True)] == [
+            "second"
+        ]
+
+    def test_mark_all_read(self, tmp_path: Path) -> None:
+        """Test that all notifications are marked as read without an identifier."""
+        store = NotificationStore(tmp_path / "notifications.json")
+        store.add("info", "first")
+        store.add("info", "second")
+
+        assert store.mark_read() == 2
+        assert store.unread_count() == 0
+        assert store.mark_read() == 0
+
+    def test_undelivered_until_acknowledged(self, tmp_path: Path) -> None:
+        """Test that a stored result stays undelivered across restarts until shown."""
+        store = NotificationStore(tmp_path / "notifications.json")
+        store.add("info", "shown right away")
+        result = store.add("error", "Download failed", delivered=False)
+
+        reopened = NotificationStore(tmp_path / "notifications.json")
+        assert [item.message for item in reopened.undelivered()] == ["Download failed"]
+
+        assert reopened.mark_delivered(result.notification_id)
+        assert not reopened.mark_delivered(result.notification_id)
+        assert reopened.undelivered() == []
+        assert reopened.unread_count() == 2
+
+    def test_files_without_delivery_state_count_as_delivered(self, tmp_path: Path) -> None:
+        """Test that notifications stored before delivery tracking aren't shown again."""
+        path = tmp_path / "notifications.json"
+        path.write_text(
+            '[{"notification_id": "a", "severity": "info", "message": "old", '
+            '"created_at": "2026-01-01T00:00:00", "read": false}]',
+            encoding="utf-8",
+        )
+
+        assert NotificationStore(path).undelivered() == []
+
+    def test_clear(self, tmp_path: Path) -> None:
+        """Test that clear removes all notifications."""
+        store = NotificationStore(tmp_path / "notifications.json")
+        store.add("warning", "Output share disconnected")
+
+        store.clear()
+
+        assert store.list_notifications() == []
+
+    def test_oldest_notifications_are_dropped(self, tmp_path: Path) -> None:
+        """Test that only the newest notifications are kept."""
+        store = NotificationStore(tmp_path / "notifications.json")
+
+        with patch("resource_fetcher_gui.gui.core.notifications.MAX_NOTIFICATIONS", 2):
+            for index in range(3):
+                store.add("info", str(index))
+
+        assert [item.message for item in store.list_notifications()] == ["2", "1"]
+
+    def test_invalid_file(self, tmp_path: Path) -> None:
+        """Test that an invalid notifications file is treated as empty."""
+        path = tmp_path / "notifications.json"
+        path.write_text("[{", encoding="utf-8")
+
+        assert NotificationStore(path).list_notifications() == []
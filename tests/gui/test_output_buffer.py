@@ -0,0 +1,47 @@
+"""Unit tests for OutputBuffer."""
+
+import threading
+
+from resource_fetcher_gui.gui.core.output_buffer import OutputBuffer
+
+
+class TestOutputBuffer:
+    """Test the bounded CLI output buffer."""
+
+    def test_drain_returns_lines_in_order(self) -> None:
+        """Test that collected lines keep their order and empty the buffer."""
+        buffer = OutputBuffer(max_lines=10)
+        buffer.put("first")
+        buffer.put("second")
+
+        assert buffer.drain() == (["first", "second"], 0)
+        assert buffer.drain() == ([], 0)
+
+    def test_oldest_lines_are_dropped_and_counted(self) -> None:
+        """Test that a full buffer keeps the newest lines and counts the rest."""
+        buffer = OutputBuffer(max_lines=3)
+        for index in range(5):
+            buffer.put(str(index))
+
+        assert buffer.drain() == (["2", "3", "4"], 2)
+        buffer.put("5")
+        assert buffer.drain() == (["5"], 0)
+        assert buffer.total_dropped == 2
+
+    def test_concurrent_writers(self) -> None:
+        """Test that no line is lost or double-counted across threads."""
+        buffer = OutputBuffer(max_lines=100)
+
+        def write() -> None:
+            for index in range(1000):
+                buffer.put(str(index))
+
+        threads = [threading.Thread(target=write) for _ in range(4)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        lines, dropped = buffer.drain()
+        assert len(lines) == 100
+        assert len(lines) + dropped == 4000
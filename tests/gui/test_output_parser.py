@@ -1,11 +1,19 @@
 """Unit tests for OutputParser."""
 
+import pytest
 
 from resource_fetcher_gui.gui.core.output_parser import (
     AlbumInfo,
     DownloadSummary,
     OutputParser,
     SongProgress,
+    parse_downloaded_bytes,
+    parse_failed_tracks,
+    parse_processed_files,
+    parse_rate_ban,
+    parse_track_position,
+    parse_track_transfer,
+    parse_transferred_bytes,
 )
 
 
@@ -165,6 +173,179 @@ class TestOutputParser:
             assert result.total == 10
             assert result.status == "downloading"
 
+    def test_parse_album_header(self) -> None:
+        """Test that the album header is collected into album info."""
+        parser = OutputParser()
+
+        for line in ["专辑 (Album): Hymns", "来源 (Source): izanmei", "歌曲数 (Songs): 12"]:
+            result = parser.parse_line(line)
+
+        assert result == AlbumInfo(title="Hymns", source="izanmei", song_count=12)
+        assert parser.album == result
+
+    @pytest.mark.parametrize(
+        "line, politeness",
+        [
+            (
+                "礼貌策略 (Politeness): delay=1.0s workers=2 window=01:00-06:00",
+                {"delay": 1.0, "workers": 2, "window": "01:00-06:00", "relaxed": False},
+            ),
+            (
+                "礼貌策略 (Politeness): delay=0.5s workers=4 (relaxed)",
+                {"delay": 0.5, "workers": 4, "window": None, "relaxed": True},
+            ),
+        ],
+    )
+    def test_parse_politeness(self, line: str, politeness: dict) -> None:
+        """Test that the effective politeness policy is part of the album info."""
+        parser = OutputParser()
+
+        for header in ["专辑 (Album): Hymns", "来源 (Source): izanmei", line, "歌曲数 (Songs): 12"]:
+            result = parser.parse_line(header)
+
+        assert isinstance(result, AlbumInfo)
+        assert result.politeness == politeness
+
+    def test_parse_merge_progress(self) -> None:
+        """Test parsing merge progress lines."""
+        parser = OutputParser()
+
+        assert parser.parse_line("合并 (Merge): 40%") == {"type": "merge_progress", "percent": 40}
+        assert parser.parse_line("合并 (Merge): 12 首 -> Album.m4b") is None
+
+    def test_parse_processed_files(self) -> None:
+        """Test parsing post-processing progress lines."""
+        parser = OutputParser()
+
+        result = parser.parse_line("  处理 (Processed): 3/5")
+
+        assert result == {"type": "processed", "done": 3, "total": 5}
+        assert parse_processed_files("  处理 (Processed): 3/5") == 3
+        assert parse_processed_files("[3/5] Amazing Grace") is None
+
+    def test_parse_failures(self) -> None:
+        """Test reading failed tracks and rate limits from single lines."""
+        assert parse_failed_tracks("  失败 (Failed): 2") == 2
+        assert parse_failed_tracks("  成功 (Success): 2") is None
+        assert parse_rate_ban("  限流 (Rate limited): 建议冷却 (cooldown) 600 秒") == 600
+        assert parse_rate_ban("[3/5] Amazing Grace") is None
+
+    def test_parse_storage_events(self) -> None:
+        """Test parsing network share disconnects and reconnects."""
+        parser = OutputParser()
+
+        lost = parser.parse_line("  存储 (Storage): 网络共享已断开, 等待重新连接 (mount lost)")
+        restored = parser.parse_line("  存储 (Storage): 网络共享已恢复 (mount restored)")
+
+        assert lost == {"type": "mount_lost"}
+        assert restored == {"type": "mount_restored"}
+
+    def test_parse_rate_banned(self) -> None:
+        """Test parsing a site refusing requests with a cooldown."""
+        parser = OutputParser()
+
+        result = parser.parse_line("  限流 (Rate limited): 建议冷却 (cooldown) 600 秒")
+
+        assert result == {"type": "rate_banned", "cooldown": 600}
+
+    def test_parse_scan_failed(self) -> None:
+        """Test parsing a song rejected by the scanner."""
+        parser = OutputParser()
+        parser.parse_line("[2/10] Song Two")
+
+        result = parser.parse_line("  扫描 (Scan): 未通过 (failed) Win.Test.EICAR FOUND")
+
+        assert result == {
+            "type": "scan_failed",
+            "title": "Song Two",
+            "reason": "Win.Test.EICAR FOUND",
+        }
+
+    def test_parse_wrong_content_type(self) -> None:
+        """Test parsing a song whose file wasn't audio."""
+        parser = OutputParser()
+        parser.parse_line("[2/10] Song Two")
+
+        result = parser.parse_line(
+            "  类型 (Content type): 错误 (wrong-content-type) expected mp3, got html"
+        )
+
+        assert result == {
+            "type": "wrong_content_type",
+            "title": "Song Two",
+            "reason": "expected mp3, got html",
+        }
+
+    def test_parse_sanity_failed(self) -> None:
+        """Test parsing a song that failed the size or silence checks."""
+        parser = OutputParser()
+        parser.parse_line("[3/10] Song Three")
+
+        result = parser.parse_line("  检查 (Sanity): 未通过 (failed) audio is silent")
+
+        assert result == {
+            "type": "sanity_failed",
+            "title": "Song Three",
+            "reason": "audio is silent",
+        }
+
+    def test_parse_skipped_song(self) -> None:
+        """Test that a skip reason marks the previous song as skipped."""
+        parser = OutputParser()
+
+        parser.parse_line("[3/10] Amazing Grace (Remix)")
+        result = parser.parse_line('  跳过 (Skip): 标题黑名单 (title blacklist) "remix"')
+
+        assert result == SongProgress(
+            index=3,
+            total=10,
+            title="Amazing Grace (Remix)",
+            status="skipped",
+            reason='标题黑名单 (title blacklist) "remix"',
+        )
+
+    def test_parse_summary_with_data(self) -> None:
+        """Test that the summary includes the downloaded data."""
+        parser = OutputParser()
+        lines = [
+            "下载完成! Download Summary",
+            "  成功 (Success): 2",
+            "  失败 (Failed): 0",
+            "  跳过 (Skipped): 0",
+            "  总计 (Total): 2",
+            "  数据量 (Data): 8000000 字节 (bytes)",
+            "  耗时 (Time): 4.0 秒",
+            "  速度 (Speed): 0.50 首/秒",
+        ]
+
+        results = [parser.parse_line(line) for line in lines]
+
+        assert isinstance(results[-1], DownloadSummary)
+        assert results[-1].downloaded_bytes == 8_000_000
+
+    def test_parse_downloaded_bytes(self) -> None:
+        """Test reading the downloaded data from a single line."""
+        assert parse_downloaded_bytes("  数据量 (Data): 1024 字节 (bytes)") == 1024
+        assert parse_downloaded_bytes("  总计 (Total): 2") is None
+
+    def test_parse_transferred_data(self) -> None:
+        """Test reading the data used by a track and by the whole download."""
+        assert parse_track_transfer("  传输 (Transferred): [3] 1500 字节 (bytes)") == (3, 1500)
+        assert parse_track_transfer("  传输量 (Transferred): 4500 字节 (bytes)") is None
+        assert parse_transferred_bytes("  传输量 (Transferred): 4500 字节 (bytes)") == 4500
+        assert parse_transferred_bytes("  数据量 (Data): 1024 字节 (bytes)") is None
+
+    def test_parse_track_position(self) -> None:
+        """Test reading the track number and count from a single line."""
+        assert parse_track_position("[3/10] Amazing Grace") == (3, 10)
+        assert parse_track_position("  总计 (Total): 2") is None
+
+    def test_skip_reason_without_song_is_ignored(self) -> None:
+        """Test that a skip reason before any song is ignored."""
+        parser = OutputParser()
+
+        assert parser.parse_line("  跳过 (Skip): something") is None
+
     def test_regex_patterns_compilation(self) -> None:
         """Test that regex patterns are compiled correctly."""
         parser = OutputParser()
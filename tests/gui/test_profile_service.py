@@ -0,0 +1,81 @@
+"""Unit tests for ProfileService."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+
+
+class TestProfileService:
+    """Test ProfileService functionality."""
+
+    @pytest.fixture
+    def service(self, tmp_path: Path) -> ProfileService:
+        """Create a profile service backed by a temporary file."""
+        return ProfileService(profiles_path=tmp_path / "profiles.json")
+
+    def test_list_profiles_when_file_not_exists(self, service: ProfileService) -> None:
+        """Test that no profiles are listed before any are saved."""
+        assert service.list_profiles() == []
+
+    def test_save_and_apply_profile(self, service: ProfileService) -> None:
+        """Test saving a profile and applying it again."""
+        config = DownloadConfig(output_dir="/podcasts", retries=5, delay=2.0)
+
+        service.save_profile("Podcasts", config)
+
+        assert service.list_profiles() == ["Podcasts"]
+        assert service.apply_profile("Podcasts") == config
+
+    def test_save_profile_replaces_existing(self, service: ProfileService) -> None:
+        """Test that saving under an existing name replaces the profile."""
+        service.save_profile("Albums", DownloadConfig(retries=1))
+        service.save_profile("Albums", DownloadConfig(retries=7))
+
+        assert service.list_profiles() == ["Albums"]
+        assert service.apply_profile("Albums").retries == 7
+
+    def test_save_profile_with_empty_name(self, service: ProfileService) -> None:
+        """Test that an empty profile name is rejected."""
+        with pytest.raises(ValueError, match="cannot be empty"):
+            service.save_profile("  ", DownloadConfig())
+
+    def test_list_profiles_sorted(self, service: ProfileService) -> None:
+        """Test that profiles are listed by name."""
+        service.save_profile("b", DownloadConfig())
+        service.save_profile("a", DownloadConfig())
+
+        assert service.list_profiles() == ["a", "b"]
+
+    def test_apply_unknown_profile(self, service: ProfileService) -> None:
+        """Test that applying an unknown profile raises ValueError."""
+        with pytest.raises(ValueError, match="Profile not found"):
+            service.apply_profile("missing")
+
+    def test_apply_profile_validates_values(self, service: ProfileService) -> None:
+        """Test that invalid stored values fall back to defaults."""
+        service.profiles_path.write_text(
+            json.dumps({"Broken": {"timeout": "soon", "retries": 2}}), encoding="utf-8"
+        )
+
+        config = service.apply_profile("Broken")
+
+        assert config.timeout == DownloadConfig().timeout
+        assert config.retries == 2
+
+    def test_delete_profile(self, service: ProfileService) -> None:
+        """Test deleting profiles."""
+        service.save_profile("Temp", DownloadConfig())
+
+        assert service.delete_profile("Temp") is True
+        assert service.delete_profile("Temp") is False
+        assert service.list_profiles() == []
+
+    def test_invalid_profiles_file(self, service: ProfileService) -> None:
+        """Test that a corrupt profiles file is treated as empty."""
+        service.profiles_path.write_text("{ invalid json", encoding="utf-8")
+
+        assert service.list_profiles() == []
@@ -0,0 +1,88 @@
+"""Unit tests for progress summaries."""
+
+from resource_fetcher_gui.gui.core.output_parser import SongProgress
+from resource_fetcher_gui.gui.core.progress_summary import ProgressSummary, summarize
+
+
+class FakeClock:
+    """Clock advanced by hand."""
+
+    def __init__(self) -> None:
+        self.now = 0.0
+
+    def __call__(self) -> float:
+        return self.now
+
+
+class TestSummarize:
+    """Test the wording of summaries."""
+
+    def test_minutes_remaining(self) -> None:
+        """Test that the remaining time is extrapolated from finished tracks."""
+        assert summarize(3, 12, 160.0) == "3 of 12 tracks done, about 8 minutes remaining"
+
+    def test_less_than_a_minute(self) -> None:
+        """Test that a short remaining time isn't rounded up to a minute."""
+        assert summarize(9, 10, 45.0) == "9 of 10 tracks done, less than a minute remaining"
+
+    def test_nothing_done_yet(self) -> None:
+        """Test that no estimate is given before a track has finished."""
+        assert summarize(0, 5, 10.0) == "0 of 5 tracks done, estimating time remaining"
+
+    def test_finished(self) -> None:
+        """Test the summary of a finished download."""
+        assert summarize(5, 5, 100.0) == "All 5 tracks done"
+
+
+class TestProgressSummary:
+    """Test when summaries are passed on."""
+
+    def test_unfinished_tracks_are_ignored(self) -> None:
+        """Test that tracks still downloading don't produce summaries."""
+        received: list[str] = []
+        summary = ProgressSummary(received.append, clock=FakeClock())
+
+        assert summary.update(SongProgress(1, 4, "One")) is None
+        assert received == []
+
+    def test_summaries_are_spaced_by_interval(self) -> None:
+        """Test that summaries between the first and last track are rate limited."""
+        received: list[str] = []
+        clock = FakeClock()
+        summary = ProgressSummary(received.append, interval=30.0, clock=clock)
+
+        clock.now = 10.0
+        summary.update(SongProgress(1, 4, "One", status="success"))
+        clock.now = 20.0
+        summary.update(SongProgress(2, 4, "Two", status="failed"))
+        clock.now = 45.0
+        summary.update(SongProgress(3, 4, "Three", status="skipped"))
+        clock.now = 50.0
+        summary.update(SongProgress(4, 4, "Four", status="success"))
+
+        assert received == [
+            "1 of 4 tracks done, less than a minute remaining",
+            "3 of 4 tracks done, less than a minute remaining",
+            "All 4 tracks done",
+        ]
+
+    def test_repeated_track_counts_once(self) -> None:
+        """Test that a track reported twice isn't counted twice."""
+        received: list[str] = []
+        summary = ProgressSummary(received.append, interval=0.0, clock=FakeClock())
+
+        summary.update(SongProgress(1, 3, "One", status="success"))
+        summary.update(SongProgress(1, 3, "One", status="success"))
+
+        assert len(received) == 1
+
+    def test_reset_starts_over(self) -> None:
+        """Test that a new download starts counting from zero."""
+        received: list[str] = []
+        summary = ProgressSummary(received.append, interval=0.0, clock=FakeClock())
+        summary.update(SongProgress(1, 2, "One", status="success"))
+
+        summary.reset()
+        summary.update(SongProgress(2, 2, "Two", status="success"))
+
+        assert received[-1].startswith("1 of 2 tracks done")
@@ -0,0 +1,86 @@
+"""Unit tests for QuotaService."""
+
+from datetime import datetime
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.download_history import DownloadHistory, HistoryEntry
+from resource_fetcher_gui.gui.core.quota import (
+    MEGABYTE,
+    QuotaExceeded,
+    QuotaLimits,
+    QuotaService,
+    next_period_start,
+    period_start,
+)
+
+NOW = datetime(2026, 10, 16, 12, 0)
+
+
+class TestPeriods:
+    """Test quota period boundaries."""
+
+    def test_period_start(self) -> None:
+        """Test that days start at midnight and months on the first day."""
+        assert period_start("day", NOW) == datetime(2026, 10, 16)
+        assert period_start("month", NOW) == datetime(2026, 10, 1)
+
+    def test_next_period_start(self) -> None:
+        """Test the start of the following day and month, including year ends."""
+        assert next_period_start("day", NOW) == datetime(2026, 10, 17)
+        assert next_period_start("month", NOW) == datetime(2026, 11, 1)
+        assert next_period_start("month", datetime(2026, 12, 31, 23, 0)) == datetime(2027, 1, 1)
+
+
+class TestQuotaService:
+    """Test checking usage against quotas."""
+
+    @pytest.fixture
+    def service(self, tmp_path: Path) -> QuotaService:
+        """Create a service with 3 MB used yesterday and 2 MB today."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        for finished_at, size in (("2026-10-15T20:00:00", 3), ("2026-10-16T09:00:00", 2)):
+            history.record(
+                HistoryEntry("http://a.com/1", "", "./m", finished_at, "", size * MEGABYTE)
+            )
+        return QuotaService(history, tmp_path / "quota.json")
+
+    def test_usage(self, service: QuotaService) -> None:
+        """Test that usage counts downloads in the current period only."""
+        assert service.usage("day", NOW) == 2 * MEGABYTE
+        assert service.usage("month", NOW) == 5 * MEGABYTE
+
+    def test_no_quota_is_never_exceeded(self, service: QuotaService) -> None:
+        """Test that no quota applies until one is configured."""
+        assert service.load() == QuotaLimits()
+        assert service.exceeded(NOW) is None
+
+    def test_daily_quota_exceeded(self, service: QuotaService) -> None:
+        """Test that reaching the daily quota reports when it resets."""
+        service.save(QuotaLimits(daily_mb=2, monthly_mb=100))
+
+        assert service.exceeded(NOW) == QuotaExceeded("day", 2.0, 2, datetime(2026, 10, 17))
+
+    def test_monthly_quota_exceeded(self, service: QuotaService) -> None:
+        """Test that earlier days count towards the monthly quota."""
+        service.save(QuotaLimits(daily_mb=10, monthly_mb=5))
+
+        assert service.exceeded(NOW) == QuotaExceeded("month", 5.0, 5, datetime(2026, 11, 1))
+
+    def test_quota_not_reached(self, service: QuotaService) -> None:
+        """Test that usage below the quotas doesn't pause downloads."""
+        service.save(QuotaLimits(daily_mb=3, monthly_mb=6))
+
+        assert service.exceeded(NOW) is None
+
+    def test_non_positive_quota_is_rejected(self, service: QuotaService) -> None:
+        """Test that a zero quota can't be saved."""
+        with pytest.raises(ValueError):
+            service.save(QuotaLimits(daily_mb=0))
+
+    def test_invalid_file(self, tmp_path: Path, service: QuotaService) -> None:
+        """Test that an invalid quota file means no quotas."""
+        (tmp_path / "quota.json").write_text("{", encoding="utf-8")
+
+        assert service.load() == QuotaLimits()
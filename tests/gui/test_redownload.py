@@ -0,0 +1,67 @@
+"""Unit tests for downloading past jobs again."""
+
+import dataclasses
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.download_history import (
+    DownloadHistory,
+    HistoryEntry,
+    create_history_entry,
+)
+from resource_fetcher_gui.gui.core.redownload import redownload
+
+
+class TestRedownload:
+    """Test rebuilding jobs from the download history."""
+
+    def test_original_settings_with_overrides(self, tmp_path: Path) -> None:
+        """Test that the job keeps its settings except the overridden ones."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        original = DownloadConfig(output_dir="./old", retries=7, track_range="2-4")
+        history.record(
+            create_history_entry(
+                "http://a.com/1", "./old", "One", "job-1", config=dataclasses.asdict(original)
+            )
+        )
+        queue = MagicMock()
+
+        redownload("job-1", history, queue, DownloadConfig(retries=1), {"output_dir": "./new"})
+
+        url, config = queue.enqueue.call_args.args
+        assert url == "http://a.com/1"
+        assert config.output_dir == "./new"
+        assert config.retries == 7
+        assert config.track_range == "2-4"
+
+    def test_entry_without_settings(self, tmp_path: Path) -> None:
+        """Test that old entries use the base settings and their output folder."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(HistoryEntry("http://a.com/1", "", "./old", "2026-01-01T10:00:00", "job-1"))
+        queue = MagicMock()
+
+        redownload("job-1", history, queue, DownloadConfig(retries=4))
+
+        config = queue.enqueue.call_args.args[1]
+        assert config.output_dir == "./old"
+        assert config.retries == 4
+
+    def test_unknown_job(self, tmp_path: Path) -> None:
+        """Test that a job missing from the history is rejected."""
+        queue = MagicMock()
+
+        with pytest.raises(ValueError, match="not found"):
+            redownload("job-1", DownloadHistory(tmp_path / "h.jsonl"), queue, DownloadConfig())
+
+        queue.enqueue.assert_not_called()
+
+    def test_unknown_override(self, tmp_path: Path) -> None:
+        """Test that overrides must be download settings."""
+        history = DownloadHistory(tmp_path / "history.jsonl")
+        history.record(create_history_entry("http://a.com/1", "./old", job_id="job-1"))
+
+        with pytest.raises(ValueError, match="Unknown options: speed"):
+            redownload("job-1", history, MagicMock(), DownloadConfig(), {"speed": 1})
@@ -0,0 +1,87 @@
+"""Unit tests for the self-test download."""
+
+import threading
+from collections.abc import Callable
+from pathlib import Path
+from unittest.mock import MagicMock
+
+from resource_fetcher_core.core.models import Album, DownloadResult, DownloadStatus, Song
+from resource_fetcher_core.utils.manifest import build_manifest, write_manifest
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.self_test import run_self_test
+
+SUMMARY_LINES = [
+    "下载完成! Download Summary",
+    "  成功 (Success): 1",
+    "  失败 (Failed): 0",
+    "  跳过 (Skipped): 0",
+    "  总计 (Total): 1",
+    "  耗时 (Time): 1.0 秒",
+    "  速度 (Speed): 1.00 首/秒",
+]
+
+
+def fake_wrapper(corrupt: bool = False, exit_code: int = 0) -> MagicMock:
+    """Create a CLI wrapper whose download writes one track and its manifest."""
+
+    def execute_download(
+        url: str,
+        config: DownloadConfig,
+        progress_callback: Callable[[str], None],
+        complete_callback: Callable[[int], None],
+    ) -> threading.Thread:
+        folder = Path(config.output_dir)
+        path = folder / "song.mp3"
+        path.write_bytes(b"audio")
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        album = Album(title="Album", url=url, songs=[song], source="test")
+        result = DownloadResult(status=DownloadStatus.SUCCESS, path=path)
+        write_manifest(folder, build_manifest(album, [(1, song, result)]))
+        if corrupt:
+            path.write_bytes(b"other")
+        for line in SUMMARY_LINES:
+            progress_callback(line)
+        complete_callback(exit_code)
+        thread = threading.Thread(target=lambda: None)
+        thread.start()
+        return thread
+
+    wrapper = MagicMock()
+    wrapper.execute_download.side_effect = execute_download
+    return wrapper
+
+
+class TestRunSelfTest:
+    """Test the stages of the self-test."""
+
+    def test_all_stages_pass(self) -> None:
+        """Test that a working download passes every stage."""
+        stages = run_self_test(fake_wrapper())
+
+        assert [stage.name for stage in stages] == ["start", "download", "parse", "write", "verify"]
+        assert all(stage.passed for stage in stages)
+
+    def test_missing_cli_fails_start(self) -> None:
+        """Test that a missing CLI stops the test at the first stage."""
+        wrapper = MagicMock()
+        wrapper.execute_download.side_effect = FileNotFoundError("CLI not found")
+
+        stages = run_self_test(wrapper)
+
+        assert len(stages) == 1
+        assert stages[0].name == "start"
+        assert not stages[0].passed
+
+    def test_failed_download(self) -> None:
+        """Test that a non-zero exit code fails the download stage."""
+        stages = run_self_test(fake_wrapper(exit_code=1))
+
+        assert stages[-1].name == "download"
+        assert stages[-1].message == "Exit code 1"
+
+    def test_checksum_mismatch_fails_verify(self) -> None:
+        """Test that a file not matching the manifest fails the last stage."""
+        stages = run_self_test(fake_wrapper(corrupt=True))
+
+        assert stages[-1].name == "verify"
+        assert not stages[-1].passed
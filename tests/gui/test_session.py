@@ -0,0 +1,57 @@
+"""Unit tests for SessionStore."""
+
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.job_queue import (
+    JOB_COMPLETED,
+    JOB_RUNNING,
+    QueuedJob,
+    SessionState,
+)
+from resource_fetcher_gui.gui.core.session import SessionStore
+
+
+class TestSessionStore:
+    """Test saving and loading the job queue state."""
+
+    def test_save_and_load(self, tmp_path: Path) -> None:
+        """Test that jobs, their progress and the pause state are read back."""
+        store = SessionStore(tmp_path / "session.json")
+        running = QueuedJob(
+            job_id="job-1",
+            url="http://example.com/1",
+            config=DownloadConfig(output_dir="./music", workers=2),
+            status=JOB_RUNNING,
+            mirrors=["http://mirror.com/1"],
+            current_track=3,
+            track_count=10,
+        )
+        finished = QueuedJob("job-2", "http://example.com/2", DownloadConfig(), JOB_COMPLETED)
+        state = SessionState([running, finished], paused=True, saved_at="2026-10-16T12:00:00")
+
+        store.save(state)
+
+        assert store.load() == state
+
+    def test_missing_file_is_empty(self, tmp_path: Path) -> None:
+        """Test that without a session file there is nothing to restore."""
+        assert SessionStore(tmp_path / "session.json").load() == SessionState()
+
+    def test_invalid_file_is_empty(self, tmp_path: Path) -> None:
+        """Test that an invalid session file is ignored."""
+        path = tmp_path / "session.json"
+        path.write_text("{", encoding="utf-8")
+
+        assert SessionStore(path).load() == SessionState()
+
+    def test_invalid_jobs_are_skipped(self, tmp_path: Path) -> None:
+        """Test that jobs that can't be read don't hide valid ones."""
+        path = tmp_path / "session.json"
+        store = SessionStore(path)
+        job = QueuedJob(job_id="job-1", url="http://example.com/1", config=DownloadConfig())
+        store.save(SessionState(jobs=[job]))
+        text = path.read_text(encoding="utf-8")
+        path.write_text(text.replace('"jobs": [', '"jobs": [{"url": "x"}, '), encoding="utf-8")
+
+        assert store.load().jobs == [job]
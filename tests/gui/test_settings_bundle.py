@@ -0,0 +1,95 @@
+"""Unit tests for settings export and import."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import ConfigService, DownloadConfig
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+from resource_fetcher_gui.gui.core.settings_bundle import (
+    BUNDLE_VERSION,
+    export_config,
+    import_config,
+)
+
+
+class TestSettingsBundle:
+    """Test exporting and importing settings bundles."""
+
+    @pytest.fixture
+    def services(self, tmp_path: Path) -> tuple[ConfigService, ProfileService]:
+        """Create config and profile services backed by temporary files."""
+        config_service = ConfigService(config_path=tmp_path / "config.json")
+        profile_service = ProfileService(
+            profiles_path=tmp_path / "profiles.json", config_service=config_service
+        )
+        return config_service, profile_service
+
+    def test_export_writes_settings_and_profiles(
+        self, tmp_path: Path, services: tuple[ConfigService, ProfileService]
+    ) -> None:
+        """Test that the bundle contains settings and profiles."""
+        config_service, profile_service = services
+        config_service.save_config(DownloadConfig(output_dir="/music", retries=4))
+        profile_service.save_profile("Podcasts", DownloadConfig(output_dir="/podcasts"))
+        bundle_path = tmp_path / "export" / "bundle.json"
+
+        export_config(bundle_path, config_service, profile_service)
+
+        bundle = json.loads(bundle_path.read_text(encoding="utf-8"))
+        assert bundle["version"] == BUNDLE_VERSION
+        assert bundle["settings"]["output_dir"] == "/music"
+        assert bundle["settings"]["retries"] == 4
+        assert bundle["profiles"]["Podcasts"]["output_dir"] == "/podcasts"
+
+    def test_roundtrip_to_another_machine(self, tmp_path: Path) -> None:
+        """Test that an exported bundle restores settings elsewhere."""
+        source_config = ConfigService(config_path=tmp_path / "a" / "config.json")
+        source_profiles = ProfileService(profiles_path=tmp_path / "a" / "profiles.json")
+        source_config.save_config(DownloadConfig(output_dir="/music", log_level="debug"))
+        source_profiles.save_profile("Albums", DownloadConfig(retries=6))
+        bundle_path = tmp_path / "bundle.json"
+        export_config(bundle_path, source_config, source_profiles)
+
+        target_config = ConfigService(config_path=tmp_path / "b" / "config.json")
+        target_profiles = ProfileService(profiles_path=tmp_path / "b" / "profiles.json")
+        target_profiles.save_profile("Local", DownloadConfig())
+
+        imported = import_config(bundle_path, target_config, target_profiles)
+
+        assert imported.output_dir == "/music"
+        assert target_config.load_config() == imported
+        assert target_profiles.list_profiles() == ["Albums", "Local"]
+        assert target_profiles.apply_profile("Albums").retries == 6
+
+    def test_import_missing_file(self, tmp_path: Path, services) -> None:
+        """Test that importing a missing file raises FileNotFoundError."""
+        with pytest.raises(FileNotFoundError):
+            import_config(tmp_path / "missing.json", *services)
+
+    def test_import_invalid_json(self, tmp_path: Path, services) -> None:
+        """Test that importing invalid JSON raises ValueError."""
+        bundle_path = tmp_path / "bundle.json"
+        bundle_path.write_text("{ invalid", encoding="utf-8")
+
+        with pytest.raises(ValueError, match="Invalid settings bundle"):
+            import_config(bundle_path, *services)
+
+    def test_import_without_settings(self, tmp_path: Path, services) -> None:
+        """Test that a bundle without settings is rejected."""
+        bundle_path = tmp_path / "bundle.json"
+        bundle_path.write_text(json.dumps({"version": 1}), encoding="utf-8")
+
+        with pytest.raises(ValueError, match="missing settings"):
+            import_config(bundle_path, *services)
+
+    def test_import_newer_version(self, tmp_path: Path, services) -> None:
+        """Test that bundles from a newer format version are rejected."""
+        bundle_path = tmp_path / "bundle.json"
+        bundle_path.write_text(
+            json.dumps({"version": BUNDLE_VERSION + 1, "settings": {}}), encoding="utf-8"
+        )
+
+        with pytest.raises(ValueError, match="Unsupported settings bundle version"):
+            import_config(bundle_path, *services)
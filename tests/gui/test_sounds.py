@@ -0,0 +1,125 @@
+"""Unit tests for SoundService."""
+
+from datetime import datetime
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.sounds import (
+    SOUND_COMPLETED,
+    SOUND_FAILED,
+    SoundService,
+    SoundSettings,
+)
+
+NIGHT = datetime(2026, 10, 16, 23, 30)
+NOON = datetime(2026, 10, 16, 12, 0)
+
+
+class FakeOutput:
+    """Records bells and played files."""
+
+    def __init__(self, can_play: bool = True) -> None:
+        self.can_play = can_play
+        self.bells = 0
+        self.played: list[Path] = []
+
+    def bell(self) -> None:
+        self.bells += 1
+
+    def player(self, path: Path) -> bool:
+        self.played.append(path)
+        return self.can_play
+
+
+class TestSoundSettings:
+    """Test quiet hours."""
+
+    def test_quiet_hours_spanning_midnight(self) -> None:
+        """Test that quiet hours ending before they start cover the night."""
+        settings = SoundSettings(quiet_start="22:00", quiet_end="07:00")
+
+        assert settings.is_quiet(NIGHT)
+        assert settings.is_quiet(datetime(2026, 10, 17, 6, 59))
+        assert not settings.is_quiet(datetime(2026, 10, 17, 7, 0))
+        assert not settings.is_quiet(NOON)
+
+    def test_no_quiet_hours(self) -> None:
+        """Test that it's never quiet without quiet hours."""
+        assert not SoundSettings().is_quiet(NIGHT)
+
+
+class TestSoundService:
+    """Test playing and storing sounds."""
+
+    @pytest.fixture
+    def output(self) -> FakeOutput:
+        """Create a fake sound output."""
+        return FakeOutput()
+
+    @pytest.fixture
+    def service(self, tmp_path: Path, output: FakeOutput) -> SoundService:
+        """Create a sound service with a temporary sounds file."""
+        return SoundService(output.bell, tmp_path / "sounds.json", output.player)
+
+    def test_bell_without_sound_file(self, service: SoundService, output: FakeOutput) -> None:
+        """Test that the system bell rings by default."""
+        assert service.play(SOUND_COMPLETED, NOON)
+        assert output.bells == 1
+
+    def test_custom_sound_per_event(self, service: SoundService, output: FakeOutput) -> None:
+        """Test that each event plays its own file."""
+        service.save(SoundSettings(completed_sound="done.wav", failed_sound="error.wav"))
+
+        service.play(SOUND_COMPLETED, NOON)
+        service.play(SOUND_FAILED, NOON)
+
+        assert output.played == [Path("done.wav"), Path("error.wav")]
+        assert output.bells == 0
+
+    def test_bell_if_sound_cant_be_played(self, tmp_path: Path) -> None:
+        """Test that the bell rings when no player is available."""
+        output = FakeOutput(can_play=False)
+        service = SoundService(output.bell, tmp_path / "sounds.json", output.player)
+        service.save(SoundSettings(completed_sound="done.wav"))
+
+        assert service.play(SOUND_COMPLETED, NOON)
+        assert output.bells == 1
+
+    def test_quiet_hours_suppress_sounds(self, service: SoundService, output: FakeOutput) -> None:
+        """Test that nothing is played during quiet hours."""
+        service.save(SoundSettings(quiet_start="22:00", quiet_end="7:00"))
+
+        assert not service.play(SOUND_FAILED, NIGHT)
+        assert service.play(SOUND_FAILED, NOON)
+        assert output.bells == 1
+
+    def test_disabled(self, service: SoundService, output: FakeOutput) -> None:
+        """Test that nothing is played when sounds are off."""
+        service.save(SoundSettings(enabled=False))
+
+        assert not service.play(SOUND_COMPLETED, NOON)
+        assert output.bells == 0
+
+    def test_save_normalizes_quiet_hours(self, service: SoundService) -> None:
+        """Test that quiet hours are stored as HH:MM."""
+        service.save(SoundSettings(quiet_start="7:05", quiet_end=" 9:00"))
+
+        settings = service.load()
+        assert (settings.quiet_start, settings.quiet_end) == ("07:05", "09:00")
+
+    @pytest.mark.parametrize(
+        ("start", "end"),
+        [("22:00", None), ("08:00", "08:00"), ("25:00", "07:00")],
+    )
+    def test_invalid_quiet_hours(self, service: SoundService, start: str, end: str | None) -> None:
+        """Test that incomplete, empty or invalid quiet hours are rejected."""
+        with pytest.raises(ValueError):
+            service.save(SoundSettings(quiet_start=start, quiet_end=end))
+
+    def test_invalid_file_falls_back_to_defaults(self, tmp_path: Path) -> None:
+        """Test that an unreadable sounds file gives the defaults."""
+        path = tmp_path / "sounds.json"
+        path.write_text("not json")
+
+        assert SoundService(sounds_path=path).load() == SoundSettings()
@@ -0,0 +1,157 @@
+"""Unit tests for SourceRuleService."""
+
+from pathlib import Path
+
+import pytest
+
+from resource_fetcher_gui.gui.core.config_service import DownloadConfig
+from resource_fetcher_gui.gui.core.profile_service import ProfileService
+from resource_fetcher_gui.gui.core.source_rules import (
+    SourceRule,
+    SourceRuleService,
+    parse_overrides,
+)
+
+
+class TestSourceRule:
+    """Test URL matching of source rules."""
+
+    def test_glob_matches_case_insensitively(self) -> None:
+        """Test that glob patterns match the whole URL, ignoring case."""
+        rule = SourceRule("*izanmei.cc/album/*", output_dir="/music")
+
+        assert rule.matches("https://www.IZANMEI.cc/album/hymns-442-1.html")
+        assert not rule.matches("https://www.izanmei.cc/singer/1.html")
+
+    def test_regex_is_searched(self) -> None:
+        """Test that "re:" patterns are searched anywhere in the URL."""
+        rule = SourceRule("re:/podcast/\\d+", output_dir="/podcasts")
+
+        assert rule.matches("https://example.com/podcast/42?page=1")
+        assert not rule.matches("https://example.com/album/42")
+
+
+class TestParseOverrides:
+    """Test parsing of per-source settings."""
+
+    def test_values_are_typed(self) -> None:
+        """Test that numbers and booleans are recognized, other values kept as text."""
+        assert parse_overrides("delay=2.5 retries=5 low_priority=true track_range=1-3") == {
+            "delay": 2.5,
+            "retries": 5,
+            "low_priority": True,
+            "track_range": "1-3",
+        }
+
+    def test_empty(self) -> None:
+        """Test that no settings give an empty mapping."""
+        assert parse_overrides("  ") == {}
+
+    def test_invalid_settings(self) -> None:
+        """Test that malformed pairs and unknown settings are rejected."""
+        with pytest.raises(ValueError):
+            parse_overrides("delay")
+        with pytest.raises(ValueError):
+            parse_overrides("quality=high")
+
+
+class TestSourceRuleService:
+    """Test per-source output directories and profiles."""
+
+    @pytest.fixture
+    def profiles(self, tmp_path: Path) -> ProfileService:
+        """Create a profile service backed by a temporary file."""
+        return ProfileService(profiles_path=tmp_path / "profiles.json")
+
+    @pytest.fixture
+    def service(self, tmp_path: Path, profiles: ProfileService) -> SourceRuleService:
+        """Create a source rule service backed by a temporary file."""
+        return SourceRuleService(tmp_path / "source_rules.json", profiles)
+
+    def test_no_rules(self, service: SourceRuleService) -> None:
+        """Test that the configuration is unchanged without matching rules."""
+        config = DownloadConfig(output_dir="/downloads")
+
+        assert service.resolve("https://example.com/album/1", config) is config
+
+    def test_output_dir_is_applied(self, service: SourceRuleService) -> None:
+        """Test that a matching rule sets the output directory only."""
+        service.add_rule("*/podcast/*", output_dir="/podcasts")
+
+        config = service.resolve(
+            "https://example.com/podcast/1", DownloadConfig(output_dir="/downloads", retries=7)
+        )
+
+        assert config.output_dir == "/podcasts"
+        assert config.retries == 7
+
+    def test_profile_is_applied(self, service: SourceRuleService, profiles: ProfileService) -> None:
+        """Test that a rule's profile replaces the settings, below its own directory."""
+        profiles.save_profile("Audiobooks", DownloadConfig(output_dir="/books", delay=3.0))
+        service.add_rule("*/audiobook/*", profile="Audiobooks")
+        service.add_rule("*/album/*", output_dir="/music", profile="Audiobooks")
+
+        audiobook = service.resolve("https://example.com/audiobook/1", DownloadConfig())
+        album = service.resolve("https://example.com/album/1", DownloadConfig())
+
+        assert (audiobook.output_dir, audiobook.delay) == ("/books", 3.0)
+        assert (album.output_dir, album.delay) == ("/music", 3.0)
+
+    def test_overrides_are_merged(self, service: SourceRuleService) -> None:
+        """Test that a rule's settings are merged over the global ones and validated."""
+        service.add_rule("*slow.example.com/*", overrides={"delay": 5, "retries": -1})
+
+        config = service.resolve(
+            "https://slow.example.com/album/1", DownloadConfig(output_dir="/downloads", workers=4)
+        )
+
+        assert config.delay == 5.0
+        assert config.retries == DownloadConfig().retries
+        assert (config.output_dir, config.workers) == ("/downloads", 4)
+
+    def test_missing_profile_is_ignored(self, service: SourceRuleService) -> None:
+        """Test that a rule naming a deleted profile still sets its directory."""
+        service.add_rule("*/album/*", output_dir="/music", profile="Deleted")
+
+        config = service.resolve("https://example.com/album/1", DownloadConfig(retries=7))
+
+        assert (config.output_dir, config.retries) == ("/music", 7)
+
+    def test_first_matching_rule_wins(self, service: SourceRuleService) -> None:
+        """Test that rules are tried in the order they were added."""
+        service.add_rule("*example.com/*", output_dir="/first")
+        service.add_rule("*/album/*", output_dir="/second")
+
+        assert service.match("https://example.com/album/1").output_dir == "/first"
+
+    def test_add_replaces_same_pattern(self, service: SourceRuleService) -> None:
+        """Test that adding a rule with an existing pattern replaces it."""
+        service.add_rule("*/album/*", output_dir="/old")
+        service.add_rule(" */album/* ", output_dir="/new")
+
+        assert service.load() == [SourceRule("*/album/*", output_dir="/new")]
+
+    def test_remove_rule(self, service: SourceRuleService) -> None:
+        """Test removing a rule by its pattern."""
+        service.add_rule("*/album/*", output_dir="/music")
+
+        assert service.remove_rule("*/album/*")
+        assert not service.remove_rule("*/album/*")
+        assert service.load() == []
+
+    def test_invalid_rules_are_rejected(self, service: SourceRuleService) -> None:
+        """Test that empty, invalid and ineffective rules are rejected."""
+        with pytest.raises(ValueError):
+            service.add_rule(" ", output_dir="/music")
+        with pytest.raises(ValueError):
+            service.add_rule("re:(", output_dir="/music")
+        with pytest.raises(ValueError):
+            service.add_rule("*/album/*")
+        with pytest.raises(ValueError):
+            service.add_rule("*/album/*", overrides={"quality": "high"})
+
+    def test_invalid_file(self, tmp_path: Path, service: SourceRuleService) -> None:
+        """Test that an invalid rules file is treated as empty."""
+        (tmp_path / "source_rules.json").write_text("[{", encoding="utf-8")
+
+        assert service.load() == []
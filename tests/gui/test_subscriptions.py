@@ -0,0 +1,68 @@
+"""Unit tests for SubscriptionService."""
+
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.subscriptions import Subscription, SubscriptionService
+
+ARTIST_URL = "https://www.izanmei.cc/artist.html"
+
+
+class TestSubscriptionService:
+    """Test following artists and detecting new releases."""
+
+    def test_follow_and_load(self, tmp_path: Path) -> None:
+        """Test that subscriptions are persisted."""
+        service = SubscriptionService(tmp_path / "subscriptions.json")
+
+        service.follow(ARTIST_URL, ["https://example.com/a"], auto_download=True, template="hymns")
+
+        assert SubscriptionService(tmp_path / "subscriptions.json").load() == [
+            Subscription(ARTIST_URL, ["https://example.com/a"], True, "hymns")
+        ]
+
+    def test_follow_replaces_existing(self, tmp_path: Path) -> None:
+        """Test that following an artist again replaces the subscription."""
+        service = SubscriptionService(tmp_path / "subscriptions.json")
+
+        service.follow(ARTIST_URL, [])
+        service.follow(ARTIST_URL + "#albums", ["https://example.com/a"])
+
+        assert [item.known_albums for item in service.load()] == [["https://example.com/a"]]
+
+    def test_unfollow(self, tmp_path: Path) -> None:
+        """Test that unfollowing removes the subscription once."""
+        service = SubscriptionService(tmp_path / "subscriptions.json")
+        service.follow(ARTIST_URL, [])
+
+        assert service.unfollow(ARTIST_URL) is True
+        assert service.unfollow(ARTIST_URL) is False
+        assert service.load() == []
+
+    def test_check_reports_new_albums_once(self, tmp_path: Path) -> None:
+        """Test that only albums missing from the known ones are reported."""
+        service = SubscriptionService(tmp_path / "subscriptions.json")
+        service.follow(ARTIST_URL, ["https://example.com/a"])
+        albums = ["https://example.com/a", "https://example.com/b"]
+
+        releases = service.check(lambda url: albums)
+
+        assert len(releases) == 1
+        assert releases[0].album_urls == ["https://example.com/b"]
+        assert service.check(lambda url: albums) == []
+
+    def test_check_skips_failing_artists(self, tmp_path: Path) -> None:
+        """Test that an artist page that can't be listed doesn't stop the check."""
+        service = SubscriptionService(tmp_path / "subscriptions.json")
+        service.follow(ARTIST_URL, [])
+
+        def list_albums(url: str) -> list[str]:
+            raise ValueError(f"No albums found on {url}")
+
+        assert service.check(list_albums) == []
+
+    def test_load_invalid_file(self, tmp_path: Path) -> None:
+        """Test that an invalid subscriptions file is treated as empty."""
+        path = tmp_path / "subscriptions.json"
+        path.write_text("{ broken", encoding="utf-8")
+
+        assert SubscriptionService(path).load() == []
@@ -0,0 +1,61 @@
+"""Unit tests for ProgressThrottle."""
+
+import time
+
+import pytest
+
+from resource_fetcher_gui.gui.core.throttle import ProgressThrottle
+
+
+class TestProgressThrottle:
+    """Test coalescing of progress updates."""
+
+    def test_first_update_is_delivered_immediately(self) -> None:
+        """Test that an update after a quiet period isn't delayed."""
+        received: list[int] = []
+        throttle = ProgressThrottle(received.append, max_rate=1.0)
+
+        throttle(1)
+
+        assert received == [1]
+
+    def test_rapid_updates_are_coalesced(self) -> None:
+        """Test that only the latest of several rapid updates is delivered."""
+        received: list[int] = []
+        throttle = ProgressThrottle(received.append, max_rate=1.0)
+
+        for value in range(5):
+            throttle(value)
+        assert received == [0]
+
+        throttle.flush()
+        assert received == [0, 4]
+        assert throttle.coalesced == 3
+
+    def test_pending_update_is_delivered_after_interval(self) -> None:
+        """Test that the final state arrives without further updates."""
+        received: list[int] = []
+        throttle = ProgressThrottle(received.append, max_rate=50.0)
+
+        throttle(1)
+        throttle(2)
+        deadline = time.monotonic() + 2
+        while len(received) < 2 and time.monotonic() < deadline:
+            time.sleep(0.01)
+
+        assert received == [1, 2]
+
+    def test_flush_without_pending_update(self) -> None:
+        """Test that flushing doesn't repeat an update already delivered."""
+        received: list[int] = []
+        throttle = ProgressThrottle(received.append, max_rate=1.0)
+
+        throttle(1)
+        throttle.flush()
+
+        assert received == [1]
+
+    def test_invalid_rate(self) -> None:
+        """Test that a non-positive rate is rejected."""
+        with pytest.raises(ValueError):
+            ProgressThrottle(print, max_rate=0)
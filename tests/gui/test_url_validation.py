@@ -0,0 +1,49 @@
+"""Unit tests for URL validation."""
+
+from resource_fetcher_gui.gui.core.url_validation import SupportedSource, validate_url
+
+
+class TestValidateUrl:
+    """Test instant URL checks."""
+
+    def test_supported_album(self) -> None:
+        """Test that an album URL is normalized and its source detected."""
+        result = validate_url(" http://izanmei.cc/album/hymns-442-1.html?utm_source=share ")
+
+        assert result.valid
+        assert result.supported
+        assert result.url == "https://www.izanmei.cc/album/hymns-442-1.html"
+        assert (result.source, result.kind) == ("Izanmei", "album")
+        assert result.error == ""
+
+    def test_supported_song(self) -> None:
+        """Test that song pages are told apart from albums."""
+        assert validate_url("https://www.izanmei.cc/song/16875.html").kind == "song"
+
+    def test_unsupported_site(self) -> None:
+        """Test that other sites are valid URLs but reported as unsupported."""
+        result = validate_url("https://example.com/album/1?utm_source=x")
+
+        assert result.valid
+        assert not result.supported
+        assert result.url == "https://example.com/album/1?utm_source=x"
+        assert "Izanmei" in result.error
+
+    def test_sources_reported_by_cli(self) -> None:
+        """Test that the CLI's source list decides which sites are supported."""
+        sources = [SupportedSource("Example", ["*example.com/album/*"], ["album"])]
+
+        result = validate_url("https://example.com/album/1?utm_source=x#top", sources)
+        assert (result.source, result.kind) == ("Example", None)
+        assert result.url == "https://example.com/album/1"
+
+        result = validate_url("https://www.izanmei.cc/album/hymns-442-1.html", sources)
+        assert not result.supported
+        assert "Example" in result.error
+
+    def test_invalid_urls(self) -> None:
+        """Test that empty input, other schemes and missing hosts are invalid."""
+        for url in ("", "   ", "ftp://izanmei.cc/album/1.html", "https://"):
+            result = validate_url(url)
+            assert not result.valid
+            assert result.error
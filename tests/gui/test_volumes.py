@@ -0,0 +1,23 @@
+"""Unit tests for output volume detection."""
+
+import os
+from pathlib import Path
+
+from resource_fetcher_gui.gui.core.volumes import volume_available, volume_root
+
+
+class TestVolumes:
+    """Test finding and checking output volumes."""
+
+    def test_volume_root_of_missing_folder(self, tmp_path: Path) -> None:
+        """Test that missing folders resolve to the volume of their parent."""
+        root = volume_root(tmp_path / "album" / "disc 1")
+
+        assert os.path.ismount(root)
+        assert tmp_path.is_relative_to(root)
+        assert volume_available(root)
+
+    def test_unmounted_folder_is_unavailable(self, tmp_path: Path) -> None:
+        """Test that a mount point without a volume or that is gone is unavailable."""
+        assert not volume_available(tmp_path)
+        assert not volume_available(tmp_path / "missing")
@@ -0,0 +1,263 @@
+"""Unit tests for audio file inspection."""
+
+import subprocess
+from pathlib import Path
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from resource_fetcher_core.utils.audio import (
+    format_duration,
+    is_silent,
+    merge_chapters,
+    normalize_loudness,
+    probe_duration,
+)
+
+
+class TestProbeDuration:
+    """Test reading durations with ffprobe."""
+
+    def test_without_ffprobe(self):
+        """Test that no duration is reported when ffprobe is not installed."""
+        with patch("resource_fetcher_core.utils.audio.shutil.which", return_value=None):
+            assert probe_duration(Path("song.mp3")) is None
+
+    def test_reads_duration(self):
+        """Test that the duration printed by ffprobe is returned."""
+        run = MagicMock(return_value=MagicMock(stdout="225.512000\n"))
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffprobe"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", run),
+        ):
+            assert probe_duration(Path("song.mp3")) == pytest.approx(225.512)
+
+        assert run.call_args.args[0][-1] == "song.mp3"
+
+    @pytest.mark.parametrize(
+        "run",
+        [
+            MagicMock(side_effect=subprocess.CalledProcessError(1, "ffprobe", stderr="Invalid")),
+            MagicMock(side_effect=subprocess.TimeoutExpired("ffprobe", 30)),
+            MagicMock(return_value=MagicMock(stdout="N/A\n")),
+        ],
+    )
+    def test_unreadable_file(self, run):
+        """Test that corrupt files raise ValueError."""
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffprobe"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", run),
+        ):
+            with pytest.raises(ValueError):
+                probe_duration(Path("song.mp3"))
+
+
+class TestIsSilent:
+    """Test detecting silent files with ffmpeg."""
+
+    def test_without_ffmpeg(self):
+        """Test that silence isn't checked when ffmpeg is not installed."""
+        with patch("resource_fetcher_core.utils.audio.shutil.which", return_value=None):
+            assert is_silent(Path("song.mp3")) is None
+
+    @pytest.mark.parametrize(
+        "max_volume, expected",
+        [("-inf", True), ("-91.0", True), ("-0.5", False), ("-59.9", False)],
+    )
+    def test_peak_volume(self, max_volume, expected):
+        """Test that files peaking at or below the threshold are silent."""
+        stderr = f"[Parsed_volumedetect_0 @ 0x1] max_volume: {max_volume} dB\n"
+        run = MagicMock(return_value=MagicMock(stderr=stderr))
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", run),
+        ):
+            assert is_silent(Path("song.mp3")) is expected
+
+        assert "volumedetect" in run.call_args.args[0]
+
+    @pytest.mark.parametrize(
+        "run",
+        [
+            MagicMock(side_effect=subprocess.CalledProcessError(1, "ffmpeg", stderr="Invalid")),
+            MagicMock(return_value=MagicMock(stderr="")),
+        ],
+    )
+    def test_undecodable_file(self, run):
+        """Test that files ffmpeg can't measure raise ValueError."""
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", run),
+        ):
+            with pytest.raises(ValueError):
+                is_silent(Path("song.mp3"))
+
+
+class TestNormalizeLoudness:
+    """Test loudness normalization with ffmpeg."""
+
+    def test_without_ffmpeg(self, tmp_path):
+        """Test that files are left alone when ffmpeg is not installed."""
+        path = tmp_path / "song.mp3"
+        path.write_bytes(b"original")
+        with patch("resource_fetcher_core.utils.audio.shutil.which", return_value=None):
+            assert normalize_loudness(path) is False
+
+        assert path.read_bytes() == b"original"
+
+    def test_replaces_file(self, tmp_path):
+        """Test that the normalized output replaces the original file."""
+        path = tmp_path / "song.mp3"
+        path.write_bytes(b"original")
+
+        def fake_ffmpeg(cmd, **kwargs):
+            Path(cmd[-1]).write_bytes(b"normalized")
+            return MagicMock()
+
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", side_effect=fake_ffmpeg),
+        ):
+            assert normalize_loudness(path, target=-14.0) is True
+
+        assert path.read_bytes() == b"normalized"
+        assert [p.name for p in tmp_path.iterdir()] == ["song.mp3"]
+
+    def test_temp_dir(self, tmp_path):
+        """Test that the file is re-encoded in the temp folder."""
+        path = tmp_path / "song.mp3"
+        path.write_bytes(b"original")
+        temp_dir = tmp_path / "temp"
+        temp_dir.mkdir()
+        outputs = []
+
+        def fake_ffmpeg(cmd, **kwargs):
+            outputs.append(Path(cmd[-1]))
+            Path(cmd[-1]).write_bytes(b"normalized")
+            return MagicMock()
+
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", side_effect=fake_ffmpeg),
+        ):
+            assert normalize_loudness(path, temp_dir=temp_dir) is True
+
+        assert outputs[0].parent == temp_dir
+        assert path.read_bytes() == b"normalized"
+        assert list(temp_dir.iterdir()) == []
+
+    def test_failure_keeps_original(self, tmp_path):
+        """Test that a failed run raises ValueError and keeps the original file."""
+        path = tmp_path / "song.mp3"
+        path.write_bytes(b"original")
+        error = subprocess.CalledProcessError(1, "ffmpeg", stderr="...\nInvalid data found")
+
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.subprocess.run", side_effect=error),
+        ):
+            with pytest.raises(ValueError, match="Invalid data found"):
+                normalize_loudness(path)
+
+        assert path.read_bytes() == b"original"
+
+
+class TestMergeChapters:
+    """Test merging albums into chaptered files."""
+
+    @pytest.fixture
+    def tracks(self, tmp_path):
+        """Two audio files with chapter titles."""
+        paths = [tmp_path / "1.mp3", tmp_path / "it's 2.mp3"]
+        for path in paths:
+            path.write_bytes(b"audio")
+        return [(paths[0], "First"), (paths[1], "Second = Two")]
+
+    def fake_ffmpeg(self, captured, returncode=0):
+        """Create a Popen stand-in that records its inputs and reports progress."""
+
+        def popen(cmd, **kwargs):
+            file_list, metadata = [cmd[i + 1] for i, arg in enumerate(cmd) if arg == "-i"]
+            captured["cmd"] = cmd
+            captured["files"] = Path(file_list).read_text(encoding="utf-8")
+            captured["metadata"] = Path(metadata).read_text(encoding="utf-8")
+            process = MagicMock(returncode=returncode)
+            process.stdout = iter(["out_time_ms=1500000\n", "progress=continue\n"])
+            process.communicate.return_value = ("", "Conversion failed!" if returncode else "")
+            return process
+
+        return popen
+
+    def test_writes_chapters_and_reports_progress(self, tracks, tmp_path):
+        """Test the chapter metadata, concat list and progress in percent."""
+        captured: dict = {}
+        progress: list[int] = []
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.probe_duration", side_effect=[1.0, 2.0]),
+            patch("resource_fetcher_core.utils.audio.subprocess.Popen", self.fake_ffmpeg(captured)),
+        ):
+            merge_chapters(tracks, tmp_path / "Album.m4b", "Album", progress.append)
+
+        assert progress == [50]
+        assert captured["cmd"][-1] == str(tmp_path / "Album.m4b")
+        assert "mp4" in captured["cmd"]
+        assert "it'\\''s 2.mp3'" in captured["files"]
+        assert "START=1000\nEND=3000\ntitle=Second \\= Two" in captured["metadata"]
+
+    def test_ffmpeg_failure(self, tracks, tmp_path):
+        """Test that a failed conversion raises ValueError."""
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.probe_duration", return_value=1.0),
+            patch("resource_fetcher_core.utils.audio.subprocess.Popen", self.fake_ffmpeg({}, 1)),
+        ):
+            with pytest.raises(ValueError, match="Conversion failed"):
+                merge_chapters(tracks, tmp_path / "Album.mka")
+
+    def test_temp_dir(self, tracks, tmp_path):
+        """Test that the merged file is written to the temp folder and then moved."""
+        captured: dict = {}
+        temp_dir = tmp_path / "temp"
+        temp_dir.mkdir()
+        popen = self.fake_ffmpeg(captured)
+
+        def write_output(cmd, **kwargs):
+            Path(cmd[-1]).write_bytes(b"merged")
+            return popen(cmd, **kwargs)
+
+        with (
+            patch("resource_fetcher_core.utils.audio.shutil.which", return_value="ffmpeg"),
+            patch("resource_fetcher_core.utils.audio.probe_duration", return_value=1.0),
+            patch("resource_fetcher_core.utils.audio.subprocess.Popen", write_output),
+        ):
+            merge_chapters(tracks, tmp_path / "Album.mka", temp_dir=temp_dir)
+
+        assert Path(captured["cmd"][-1]).parent.parent == temp_dir
+        assert (tmp_path / "Album.mka").read_bytes() == b"merged"
+        assert list(temp_dir.iterdir()) == []
+
+    @pytest.mark.parametrize(
+        "output, which, message",
+        [
+            ("Album.mp3", "ffmpeg", "Unsupported merge format"),
+            ("Album.m4b", None, "ffmpeg not found"),
+        ],
+    )
+    def test_invalid_setup(self, tracks, tmp_path, output, which, message):
+        """Test that unsupported formats and a missing ffmpeg are rejected."""
+        with patch("resource_fetcher_core.utils.audio.shutil.which", return_value=which):
+            with pytest.raises(ValueError, match=message):
+                merge_chapters(tracks, tmp_path / output)
+
+
+class TestFormatDuration:
+    """Test formatting durations."""
+
+    @pytest.mark.parametrize(
+        "seconds, expected",
+        [(0, "0:00"), (59.6, "1:00"), (225.5, "3:46"), (3725, "1:02:05")],
+    )
+    def test_format(self, seconds, expected):
+        """Test minutes:seconds and hours for long files."""
+        assert format_duration(seconds) == expected
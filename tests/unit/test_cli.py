@@ -0,0 +1,1373 @@
+"""Unit tests for the CLI."""
+
+import io
+import json
+import shlex
+import sys
+from pathlib import Path
+from unittest.mock import MagicMock, patch
+
+import pytest
+import requests
+
+from resource_fetcher_cli.cli.main import (
+    FileScanner,
+    PostProcessor,
+    Prefetcher,
+    RateLimiter,
+    benchmark_connection,
+    check_sanity,
+    confirm_track,
+    create_parser,
+    create_scanner,
+    download_album,
+    download_song,
+    follow_speed_limit,
+    list_albums,
+    list_sources,
+    load_custom_sources,
+    merge_album,
+    pass_challenges,
+    rate_ban_cooldown,
+    repair_album,
+    report_content_type,
+    report_duration,
+    report_scan,
+    suggest_workers,
+    sync_album,
+    verify_written_files,
+)
+from resource_fetcher_core.adapters import registry
+from resource_fetcher_core.core.models import (
+    Album,
+    DownloadResult,
+    DownloadStatus,
+    PolitenessPolicy,
+    Song,
+)
+from resource_fetcher_core.utils.manifest import (
+    build_manifest,
+    read_manifest,
+    verify_manifest,
+    write_manifest,
+)
+from resource_fetcher_core.utils.storage import move_staged_file
+
+
+class TestLogLevelArguments:
+    """Test log level selection on the command line."""
+
+    def test_default_log_level(self):
+        """Test that the default log level is info."""
+        args = create_parser().parse_args(["--url", "https://example.com"])
+        assert args.log_level == "info"
+
+    def test_explicit_log_level(self):
+        """Test selecting a log level explicitly."""
+        args = create_parser().parse_args(["--url", "https://example.com", "--log-level", "error"])
+        assert args.log_level == "error"
+
+    def test_verbose_maps_to_debug(self):
+        """Test that --verbose is an alias for the debug level."""
+        args = create_parser().parse_args(["--url", "https://example.com", "--verbose"])
+        assert args.log_level == "debug"
+
+    def test_quiet_maps_to_warning(self):
+        """Test that --quiet is an alias for the warning level."""
+        args = create_parser().parse_args(["--url", "https://example.com", "-q"])
+        assert args.log_level == "warning"
+
+    def test_verbose_and_quiet_are_exclusive(self):
+        """Test that conflicting verbosity flags are rejected."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "-v", "-q"])
+
+
+class TestRangeArguments:
+    """Test selecting tracks on the command line."""
+
+    def test_track_range(self):
+        """Test that --range accepts a valid track range."""
+        args = create_parser().parse_args(["--url", "https://example.com", "--range", "5-12,15"])
+        assert args.track_range == "5-12,15"
+
+    def test_invalid_track_range(self):
+        """Test that an invalid track range is rejected."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "--range", "12-5"])
+
+
+class TestSkipTitleArguments:
+    """Test the title blacklist on the command line."""
+
+    def test_skip_titles_default(self):
+        """Test that no titles are skipped by default."""
+        args = create_parser().parse_args(["--url", "https://example.com"])
+        assert args.skip_titles is None
+
+    def test_skip_titles_repeatable(self):
+        """Test that --skip-title can be given several times."""
+        args = create_parser().parse_args(
+            ["--url", "https://example.com", "--skip-title", "remix", "-S", "instrumental"]
+        )
+        assert args.skip_titles == ["remix", "instrumental"]
+
+
+class TestTitlePatternArguments:
+    """Test include and exclude patterns on the command line."""
+
+    def test_include_and_exclude(self):
+        """Test that valid patterns are accepted."""
+        args = create_parser().parse_args(
+            ["--url", "https://example.com", "--include", "*grace*", "--exclude", "re:live$"]
+        )
+        assert (args.include, args.exclude) == ("*grace*", "re:live$")
+
+    def test_invalid_regex(self):
+        """Test that a malformed regular expression is rejected."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "--include", "re:("])
+
+
+class TestFileNameArguments:
+    """Test file name options."""
+
+    def test_defaults(self):
+        """Test that illegal characters become underscores and names aren't shortened."""
+        args = create_parser().parse_args(["--url", "https://example.com"])
+        assert args.replacement == "_"
+        assert args.max_name_length is None
+
+    def test_illegal_replacement(self):
+        """Test that a replacement with illegal characters is rejected."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "--replace-char", ":"])
+
+
+class TestRepairArguments:
+    """Test selecting repair mode on the command line."""
+
+    def test_repair_folder(self):
+        """Test that --repair can be used instead of --url."""
+        args = create_parser().parse_args(["--repair", "./music"])
+        assert args.repair == "./music"
+        assert args.url is None
+
+    def test_url_or_repair_required(self):
+        """Test that one of --url and --repair is required."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args([])
+
+    def test_url_and_repair_are_exclusive(self):
+        """Test that --url and --repair can't be combined."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "--repair", "./music"])
+
+
+class TestDownloadAlbum:
+    """Test downloading albums."""
+
+    @pytest.fixture
+    def album_page(self):
+        """Patch fetching and parsing of an album with three songs."""
+        songs = [
+            Song(id="1", title="Amazing Grace", url="https://example.com/1.mp3"),
+            Song(id="2", title="Amazing Grace (Remix)", url="https://example.com/2.mp3"),
+            Song(id="3", title="Holy Holy Holy", url="https://example.com/3.mp3"),
+        ]
+        adapter = MagicMock()
+        adapter.POLITENESS = PolitenessPolicy()
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album.return_value = Album(
+            title="Album", url="https://example.com/album", songs=songs, source="test"
+        )
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get"),
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+        ):
+            yield songs
+
+    def test_blacklisted_titles_are_skipped(self, album_page, tmp_path, capsys):
+        """Test that songs matching the blacklist are skipped with a reason."""
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success) as dl:
+            assert download_album(
+                "https://example.com/album", tmp_path, delay=0, skip_titles=["REMIX"]
+            )
+
+        assert [call.kwargs["url"] for call in dl.call_args_list] == [
+            "https://example.com/1.mp3",
+            "https://example.com/3.mp3",
+        ]
+        output = capsys.readouterr().out
+        assert '跳过 (Skip): 标题黑名单 (title blacklist) "REMIX"' in output
+        assert "跳过 (Skipped): 1" in output
+
+    def test_next_song_is_prefetched(self, album_page, tmp_path):
+        """Test that each following song is opened ahead, except skipped ones."""
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with (
+            patch("resource_fetcher_cli.cli.main.download_song", return_value=success),
+            patch.object(Prefetcher, "start", return_value=0) as start,
+        ):
+            assert download_album("https://example.com/album", tmp_path, delay=0)
+            assert download_album(
+                "https://example.com/album", tmp_path, delay=0, skip_titles=["REMIX"]
+            )
+
+        assert [call.args[0] for call in start.call_args_list] == [
+            "https://example.com/2.mp3",
+            "https://example.com/3.mp3",
+        ]
+
+    def test_summary_reports_downloaded_data(self, album_page, tmp_path, capsys):
+        """Test that the summary adds up the size of the downloaded files."""
+        success = DownloadResult(status=DownloadStatus.SUCCESS, size=1000)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success):
+            assert download_album("https://example.com/album", tmp_path, delay=0)
+
+        assert "数据量 (Data): 3000 字节 (bytes)" in capsys.readouterr().out
+
+    def test_tracks_and_summary_report_transfers(self, album_page, tmp_path, capsys):
+        """Test that each track's data and the total including retries are printed."""
+        success = DownloadResult(status=DownloadStatus.SUCCESS, size=1000, transferred=1500)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success):
+            assert download_album("https://example.com/album", tmp_path, delay=0)
+
+        out = capsys.readouterr().out
+        assert "传输 (Transferred): [1] 1500 字节 (bytes)" in out
+        assert "传输量 (Transferred): 4500 字节 (bytes)" in out
+
+    def test_title_patterns_select_songs(self, album_page, tmp_path):
+        """Test that only songs passing the include and exclude patterns are downloaded."""
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success) as dl:
+            assert download_album(
+                "https://example.com/album",
+                tmp_path,
+                delay=0,
+                include="*grace*",
+                exclude="re:remix",
+            )
+
+        assert [call.kwargs["url"] for call in dl.call_args_list] == ["https://example.com/1.mp3"]
+
+    def test_colliding_file_names_are_numbered(self, album_page, tmp_path):
+        """Test that a track whose file name collides is saved under a new name."""
+        album_page[2] = Song(id="3", title="amazing grace", url="https://example.com/3.mp3")
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with (
+            patch("resource_fetcher_core.utils.paths.CASE_INSENSITIVE", True),
+            patch("resource_fetcher_cli.cli.main.download_song", return_value=success) as dl,
+        ):
+            assert download_album("https://example.com/album", tmp_path, delay=0)
+
+        assert [call.kwargs["filename"] for call in dl.call_args_list] == [
+            None,
+            None,
+            "amazing grace (2).mp3",
+        ]
+
+    def test_declined_tracks_are_skipped(self, album_page, tmp_path, monkeypatch, capsys):
+        """Test that songs the user declines in confirmation mode aren't downloaded."""
+        monkeypatch.setattr("sys.stdin", io.StringIO("1 yes\n2 no\n3 yes\n"))
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success) as dl:
+            assert download_album(
+                "https://example.com/album", tmp_path, delay=0, confirm_tracks=True
+            )
+
+        assert [call.kwargs["url"] for call in dl.call_args_list] == [
+            "https://example.com/1.mp3",
+            "https://example.com/3.mp3",
+        ]
+        assert "跳过 (Skip): 未确认 (not confirmed)" in capsys.readouterr().out
+
+    def test_parallel_downloads(self, album_page, tmp_path, capsys):
+        """Test that concurrent downloads report every song once in finishing order."""
+        results = {
+            "https://example.com/1.mp3": DownloadResult(status=DownloadStatus.SUCCESS),
+            "https://example.com/3.mp3": DownloadResult(status=DownloadStatus.FAILED),
+        }
+        with patch(
+            "resource_fetcher_cli.cli.main.download_song",
+            side_effect=lambda **kwargs: results[kwargs["url"]],
+        ):
+            assert not download_album(
+                "https://example.com/album", tmp_path, skip_titles=["remix"], workers=2
+            )
+
+        output = capsys.readouterr().out
+        assert output.index("[1/3] Amazing Grace (Remix)") < output.index("跳过 (Skip)")
+        assert "[2/3]" in output and "[3/3]" in output
+        assert "成功 (Success): 1" in output
+        assert "失败 (Failed): 1" in output
+
+    def test_sync_downloads_only_new_and_incomplete_tracks(self, album_page, tmp_path):
+        """Test that sync leaves out tracks recorded as complete in the manifest."""
+        complete = tmp_path / "Amazing Grace.mp3"
+        complete.write_bytes(b"audio")
+        album = Album(title="Album", url="https://example.com/album", songs=[], source="test")
+        manifest = build_manifest(
+            album,
+            [
+                (
+                    1,
+                    album_page[0],
+                    DownloadResult(status=DownloadStatus.SUCCESS, path=complete),
+                ),
+                (2, album_page[1], DownloadResult(status=DownloadStatus.FAILED)),
+            ],
+        )
+        write_manifest(tmp_path, manifest)
+
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success) as dl:
+            assert sync_album("https://example.com/album", tmp_path, delay=0)
+
+        assert [call.kwargs["url"] for call in dl.call_args_list] == [
+            "https://example.com/2.mp3",
+            "https://example.com/3.mp3",
+        ]
+        assert all(call.kwargs["overwrite"] for call in dl.call_args_list)
+        assert len(read_manifest(tmp_path).tracks) == 3
+
+    def test_checksum_sidecars(self, album_page, tmp_path):
+        """Test that a sidecar is written next to each downloaded file when asked."""
+
+        def download(**kwargs):
+            path = tmp_path / f"{kwargs['song_title']}.mp3"
+            path.write_bytes(b"audio")
+            return DownloadResult(status=DownloadStatus.SUCCESS, path=path)
+
+        with patch("resource_fetcher_cli.cli.main.download_song", side_effect=download):
+            assert download_album(
+                "https://example.com/album", tmp_path, delay=0, checksum_sidecars=True
+            )
+
+        assert sorted(path.name for path in tmp_path.glob("*.sha256")) == [
+            "Amazing Grace (Remix).mp3.sha256",
+            "Amazing Grace.mp3.sha256",
+            "Holy Holy Holy.mp3.sha256",
+        ]
+
+    def test_sync_without_manifest_downloads_everything(self, album_page, tmp_path):
+        """Test that sync downloads the whole album into a folder without manifest."""
+        success = DownloadResult(status=DownloadStatus.SUCCESS)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=success) as dl:
+            assert sync_album("https://example.com/album", tmp_path, delay=0)
+
+        assert dl.call_count == 3
+
+
+class TestWorkersArgument:
+    """Test the --workers argument."""
+
+    def test_workers_default(self):
+        """Test that songs are downloaded one at a time by default."""
+        args = create_parser().parse_args(["--url", "https://example.com"])
+        assert args.workers == 1
+
+    def test_invalid_workers(self):
+        """Test that fewer than one worker is rejected."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "--workers", "0"])
+
+
+class TestSpeedLimit:
+    """Test the --max-speed argument and the rate limiter."""
+
+    def test_unlimited_by_default(self):
+        """Test that downloads are unlimited without --max-speed."""
+        args = create_parser().parse_args(["--url", "https://example.com"])
+        assert args.max_speed is None
+
+    def test_invalid_max_speed(self):
+        """Test that a non-positive speed limit is rejected."""
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "https://example.com", "--max-speed", "0"])
+
+    def test_rate_limiter_sleeps_over_limit(self):
+        """Test that data received faster than the limit is delayed."""
+        limiter = RateLimiter(max_speed=1000)
+        with (
+            patch("resource_fetcher_cli.cli.main.time.monotonic", return_value=100.0),
+            patch("resource_fetcher_cli.cli.main.time.sleep") as sleep,
+        ):
+            limiter._start = 100.0
+            limiter.wait(500)
+            sleep.assert_called_once_with(0.5)
+
+    def test_rate_limiter_idle_time_is_not_saved(self):
+        """Test that time spent idle doesn't allow a burst above the limit."""
+        limiter = RateLimiter(max_speed=1000)
+        limiter._start = 0.0
+        with (
+            patch("resource_fetcher_cli.cli.main.time.monotonic", return_value=60.0),
+            patch("resource_fetcher_cli.cli.main.time.sleep") as sleep,
+        ):
+            limiter.wait(2000)
+            sleep.assert_called_once_with(2.0)
+
+    def test_rate_limiter_limit_changes(self):
+        """Test that a speed limit command sets, lifts or is ignored when invalid."""
+        limiter = RateLimiter(max_speed=None)
+        with patch("resource_fetcher_cli.cli.main.time.sleep") as sleep:
+            limiter.wait(10**9)
+        sleep.assert_not_called()
+
+        limiter.handle_command("64")
+        assert limiter.max_speed == 64 * 1024
+        limiter.handle_command("fast")
+        assert limiter.max_speed == 64 * 1024
+        limiter.handle_command("0")
+        assert limiter.max_speed is None
+
+
+class TestListAlbums:
+    """Test listing the albums of an artist page."""
+
+    def test_prints_album_urls(self, capsys):
+        """Test that every album link is printed on its own line."""
+        adapter = MagicMock()
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album_links.return_value = [
+            "https://www.izanmei.cc/album/a.html",
+            "https://www.izanmei.cc/album/b.html",
+        ]
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get"),
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+        ):
+            assert list_albums("https://www.izanmei.cc/artist.html") is True
+
+        output = capsys.readouterr().out
+        assert "专辑数 (Albums): 2" in output
+        assert "专辑链接 (Album URL): https://www.izanmei.cc/album/b.html" in output
+
+    def test_no_albums_returns_false(self):
+        """Test that a page without album links is reported as failure."""
+        adapter = MagicMock()
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album_links.return_value = []
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get"),
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+        ):
+            assert list_albums("https://www.izanmei.cc/artist.html") is False
+
+    def test_cookies_are_sent(self):
+        """Test that the artist page is fetched with the given cookies."""
+        adapter = MagicMock()
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album_links.return_value = []
+        cookies = {"session": "abc"}
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get") as get,
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+        ):
+            list_albums("https://www.izanmei.cc/artist.html", cookies=cookies)
+
+        assert get.call_args.kwargs["cookies"] is cookies
+
+    def test_list_albums_flag(self):
+        """Test that --list-albums is off by default."""
+        parser = create_parser()
+        assert parser.parse_args(["--url", "https://example.com"]).list_albums is False
+        args = parser.parse_args(["--url", "https://example.com", "--list-albums"])
+        assert args.list_albums is True
+
+
+class TestChallenges:
+    """Test relaying captcha and verification pages to the user."""
+
+    @pytest.fixture
+    def adapter(self):
+        """Create an adapter treating pages containing "captcha" as challenges."""
+        adapter = MagicMock()
+        adapter.POLITENESS = PolitenessPolicy()
+        adapter.detect_challenge.side_effect = lambda html: (
+            "https://example.com/verify" if "captcha" in html else None
+        )
+        return adapter
+
+    def test_page_without_challenge(self, adapter):
+        """Test that a normal page is returned without asking the user."""
+        with patch("resource_fetcher_cli.cli.main.requests.get") as get:
+            assert pass_challenges("https://example.com/a", "<h1>Album</h1>", adapter) == (
+                "<h1>Album</h1>"
+            )
+
+        get.assert_not_called()
+
+    def test_page_is_fetched_again_after_answer(self, adapter, monkeypatch, capsys):
+        """Test that the user is asked via a protocol line and the page fetched again."""
+        monkeypatch.setattr("sys.stdin", io.StringIO("done\n"))
+        with patch("resource_fetcher_cli.cli.main.requests.get") as get:
+            get.return_value.text = "<h1>Album</h1>"
+            html = pass_challenges("https://example.com/a", "captcha", adapter)
+
+        assert html == "<h1>Album</h1>"
+        line = next(
+            line for line in capsys.readouterr().out.splitlines() if line.startswith("交互")
+        )
+        assert json.loads(line.split(": ", 1)[1]) == {
+            "kind": "captcha",
+            "prompt": "Complete the verification in your browser, then continue",
+            "url": "https://example.com/verify",
+        }
+
+    def test_closed_stdin_gives_up(self, adapter, monkeypatch):
+        """Test that a challenge nobody answers fails instead of hanging."""
+        monkeypatch.setattr("sys.stdin", io.StringIO(""))
+        with patch("resource_fetcher_cli.cli.main.requests.get") as get:
+            assert pass_challenges("https://example.com/a", "captcha", adapter) is None
+
+        get.assert_not_called()
+
+
+class TestConfirmTrack:
+    """Test asking the user before downloading a track."""
+
+    @pytest.fixture
+    def song(self):
+        """Song to confirm."""
+        return Song(id="3", title="Holy Holy Holy", url="https://example.com/3.mp3")
+
+    def test_answer_is_read(self, song, monkeypatch, capsys):
+        """Test that the question is a protocol line and the answer decides."""
+        monkeypatch.setattr("sys.stdin", io.StringIO("no\n"))
+
+        assert not confirm_track(3, song)
+        line = capsys.readouterr().out.strip()
+        message = json.loads(line.split(": ", 1)[1])
+        assert (message["kind"], message["index"]) == ("confirm-track", 3)
+
+    def test_late_answers_are_ignored(self, song, monkeypatch):
+        """Test that an answer to an earlier track doesn't count for this one."""
+        monkeypatch.setattr("sys.stdin", io.StringIO("2 no\n3 yes\n"))
+
+        assert confirm_track(3, song, default=False)
+
+    def test_speed_limit_lines_are_not_answers(self, song, monkeypatch):
+        """Test that a speed limit change on stdin is applied instead of answering."""
+        monkeypatch.setattr("sys.stdin", io.StringIO("speed-limit 64\nno\n"))
+        limiter = RateLimiter(max_speed=None)
+        follow_speed_limit(limiter)
+
+        assert not confirm_track(3, song)
+        assert limiter.max_speed == 64 * 1024
+
+    def test_default_without_answer(self, song, monkeypatch):
+        """Test that the default is used once stdin is closed or the time is up."""
+        monkeypatch.setattr("sys.stdin", io.StringIO(""))
+        assert not confirm_track(3, song, default=False)
+
+        with patch("resource_fetcher_cli.cli.main.StdinReader.readline", return_value=None):
+            assert confirm_track(3, song, timeout=0)
+
+
+class TestListSources:
+    """Test listing the supported sites."""
+
+    def test_json_output(self, capsys):
+        """Test that --list-sources --json describes every adapter."""
+        list_sources(as_json=True)
+
+        sources = json.loads(capsys.readouterr().out)
+        assert sources == [
+            {
+                "name": "Izanmei",
+                "url_patterns": ["*izanmei.cc*"],
+                "capabilities": ["album", "artist"],
+            }
+        ]
+
+    def test_text_output(self, capsys):
+        """Test that each site is printed on its own line."""
+        list_sources()
+
+        output = capsys.readouterr().out
+        assert "来源 (Source): Izanmei [*izanmei.cc*] (album, artist)" in output
+
+    def test_custom_sources_are_registered(self, tmp_path, capsys):
+        """Test that valid definitions in --sources-dir are listed, invalid ones skipped."""
+        definition = {
+            "name": "Example",
+            "url_patterns": ["*example.com/album/*"],
+            "song_pattern": r'href="/play/(?P<id>\d+)">(?P<title>[^<]+)<',
+            "audio_url": "https://example.com/audio/{id}.mp3",
+        }
+        (tmp_path / "example.json").write_text(json.dumps(definition), encoding="utf-8")
+        (tmp_path / "broken.json").write_text("{", encoding="utf-8")
+
+        with patch.object(registry, "ADAPTERS", list(registry.ADAPTERS)):
+            assert load_custom_sources(tmp_path) == 1
+            assert registry.get_adapter("https://example.com/album/1").name == "Example"
+            list_sources(as_json=True)
+
+        names = [source["name"] for source in json.loads(capsys.readouterr().out)]
+        assert names == ["Izanmei", "Example"]
+        assert registry.get_adapter("https://example.com/album/1") is None
+
+    def test_list_sources_is_a_target(self):
+        """Test that --list-sources replaces --url."""
+        args = create_parser().parse_args(["--list-sources", "--json"])
+        assert args.list_sources is True
+        assert args.json is True
+        assert args.url is None
+
+
+class TestBenchmark:
+    """Test the connection benchmark."""
+
+    def test_suggest_fewest_workers_near_best_speed(self):
+        """Test that more connections are only suggested if they are clearly faster."""
+        assert suggest_workers({1: 1.0, 2: 1.95, 4: 2.0}) == 2
+        assert suggest_workers({1: 2.0, 2: 2.1, 4: 2.0}) == 1
+        assert suggest_workers({}) == 1
+
+    def test_prints_speeds_and_suggestion(self, capsys):
+        """Test that each measurement and the suggestion are printed."""
+        speeds = {1: 1024 * 1024, 2: 3 * 1024 * 1024}
+        with patch(
+            "resource_fetcher_cli.cli.main.measure_throughput",
+            side_effect=lambda url, streams, duration, timeout: speeds[streams],
+        ):
+            assert benchmark_connection("https://example.com/test.mp3", streams=(1, 2))
+
+        output = capsys.readouterr().out
+        assert "测速 (Benchmark): 1 个连接 (streams): 1.00 MB/s" in output
+        assert "建议并行数 (Suggested workers): 2" in output
+
+    def test_benchmark_is_a_target(self):
+        """Test that --benchmark replaces --url."""
+        args = create_parser().parse_args(["--benchmark", "https://example.com/test.mp3"])
+        assert args.benchmark == "https://example.com/test.mp3"
+        assert args.url is None
+
+
+class TestVerifyWrittenFiles:
+    """Test the end-of-album check of written files."""
+
+    def test_reports_missing_and_short_files(self, tmp_path):
+        """Test that files missing or smaller than downloaded are reported."""
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        complete = tmp_path / "complete.mp3"
+        complete.write_bytes(b"12345")
+        short = tmp_path / "short.mp3"
+        short.write_bytes(b"123")
+        missing = tmp_path / "missing.mp3"
+        downloads = [
+            (1, song, DownloadResult(status=DownloadStatus.SUCCESS, path=complete, size=5)),
+            (2, song, DownloadResult(status=DownloadStatus.SUCCESS, path=short, size=5)),
+            (3, song, DownloadResult(status=DownloadStatus.SUCCESS, path=missing, size=5)),
+            (4, song, DownloadResult(status=DownloadStatus.SKIPPED, path=short)),
+        ]
+
+        assert verify_written_files(downloads) == [short, missing]
+
+
+class TestReportDuration:
+    """Test reporting durations of downloaded files."""
+
+    @pytest.mark.parametrize(
+        "probe, expected",
+        [
+            ({"return_value": 225.5}, "时长 (Duration): 3:46"),
+            ({"return_value": 0.0}, "zero-length audio"),
+            ({"side_effect": ValueError("Invalid data")}, "unreadable audio"),
+        ],
+    )
+    def test_reports_duration_and_problems(self, tmp_path, capsys, probe, expected):
+        """Test that durations are printed and bad files stand out."""
+        result = DownloadResult(status=DownloadStatus.SUCCESS, path=tmp_path / "a.mp3")
+        with patch("resource_fetcher_cli.cli.main.probe_duration", **probe):
+            report_duration(result)
+
+        assert expected in capsys.readouterr().out
+
+    def test_failed_downloads_are_not_probed(self):
+        """Test that nothing is probed for failed downloads."""
+        with patch("resource_fetcher_cli.cli.main.probe_duration") as probe:
+            report_duration(DownloadResult(status=DownloadStatus.FAILED))
+
+        probe.assert_not_called()
+
+
+class TestRateBan:
+    """Test handling servers that refuse requests for sending too many."""
+
+    @staticmethod
+    def banned_response():
+        """Create a 429 response asking to wait two minutes."""
+        response = requests.Response()
+        response.status_code = 429
+        response.headers["Retry-After"] = "120"
+        response._content = b"Too Many Requests"
+        return response
+
+    def test_download_is_not_retried(self, tmp_path):
+        """Test that a rate limited download fails at once with the cooldown."""
+        response = self.banned_response()
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get", return_value=response) as get,
+            patch("resource_fetcher_cli.cli.main.time.sleep") as sleep,
+        ):
+            result = download_song("https://example.com/1.mp3", tmp_path, retries=3)
+
+        assert get.call_count == 1
+        sleep.assert_not_called()
+        assert result.status == DownloadStatus.FAILED
+        assert rate_ban_cooldown(result) == 120
+
+    def test_album_stops_after_ban(self, tmp_path, capsys):
+        """Test that the rest of the album isn't requested once a track is rate limited."""
+        songs = [
+            Song(id=str(i), title=f"Song {i}", url=f"https://example.com/{i}.mp3")
+            for i in (1, 2, 3)
+        ]
+        adapter = MagicMock()
+        adapter.POLITENESS = PolitenessPolicy()
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album.return_value = Album(
+            title="Album", url="https://example.com/album", songs=songs, source="test"
+        )
+        banned = DownloadResult(status=DownloadStatus.FAILED, message="Rate limited, cooldown: 60")
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get"),
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+            patch("resource_fetcher_cli.cli.main.download_song", return_value=banned) as dl,
+        ):
+            assert not download_album("https://example.com/album", tmp_path, delay=0)
+
+        assert dl.call_count == 1
+        output = capsys.readouterr().out
+        assert output.count("限流 (Rate limited): 建议冷却 (cooldown) 60 秒") == 1
+        assert "失败 (Failed): 3" in output
+
+    def test_banned_album_page(self, tmp_path, capsys):
+        """Test that a rate limited album page is reported before parsing it."""
+        with (
+            patch(
+                "resource_fetcher_cli.cli.main.requests.get", return_value=self.banned_response()
+            ),
+            patch("resource_fetcher_cli.cli.main.get_adapter") as get_adapter,
+        ):
+            assert not download_album("https://example.com/album", tmp_path, delay=0)
+
+        get_adapter.assert_not_called()
+        assert "建议冷却 (cooldown) 120 秒" in capsys.readouterr().out
+
+
+class TestTempDir:
+    """Test downloading through a temp folder."""
+
+    def test_file_is_moved_to_output_when_complete(self, tmp_path):
+        """Test that the partial file is written to the temp folder."""
+        output = tmp_path / "out"
+        temp_dir = tmp_path / "temp"
+
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get") as get,
+            patch(
+                "resource_fetcher_cli.cli.main.move_staged_file", wraps=move_staged_file
+            ) as move,
+        ):
+            get.return_value.headers = {"content-length": "5"}
+            get.return_value.iter_content.return_value = [b"audio"]
+            result = download_song(
+                "https://example.com/1.mp3", output, song_title="Song", temp_dir=temp_dir
+            )
+
+        assert result.is_success()
+        assert Path(move.call_args.args[0]).parent == temp_dir
+        assert (output / "Song.mp3").read_bytes() == b"audio"
+        assert list(temp_dir.iterdir()) == []
+
+    def test_broken_attempts_leave_no_partial_files(self, tmp_path):
+        """Test that the partial file of each broken attempt is removed."""
+        temp_dir = tmp_path / "temp"
+
+        def broken(*args, **kwargs):
+            response = MagicMock(ok=True, headers={})
+
+            def chunks():
+                yield b"ID3ab"
+                raise requests.exceptions.ChunkedEncodingError("Connection broken")
+
+            response.iter_content.return_value = chunks()
+            return response
+
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get", side_effect=broken),
+            patch("resource_fetcher_cli.cli.main.time.sleep"),
+        ):
+            result = download_song(
+                "https://example.com/1.mp3",
+                tmp_path / "out",
+                song_title="Song",
+                retries=3,
+                temp_dir=temp_dir,
+            )
+
+        assert result.status == DownloadStatus.FAILED
+        assert list(temp_dir.iterdir()) == []
+
+    def test_temp_dir_argument(self):
+        """Test the --temp-dir option."""
+        args = create_parser().parse_args(["--url", "http://x", "--temp-dir", "/fast/tmp"])
+
+        assert args.temp_dir == "/fast/tmp"
+
+
+class TestFileScanner:
+    """Test scanning downloads in quarantine."""
+
+    @staticmethod
+    def scanner(tmp_path, code):
+        """Create a scanner exiting with the given code."""
+        script = f"import sys; print('verdict'); sys.exit({code})"
+        command = shlex.join([sys.executable, "-c", script])
+        return FileScanner(command, quarantine_dir=tmp_path / "quarantine")
+
+    @pytest.fixture
+    def response(self):
+        """Patch the request of a song to return a few bytes."""
+        with patch("resource_fetcher_cli.cli.main.requests.get") as get:
+            get.return_value.headers = {"content-length": "5"}
+            get.return_value.iter_content.return_value = [b"audio"]
+            yield get
+
+    def test_clean_file_is_moved_to_output(self, tmp_path, response):
+        """Test that a file passing the scan ends up in the output folder."""
+        output = tmp_path / "out"
+        output.mkdir()
+        result = download_song(
+            "https://example.com/1.mp3",
+            output,
+            song_title="Song",
+            scanner=self.scanner(tmp_path, 0),
+        )
+
+        assert result.is_success()
+        assert (output / "Song.mp3").read_bytes() == b"audio"
+        assert list((tmp_path / "quarantine").iterdir()) == []
+
+    def test_rejected_file_stays_in_quarantine(self, tmp_path, response, capsys):
+        """Test that a rejected file is reported and not moved to the output folder."""
+        output = tmp_path / "out"
+        output.mkdir()
+        result = download_song(
+            "https://example.com/1.mp3",
+            output,
+            song_title="Song",
+            scanner=self.scanner(tmp_path, 1),
+        )
+
+        assert result.status == DownloadStatus.FAILED
+        assert result.message == "Scan failed: verdict"
+        assert list(output.iterdir()) == []
+        assert len(list((tmp_path / "quarantine").iterdir())) == 1
+
+        report_scan(result)
+        assert "扫描 (Scan): 未通过 (failed) verdict" in capsys.readouterr().out
+
+    def test_broken_download_leaves_no_quarantined_file(self, tmp_path):
+        """Test that the partial file of a broken download is removed from quarantine."""
+
+        def broken(*args, **kwargs):
+            response = MagicMock(ok=True, headers={})
+
+            def chunks():
+                yield b"ID3ab"
+                raise requests.exceptions.ChunkedEncodingError("Connection broken")
+
+            response.iter_content.return_value = chunks()
+            return response
+
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get", side_effect=broken),
+            patch("resource_fetcher_cli.cli.main.time.sleep"),
+        ):
+            result = download_song(
+                "https://example.com/1.mp3",
+                tmp_path / "out",
+                song_title="Song",
+                retries=2,
+                scanner=self.scanner(tmp_path, 1),
+            )
+
+        assert result.status == DownloadStatus.FAILED
+        assert list((tmp_path / "quarantine").iterdir()) == []
+
+    def test_rejected_file_is_quarantined_in_temp_dir(self, tmp_path, response):
+        """Test that the quarantine is kept in the temp folder if one is set."""
+        scanner = create_scanner("false", tmp_path / "temp")
+
+        assert scanner is not None
+        assert scanner.quarantine_dir == tmp_path / "temp" / "resource-fetcher-quarantine"
+        assert create_scanner(None, tmp_path / "temp") is None
+
+    def test_missing_scanner(self, tmp_path):
+        """Test that a scanner that can't be started rejects the file."""
+        scanner = FileScanner("no-such-scanner-command")
+
+        assert "scanner could not run" in scanner.scan(tmp_path / "file.mp3")
+
+
+class TestContentType:
+    """Test rejecting downloads that aren't the expected audio format."""
+
+    def test_error_page_fails(self, tmp_path, capsys):
+        """Test that an HTML page saved as .mp3 fails instead of counting as success."""
+        with patch("resource_fetcher_cli.cli.main.requests.get") as get:
+            get.return_value.headers = {}
+            get.return_value.iter_content.return_value = [b"<html>Access denied</html>"]
+            result = download_song("https://example.com/1.mp3", tmp_path, song_title="Song")
+
+        assert result.status == DownloadStatus.FAILED
+        assert result.message == "Wrong content type: expected mp3, got html"
+        assert list(tmp_path.iterdir()) == []
+
+        report_content_type(result)
+        report_content_type(DownloadResult(status=DownloadStatus.FAILED, message="Timeout"))
+        output = capsys.readouterr().out
+        assert output == "  类型 (Content type): 错误 (wrong-content-type) expected mp3, got html\n"
+
+    def test_audio_passes(self, tmp_path):
+        """Test that a file with audio magic bytes is kept."""
+        with patch("resource_fetcher_cli.cli.main.requests.get") as get:
+            get.return_value.headers = {}
+            get.return_value.iter_content.return_value = [b"ID3\x04\x00audio"]
+            result = download_song("https://example.com/1.mp3", tmp_path, song_title="Song")
+
+        assert result.is_success()
+        assert (tmp_path / "Song.mp3").exists()
+
+
+class TestPrefetcher:
+    """Test opening the next song's request ahead of time."""
+
+    @staticmethod
+    def response(chunks):
+        """Create a successful streamed response."""
+        response = MagicMock(ok=True, headers={})
+        response.iter_content.return_value = chunks
+        return response
+
+    def test_download_continues_prefetched_response(self, tmp_path):
+        """Test that the bytes read ahead and the rest end up in the file."""
+        response = self.response([b"ID3a", b"bc", b"def"])
+        prefetcher = Prefetcher(size=5)
+        with patch("resource_fetcher_cli.cli.main.requests.get", return_value=response) as get:
+            prefetcher.start("https://example.com/1.mp3")
+            prefetched = prefetcher.take("https://example.com/1.mp3")
+            assert prefetched is not None
+            assert prefetched.head == [b"ID3a", b"bc"]
+
+            result = download_song(
+                "https://example.com/1.mp3", tmp_path, song_title="Song", prefetched=prefetched
+            )
+
+        assert get.call_count == 1
+        assert result.is_success()
+        assert (tmp_path / "Song.mp3").read_bytes() == b"ID3abcdef"
+
+    def test_failed_prefetched_response_is_retried(self, tmp_path):
+        """Test that an error response read ahead is requested again."""
+        failed = MagicMock(ok=False)
+        failed.raise_for_status.side_effect = requests.exceptions.HTTPError("503")
+        prefetcher = Prefetcher()
+        with patch("resource_fetcher_cli.cli.main.requests.get", return_value=failed):
+            prefetcher.start("https://example.com/1.mp3")
+            prefetched = prefetcher.take("https://example.com/1.mp3")
+        with (
+            patch(
+                "resource_fetcher_cli.cli.main.requests.get",
+                return_value=self.response([b"ID3abc"]),
+            ) as get,
+            patch("resource_fetcher_cli.cli.main.time.sleep"),
+        ):
+            result = download_song(
+                "https://example.com/1.mp3", tmp_path, song_title="Song", prefetched=prefetched
+            )
+
+        assert get.call_count == 1
+        assert result.is_success()
+
+    def test_unused_prefetch_is_closed(self):
+        """Test that a prefetch for another song is dropped and its connection closed."""
+        response = self.response([b"ID3"])
+        prefetcher = Prefetcher()
+        with patch("resource_fetcher_cli.cli.main.requests.get", return_value=response):
+            prefetcher.start("https://example.com/1.mp3")
+            assert prefetcher.take("https://example.com/2.mp3") is None
+            assert prefetcher.close() == 3
+
+        response.close.assert_called_once()
+        assert prefetcher.close() == 0
+
+    def test_skipped_prefetched_song_counts_head(self, tmp_path):
+        """Test that the bytes read ahead count as transferred when the song is skipped."""
+        (tmp_path / "Song.mp3").write_bytes(b"old")
+        prefetcher = Prefetcher(size=5)
+        with patch(
+            "resource_fetcher_cli.cli.main.requests.get",
+            return_value=self.response([b"ID3a", b"bc", b"def"]),
+        ):
+            prefetcher.start("https://example.com/1.mp3")
+            prefetched = prefetcher.take("https://example.com/1.mp3")
+            result = download_song(
+                "https://example.com/1.mp3", tmp_path, song_title="Song", prefetched=prefetched
+            )
+
+        assert result.status == DownloadStatus.SKIPPED
+        assert result.transferred == 6
+
+    def test_prefetched_head_counted_once(self, tmp_path):
+        """Test that a downloaded song doesn't count the bytes read ahead twice."""
+        prefetcher = Prefetcher(size=5)
+        with patch(
+            "resource_fetcher_cli.cli.main.requests.get",
+            return_value=self.response([b"ID3a", b"bc", b"def"]),
+        ):
+            prefetcher.start("https://example.com/1.mp3")
+            prefetched = prefetcher.take("https://example.com/1.mp3")
+            result = download_song(
+                "https://example.com/1.mp3", tmp_path, song_title="Song", prefetched=prefetched
+            )
+
+        assert result.transferred == 9
+
+    def test_connection_error(self):
+        """Test that a prefetch failing to connect leaves the request to the download."""
+        prefetcher = Prefetcher()
+        with patch(
+            "resource_fetcher_cli.cli.main.requests.get",
+            side_effect=requests.exceptions.ConnectionError("refused"),
+        ):
+            prefetcher.start("https://example.com/1.mp3")
+
+            assert prefetcher.take("https://example.com/1.mp3") is None
+
+
+class TestDataUsage:
+    """Test counting the data tracks use, including retries and discarded files."""
+
+    @staticmethod
+    def response(chunks, headers=None):
+        """Create a successful streamed response."""
+        response = MagicMock(ok=True, headers=headers or {})
+        response.iter_content.return_value = chunks
+        return response
+
+    def test_failed_attempts_are_counted(self, tmp_path):
+        """Test that data received before a connection broke counts too."""
+
+        def broken():
+            yield b"ID3ab"
+            raise requests.exceptions.ChunkedEncodingError("Connection broken")
+
+        responses = [self.response(broken()), self.response([b"ID3abcdef"])]
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get", side_effect=responses),
+            patch("resource_fetcher_cli.cli.main.time.sleep"),
+        ):
+            result = download_song(
+                "https://example.com/1.mp3", tmp_path, song_title="Song", overwrite=True
+            )
+
+        assert result.is_success()
+        assert result.size == 9
+        assert result.transferred == 14
+
+    def test_discarded_file_is_counted(self, tmp_path):
+        """Test that a file thrown away for its content still counts."""
+        page = b"<html>Not found</html>"
+        with patch(
+            "resource_fetcher_cli.cli.main.requests.get", return_value=self.response([page])
+        ):
+            result = download_song("https://example.com/1.mp3", tmp_path, song_title="Song")
+
+        assert result.status == DownloadStatus.FAILED
+        assert result.transferred == len(page)
+
+
+class TestCheckSanity:
+    """Test failing suspicious downloads."""
+
+    @staticmethod
+    def result(tmp_path, content=b"ID3audio"):
+        """Create a successful result for a downloaded file."""
+        path = tmp_path / "Song.mp3"
+        path.write_bytes(content)
+        return DownloadResult(status=DownloadStatus.SUCCESS, path=path, size=len(content))
+
+    def test_small_file_fails(self, tmp_path, capsys):
+        """Test that a file below the minimum size is deleted and failed."""
+        result = self.result(tmp_path)
+
+        check_sanity(result, min_size=1024)
+
+        assert result.status == DownloadStatus.FAILED
+        assert result.message == "Sanity check failed: 8 bytes, expected at least 1024"
+        assert not (tmp_path / "Song.mp3").exists()
+        assert "检查 (Sanity): 未通过 (failed) 8 bytes" in capsys.readouterr().out
+
+    def test_silent_file_fails(self, tmp_path):
+        """Test that a silent file fails if silence detection is on."""
+        result = self.result(tmp_path)
+
+        with patch("resource_fetcher_cli.cli.main.is_silent", return_value=True):
+            check_sanity(result, detect_silence=True)
+
+        assert result.status == DownloadStatus.FAILED
+        assert result.message == "Sanity check failed: audio is silent"
+
+    @pytest.mark.parametrize("silent", [False, None, ValueError("Invalid data")])
+    def test_passing_file(self, tmp_path, silent):
+        """Test that files pass if audible or silence can't be checked."""
+        result = self.result(tmp_path)
+
+        with patch("resource_fetcher_cli.cli.main.is_silent", side_effect=[silent]):
+            check_sanity(result, min_size=8, detect_silence=True)
+
+        assert result.is_success()
+        assert (tmp_path / "Song.mp3").exists()
+
+    def test_silence_not_checked_by_default(self, tmp_path):
+        """Test that ffmpeg isn't run unless silence detection is on."""
+        with patch("resource_fetcher_cli.cli.main.is_silent") as silent:
+            check_sanity(self.result(tmp_path))
+
+        silent.assert_not_called()
+
+    def test_arguments(self):
+        """Test the --min-size and --detect-silence options."""
+        args = create_parser().parse_args(
+            ["--url", "http://x", "--min-size", "4096", "--detect-silence"]
+        )
+
+        assert args.min_size == 4096
+        assert args.detect_silence
+        assert create_parser().parse_args(["--url", "http://x"]).min_size == 0
+        assert create_parser().parse_args(["--url", "http://x", "--min-size", "0"]).min_size == 0
+        with pytest.raises(SystemExit):
+            create_parser().parse_args(["--url", "http://x", "--min-size", "-1"])
+
+
+class TestPostProcessor:
+    """Test processing downloaded files while the album downloads."""
+
+    def test_only_new_downloads_are_normalized(self, tmp_path, capsys):
+        """Test that new files are normalized, skipped files only checksummed."""
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        for name in ("new.mp3", "old.mp3"):
+            (tmp_path / name).write_bytes(b"audio")
+        post = PostProcessor(normalize=True)
+        with patch("resource_fetcher_cli.cli.main.normalize_loudness", return_value=True) as norm:
+            post.submit(
+                1, song, DownloadResult(status=DownloadStatus.SUCCESS, path=tmp_path / "new.mp3")
+            )
+            post.submit(
+                2, song, DownloadResult(status=DownloadStatus.SKIPPED, path=tmp_path / "old.mp3")
+            )
+            post.submit(3, song, DownloadResult(status=DownloadStatus.FAILED))
+            post.finish()
+
+        norm.assert_called_once_with(tmp_path / "new.mp3", temp_dir=None)
+        assert sorted(post.checksums) == [1, 2]
+        assert "处理 (Processed): 2/2" in capsys.readouterr().out
+
+    def test_stops_without_ffmpeg(self, tmp_path, capsys):
+        """Test that a missing ffmpeg is reported once."""
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        post = PostProcessor(normalize=True, workers=1)
+        with patch("resource_fetcher_cli.cli.main.normalize_loudness", return_value=False) as norm:
+            for i in (1, 2):
+                (tmp_path / f"{i}.mp3").write_bytes(b"audio")
+                post.submit(
+                    i,
+                    song,
+                    DownloadResult(status=DownloadStatus.SUCCESS, path=tmp_path / f"{i}.mp3"),
+                )
+            post.finish()
+
+        norm.assert_called_once()
+        assert capsys.readouterr().out.count("ffmpeg not found") == 1
+
+    def test_incomplete_files_are_not_normalized(self, tmp_path):
+        """Test that files not complete on disk are reported and left alone."""
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        path = tmp_path / "short.mp3"
+        path.write_bytes(b"abc")
+        post = PostProcessor(normalize=True, verify=True)
+        with patch("resource_fetcher_cli.cli.main.normalize_loudness") as norm:
+            post.submit(1, song, DownloadResult(status=DownloadStatus.SUCCESS, path=path, size=5))
+            post.finish()
+
+        norm.assert_not_called()
+        assert post.damaged == [path]
+
+    def test_album_download_reports_processed_files(self, tmp_path, capsys):
+        """Test that an album download reports processed files and reuses their checksums."""
+        songs = [
+            Song(id=str(i), title=f"Song {i}", url=f"https://example.com/{i}.mp3") for i in (1, 2)
+        ]
+        adapter = MagicMock()
+        adapter.POLITENESS = PolitenessPolicy()
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album.return_value = Album(
+            title="Album", url="https://example.com/album", songs=songs, source="test"
+        )
+
+        def download(**kwargs):
+            path = tmp_path / f"{kwargs['song_title']}.mp3"
+            path.write_bytes(b"audio")
+            return DownloadResult(status=DownloadStatus.SUCCESS, path=path, size=5)
+
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get"),
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+            patch("resource_fetcher_cli.cli.main.download_song", side_effect=download),
+        ):
+            assert download_album("https://example.com/album", tmp_path, delay=0)
+
+        output = capsys.readouterr().out
+        assert "处理 (Processed): 2/2" in output
+        assert output.index("处理 (Processed): 2/2") < output.index("下载完成!")
+        assert all(track.sha256 for track in read_manifest(tmp_path).tracks)
+
+    @pytest.mark.parametrize(
+        "relax, expected",
+        [
+            (False, "礼貌策略 (Politeness): delay=1.0s workers=2 window=01:00-06:00"),
+            (True, "礼貌策略 (Politeness): delay=0s workers=4 (relaxed)"),
+        ],
+    )
+    def test_politeness_policy(self, tmp_path, capsys, relax, expected):
+        """Test that the site's policy is applied and reported unless relaxed."""
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        adapter = MagicMock()
+        adapter.POLITENESS = PolitenessPolicy(
+            min_delay=1.0, max_workers=2, window=("01:00", "06:00")
+        )
+        adapter.detect_challenge.return_value = None
+        adapter.extract_album.return_value = Album(
+            title="Album", url="https://example.com/album", songs=[song], source="test"
+        )
+
+        with (
+            patch("resource_fetcher_cli.cli.main.requests.get"),
+            patch("resource_fetcher_cli.cli.main.get_adapter", return_value=adapter),
+            patch(
+                "resource_fetcher_cli.cli.main.download_song",
+                return_value=DownloadResult(status=DownloadStatus.SKIPPED),
+            ),
+            patch("resource_fetcher_cli.cli.main.wait_for_window") as wait,
+        ):
+            download_album(
+                "https://example.com/album",
+                tmp_path,
+                delay=0,
+                workers=4,
+                relax_politeness=relax,
+            )
+
+        assert expected in capsys.readouterr().out
+        assert wait.called is not relax
+
+
+class TestMergeAlbum:
+    """Test merging an album after download."""
+
+    def test_merges_existing_files_in_track_order(self, tmp_path, capsys):
+        """Test that downloaded and skipped files are merged in album order."""
+        song = Song(id="1", title="Song", url="https://example.com/1.mp3")
+        downloads = [
+            (3, song, DownloadResult(status=DownloadStatus.SKIPPED, path=tmp_path / "3.mp3")),
+            (1, song, DownloadResult(status=DownloadStatus.SUCCESS, path=tmp_path / "1.mp3")),
+            (2, song, DownloadResult(status=DownloadStatus.FAILED)),
+        ]
+
+        def fake_merge(tracks, output, title, progress_callback, temp_dir):
+            for percent in (5, 12, 100):
+                progress_callback(percent)
+
+        with patch("resource_fetcher_cli.cli.main.merge_chapters", side_effect=fake_merge) as merge:
+            assert merge_album("Album", downloads, tmp_path / "Album.m4b") is True
+
+        tracks = merge.call_args.args[0]
+        assert [path.name for path, _ in tracks] == ["1.mp3", "3.mp3"]
+        output = capsys.readouterr().out
+        assert "合并 (Merge): 5%" in output
+        assert "合并 (Merge): 12%" not in output
+        assert "合并 (Merge): 100%" in output
+
+    def test_merge_failure(self, tmp_path):
+        """Test that merge errors are reported as a failed download."""
+        with patch(
+            "resource_fetcher_cli.cli.main.merge_chapters", side_effect=ValueError("no ffmpeg")
+        ):
+            assert merge_album("Album", [], tmp_path / "Album.m4b") is False
+
+
+class TestRepairAlbum:
+    """Test repairing downloaded albums."""
+
+    @pytest.fixture
+    def album_folder(self, tmp_path):
+        """Album folder with one intact and one corrupted file."""
+        good = tmp_path / "good.mp3"
+        good.write_bytes(b"good")
+        bad = tmp_path / "bad.mp3"
+        bad.write_bytes(b"original")
+        album = Album(title="Album", url="https://example.com/album", songs=[], source="test")
+        manifest = build_manifest(
+            album,
+            [
+                (
+                    1,
+                    Song(id="1", title="Good", url="https://example.com/good.mp3"),
+                    DownloadResult(status=DownloadStatus.SUCCESS, path=good),
+                ),
+                (
+                    2,
+                    Song(id="2", title="Bad", url="https://example.com/bad.mp3"),
+                    DownloadResult(status=DownloadStatus.SUCCESS, path=bad),
+                ),
+            ],
+        )
+        write_manifest(tmp_path, manifest)
+        bad.write_bytes(b"corrupt!")
+        return tmp_path
+
+    def test_repairs_only_damaged_files(self, album_folder):
+        """Test that only corrupted files are downloaded again."""
+
+        def fake_download(url, output_dir, **kwargs):
+            path = output_dir / "bad.mp3"
+            path.write_bytes(b"original")
+            return DownloadResult(status=DownloadStatus.SUCCESS, path=path)
+
+        with patch("resource_fetcher_cli.cli.main.download_song", side_effect=fake_download) as dl:
+            assert repair_album(album_folder, delay=0) is True
+
+        dl.assert_called_once()
+        assert dl.call_args.kwargs["url"] == "https://example.com/bad.mp3"
+        assert dl.call_args.kwargs["overwrite"] is True
+        assert verify_manifest(album_folder, read_manifest(album_folder)) == []
+
+    def test_failed_repair_returns_false(self, album_folder):
+        """Test that a failed re-download is reported and kept in the manifest."""
+        failed = DownloadResult(status=DownloadStatus.FAILED)
+        with patch("resource_fetcher_cli.cli.main.download_song", return_value=failed):
+            assert repair_album(album_folder, delay=0) is False
+
+        manifest = read_manifest(album_folder)
+        assert [track.status for track in manifest.tracks] == ["success", "failed"]
+
+    def test_missing_manifest_returns_false(self, tmp_path):
+        """Test that folders without manifest can't be repaired."""
+        assert repair_album(tmp_path) is False
@@ -0,0 +1,59 @@
+"""Unit tests for content-type verification."""
+
+import pytest
+
+from resource_fetcher_core.utils.content_type import check_content_type, sniff_format
+
+
+class TestSniffFormat:
+    """Test recognizing formats by their magic bytes."""
+
+    @pytest.mark.parametrize(
+        "head, expected",
+        [
+            (b"ID3\x04\x00", "mp3"),
+            (b"\xff\xfb\x90\x64", "mp3"),
+            (b"\xff\xf1\x50\x80", "aac"),
+            (b"fLaC\x00\x00", "flac"),
+            (b"OggS\x00\x02", "ogg"),
+            (b"RIFF\x24\x08\x00\x00WAVEfmt ", "wav"),
+            (b"\x00\x00\x00\x20ftypM4A ", "mp4"),
+            (b"\x1a\x45\xdf\xa3\x01", "matroska"),
+            (b"\xef\xbb\xbf\n  <!DOCTYPE html><html>", "html"),
+            (b"<HTML><body>Not Found", "html"),
+            (b'{"error": "forbidden"}', "json"),
+            (b"audio", None),
+            (b"", None),
+        ],
+    )
+    def test_formats(self, head, expected):
+        """Test that audio formats and error pages are recognized."""
+        assert sniff_format(head) == expected
+
+
+class TestCheckContentType:
+    """Test comparing a file's content with its extension."""
+
+    def test_matching_file(self, tmp_path):
+        """Test that a file with the promised format passes."""
+        path = tmp_path / "Song.mp3"
+        path.write_bytes(b"ID3\x04\x00audio")
+
+        assert check_content_type(path) is None
+
+    def test_html_saved_as_mp3(self, tmp_path):
+        """Test that an error page saved as .mp3 is reported."""
+        path = tmp_path / "Song.mp3"
+        path.write_bytes(b"<!doctype html><title>403 Forbidden</title>")
+
+        assert check_content_type(path) == "expected mp3, got html"
+
+    def test_unrecognized_and_unchecked_files_pass(self, tmp_path):
+        """Test that unknown content and unknown extensions aren't rejected."""
+        unknown = tmp_path / "Song.mp3"
+        unknown.write_bytes(b"audio")
+        other = tmp_path / "Song.bin"
+        other.write_bytes(b"<html>")
+
+        assert check_content_type(unknown) is None
+        assert check_content_type(other) is None
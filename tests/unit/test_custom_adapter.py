@@ -0,0 +1,131 @@
+"""Test CustomAdapter for user-defined sources."""
+
+import json
+
+import pytest
+
+from resource_fetcher_core.adapters.custom import CustomAdapter, load_source_definition
+from resource_fetcher_core.core.models import PolitenessPolicy
+
+DEFINITION = {
+    "name": "Example",
+    "url_patterns": ["*example.com/album/*"],
+    "song_pattern": r'<a class="song" href="/play/(?P<id>\d+)">(?P<title>[^<]+)</a>',
+    "audio_url": "/audio/{id}.mp3",
+    "base_url": "https://example.com",
+}
+
+HTML = """
+<h1>Example Album</h1>
+<a class="song" href="/play/1">First Song </a>
+<a class="song" href="/play/2">Second Song</a>
+<a class="song" href="/play/1">First Song</a>
+"""
+
+
+class TestCustomAdapter:
+    """Test adapters created from source definitions."""
+
+    def test_can_handle_matching_urls(self):
+        """Test that URLs are matched against the definition's globs."""
+        adapter = CustomAdapter(DEFINITION)
+
+        assert adapter.can_handle("https://EXAMPLE.com/album/1.html") is True
+        assert adapter.can_handle("https://example.com/artist/1.html") is False
+        assert adapter.name == "Example"
+        assert adapter.capabilities() == ["album"]
+
+    def test_extract_album(self):
+        """Test that songs are extracted once each with absolute audio URLs."""
+        album = CustomAdapter(DEFINITION).extract_album(HTML)
+
+        assert album.title == "Example Album"
+        assert album.source == "Example"
+        assert [song.title for song in album.songs] == ["First Song", "Second Song"]
+        assert album.songs[1].url == "https://example.com/audio/2.mp3"
+        assert album.songs[1].metadata["track_number"] == "2"
+
+    def test_audio_url_from_pattern(self):
+        """Test that a "url" group is used instead of the audio URL template."""
+        definition = {
+            **DEFINITION,
+            "song_pattern": r'data-id="(?P<id>\d+)" data-src="(?P<url>[^"]+)">(?P<title>[^<]+)<',
+        }
+        del definition["audio_url"]
+        html = '<li data-id="7" data-src="https://cdn.example.com/7.m4a">Song</li>'
+
+        album = CustomAdapter(definition).extract_album(html)
+
+        assert album.songs[0].url == "https://cdn.example.com/7.m4a"
+        assert album.title == "未知专辑"
+
+    def test_no_songs(self):
+        """Test that a page without songs raises ValueError."""
+        with pytest.raises(ValueError, match="No songs found"):
+            CustomAdapter(DEFINITION).extract_album("<html></html>")
+
+    def test_album_links(self):
+        """Test that artist pages are supported with an album_link_pattern."""
+        adapter = CustomAdapter({**DEFINITION, "album_link_pattern": r'href="(/album/\d+)"'})
+        html = '<a href="/album/1">A</a><a href="/album/2">B</a><a href="/album/1">A</a>'
+
+        assert adapter.capabilities() == ["album", "artist"]
+        assert adapter.extract_album_links(html) == [
+            "https://example.com/album/1",
+            "https://example.com/album/2",
+        ]
+
+    def test_detect_challenge(self):
+        """Test that pages matching the challenge_pattern are recognized."""
+        adapter = CustomAdapter({**DEFINITION, "challenge_pattern": r'id="captcha" src="([^"]+)"'})
+
+        assert adapter.detect_challenge(HTML) is None
+        assert adapter.detect_challenge('<img id="captcha" src="/verify">') == (
+            "https://example.com/verify"
+        )
+        assert CustomAdapter(DEFINITION).detect_challenge('id="captcha"') is None
+
+    @pytest.mark.parametrize(
+        "changes",
+        [
+            {"name": ""},
+            {"url_patterns": []},
+            {"song_pattern": "("},
+            {"song_pattern": r"(?P<id>\d+)"},
+            {"audio_url": "/audio.mp3"},
+            {"politeness": {"min_delay": "slow"}},
+            {"politeness": {"window": ["01:00"]}},
+            {"politeness": {"window": ["1 am", "6 am"]}},
+        ],
+    )
+    def test_invalid_definitions(self, changes):
+        """Test that incomplete or invalid definitions raise ValueError."""
+        with pytest.raises(ValueError):
+            CustomAdapter({**DEFINITION, **changes})
+
+    def test_politeness(self):
+        """Test that a definition can recommend a delay, concurrency and window."""
+        politeness = {"min_delay": 2, "max_workers": 1, "window": ["01:00", "06:00"]}
+
+        adapter = CustomAdapter({**DEFINITION, "politeness": politeness})
+
+        assert adapter.POLITENESS == PolitenessPolicy(2.0, 1, ("01:00", "06:00"))
+        assert CustomAdapter(DEFINITION).POLITENESS == PolitenessPolicy()
+
+    def test_missing_key(self):
+        """Test that a definition without song_pattern raises ValueError."""
+        definition = dict(DEFINITION)
+        del definition["song_pattern"]
+
+        with pytest.raises(ValueError, match="missing"):
+            CustomAdapter(definition)
+
+    def test_load_source_definition(self, tmp_path):
+        """Test loading a definition file, and rejecting invalid JSON."""
+        path = tmp_path / "example.json"
+        path.write_text(json.dumps(DEFINITION), encoding="utf-8")
+        assert load_source_definition(path).name == "Example"
+
+        path.write_text("[]", encoding="utf-8")
+        with pytest.raises(ValueError):
+            load_source_definition(path)
@@ -1,8 +1,15 @@
 """Unit tests for HTTP utility functions."""
 
+import pytest
+
 from resource_fetcher_core.utils.http import (
+    DEFAULT_COOLDOWN,
+    clean_url,
+    detect_rate_ban,
     extract_filename_from_headers,
     fix_mojibake,
+    load_cookies,
+    parse_retry_after,
     sanitize_filename,
 )
 
@@ -133,3 +140,84 @@ class TestSanitizeFilename:
     def test_empty_filename(self):
         """Test empty filename."""
         assert sanitize_filename("") == ""
+
+    def test_custom_replacement(self):
+        """Test replacing illegal characters with other text."""
+        assert sanitize_filename("AC/DC: Live.mp3", replacement="") == "ACDC Live.mp3"
+        assert sanitize_filename("a*b.mp3", replacement="-") == "a-b.mp3"
+
+    def test_max_length_keeps_extension(self):
+        """Test that long names are shortened before the extension."""
+        assert sanitize_filename("a" * 20 + ".mp3", max_length=10) == "aaaaaa.mp3"
+        assert sanitize_filename("short.mp3", max_length=10) == "short.mp3"
+
+
+class TestCleanUrl:
+    """Test removal of tracking parameters from URLs."""
+
+    def test_tracking_params_and_fragment_removed(self):
+        """Test that trackers and the fragment are dropped, other parameters kept."""
+        url = "HTTPS://Example.com/Album/1.html?utm_source=x&page=2&fbclid=abc#songs"
+        assert clean_url(url) == "https://example.com/Album/1.html?page=2"
+
+    def test_clean_url_unchanged(self):
+        """Test that a URL without trackers is unchanged."""
+        assert clean_url(" https://example.com/album/1.html ") == (
+            "https://example.com/album/1.html"
+        )
+
+
+class TestLoadCookies:
+    """Test loading cookies exported from a browser."""
+
+    def test_session_cookies_are_kept(self, tmp_path):
+        """Test that a cookies.txt file loads, including session cookies."""
+        path = tmp_path / "cookies.txt"
+        path.write_text(
+            "# Netscape HTTP Cookie File\n"
+            ".example.com\tTRUE\t/\tFALSE\t0\tsession\tabc\n"
+            ".example.com\tTRUE\t/\tTRUE\t4102444800\ttoken\txyz\n",
+            encoding="utf-8",
+        )
+
+        jar = load_cookies(path)
+
+        assert {cookie.name: cookie.value for cookie in jar} == {"session": "abc", "token": "xyz"}
+
+    def test_invalid_format(self, tmp_path):
+        """Test that a file not in cookies.txt format is rejected."""
+        path = tmp_path / "cookies.json"
+        path.write_text('[{"name": "session"}]', encoding="utf-8")
+
+        with pytest.raises(ValueError, match="Invalid cookies file"):
+            load_cookies(path)
+
+    def test_missing_file(self, tmp_path):
+        """Test that a missing file is rejected."""
+        with pytest.raises(ValueError, match="not found"):
+            load_cookies(tmp_path / "cookies.txt")
+
+
+class TestDetectRateBan:
+    """Test recognizing responses that block a client for too many requests."""
+
+    @pytest.mark.parametrize(
+        "status, headers, text, expected",
+        [
+            (429, {"Retry-After": "120"}, "", 120.0),
+            (429, {}, "", float(DEFAULT_COOLDOWN)),
+            (403, {}, "<h1>Too Many Requests</h1>", float(DEFAULT_COOLDOWN)),
+            (503, {}, "您的请求过于频繁, 请稍后再试", float(DEFAULT_COOLDOWN)),
+            (404, {}, "Not Found", None),
+            (200, {}, "too many requests in this playlist", None),
+        ],
+    )
+    def test_signatures(self, status, headers, text, expected):
+        """Test rate limit statuses, ban pages and ordinary errors."""
+        assert detect_rate_ban(status, headers, text) == expected
+
+    def test_retry_after_date(self):
+        """Test a Retry-After header with an HTTP date."""
+        assert parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT") == 0.0
+        assert parse_retry_after("soon") is None
+        assert parse_retry_after(None) is None
@@ -57,3 +57,12 @@ class TestSiteAdapterInterface:
         album = adapter.extract_album("https://example.com/album")
         assert album.title == "Test Album"
         assert album.source == "example.com"
+
+        # Optional methods have generic defaults
+        assert adapter.name == "Concrete"
+        assert adapter.capabilities() == ["album"]
+        assert adapter.canonicalize_url("https://example.com/album?utm_source=x") == (
+            "https://example.com/album"
+        )
+        assert adapter.content_kind("https://example.com/album") is None
+        assert adapter.extract_album_links("<html></html>") == []
@@ -17,6 +17,23 @@ class TestIzanmeiAdapter:
         assert adapter.can_handle("https://music.163.com/album/123") is False
         assert adapter.can_handle("https://youtube.com/watch?v=123") is False
 
+    def test_canonicalize_url(self):
+        """Test that URLs are moved to the canonical host without trackers."""
+        adapter = IzanmeiAdapter()
+
+        assert (
+            adapter.canonicalize_url("http://IZANMEI.cc/album/hymns-442-1.html?utm_medium=share#x")
+            == "https://www.izanmei.cc/album/hymns-442-1.html"
+        )
+
+    def test_content_kind(self):
+        """Test detection of album and song pages."""
+        adapter = IzanmeiAdapter()
+
+        assert adapter.content_kind("https://www.izanmei.cc/album/hymns-442-1.html") == "album"
+        assert adapter.content_kind("https://www.izanmei.cc/song/16875.html") == "song"
+        assert adapter.content_kind("https://www.izanmei.cc/") is None
+
     def test_extract_album_from_html(self, sample_izanmei_html):
         """Test extracting album from HTML fixture."""
         adapter = IzanmeiAdapter()
@@ -57,6 +74,21 @@ class TestIzanmeiAdapter:
             "https://play.xiaoh.ai/song/p/16879.mp3",
         ]
 
+    def test_extract_album_links(self):
+        """Test that album links of an artist page are absolute and unique."""
+        adapter = IzanmeiAdapter()
+        html = """
+        <a href="/album/hymns-442-1.html">Hymns 1</a>
+        <a href="https://www.izanmei.cc/album/hymns-442-2.html">Hymns 2</a>
+        <a href="/album/hymns-442-1.html"><img src="cover.jpg"></a>
+        <a href="/song/16875.html">Song</a>
+        """
+
+        assert adapter.extract_album_links(html) == [
+            "https://www.izanmei.cc/album/hymns-442-1.html",
+            "https://www.izanmei.cc/album/hymns-442-2.html",
+        ]
+
 
 @pytest.fixture
 def sample_izanmei_html():
@@ -0,0 +1,218 @@
+"""Unit tests for album manifests."""
+
+import hashlib
+import json
+
+import pytest
+
+from resource_fetcher_core.core.models import Album, DownloadResult, DownloadStatus, Song
+from resource_fetcher_core.utils.manifest import (
+    MANIFEST_FILENAME,
+    build_manifest,
+    file_checksum,
+    merge_manifests,
+    read_manifest,
+    verify_manifest,
+    write_checksum_sidecars,
+    write_manifest,
+)
+
+
+@pytest.fixture
+def album():
+    """Album with two songs."""
+    songs = [
+        Song(id="1", title="Song One", url="https://example.com/1.mp3"),
+        Song(id="2", title="Song Two", url="https://example.com/2.mp3"),
+    ]
+    return Album(
+        title="Test Album", url="https://example.com/album", songs=songs, source="example"
+    )
+
+
+class TestBuildManifest:
+    """Test building manifests from download results."""
+
+    def test_records_files_and_failures(self, album, tmp_path):
+        """Test that downloaded files get sizes and checksums and failures don't."""
+        path = tmp_path / "Song One.mp3"
+        path.write_bytes(b"audio data")
+        downloads = [
+            (1, album.songs[0], DownloadResult(status=DownloadStatus.SUCCESS, path=path, size=10)),
+            (2, album.songs[1], DownloadResult(status=DownloadStatus.FAILED)),
+        ]
+
+        manifest = build_manifest(album, downloads)
+
+        assert manifest.source_url == "https://example.com/album"
+        assert manifest.title == "Test Album"
+        first, second = manifest.tracks
+        assert (first.index, first.filename, first.size) == (1, "Song One.mp3", 10)
+        assert first.sha256 == hashlib.sha256(b"audio data").hexdigest()
+        assert first.status == "success"
+        assert (second.index, second.filename, second.sha256) == (2, None, None)
+        assert second.status == "failed"
+
+    def test_skipped_files_use_size_on_disk(self, album, tmp_path):
+        """Test that skipped (existing) files are recorded with their real size."""
+        path = tmp_path / "Song One.mp3"
+        path.write_bytes(b"12345")
+        downloads = [(1, album.songs[0], DownloadResult(status=DownloadStatus.SKIPPED, path=path))]
+
+        manifest = build_manifest(album, downloads)
+
+        assert manifest.tracks[0].size == 5
+        assert manifest.tracks[0].status == "skipped"
+
+    def test_records_duration(self, album, tmp_path):
+        """Test that probed durations are kept in the manifest."""
+        path = tmp_path / "Song One.mp3"
+        path.write_bytes(b"audio")
+        result = DownloadResult(status=DownloadStatus.SUCCESS, path=path, duration=225.5)
+
+        manifest = build_manifest(album, [(1, album.songs[0], result)])
+
+        assert manifest.tracks[0].duration == 225.5
+
+    def test_uses_calculated_checksums(self, album, tmp_path):
+        """Test that checksums calculated during the download aren't calculated again."""
+        path = tmp_path / "Song One.mp3"
+        path.write_bytes(b"audio")
+        result = DownloadResult(status=DownloadStatus.SUCCESS, path=path)
+
+        manifest = build_manifest(album, [(1, album.songs[0], result)], {1: "abc123"})
+
+        assert manifest.tracks[0].sha256 == "abc123"
+
+    def test_uses_album_track_numbers(self, album):
+        """Test that tracks keep their album number when only a range was downloaded."""
+        downloads = [(2, album.songs[1], DownloadResult(status=DownloadStatus.FAILED))]
+
+        manifest = build_manifest(album, downloads)
+
+        assert manifest.tracks[0].index == 2
+
+
+class TestMergeManifests:
+    """Test combining manifests of partial downloads."""
+
+    def test_current_tracks_replace_previous(self, album):
+        """Test that tracks are merged by number and re-downloaded tracks are replaced."""
+        previous = build_manifest(
+            album,
+            [
+                (1, album.songs[0], DownloadResult(status=DownloadStatus.SUCCESS)),
+                (2, album.songs[1], DownloadResult(status=DownloadStatus.FAILED)),
+            ],
+        )
+        third = Song(id="3", title="Song Three", url="https://example.com/3.mp3")
+        current = build_manifest(
+            album,
+            [
+                (3, third, DownloadResult(status=DownloadStatus.SUCCESS)),
+                (2, album.songs[1], DownloadResult(status=DownloadStatus.SUCCESS)),
+            ],
+        )
+
+        merged = merge_manifests(previous, current)
+
+        assert [track.index for track in merged.tracks] == [1, 2, 3]
+        assert [track.status for track in merged.tracks] == ["success"] * 3
+
+    def test_different_albums_are_not_merged(self, album):
+        """Test that a manifest of another album is replaced."""
+        previous = build_manifest(
+            album, [(1, album.songs[0], DownloadResult(status=DownloadStatus.SUCCESS))]
+        )
+        other = Album(title="Other", url="https://example.com/other", songs=[], source="example")
+        current = build_manifest(other, [])
+
+        assert merge_manifests(previous, current) == current
+
+
+class TestManifestFiles:
+    """Test writing and reading manifest files."""
+
+    def test_write_and_read_round_trip(self, album, tmp_path):
+        """Test that a written manifest reads back unchanged."""
+        path = tmp_path / "Song One.mp3"
+        path.write_bytes(b"audio")
+        manifest = build_manifest(
+            album, [(1, album.songs[0], DownloadResult(status=DownloadStatus.SUCCESS, path=path))]
+        )
+
+        manifest_path = write_manifest(tmp_path / "album", manifest)
+
+        assert manifest_path.name == MANIFEST_FILENAME
+        assert read_manifest(manifest_path) == manifest
+        assert read_manifest(tmp_path / "album") == manifest
+
+    def test_read_missing_manifest(self, tmp_path):
+        """Test that a folder without manifest raises FileNotFoundError."""
+        with pytest.raises(FileNotFoundError):
+            read_manifest(tmp_path)
+
+    def test_read_invalid_manifest(self, tmp_path):
+        """Test that malformed manifests raise ValueError."""
+        (tmp_path / MANIFEST_FILENAME).write_text("[1, 2]", encoding="utf-8")
+
+        with pytest.raises(ValueError):
+            read_manifest(tmp_path)
+
+    def test_read_newer_manifest_version(self, tmp_path):
+        """Test that manifests from a newer version are rejected."""
+        (tmp_path / MANIFEST_FILENAME).write_text(json.dumps({"version": 99}), encoding="utf-8")
+
+        with pytest.raises(ValueError, match="Unsupported manifest version"):
+            read_manifest(tmp_path)
+
+    def test_write_checksum_sidecars(self, album, tmp_path):
+        """Test that each downloaded file gets a sidecar in sha256sum format."""
+        path = tmp_path / "Song One.mp3"
+        path.write_bytes(b"audio")
+        manifest = build_manifest(
+            album,
+            [
+                (1, album.songs[0], DownloadResult(status=DownloadStatus.SUCCESS, path=path)),
+                (2, album.songs[1], DownloadResult(status=DownloadStatus.FAILED)),
+            ],
+        )
+
+        written = write_checksum_sidecars(tmp_path, manifest)
+
+        assert written == [tmp_path / "Song One.mp3.sha256"]
+        checksum = hashlib.sha256(b"audio").hexdigest()
+        assert written[0].read_text(encoding="utf-8") == f"{checksum}  Song One.mp3\n"
+
+    def test_file_checksum(self, tmp_path):
+        """Test SHA-256 checksum of a file."""
+        path = tmp_path / "data.bin"
+        path.write_bytes(b"x" * 100000)
+
+        assert file_checksum(path) == hashlib.sha256(b"x" * 100000).hexdigest()
+
+
+class TestVerifyManifest:
+    """Test detecting damaged files."""
+
+    def test_detects_missing_corrupted_and_failed_tracks(self, album, tmp_path):
+        """Test that missing, changed and never-downloaded files are reported."""
+        one = tmp_path / "one.mp3"
+        one.write_bytes(b"one")
+        two = tmp_path / "two.mp3"
+        two.write_bytes(b"two")
+        songs = [*album.songs, Song(id="3", title="Song Three", url="https://example.com/3.mp3")]
+        manifest = build_manifest(
+            album,
+            [
+                (1, songs[0], DownloadResult(status=DownloadStatus.SUCCESS, path=one)),
+                (2, songs[1], DownloadResult(status=DownloadStatus.SUCCESS, path=two)),
+                (3, songs[2], DownloadResult(status=DownloadStatus.FAILED)),
+            ],
+        )
+        assert verify_manifest(tmp_path, manifest) == [manifest.tracks[2]]
+
+        one.unlink()
+        two.write_bytes(b"TWO")
+
+        assert verify_manifest(tmp_path, manifest) == manifest.tracks
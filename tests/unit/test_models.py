@@ -1,6 +1,14 @@
 """Test models for music downloader."""
 
-from resource_fetcher_core.core.models import Album, DownloadResult, DownloadStatus, Song
+from datetime import datetime
+
+from resource_fetcher_core.core.models import (
+    Album,
+    DownloadResult,
+    DownloadStatus,
+    PolitenessPolicy,
+    Song,
+)
 
 
 class TestSong:
@@ -127,3 +135,32 @@ class TestDownloadResult:
 
         assert failed_result.is_failed()
         assert not success_result.is_failed()
+
+
+class TestPolitenessPolicy:
+    """Test the PolitenessPolicy model."""
+
+    def test_apply_is_never_less_polite(self):
+        """Test that settings are only made more polite."""
+        policy = PolitenessPolicy(min_delay=1.0, max_workers=2)
+
+        assert policy.apply(0.5, 4) == (1.0, 2)
+        assert policy.apply(3.0, 1) == (3.0, 1)
+        assert PolitenessPolicy().apply(0.0, 8) == (0.0, 8)
+
+    def test_no_window(self):
+        """Test that downloads may start any time without a window."""
+        assert PolitenessPolicy().seconds_until_window(datetime(2024, 1, 1, 12, 0)) == 0
+
+    def test_window_spanning_midnight(self):
+        """Test waiting for a nightly window."""
+        policy = PolitenessPolicy(window=("23:00", "06:00"))
+
+        assert policy.seconds_until_window(datetime(2024, 1, 1, 2, 0)) == 0
+        assert policy.seconds_until_window(datetime(2024, 1, 1, 22, 30)) == 30 * 60
+
+    def test_window_opening_tomorrow(self):
+        """Test waiting until the next day once today's window has passed."""
+        policy = PolitenessPolicy(window=("01:00", "06:00"))
+
+        assert policy.seconds_until_window(datetime(2024, 1, 1, 7, 0)) == 18 * 60 * 60
@@ -0,0 +1,78 @@
+"""Unit tests for output paths."""
+
+from pathlib import Path
+from unittest.mock import patch
+
+import pytest
+
+from resource_fetcher_core.utils.paths import (
+    MAX_PATH,
+    exceeds_max_path,
+    extended_path,
+    unique_filenames,
+)
+
+
+class TestExtendedPath:
+    """Test the Windows extended-length prefix."""
+
+    def test_unchanged_on_other_platforms(self):
+        """Test that paths are left alone outside Windows."""
+        with patch("resource_fetcher_core.utils.paths.sys.platform", "linux"):
+            assert extended_path(Path("/music/album")) == Path("/music/album")
+
+    @pytest.mark.parametrize(
+        "absolute, expected",
+        [
+            ("C:\\Music\\Album", "\\\\?\\C:\\Music\\Album"),
+            ("\\\\nas\\music\\Album", "\\\\?\\UNC\\nas\\music\\Album"),
+            ("\\\\?\\C:\\Music", "\\\\?\\C:\\Music"),
+        ],
+    )
+    def test_prefix_on_windows(self, absolute, expected):
+        """Test that drive, UNC and already extended paths get the right prefix."""
+        with (
+            patch("resource_fetcher_core.utils.paths.sys.platform", "win32"),
+            patch("resource_fetcher_core.utils.paths.os.path.abspath", return_value=absolute),
+        ):
+            assert str(extended_path(Path("Album"))) == expected
+
+
+class TestExceedsMaxPath:
+    """Test detecting paths that are too long."""
+
+    def test_long_path(self):
+        """Test that paths of MAX_PATH characters or more are too long."""
+        assert exceeds_max_path(Path("/" + "a" * MAX_PATH))
+        assert not exceeds_max_path(Path("/music/album/song.mp3"))
+
+    def test_prefix_is_not_counted(self):
+        """Test that the extended-length prefix doesn't count towards the limit."""
+        path = "\\\\?\\C:\\" + "a" * (MAX_PATH - 10)
+        with patch("resource_fetcher_core.utils.paths.os.path.abspath", return_value=path):
+            assert not exceeds_max_path(Path("song.mp3"))
+
+
+class TestUniqueFilenames:
+    """Test numbering of colliding file names."""
+
+    def test_case_insensitive_collisions(self):
+        """Test that names differing only in case are numbered."""
+        names = ["Track.mp3", "track.mp3", "TRACK.mp3", "Other.mp3"]
+
+        assert unique_filenames(names, case_insensitive=True) == [
+            "Track.mp3",
+            "track (2).mp3",
+            "TRACK (3).mp3",
+            "Other.mp3",
+        ]
+
+    def test_case_sensitive_collisions(self):
+        """Test that only identical names collide on case-sensitive file systems."""
+        names = ["Track.mp3", "track.mp3", "Track.mp3"]
+
+        assert unique_filenames(names, case_insensitive=False) == [
+            "Track.mp3",
+            "track.mp3",
+            "Track (2).mp3",
+        ]
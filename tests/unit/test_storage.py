@@ -0,0 +1,157 @@
+"""Unit tests for writes to network shares."""
+
+import errno
+from pathlib import Path
+from unittest.mock import MagicMock, mock_open, patch
+
+import pytest
+
+from resource_fetcher_core.utils.storage import (
+    is_mount_lost_error,
+    is_network_path,
+    move_staged_file,
+    move_to_share,
+    retry_io,
+    same_volume,
+    wait_for_path,
+)
+
+MOUNTS = """sysfs /sys sysfs rw 0 0
+/dev/sda1 / ext4 rw 0 0
+//nas/music /mnt/music cifs rw 0 0
+/dev/sdb1 /mnt/music/local ext4 rw 0 0
+"""
+
+
+class TestIsNetworkPath:
+    """Test detecting output folders on network shares."""
+
+    @pytest.mark.parametrize(
+        "path, expected",
+        [
+            ("/mnt/music/album", True),
+            ("/mnt/music/local/album", False),
+            ("/mnt/musicals", False),
+            ("/home/user", False),
+        ],
+    )
+    def test_longest_mount_point_decides(self, path, expected):
+        """Test that the innermost mount containing the path is used."""
+        with (
+            patch("resource_fetcher_core.utils.storage.sys.platform", "linux"),
+            patch("builtins.open", mock_open(read_data=MOUNTS)),
+        ):
+            assert is_network_path(Path(path)) is expected
+
+
+class TestRetryIO:
+    """Test retrying file operations."""
+
+    def test_retries_transient_errors(self):
+        """Test that transient errors are retried until the operation succeeds."""
+        operation = MagicMock(side_effect=[OSError(errno.EIO, "I/O error"), "done"])
+
+        with patch("resource_fetcher_core.utils.storage.time.sleep") as sleep:
+            assert retry_io(operation, retries=3, delay=1.0) == "done"
+
+        sleep.assert_called_once_with(1.0)
+
+    def test_gives_up_after_last_attempt(self):
+        """Test that the last transient error is raised."""
+        operation = MagicMock(side_effect=OSError(errno.EAGAIN, "Try again"))
+
+        with patch("resource_fetcher_core.utils.storage.time.sleep"):
+            with pytest.raises(OSError):
+                retry_io(operation, retries=3)
+
+        assert operation.call_count == 3
+
+    def test_other_errors_are_not_retried(self):
+        """Test that permanent errors are raised immediately."""
+        operation = MagicMock(side_effect=OSError(errno.EACCES, "Permission denied"))
+
+        with pytest.raises(PermissionError):
+            retry_io(operation, retries=3)
+
+        assert operation.call_count == 1
+
+
+class TestMountLost:
+    """Test detecting and waiting for disconnected shares."""
+
+    def test_mount_lost_errors(self):
+        """Test that stale handles count as a lost mount and I/O errors don't."""
+        assert is_mount_lost_error(OSError(errno.ESTALE, "Stale file handle"))
+        assert not is_mount_lost_error(OSError(errno.EIO, "I/O error"))
+
+    def test_wait_for_reachable_path(self, tmp_path):
+        """Test that a reachable folder doesn't wait."""
+        assert wait_for_path(tmp_path, timeout=0)
+
+    def test_wait_times_out(self, tmp_path):
+        """Test that an unreachable folder gives up after the timeout."""
+        missing = tmp_path / "gone" / "album"
+
+        assert not wait_for_path(missing, timeout=0.05, interval=0.01)
+
+
+class TestMoveToShare:
+    """Test copying finished downloads onto a share."""
+
+    def test_moves_file(self, tmp_path):
+        """Test that the file is copied and the local file removed."""
+        source = tmp_path / "staged.mp3"
+        source.write_bytes(b"audio data")
+        destination = tmp_path / "share" / "song.mp3"
+        destination.parent.mkdir()
+
+        move_to_share(source, destination)
+
+        assert destination.read_bytes() == b"audio data"
+        assert not source.exists()
+
+    def test_failed_copy_removes_local_file(self, tmp_path):
+        """Test that a failed copy raises and doesn't leave the staged file behind."""
+        source = tmp_path / "staged.mp3"
+        source.write_bytes(b"audio data")
+
+        with pytest.raises(OSError):
+            move_to_share(source, tmp_path / "missing" / "song.mp3")
+
+        assert not source.exists()
+
+
+class TestMoveStagedFile:
+    """Test moving finished files out of the temp folder."""
+
+    def test_same_volume_is_renamed(self, tmp_path):
+        """Test that files on the same volume are renamed instead of copied."""
+        source = tmp_path / "staged.mp3"
+        source.write_bytes(b"audio data")
+        destination = tmp_path / "song.mp3"
+
+        assert same_volume(tmp_path, tmp_path)
+        with patch("resource_fetcher_core.utils.storage.move_to_share") as copy:
+            move_staged_file(source, destination)
+
+        copy.assert_not_called()
+        assert destination.read_bytes() == b"audio data"
+        assert not source.exists()
+
+    def test_other_volume_is_copied(self, tmp_path):
+        """Test that files on another volume are copied and checked."""
+        source = tmp_path / "staged.mp3"
+        source.write_bytes(b"audio data")
+        destination = tmp_path / "song.mp3"
+
+        with (
+            patch("resource_fetcher_core.utils.storage.same_volume", return_value=False),
+            patch("resource_fetcher_core.utils.storage.move_to_share") as copy,
+        ):
+            move_staged_file(source, destination, retries=5)
+
+        copy.assert_called_once_with(source, destination, 5)
+
+    def test_missing_folder_is_not_same_volume(self, tmp_path):
+        """Test that an unreachable folder counts as another volume."""
+        assert not same_volume(tmp_path, tmp_path / "missing")
@@ -0,0 +1,63 @@
+"""Unit tests for title-based track selection."""
+
+import pytest
+
+from resource_fetcher_core.utils.title_filter import (
+    compile_title_pattern,
+    match_blacklist,
+    title_selected,
+)
+
+
+class TestMatchBlacklist:
+    """Test matching song titles against a blacklist."""
+
+    @pytest.mark.parametrize(
+        "title, expected",
+        [
+            ("Amazing Grace (Remix)", "remix"),
+            ("Amazing Grace - INSTRUMENTAL", "instrumental"),
+            ("Amazing Grace", None),
+        ],
+    )
+    def test_case_insensitive_match(self, title, expected):
+        """Test that patterns match anywhere in the title, ignoring case."""
+        assert match_blacklist(title, ["remix", "instrumental"]) == expected
+
+    def test_blank_patterns_are_ignored(self):
+        """Test that empty patterns never match."""
+        assert match_blacklist("Amazing Grace", ["", "  "]) is None
+
+
+class TestTitlePatterns:
+    """Test include and exclude patterns."""
+
+    @pytest.mark.parametrize(
+        "pattern, title, matches",
+        [
+            ("*grace*", "Amazing GRACE", True),
+            ("amazing*", "Amazing Grace", True),
+            ("grace*", "Amazing Grace", False),
+            ("re:grace", "Amazing Grace", True),
+            (r"re:^\d+\.", "12. Amazing Grace", True),
+            (r"re:\(live\)$", "Amazing Grace (Live) Intro", False),
+        ],
+    )
+    def test_globs_and_regexes(self, pattern, title, matches):
+        """Test that globs match the whole title and regexes any part of it."""
+        assert bool(compile_title_pattern(pattern).search(title)) is matches
+
+    @pytest.mark.parametrize("pattern", ["", "  ", "re:(unclosed"])
+    def test_invalid_patterns(self, pattern):
+        """Test that empty and malformed patterns raise ValueError."""
+        with pytest.raises(ValueError, match="Invalid title pattern"):
+            compile_title_pattern(pattern)
+
+    def test_include_and_exclude(self):
+        """Test that a title must match include and must not match exclude."""
+        titles = ["Amazing Grace", "Amazing Grace (Remix)", "Holy Holy Holy"]
+
+        selected = [title for title in titles if title_selected(title, "*grace*", "*remix*")]
+
+        assert selected == ["Amazing Grace"]
+        assert all(title_selected(title) for title in titles)
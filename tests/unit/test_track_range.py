@@ -0,0 +1,46 @@
+"""Unit tests for track range selection."""
+
+import pytest
+
+from resource_fetcher_core.utils.track_range import parse_track_range, track_in_range
+
+
+class TestParseTrackRange:
+    """Test parsing track range specifications."""
+
+    @pytest.mark.parametrize(
+        "spec, expected",
+        [
+            ("15", [(15, 15)]),
+            ("5-12", [(5, 12)]),
+            ("5-12,15", [(5, 12), (15, 15)]),
+            ("20-", [(20, None)]),
+            (" 1 - 3 , 7 ", [(1, 3), (7, 7)]),
+        ],
+    )
+    def test_valid_specs(self, spec, expected):
+        """Test track numbers, closed ranges and open ranges."""
+        assert parse_track_range(spec) == expected
+
+    @pytest.mark.parametrize("spec", ["", "5,,6", "a-3", "0", "12-5", "-3", "1-2-3"])
+    def test_invalid_specs(self, spec):
+        """Test that malformed specifications raise ValueError."""
+        with pytest.raises(ValueError, match="Invalid track range"):
+            parse_track_range(spec)
+
+
+class TestTrackInRange:
+    """Test selecting tracks by range."""
+
+    def test_selected_tracks(self):
+        """Test that only tracks inside one of the ranges are selected."""
+        ranges = parse_track_range("2-3,5,8-")
+
+        assert [index for index in range(1, 11) if track_in_range(index, ranges)] == [
+            2,
+            3,
+            5,
+            8,
+            9,
+            10,
+        ]